@@ -2,8 +2,14 @@
 // All Rights Reserved.
 
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::process::Command;
+use std::rc::Rc;
 
-use crate::{App, UIBackend};
+use crate::platform::headless::HeadlessContext;
+use crate::{App, UIBackend, ViewHandle};
+
+pub(crate) type UrlOpener = dyn Fn(&str);
 
 thread_local! {
     pub static CURRENT: RefCell<AppContext> = RefCell::new(AppContext::default());
@@ -12,12 +18,22 @@ thread_local! {
 #[derive(Default)]
 pub struct AppContext {
     backend: UIBackend,
+
+    /// The views that were given an identifier, see
+    /// [`BaseViewExt::with_id()`](crate::BaseViewExt::with_id). Finestra apps
+    /// have a single window, so the views are registered per app.
+    views: HashMap<String, ViewHandle>,
+
+    /// See [`App::with_url_opener()`].
+    url_opener: Option<Rc<UrlOpener>>,
 }
 
 impl AppContext {
     pub fn initialize<Delegate, State>(app: &App<Delegate, State>) {
         Self::with_current(|ctx| {
             ctx.backend = app.backend;
+            ctx.views.clear();
+            ctx.url_opener.clone_from(&app.url_opener);
         });
     }
 
@@ -29,4 +45,101 @@ impl AppContext {
     pub fn backend() -> UIBackend {
         Self::with_current(|ctx| ctx.backend)
     }
+
+    pub fn register_view(handle: ViewHandle) {
+        Self::with_current(|ctx| {
+            ctx.views.insert(handle.id().to_owned(), handle);
+        });
+    }
+
+    #[must_use]
+    pub fn view_by_id(id: &str) -> Option<ViewHandle> {
+        Self::with_current(|ctx| ctx.views.get(id).cloned())
+    }
+
+    /// Opens the `url` using the opener of the app. By default, the URL is
+    /// opened in the browser, except by the [`UIBackend::Headless`], which
+    /// only remembers the URL. Only absolute `http`, `https` and `mailto`
+    /// URLs are opened in the browser, and other URLs are ignored.
+    pub fn open_url(url: &str) {
+        // Don't hold the borrow, as the opener can use the context.
+        let opener = Self::with_current(|ctx| ctx.url_opener.clone());
+
+        match opener {
+            Some(opener) => opener(url),
+            None if Self::backend() == UIBackend::Headless => HeadlessContext::open_url(url),
+            None => open_in_browser(url),
+        }
+    }
+}
+
+/// Opens the `url` in the default browser of the user. When the URL can't be
+/// opened in a browser, or the browser can't be launched, nothing happens.
+fn open_in_browser(url: &str) {
+    // The launchers open files and other programs as well, so only URLs of
+    // web pages and email addresses are passed to them.
+    if !is_browser_url(url) {
+        return;
+    }
+
+    #[cfg(target_os = "macos")]
+    let mut command = Command::new("open");
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    };
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = Command::new("xdg-open");
+
+    if let Ok(mut child) = command.arg(url).spawn() {
+        // The launcher exits after it handed the URL to the browser.
+        std::thread::spawn(move || child.wait());
+    }
+}
+
+/// Whether the `url` is an absolute `http`, `https` or `mailto` URL, without
+/// whitespace or control characters.
+fn is_browser_url(url: &str) -> bool {
+    if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return false;
+    };
+
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" | "https" => rest.strip_prefix("//").is_some_and(|authority| {
+            !authority.is_empty() && !authority.starts_with(['/', '?', '#'])
+        }),
+        "mailto" => !rest.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn browser_urls() {
+        assert!(is_browser_url("https://docs.rs/finestra"));
+        assert!(is_browser_url("HTTP://example.com?q=1"));
+        assert!(is_browser_url("mailto:tristan@thewoosh.org"));
+
+        assert!(!is_browser_url("docs.rs/finestra"));
+        assert!(!is_browser_url("/usr/bin/calc"));
+        assert!(!is_browser_url("C:\\Windows\\System32\\calc.exe"));
+        assert!(!is_browser_url("file:///etc/passwd"));
+        assert!(!is_browser_url("javascript:alert(1)"));
+        assert!(!is_browser_url("-https://example.com"));
+        assert!(!is_browser_url("https:example.com"));
+        assert!(!is_browser_url("https:///path"));
+        assert!(!is_browser_url("https://example.com --new-window"));
+        assert!(!is_browser_url("mailto:"));
+    }
 }
@@ -4,6 +4,8 @@
 mod context;
 mod platform;
 
+use std::rc::Rc;
+
 use crate::{MenuItem, View, Window, WindowConfiguration};
 
 pub use self::platform::UIBackend;
@@ -44,6 +46,7 @@ pub(crate) use self::context::*;
 ///    [`Button::with_on_click()`](crate::Button::with_on_click()).
 pub struct App<Delegate, State=()> {
     backend: UIBackend,
+    url_opener: Option<Rc<UrlOpener>>,
     pub(crate) delegate: Delegate,
     pub(crate) state: State,
 }
@@ -60,6 +63,7 @@ impl<Delegate, State: 'static> App<Delegate, State>
     pub fn new(delegate: Delegate) -> App<Delegate, ()> {
         App {
             backend: Default::default(),
+            url_opener: None,
             delegate,
             state: (),
         }
@@ -84,6 +88,7 @@ impl<Delegate, State: 'static> App<Delegate, State>
     pub fn with_state<NewState>(delegate: Delegate, state: NewState) -> App<Delegate, NewState> {
         App {
             backend: Default::default(),
+            url_opener: None,
             delegate,
             state,
         }
@@ -96,6 +101,23 @@ impl<Delegate, State: 'static> App<Delegate, State>
         self.backend = backend;
         self
     }
+
+    /// Override how URLs are opened, e.g. by a [`Link`](crate::Link) or by
+    /// [`Window::open_url()`]. By default, URLs are opened in the default
+    /// browser of the user, using `xdg-open` on Linux.
+    ///
+    /// ```no_run
+    /// # use finestra::*;
+    /// # struct MyApp;
+    /// # impl AppDelegate for MyApp {}
+    /// App::new(MyApp)
+    ///     .with_url_opener(|url| println!("Not opening {url}"))
+    ///     .run();
+    /// ```
+    pub fn with_url_opener(mut self, opener: impl Fn(&str) + 'static) -> Self {
+        self.url_opener = Some(Rc::new(opener));
+        self
+    }
 }
 
 impl<Delegate, State> App<Delegate, State>
@@ -129,6 +151,57 @@ impl<Delegate, State> App<Delegate, State>
                 #[cfg(not(target_os = "windows"))]
                 { panic!("The UIBackend::Win32 is not supported on this platform.") }
             }
+
+            UIBackend::Gtk => {
+                #[cfg(all(target_os = "linux", feature = "gtk"))]
+                { crate::platform::gtk::run_app(self) }
+
+                #[cfg(all(target_os = "linux", not(feature = "gtk")))]
+                { panic!("The UIBackend::Gtk requires the `gtk` feature of Finestra, which is disabled. Enable it, or select the UIBackend::Headless explicitly to run without a window.") }
+
+                #[cfg(not(target_os = "linux"))]
+                { panic!("The UIBackend::Gtk is not supported on this platform.") }
+            }
+
+            UIBackend::Headless => crate::platform::headless::run_app(self),
+        }
+    }
+
+    /// Start the application like [`App::run()`], but return the exit code
+    /// when the event loop finishes, instead of terminating the process. This
+    /// allows you to run an app inside a test, for example.
+    ///
+    /// Only the [`UIBackend::Headless`] and [`UIBackend::Gtk`] are able to
+    /// return, as the other backends hand over the process to the platform,
+    /// and will therefore never return.
+    ///
+    /// ```
+    /// # use finestra::*;
+    /// struct MyApp;
+    ///
+    /// impl AppDelegate for MyApp {
+    ///     fn will_show_window(&mut self, window: Window, _: &mut ()) {
+    ///         window.close();
+    ///     }
+    /// }
+    ///
+    /// let code = App::new(MyApp)
+    ///     .with_backend(UIBackend::Headless)
+    ///     .run_until_exit();
+    ///
+    /// assert_eq!(code, 0);
+    /// ```
+    pub fn run_until_exit(self) -> i32 {
+        match self.backend {
+            UIBackend::Headless => crate::platform::headless::run_app_until_exit(self),
+
+            #[cfg(all(target_os = "linux", feature = "gtk"))]
+            UIBackend::Gtk => {
+                AppContext::initialize(&self);
+                crate::platform::gtk::run_app_until_exit(self)
+            }
+
+            _ => self.run(),
         }
     }
 }
@@ -19,6 +19,11 @@
 /// The [`Default`] value of [`UIBackend`] is already the most appropriate for
 /// the given platform. For advanced users, you can override this value, but in
 /// most cases the given [`UIBackend`] is not available for that platform.
+///
+/// On Linux, the default is [`UIBackend::Gtk`], even when the `gtk` feature is
+/// disabled. Running an app without this feature panics, instead of silently
+/// running without a window. Use [`UIBackend::Headless`] explicitly to run
+/// without a window system.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UIBackend {
     /// [AppKit](https://developer.apple.com/documentation/appkit) is the native
@@ -31,6 +36,22 @@ pub enum UIBackend {
     /// API is the low-level native Windows API, which is supported on every
     /// Windows version since Windows NT.
     Win32,
+
+    /// [GTK 4](https://docs.gtk.org/gtk4/) is the toolkit used by GNOME and
+    /// many other Linux applications. This backend requires the `gtk` feature
+    /// of Finestra, and the GTK 4 development files to be installed.
+    Gtk,
+
+    /// A backend that doesn't require a window system: the views are built as
+    /// an in-memory tree, and the event loop runs on the current thread. This
+    /// is useful for running apps in environments without a display, such as
+    /// Continuous Integration.
+    ///
+    /// Timers in this backend use a virtual clock: when there is nothing else
+    /// to do, the clock advances to the next timer immediately. The event loop
+    /// exits when [`Window::close()`](crate::Window::close) is called, or when
+    /// there are no events or timers left.
+    Headless,
 }
 
 impl UIBackend {
@@ -42,6 +63,8 @@ impl UIBackend {
         match self {
             Self::AppKit => cfg!(target_os = "macos"),
             Self::Win32 => cfg!(target_os = "windows"),
+            Self::Gtk => cfg!(all(target_os = "linux", feature = "gtk")),
+            Self::Headless => true,
         }
     }
 }
@@ -53,9 +76,25 @@ impl Default for UIBackend {
     }
 }
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(target_os = "windows")]
 impl Default for UIBackend {
     fn default() -> Self {
         Self::Win32
     }
 }
+
+#[cfg(target_os = "linux")]
+#[allow(clippy::derivable_impls)]
+impl Default for UIBackend {
+    fn default() -> Self {
+        Self::Gtk
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+#[allow(clippy::derivable_impls)]
+impl Default for UIBackend {
+    fn default() -> Self {
+        Self::Headless
+    }
+}
@@ -1,30 +1,85 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::rc::Rc;
 use std::sync::Arc;
 use dashmap::DashMap;
 
 #[cfg(target_os = "macos")]
 use crate::platform::macos::cacao_delegates::{EventDispatcher, StatefulEventDispatcher};
 
-use crate::Window;
+#[cfg(all(target_os = "linux", feature = "gtk"))]
+use crate::platform::gtk::EventDispatcher;
 
+use crate::{Color, Window};
+
+// The handlers are cloned out of the registry before they are invoked, as they
+// can build views that are added to the registry.
 type EventHandlerCallback<State> = dyn Fn(&mut State, Window);
-type EventHandler<State> = Option<Box<EventHandlerCallback<State>>>;
+type EventHandler<State> = Option<Rc<EventHandlerCallback<State>>>;
 
 type BoolEventHandlerCallback<State> = dyn Fn(&mut State, bool, Window);
-type BoolEventHandler<State> = Option<Box<BoolEventHandlerCallback<State>>>;
+type BoolEventHandler<State> = Option<Rc<BoolEventHandlerCallback<State>>>;
 
 type TextEventHandlerCallback<State> = dyn Fn(&mut State, String, Window);
-type TextEventHandler<State> = Option<Box<TextEventHandlerCallback<State>>>;
+type TextEventHandler<State> = Option<Rc<TextEventHandlerCallback<State>>>;
+
+type IndexEventHandlerCallback<State> = dyn Fn(&mut State, usize, Window);
+type IndexEventHandler<State> = Option<Rc<IndexEventHandlerCallback<State>>>;
+
+type OptionIndexEventHandlerCallback<State> = dyn Fn(&mut State, Option<usize>, Window);
+type OptionIndexEventHandler<State> = Option<Rc<OptionIndexEventHandlerCallback<State>>>;
+
+type ValueEventHandlerCallback<State> = dyn Fn(&mut State, f64, Window);
+type ValueEventHandler<State> = Option<Rc<ValueEventHandlerCallback<State>>>;
+
+type ColorEventHandlerCallback<State> = dyn Fn(&mut State, Color, Window);
+type ColorEventHandler<State> = Option<Rc<ColorEventHandlerCallback<State>>>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct ViewId(pub usize);
 
+/// The text the user entered in a view. The text of a secure
+/// [`TextField`](crate::TextField) is left out of the `Debug` output, so that
+/// passwords don't end up in logs.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct ChangedText {
+    pub(crate) text: String,
+    pub(crate) secure: bool,
+}
+
+impl ChangedText {
+    pub(crate) fn new(text: String, secure: bool) -> Self {
+        Self { text, secure }
+    }
+}
+
+impl From<String> for ChangedText {
+    fn from(text: String) -> Self {
+        Self::new(text, false)
+    }
+}
+
+impl Debug for ChangedText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.secure {
+            f.write_str("<redacted>")
+        } else {
+            Debug::fmt(&self.text, f)
+        }
+    }
+}
+
 pub(crate) struct EventHandlerMap<State> {
     pub(crate) click: EventHandler<State>,
     pub(crate) checked: BoolEventHandler<State>,
     pub(crate) text_changed: TextEventHandler<State>,
+    pub(crate) activate: IndexEventHandler<State>,
+    pub(crate) selection_changed: OptionIndexEventHandler<State>,
+    pub(crate) value_changed: ValueEventHandler<State>,
+    pub(crate) color_changed: ColorEventHandler<State>,
 }
 
 impl<State> Default for EventHandlerMap<State> {
@@ -33,6 +88,10 @@ impl<State> Default for EventHandlerMap<State> {
             click: None,
             checked: None,
             text_changed: None,
+            activate: None,
+            selection_changed: None,
+            value_changed: None,
+            color_changed: None,
         }
     }
 }
@@ -58,13 +117,16 @@ pub struct ViewTree<State> {
     registry: EventHandlerMapRegistry<State>,
 
     #[cfg(target_os = "macos")]
-    dispatcher: StatefulEventDispatcher<State>,
+    dispatcher: Option<StatefulEventDispatcher<State>>,
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    dispatcher: Option<std::rc::Rc<dyn EventDispatcher>>,
 
     parent_id: Option<ViewId>,
 }
 
 impl<State: 'static> ViewTree<State> {
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "windows")]
     pub(crate) fn new(registry: EventHandlerMapRegistry<State>) -> Self {
         Self {
             id_generator: Default::default(),
@@ -78,7 +140,43 @@ impl<State: 'static> ViewTree<State> {
         Self {
             id_generator: Default::default(),
             registry,
-            dispatcher,
+            dispatcher: Some(dispatcher),
+            parent_id: None,
+        }
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    pub(crate) fn new(registry: EventHandlerMapRegistry<State>, dispatcher: std::rc::Rc<dyn EventDispatcher>) -> Self {
+        Self {
+            id_generator: Default::default(),
+            registry,
+            dispatcher: Some(dispatcher),
+            parent_id: None,
+        }
+    }
+
+    /// Create a tree for the [`UIBackend::Headless`](crate::UIBackend::Headless),
+    /// which doesn't dispatch events through the platform.
+    pub(crate) fn headless(registry: EventHandlerMapRegistry<State>) -> Self {
+        Self {
+            id_generator: Default::default(),
+            registry,
+            #[cfg(any(target_os = "macos", all(target_os = "linux", feature = "gtk")))]
+            dispatcher: None,
+            parent_id: None,
+        }
+    }
+
+    /// Create a tree for building views after the window was built, e.g. the
+    /// rows of a [`List`](crate::List) that were added to its items. The views
+    /// share the event handlers and identifiers with this tree.
+    #[must_use]
+    pub(crate) fn fork(&self) -> Self {
+        Self {
+            id_generator: self.id_generator.clone(),
+            registry: self.registry.clone(),
+            #[cfg(any(target_os = "macos", all(target_os = "linux", feature = "gtk")))]
+            dispatcher: self.dispatcher.clone(),
             parent_id: None,
         }
     }
@@ -89,7 +187,29 @@ impl<State: 'static> ViewTree<State> {
         id
     }
 
-    #[cfg(windows)]
+    /// Builds views using `build`, and returns the identifiers of the views
+    /// that were built, so their event handlers can be removed when the views
+    /// are gone, e.g. when a row of a [`List`](crate::List) is replaced.
+    pub(crate) fn scope<R>(&mut self, build: impl FnOnce(&mut Self) -> R) -> (R, ViewScope) {
+        let start = self.id_generator.peek().0;
+        let result = build(self);
+        let end = self.id_generator.peek().0;
+        (result, ViewScope { ids: start..end })
+    }
+
+    /// Removes the event handlers of the view with the given `id`.
+    pub(crate) fn remove_events(&self, id: ViewId) {
+        self.registry.map.remove(&id);
+    }
+
+    /// Removes the event handlers of the views that were built in the `scope`.
+    pub(crate) fn remove_scope(&self, scope: &ViewScope) {
+        for id in scope.ids.clone() {
+            self.remove_events(ViewId(id));
+        }
+    }
+
+    #[cfg(any(target_os = "macos", windows))]
     pub(crate) fn put_event_handlers_with_id(&mut self, id: ViewId, map: EventHandlerMap<State>) -> ViewId {
         self.registry.map.insert(id, map);
         id
@@ -97,9 +217,18 @@ impl<State: 'static> ViewTree<State> {
 
     #[cfg(target_os = "macos")]
     pub(crate) fn create_dispatcher(&self) -> Box<dyn EventDispatcher> {
-        Box::new(self.dispatcher.clone())
+        let dispatcher = self.dispatcher.clone()
+            .expect("native views can't be built in a headless ViewTree");
+        Box::new(dispatcher)
     }
 
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    pub(crate) fn create_dispatcher(&self) -> std::rc::Rc<dyn EventDispatcher> {
+        self.dispatcher.clone()
+            .expect("native views can't be built in a headless ViewTree")
+    }
+
+    #[cfg_attr(not(target_os = "macos"), allow(unused))]
     pub(crate) fn parent_id(&self) -> Option<ViewId> {
         self.parent_id
     }
@@ -109,15 +238,39 @@ impl<State: 'static> ViewTree<State> {
     }
 }
 
-#[derive(Debug, Default)]
+/// The identifiers of the views built by [`ViewTree::scope()`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ViewScope {
+    ids: std::ops::Range<usize>,
+}
+
+/// Generates the identifiers of the views. Forks of a [`ViewTree`] share the
+/// generator, so the identifiers stay unique.
+#[derive(Clone, Debug, Default)]
 pub(crate) struct IdGenerator {
-    current_id: usize,
+    current_id: Rc<Cell<usize>>,
 }
 
 impl IdGenerator {
     pub(crate) fn next(&mut self) -> ViewId {
-        let id = ViewId(self.current_id);
-        self.current_id += 1;
+        let id = self.peek();
+        self.current_id.set(id.0 + 1);
         id
     }
+
+    /// The identifier that is generated next.
+    fn peek(&self) -> ViewId {
+        ViewId(self.current_id.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secure_text_is_redacted() {
+        assert_eq!(format!("{:?}", ChangedText::from("hunter2".to_owned())), "\"hunter2\"");
+        assert_eq!(format!("{:?}", ChangedText::new("hunter2".to_owned(), true)), "<redacted>");
+    }
 }
@@ -0,0 +1,216 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::{Point2D, Rect, Size2D, Transform2D, Vector2D};
+use euclid::Angle;
+
+use crate::{Color, Image, Path};
+
+/// The font size of text, unless changed using
+/// [`DrawingContext::set_font_size()`].
+const DEFAULT_FONT_SIZE: f32 = 13.0;
+
+/// Draws the contents of a [`Canvas`](crate::Canvas). The coordinates start at
+/// the top left corner of the canvas, and are transformed by the current
+/// [transform](DrawingContext::transform).
+///
+/// ```
+/// # use finestra::*;
+/// use finestra::euclid::{point2, rect, Angle};
+///
+/// # let _: Canvas<()> =
+/// Canvas::new(|context| {
+///     context.fill_rect(rect(0.0, 0.0, 100.0, 100.0), Color::system(SystemColor::White));
+///
+///     context.save();
+///     context.translate(50.0, 50.0);
+///     context.rotate(Angle::degrees(45.0));
+///     context.stroke_rect(rect(-20.0, -20.0, 40.0, 40.0), Color::system(SystemColor::Blue), 2.0);
+///     context.restore();
+///
+///     context.draw_text("Hello", point2(8.0, 92.0), Color::system(SystemColor::Label));
+/// });
+/// ```
+pub struct DrawingContext {
+    size: Size2D<f32>,
+    state: DrawingState,
+    saved: Vec<DrawingState>,
+    commands: Vec<DrawCommand>,
+}
+
+#[derive(Clone, Copy)]
+struct DrawingState {
+    transform: Transform2D<f32>,
+    font_size: f32,
+}
+
+impl DrawingContext {
+    pub(crate) fn new(size: Size2D<f32>) -> Self {
+        Self {
+            size,
+            state: DrawingState {
+                transform: Transform2D::identity(),
+                font_size: DEFAULT_FONT_SIZE,
+            },
+            saved: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// The commands that were drawn, in order.
+    pub(crate) fn into_commands(self) -> Vec<DrawCommand> {
+        self.commands
+    }
+
+    /// Get the size of the canvas.
+    #[must_use]
+    pub fn size(&self) -> Size2D<f32> {
+        self.size
+    }
+
+    /// Fills the inside of the `path`, using the nonzero winding rule.
+    pub fn fill_path(&mut self, path: &Path, color: impl Into<Color>) {
+        self.commands.push(DrawCommand::Fill {
+            path: path.clone(),
+            color: color.into(),
+            transform: self.state.transform,
+        });
+    }
+
+    /// Draws a line of the given `width` along the `path`. Lines are joined
+    /// using round joins.
+    pub fn stroke_path(&mut self, path: &Path, color: impl Into<Color>, width: f32) {
+        self.commands.push(DrawCommand::Stroke {
+            path: path.clone(),
+            color: color.into(),
+            width,
+            transform: self.state.transform,
+        });
+    }
+
+    /// Fills the `rect`.
+    pub fn fill_rect(&mut self, rect: Rect<f32>, color: impl Into<Color>) {
+        self.fill_path(&Path::rect(rect), color);
+    }
+
+    /// Draws a line of the given `width` along the edges of the `rect`.
+    pub fn stroke_rect(&mut self, rect: Rect<f32>, color: impl Into<Color>, width: f32) {
+        self.stroke_path(&Path::rect(rect), color, width);
+    }
+
+    /// Fills the ellipse that fits in the `rect`.
+    pub fn fill_ellipse(&mut self, rect: Rect<f32>, color: impl Into<Color>) {
+        self.fill_path(&Path::ellipse(rect), color);
+    }
+
+    /// Draws a line of the given `width` along the ellipse that fits in the
+    /// `rect`.
+    pub fn stroke_ellipse(&mut self, rect: Rect<f32>, color: impl Into<Color>, width: f32) {
+        self.stroke_path(&Path::ellipse(rect), color, width);
+    }
+
+    /// Draws a single line of `text` in the system font, with the baseline
+    /// starting at the `position`.
+    ///
+    /// ## Remarks
+    /// The software renderer of the [`UIBackend::Headless`](crate::UIBackend::Headless)
+    /// doesn't have any fonts, so the text is recorded, but not painted.
+    pub fn draw_text(&mut self, text: impl Into<String>, position: Point2D<f32>, color: impl Into<Color>) {
+        self.commands.push(DrawCommand::Text {
+            text: text.into(),
+            position,
+            font_size: self.state.font_size,
+            color: color.into(),
+            transform: self.state.transform,
+        });
+    }
+
+    /// Set the size of the text drawn using [`DrawingContext::draw_text()`],
+    /// in points.
+    pub fn set_font_size(&mut self, size: f32) {
+        self.state.font_size = size;
+    }
+
+    /// Draws the `image`, scaled to fill the `rect`.
+    ///
+    /// ## Remarks
+    /// The software renderer of the [`UIBackend::Headless`](crate::UIBackend::Headless)
+    /// can't decode images, so the image is recorded, but not painted.
+    pub fn draw_image(&mut self, image: &Image, rect: Rect<f32>) {
+        self.commands.push(DrawCommand::Image {
+            image: image.clone(),
+            rect,
+            transform: self.state.transform,
+        });
+    }
+
+    /// Get the transform that is applied to everything that is drawn.
+    #[must_use]
+    pub fn transform(&self) -> Transform2D<f32> {
+        self.state.transform
+    }
+
+    /// Replace the transform that is applied to everything that is drawn.
+    pub fn set_transform(&mut self, transform: Transform2D<f32>) {
+        self.state.transform = transform;
+    }
+
+    /// Moves the origin of the coordinates by `x` and `y`.
+    pub fn translate(&mut self, x: f32, y: f32) {
+        self.state.transform = self.state.transform.pre_translate(Vector2D::new(x, y));
+    }
+
+    /// Scales the coordinates by `x` horizontally and `y` vertically.
+    pub fn scale(&mut self, x: f32, y: f32) {
+        self.state.transform = self.state.transform.pre_scale(x, y);
+    }
+
+    /// Rotates the coordinates clockwise around the origin.
+    pub fn rotate(&mut self, angle: Angle<f32>) {
+        self.state.transform = self.state.transform.pre_rotate(angle);
+    }
+
+    /// Saves the transform and the font size, such that they can be restored
+    /// using [`DrawingContext::restore()`].
+    pub fn save(&mut self) {
+        self.saved.push(self.state);
+    }
+
+    /// Restores the transform and the font size of the last call to
+    /// [`DrawingContext::save()`]. This doesn't do anything if nothing was
+    /// saved.
+    pub fn restore(&mut self) {
+        if let Some(state) = self.saved.pop() {
+            self.state = state;
+        }
+    }
+}
+
+/// What was drawn on a [`DrawingContext`], which is replayed by the backend.
+#[derive(Clone, Debug)]
+#[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
+pub(crate) enum DrawCommand {
+    Fill {
+        path: Path,
+        color: Color,
+        transform: Transform2D<f32>,
+    },
+    Stroke {
+        path: Path,
+        color: Color,
+        width: f32,
+        transform: Transform2D<f32>,
+    },
+    Text {
+        text: String,
+        position: Point2D<f32>,
+        font_size: f32,
+        color: Color,
+        transform: Transform2D<f32>,
+    },
+    Image {
+        image: Image,
+        rect: Rect<f32>,
+        transform: Transform2D<f32>,
+    },
+}
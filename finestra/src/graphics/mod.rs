@@ -0,0 +1,11 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+mod context;
+mod path;
+pub(crate) mod raster;
+
+pub use self::context::DrawingContext;
+pub(crate) use self::context::DrawCommand;
+pub use self::path::Path;
+pub(crate) use self::path::PathElement;
@@ -0,0 +1,123 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::{Point2D, Rect};
+
+/// The constant that places the control points of a cubic Bézier curve, such
+/// that it approximates a quarter of a circle.
+const KAPPA: f32 = 0.552_284_8;
+
+/// A shape made of lines and curves, which can be filled or stroked using a
+/// [`DrawingContext`](crate::DrawingContext).
+///
+/// ```
+/// # use finestra::*;
+/// use finestra::euclid::point2;
+///
+/// let triangle = Path::new()
+///     .move_to(point2(10.0, 90.0))
+///     .line_to(point2(50.0, 10.0))
+///     .line_to(point2(90.0, 90.0))
+///     .close();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    elements: Vec<PathElement>,
+}
+
+impl Path {
+    /// Creates an empty path.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a closed path along the edges of the `rect`.
+    #[must_use]
+    pub fn rect(rect: Rect<f32>) -> Self {
+        Self::new()
+            .move_to(rect.min())
+            .line_to(Point2D::new(rect.max_x(), rect.min_y()))
+            .line_to(rect.max())
+            .line_to(Point2D::new(rect.min_x(), rect.max_y()))
+            .close()
+    }
+
+    /// Creates a closed path along the ellipse that fits in the `rect`.
+    #[must_use]
+    pub fn ellipse(rect: Rect<f32>) -> Self {
+        let center = rect.center();
+        let rx = rect.width() / 2.0;
+        let ry = rect.height() / 2.0;
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+
+        let right = Point2D::new(center.x + rx, center.y);
+        let bottom = Point2D::new(center.x, center.y + ry);
+        let left = Point2D::new(center.x - rx, center.y);
+        let top = Point2D::new(center.x, center.y - ry);
+
+        Self::new()
+            .move_to(right)
+            .cubic_to(Point2D::new(right.x, right.y + ky), Point2D::new(bottom.x + kx, bottom.y), bottom)
+            .cubic_to(Point2D::new(bottom.x - kx, bottom.y), Point2D::new(left.x, left.y + ky), left)
+            .cubic_to(Point2D::new(left.x, left.y - ky), Point2D::new(top.x - kx, top.y), top)
+            .cubic_to(Point2D::new(top.x + kx, top.y), Point2D::new(right.x, right.y - ky), right)
+            .close()
+    }
+
+    /// Starts a new subpath at the `point`.
+    #[must_use]
+    pub fn move_to(mut self, point: Point2D<f32>) -> Self {
+        self.elements.push(PathElement::MoveTo(point));
+        self
+    }
+
+    /// Adds a straight line from the current point to the `point`.
+    #[must_use]
+    pub fn line_to(mut self, point: Point2D<f32>) -> Self {
+        self.elements.push(PathElement::LineTo(point));
+        self
+    }
+
+    /// Adds a quadratic Bézier curve from the current point to the `point`.
+    #[must_use]
+    pub fn quadratic_to(mut self, control: Point2D<f32>, point: Point2D<f32>) -> Self {
+        self.elements.push(PathElement::QuadraticTo(control, point));
+        self
+    }
+
+    /// Adds a cubic Bézier curve from the current point to the `point`.
+    #[must_use]
+    pub fn cubic_to(mut self, control1: Point2D<f32>, control2: Point2D<f32>, point: Point2D<f32>) -> Self {
+        self.elements.push(PathElement::CubicTo(control1, control2, point));
+        self
+    }
+
+    /// Closes the current subpath, by adding a straight line back to its
+    /// start.
+    #[must_use]
+    pub fn close(mut self) -> Self {
+        self.elements.push(PathElement::Close);
+        self
+    }
+
+    /// Returns `true` if nothing was added to the path.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    #[must_use]
+    pub(crate) fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum PathElement {
+    MoveTo(Point2D<f32>),
+    LineTo(Point2D<f32>),
+    QuadraticTo(Point2D<f32>, Point2D<f32>),
+    CubicTo(Point2D<f32>, Point2D<f32>, Point2D<f32>),
+    Close,
+}
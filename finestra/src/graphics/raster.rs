@@ -0,0 +1,474 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! A software rasterizer for the [`DrawCommand`]s of a [`Canvas`](crate::Canvas),
+//! used by the [`UIBackend::Headless`](crate::UIBackend::Headless), such that
+//! custom drawings can be tested without a GPU or a display server. Paths are
+//! anti-aliased by sampling every pixel row at several heights, and measuring
+//! the exact horizontal coverage of every span.
+
+use euclid::default::{Point2D, Size2D, Transform2D};
+
+use crate::{Color, ColorKind, DrawCommand, PathElement, SystemColor};
+
+/// The number of rows sampled per pixel row.
+const SAMPLES_PER_ROW: usize = 16;
+
+/// The maximum distance between a flattened curve and the real curve, in
+/// pixels.
+const TOLERANCE: f32 = 0.05;
+
+/// The RGBA pixels of a rendered canvas, with premultiplied alpha.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Pixmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Pixmap {
+    /// Creates a transparent pixmap, rounding the `size` up to whole pixels.
+    pub(crate) fn new(size: Size2D<f32>) -> Self {
+        let width = size.width.max(0.0).ceil() as usize;
+        let height = size.height.max(0.0).ceil() as usize;
+
+        Self {
+            width,
+            height,
+            pixels: vec![[0.0; 4]; width * height],
+        }
+    }
+
+    /// Renders the `commands` on a transparent pixmap of the given `size`.
+    pub(crate) fn render(size: Size2D<f32>, commands: &[DrawCommand]) -> Self {
+        let mut pixmap = Self::new(size);
+        for command in commands {
+            pixmap.draw(command);
+        }
+        pixmap
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the color of the pixel, or [`None`] if it is outside the pixmap.
+    pub(crate) fn pixel(&self, x: usize, y: usize) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let [red, green, blue, alpha] = self.pixels[y * self.width + x];
+        if alpha == 0.0 {
+            return Some(Color::rgba(0, 0, 0, 0));
+        }
+
+        Some(Color::from_components(
+            f64::from(red / alpha),
+            f64::from(green / alpha),
+            f64::from(blue / alpha),
+            f64::from(alpha),
+        ))
+    }
+
+    /// Get the pixels row by row, as red, green, blue and alpha bytes without
+    /// premultiplied alpha.
+    pub(crate) fn to_rgba8(&self) -> Vec<u8> {
+        self.pixels.iter()
+            .flat_map(|&[red, green, blue, alpha]| {
+                let unpremultiply = |value: f32| if alpha == 0.0 { 0.0 } else { value / alpha };
+                [unpremultiply(red), unpremultiply(green), unpremultiply(blue), alpha]
+                    .map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8)
+            })
+            .collect()
+    }
+
+    fn draw(&mut self, command: &DrawCommand) {
+        match command {
+            DrawCommand::Fill { path, color, transform } => {
+                let polygons = flatten(path.elements(), transform).into_iter()
+                    .map(|polyline| polyline.points.iter().map(|point| transform.transform_point(*point)).collect())
+                    .collect::<Vec<_>>();
+                self.fill(&polygons, color);
+            }
+
+            DrawCommand::Stroke { path, color, width, transform } => {
+                let polygons = flatten(path.elements(), transform).iter()
+                    .flat_map(|polyline| stroke(polyline, *width, transform))
+                    .map(|polygon| polygon.iter().map(|point| transform.transform_point(*point)).collect())
+                    .collect::<Vec<_>>();
+                self.fill(&polygons, color);
+            }
+
+            // There are no fonts and no image decoders, so these are only
+            // recorded.
+            DrawCommand::Text { .. } | DrawCommand::Image { .. } => (),
+        }
+    }
+
+    /// Fills the polygons, using the nonzero winding rule.
+    fn fill(&mut self, polygons: &[Vec<Point2D<f32>>], color: &Color) {
+        let [red, green, blue, alpha] = components(color);
+        if alpha == 0.0 {
+            return;
+        }
+
+        let edges: Vec<Edge> = polygons.iter()
+            .flat_map(|polygon| {
+                let next = polygon.iter().cycle().skip(1);
+                polygon.iter().zip(next).filter_map(|(from, to)| Edge::new(*from, *to))
+            })
+            .collect();
+
+        let Some(top) = edges.iter().map(|edge| edge.top).reduce(f32::min) else {
+            return;
+        };
+        let bottom = edges.iter().map(|edge| edge.bottom).fold(top, f32::max);
+
+        let first_row = top.floor().max(0.0) as usize;
+        let last_row = (bottom.ceil().max(0.0) as usize).min(self.height);
+
+        let mut coverage = vec![0.0_f32; self.width];
+        let mut crossings = Vec::new();
+
+        for row in first_row..last_row {
+            coverage.fill(0.0);
+
+            for sample in 0..SAMPLES_PER_ROW {
+                let y = row as f32 + (sample as f32 + 0.5) / SAMPLES_PER_ROW as f32;
+
+                crossings.clear();
+                crossings.extend(edges.iter().filter_map(|edge| edge.crossing(y)));
+                crossings.sort_by(|a: &(f32, i32), b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for pair in crossings.windows(2) {
+                    winding += pair[0].1;
+                    if winding != 0 {
+                        add_span(&mut coverage, pair[0].0, pair[1].0);
+                    }
+                }
+            }
+
+            let pixels = &mut self.pixels[row * self.width..(row + 1) * self.width];
+            for (pixel, coverage) in pixels.iter_mut().zip(&coverage) {
+                let coverage = (coverage / SAMPLES_PER_ROW as f32).min(1.0) * alpha;
+                if coverage == 0.0 {
+                    continue;
+                }
+
+                // Source-over compositing.
+                let source = [red * coverage, green * coverage, blue * coverage, coverage];
+                for (destination, source) in pixel.iter_mut().zip(source) {
+                    *destination = source + *destination * (1.0 - coverage);
+                }
+            }
+        }
+    }
+}
+
+/// Adds the horizontal coverage of the span `start..end` to the pixels of the
+/// row.
+fn add_span(coverage: &mut [f32], start: f32, end: f32) {
+    let start = start.max(0.0);
+    let end = end.min(coverage.len() as f32);
+    if start >= end {
+        return;
+    }
+
+    let first = start.floor() as usize;
+    let last = end.floor() as usize;
+    if first == last {
+        coverage[first] += end - start;
+        return;
+    }
+
+    coverage[first] += (first + 1) as f32 - start;
+    for pixel in &mut coverage[first + 1..last] {
+        *pixel += 1.0;
+    }
+    if last < coverage.len() {
+        coverage[last] += end - last as f32;
+    }
+}
+
+/// A non-horizontal edge of a polygon.
+struct Edge {
+    from: Point2D<f32>,
+    to: Point2D<f32>,
+    top: f32,
+    bottom: f32,
+    winding: i32,
+}
+
+impl Edge {
+    fn new(from: Point2D<f32>, to: Point2D<f32>) -> Option<Self> {
+        if from.y == to.y || !from.y.is_finite() || !to.y.is_finite() {
+            return None;
+        }
+
+        Some(Self {
+            from,
+            to,
+            top: from.y.min(to.y),
+            bottom: from.y.max(to.y),
+            winding: if to.y > from.y { 1 } else { -1 },
+        })
+    }
+
+    /// Returns where the horizontal line at `y` crosses the edge, and the
+    /// direction of the edge.
+    fn crossing(&self, y: f32) -> Option<(f32, i32)> {
+        if y < self.top || y >= self.bottom {
+            return None;
+        }
+
+        let t = (y - self.from.y) / (self.to.y - self.from.y);
+        Some((self.from.x + t * (self.to.x - self.from.x), self.winding))
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Polyline {
+    points: Vec<Point2D<f32>>,
+    closed: bool,
+}
+
+/// Converts the curves of the path to straight lines. The number of lines
+/// depends on the size of the curve after the `transform` is applied, but the
+/// points aren't transformed.
+fn flatten(elements: &[PathElement], transform: &Transform2D<f32>) -> Vec<Polyline> {
+    let mut polylines = Vec::new();
+    let mut current = Polyline::default();
+
+    let mut finish = |current: &mut Polyline, closed: bool| {
+        let mut polyline = std::mem::take(current);
+        polyline.closed = closed;
+        if polyline.points.len() > 1 {
+            if closed {
+                current.points.push(polyline.points[0]);
+            }
+            polylines.push(polyline);
+        }
+    };
+
+    for element in elements {
+        let last = current.points.last().copied();
+        match *element {
+            PathElement::MoveTo(point) => {
+                finish(&mut current, false);
+                current.points.clear();
+                current.points.push(point);
+            }
+
+            PathElement::LineTo(point) => current.points.push(point),
+
+            PathElement::QuadraticTo(control, point) => {
+                let from = last.unwrap_or(control);
+                let segments = segment_count(&[from, control, point], transform);
+                current.points.extend((1..=segments).map(|i| {
+                    let t = i as f32 / segments as f32;
+                    let u = 1.0 - t;
+                    (from.to_vector() * (u * u) + control.to_vector() * (2.0 * u * t) + point.to_vector() * (t * t)).to_point()
+                }));
+            }
+
+            PathElement::CubicTo(control1, control2, point) => {
+                let from = last.unwrap_or(control1);
+                let segments = segment_count(&[from, control1, control2, point], transform);
+                current.points.extend((1..=segments).map(|i| {
+                    let t = i as f32 / segments as f32;
+                    let u = 1.0 - t;
+                    (from.to_vector() * (u * u * u)
+                        + control1.to_vector() * (3.0 * u * u * t)
+                        + control2.to_vector() * (3.0 * u * t * t)
+                        + point.to_vector() * (t * t * t)).to_point()
+                }));
+            }
+
+            PathElement::Close => finish(&mut current, true),
+        }
+    }
+
+    finish(&mut current, false);
+    polylines
+}
+
+/// The number of lines needed to approximate the Bézier curve with the given
+/// control points, using [Wang's formula](https://doi.org/10.1145/15922.15923).
+fn segment_count(points: &[Point2D<f32>], transform: &Transform2D<f32>) -> usize {
+    let points: Vec<_> = points.iter().map(|point| transform.transform_point(*point)).collect();
+    let degree = (points.len() - 1) as f32;
+
+    let bend = points.windows(3)
+        .map(|window| (window[2].to_vector() - window[1].to_vector() * 2.0 + window[0].to_vector()).length())
+        .fold(0.0, f32::max);
+
+    ((degree * (degree - 1.0) / 8.0 * bend / TOLERANCE).sqrt().ceil() as usize).clamp(1, 256)
+}
+
+/// The number of lines needed to approximate a circle with the given radius,
+/// such that the lines are within the tolerance of the circle.
+fn circle_segment_count(radius: f32, transform: &Transform2D<f32>) -> usize {
+    let radius = radius * transform.determinant().abs().sqrt();
+    if radius <= TOLERANCE {
+        return 8;
+    }
+
+    let angle = 2.0 * (1.0 - TOLERANCE / radius).acos();
+    ((std::f32::consts::TAU / angle).ceil() as usize).clamp(8, 256)
+}
+
+/// Creates the polygons covered by a line of the given `width` along the
+/// `polyline`: a rectangle for every segment, and a circle for every join.
+/// These have the same orientation, so the nonzero winding rule fills their
+/// union.
+fn stroke(polyline: &Polyline, width: f32, transform: &Transform2D<f32>) -> Vec<Vec<Point2D<f32>>> {
+    let radius = width / 2.0;
+    let mut points = polyline.points.clone();
+    points.dedup();
+    if radius <= 0.0 || points.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut polygons = Vec::new();
+    let segments = if polyline.closed { points.len() } else { points.len() - 1 };
+    for i in 0..segments {
+        let from = points[i];
+        let to = points[(i + 1) % points.len()];
+        if from == to {
+            continue;
+        }
+
+        let direction = (to - from).normalize();
+        let normal = euclid::default::Vector2D::new(-direction.y, direction.x) * radius;
+        polygons.push(vec![from + normal, from - normal, to - normal, to + normal]);
+    }
+
+    let joins = if polyline.closed { 0..points.len() } else { 1..points.len() - 1 };
+    let circle_segments = circle_segment_count(radius, transform);
+    for center in &points[joins] {
+        polygons.push((0..circle_segments).map(|i| {
+            let angle = std::f32::consts::TAU * i as f32 / circle_segments as f32;
+            Point2D::new(center.x + radius * angle.cos(), center.y + radius * angle.sin())
+        }).collect());
+    }
+
+    polygons
+}
+
+/// The red, green, blue and alpha components of the color. The system colors
+/// use the [GNOME palette](https://developer.gnome.org/hig/reference/palette.html),
+/// like the GTK backend does, and the default color is black.
+pub(crate) fn components(color: &Color) -> [f32; 4] {
+    let rgb = |hex: u32| [hex >> 16, hex >> 8, hex].map(|value| (value & 0xFF) as u8);
+
+    let [red, green, blue, alpha] = match *color.kind() {
+        ColorKind::Default => [0, 0, 0, 255],
+        ColorKind::Transparent => [0, 0, 0, 0],
+        ColorKind::Rgb { red, green, blue } => [red, green, blue, 255],
+        ColorKind::Rgba { red, green, blue, alpha } => [red, green, blue, alpha],
+        ColorKind::System(system) => {
+            let [red, green, blue] = rgb(match system {
+                SystemColor::Background => 0xFAFAFA,
+                SystemColor::Label => 0x000000,
+                SystemColor::Link => 0x1B6ACB,
+
+                SystemColor::Black => 0x000000,
+                SystemColor::Blue => 0x3584E4,
+                SystemColor::Brown => 0x986A44,
+                SystemColor::Gray => 0x9A9996,
+                SystemColor::Green => 0x33D17A,
+                SystemColor::Indigo => 0x1C71D8,
+                SystemColor::Orange => 0xFF7800,
+                SystemColor::Pink => 0xDC8ADD,
+                SystemColor::Purple => 0x9141AC,
+                SystemColor::Red => 0xE01B24,
+                SystemColor::Teal => 0x2190A4,
+                SystemColor::White => 0xFFFFFF,
+                SystemColor::Yellow => 0xF6D32D,
+            });
+            [red, green, blue, 255]
+        }
+    };
+
+    [red, green, blue, alpha].map(|value| f32::from(value) / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use euclid::{point2, rect, size2};
+
+    use super::*;
+    use crate::{DrawingContext, Path};
+
+    fn render(width: f32, height: f32, paint: impl FnOnce(&mut DrawingContext)) -> Pixmap {
+        let mut context = DrawingContext::new(size2(width, height));
+        paint(&mut context);
+        Pixmap::render(context.size(), &context.into_commands())
+    }
+
+    #[test]
+    fn fill_rect_covers_whole_pixels() {
+        let pixmap = render(4.0, 4.0, |context| context.fill_rect(rect(1.0, 1.0, 2.0, 2.0), Color::rgb(255, 0, 0)));
+
+        assert_eq!(pixmap.pixel(1, 1), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(pixmap.pixel(2, 2), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(pixmap.pixel(0, 0), Some(Color::rgba(0, 0, 0, 0)));
+        assert_eq!(pixmap.pixel(3, 2), Some(Color::rgba(0, 0, 0, 0)));
+        assert_eq!(pixmap.pixel(4, 0), None);
+    }
+
+    #[test]
+    fn edges_are_anti_aliased() {
+        let pixmap = render(2.0, 1.0, |context| context.fill_rect(rect(0.0, 0.0, 1.5, 1.0), Color::rgb(0, 0, 255)));
+
+        assert_eq!(pixmap.pixel(0, 0), Some(Color::rgb(0, 0, 255)));
+        assert_eq!(pixmap.pixel(1, 0), Some(Color::rgba(0, 0, 255, 128)));
+        assert_eq!(pixmap.to_rgba8(), [0, 0, 255, 255, 0, 0, 255, 128]);
+    }
+
+    #[test]
+    fn overlapping_subpaths_are_filled_once() {
+        let path = Path::rect(rect(0.0, 0.0, 2.0, 1.0))
+            .move_to(point2(1.0, 0.0))
+            .line_to(point2(3.0, 0.0))
+            .line_to(point2(3.0, 1.0))
+            .line_to(point2(1.0, 1.0))
+            .close();
+        let pixmap = render(3.0, 1.0, |context| context.fill_path(&path, Color::rgba(0, 0, 0, 128)));
+
+        for x in 0..3 {
+            assert_eq!(pixmap.pixel(x, 0), Some(Color::rgba(0, 0, 0, 128)));
+        }
+    }
+
+    #[test]
+    fn stroke_is_transformed() {
+        let line = Path::new()
+            .move_to(point2(0.0, 2.0))
+            .line_to(point2(4.0, 2.0));
+        let pixmap = render(4.0, 5.0, |context| {
+            context.translate(0.0, 1.0);
+            context.stroke_path(&line, SystemColor::Black, 2.0);
+        });
+
+        assert_eq!(pixmap.pixel(1, 1), Some(Color::rgba(0, 0, 0, 0)));
+        assert_eq!(pixmap.pixel(1, 2), Some(Color::rgb(0, 0, 0)));
+        assert_eq!(pixmap.pixel(1, 3), Some(Color::rgb(0, 0, 0)));
+        assert_eq!(pixmap.pixel(1, 4), Some(Color::rgba(0, 0, 0, 0)));
+    }
+
+    #[test]
+    fn ellipse_has_the_area_of_a_circle() {
+        let pixmap = render(20.0, 20.0, |context| context.fill_ellipse(rect(0.0, 0.0, 20.0, 20.0), SystemColor::Black));
+
+        let area: f32 = pixmap.pixels.iter().map(|pixel| pixel[3]).sum();
+        let expected = std::f32::consts::PI * 100.0;
+        assert!((area - expected).abs() < expected * 0.01, "area is {area}, expected {expected}");
+    }
+}
@@ -0,0 +1,386 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::Point2D;
+
+use crate::{CellAlignment, GridTrack, Number, Padding};
+
+use super::{inset, LayoutFrame, LayoutNode, LayoutRect, LayoutSize};
+
+/// The layout properties of a [`Grid`](crate::Grid).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct GridLayout {
+    pub(crate) columns: Vec<GridTrack>,
+    pub(crate) rows: Vec<GridTrack>,
+    pub(crate) column_spacing: Number,
+    pub(crate) row_spacing: Number,
+    pub(crate) padding: Padding,
+
+    /// The placement of every child, in the same order as the children.
+    pub(crate) cells: Vec<GridPlacement>,
+}
+
+impl GridLayout {
+    pub(crate) const DEFAULT_SPACING: Number = 8.0;
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_spacing: Self::DEFAULT_SPACING,
+            row_spacing: Self::DEFAULT_SPACING,
+            padding: Padding::default(),
+            cells: Vec::new(),
+        }
+    }
+}
+
+/// The cell(s) a child of a [`Grid`](crate::Grid) occupies.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct GridPlacement {
+    pub(crate) row: usize,
+    pub(crate) column: usize,
+    pub(crate) row_span: usize,
+    pub(crate) column_span: usize,
+    pub(crate) horizontal_alignment: CellAlignment,
+    pub(crate) vertical_alignment: CellAlignment,
+}
+
+impl GridPlacement {
+    pub(crate) fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal_alignment: CellAlignment::default(),
+            vertical_alignment: CellAlignment::default(),
+        }
+    }
+}
+
+/// An item of a row or column: the first track, the number of tracks, and the
+/// preferred size of the item along the axis.
+#[derive(Clone, Copy, Debug)]
+struct TrackItem {
+    start: usize,
+    span: usize,
+    size: Number,
+}
+
+/// Computes the sizes of the rows or the columns. When `available` is
+/// [`None`], the preferred sizes are computed, otherwise the fractional tracks
+/// share the space that remains.
+fn track_sizes(tracks: &[GridTrack], spacing: Number, items: &[TrackItem], available: Option<Number>) -> Vec<Number> {
+    let count = items.iter()
+        .map(|item| item.start + item.span)
+        .chain(std::iter::once(tracks.len()))
+        .max()
+        .unwrap_or_default();
+
+    let track = |index: usize| tracks.get(index).copied().unwrap_or_default();
+    let is_flexible = |index: usize| !matches!(track(index), GridTrack::Fixed(..));
+
+    let mut sizes: Vec<Number> = (0..count)
+        .map(|index| match track(index) {
+            GridTrack::Fixed(size) => size,
+            GridTrack::Auto | GridTrack::Fraction(..) => 0.0,
+        })
+        .collect();
+
+    for item in items.iter().filter(|item| item.span == 1) {
+        if is_flexible(item.start) {
+            sizes[item.start] = sizes[item.start].max(item.size);
+        }
+    }
+
+    // Items spanning multiple tracks enlarge the flexible tracks equally, when
+    // the tracks aren't large enough already.
+    for item in items.iter().filter(|item| item.span > 1) {
+        let range = item.start..item.start + item.span;
+        let current = sizes[range.clone()].iter().sum::<Number>() + spacing * (item.span - 1) as Number;
+        let flexible: Vec<usize> = range.filter(|index| is_flexible(*index)).collect();
+        if current >= item.size || flexible.is_empty() {
+            continue;
+        }
+
+        let extra = (item.size - current) / flexible.len() as Number;
+        for index in flexible {
+            sizes[index] += extra;
+        }
+    }
+
+    let fraction = |index: usize| match track(index) {
+        GridTrack::Fraction(fraction) => Some(fraction.max(0.0)),
+        _ => None,
+    };
+
+    let total_fraction: Number = (0..count).filter_map(fraction).sum();
+    if total_fraction <= 0.0 {
+        return sizes;
+    }
+
+    let unit = match available {
+        // The smallest size per fraction, such that every track fits its items.
+        None => (0..count)
+            .filter_map(|index| Some((sizes[index], fraction(index)?)))
+            .filter(|(_, fraction)| *fraction > 0.0)
+            .map(|(size, fraction)| size / fraction)
+            .fold(0.0, Number::max),
+
+        Some(available) => {
+            let used: Number = (0..count)
+                .filter(|index| fraction(*index).is_none())
+                .map(|index| sizes[index])
+                .sum::<Number>() + spacing * count.saturating_sub(1) as Number;
+            (available - used).max(0.0) / total_fraction
+        }
+    };
+
+    for (index, size) in sizes.iter_mut().enumerate() {
+        if let Some(fraction) = fraction(index) {
+            *size = unit * fraction;
+        }
+    }
+
+    sizes
+}
+
+fn column_items(layout: &GridLayout, sizes: &[LayoutSize]) -> Vec<TrackItem> {
+    layout.cells.iter().zip(sizes)
+        .map(|(cell, size)| TrackItem { start: cell.column, span: cell.column_span.max(1), size: size.width })
+        .collect()
+}
+
+fn row_items(layout: &GridLayout, sizes: &[LayoutSize]) -> Vec<TrackItem> {
+    layout.cells.iter().zip(sizes)
+        .map(|(cell, size)| TrackItem { start: cell.row, span: cell.row_span.max(1), size: size.height })
+        .collect()
+}
+
+fn total_size(sizes: &[Number], spacing: Number) -> Number {
+    sizes.iter().sum::<Number>() + spacing * sizes.len().saturating_sub(1) as Number
+}
+
+/// Returns the offset of every track, and the offset after the last track.
+fn track_offsets(sizes: &[Number], spacing: Number, start: Number) -> Vec<Number> {
+    let mut offsets = Vec::with_capacity(sizes.len() + 1);
+    let mut position = start;
+    for size in sizes {
+        offsets.push(position);
+        position += size + spacing;
+    }
+    offsets.push(position);
+    offsets
+}
+
+/// Positions an item of `size` within the cell from `start` with `length`.
+fn align(alignment: CellAlignment, start: Number, length: Number, size: Number) -> (Number, Number) {
+    match alignment {
+        CellAlignment::Start => (start, size),
+        CellAlignment::Center => (start + (length - size) / 2.0, size),
+        CellAlignment::End => (start + length - size, size),
+        CellAlignment::Fill => (start, length),
+    }
+}
+
+pub(super) fn measure_grid(layout: &GridLayout, children: &[LayoutNode]) -> LayoutSize {
+    let sizes: Vec<LayoutSize> = children.iter().map(LayoutNode::measure).collect();
+
+    let columns = track_sizes(&layout.columns, layout.column_spacing, &column_items(layout, &sizes), None);
+    let rows = track_sizes(&layout.rows, layout.row_spacing, &row_items(layout, &sizes), None);
+
+    LayoutSize::new(
+        total_size(&columns, layout.column_spacing) + layout.padding.horizontal(),
+        total_size(&rows, layout.row_spacing) + layout.padding.vertical(),
+    )
+}
+
+pub(super) fn arrange_grid(layout: &GridLayout, children: &[LayoutNode], rect: LayoutRect) -> Vec<LayoutFrame> {
+    let inner = inset(rect, layout.padding);
+    let sizes: Vec<LayoutSize> = children.iter().map(LayoutNode::measure).collect();
+
+    let columns = track_sizes(&layout.columns, layout.column_spacing, &column_items(layout, &sizes), Some(inner.size.width));
+    let rows = track_sizes(&layout.rows, layout.row_spacing, &row_items(layout, &sizes), Some(inner.size.height));
+
+    let column_offsets = track_offsets(&columns, layout.column_spacing, inner.origin.x);
+    let row_offsets = track_offsets(&rows, layout.row_spacing, inner.origin.y);
+
+    children.iter()
+        .zip(&layout.cells)
+        .zip(sizes)
+        .map(|((child, cell), size)| {
+            let column_end = cell.column + cell.column_span.max(1);
+            let row_end = cell.row + cell.row_span.max(1);
+
+            let x = column_offsets[cell.column];
+            let y = row_offsets[cell.row];
+            let width = column_offsets[column_end] - layout.column_spacing - x;
+            let height = row_offsets[row_end] - layout.row_spacing - y;
+
+            let (x, width) = align(cell.horizontal_alignment, x, width, size.width);
+            let (y, height) = align(cell.vertical_alignment, y, height, size.height);
+
+            child.arrange(LayoutRect::new(Point2D::new(x, y), LayoutSize::new(width, height)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(width: Number, height: Number) -> LayoutNode {
+        LayoutNode::Leaf(LayoutSize::new(width, height))
+    }
+
+    fn rect(x: Number, y: Number, width: Number, height: Number) -> LayoutRect {
+        LayoutRect::new(Point2D::new(x, y), LayoutSize::new(width, height))
+    }
+
+    fn rects(frame: &LayoutFrame) -> Vec<LayoutRect> {
+        frame.children.iter().map(|child| child.rect).collect()
+    }
+
+    /// A form with labels in the first column, and fields in the second.
+    fn form(columns: Vec<GridTrack>) -> LayoutNode {
+        let layout = GridLayout {
+            columns,
+            column_spacing: 10.0,
+            row_spacing: 5.0,
+            cells: vec![
+                GridPlacement::new(0, 0),
+                GridPlacement::new(0, 1),
+                GridPlacement::new(1, 0),
+                GridPlacement::new(1, 1),
+            ],
+            ..GridLayout::default()
+        };
+
+        LayoutNode::Grid(layout, vec![
+            leaf(40.0, 20.0),
+            leaf(100.0, 24.0),
+            leaf(64.0, 20.0),
+            leaf(100.0, 24.0),
+        ])
+    }
+
+    #[test]
+    fn measure_auto_tracks() {
+        let grid = form(Vec::new());
+        assert_eq!(grid.measure(), LayoutSize::new(174.0, 53.0));
+    }
+
+    #[test]
+    fn measure_fixed_and_fraction_tracks() {
+        // The fraction tracks are as small as possible, while keeping the
+        // proportions: the first column needs 64.0 for one fraction, so the
+        // second column gets 128.0 for two fractions.
+        let grid = form(vec![GridTrack::Fraction(1.0), GridTrack::Fraction(2.0)]);
+        assert_eq!(grid.measure(), LayoutSize::new(202.0, 53.0));
+
+        let grid = form(vec![GridTrack::Fixed(30.0), GridTrack::Auto]);
+        assert_eq!(grid.measure(), LayoutSize::new(140.0, 53.0));
+    }
+
+    #[test]
+    fn arrange_form() {
+        let grid = form(vec![GridTrack::Auto, GridTrack::Fraction(1.0)]);
+
+        let frame = grid.arrange(rect(0.0, 0.0, 300.0, 100.0));
+        assert_eq!(rects(&frame), [
+            rect(0.0, 0.0, 64.0, 24.0),
+            rect(74.0, 0.0, 226.0, 24.0),
+            rect(0.0, 29.0, 64.0, 24.0),
+            rect(74.0, 29.0, 226.0, 24.0),
+        ]);
+    }
+
+    #[test]
+    fn arrange_fractions_proportionally() {
+        let layout = GridLayout {
+            columns: vec![GridTrack::Fixed(50.0), GridTrack::Fraction(1.0), GridTrack::Fraction(3.0)],
+            column_spacing: 0.0,
+            padding: Padding::uniform(10.0),
+            cells: vec![GridPlacement::new(0, 0), GridPlacement::new(0, 1), GridPlacement::new(0, 2)],
+            ..GridLayout::default()
+        };
+        let grid = LayoutNode::Grid(layout, vec![leaf(10.0, 10.0); 3]);
+
+        let frame = grid.arrange(rect(0.0, 0.0, 270.0, 30.0));
+        assert_eq!(rects(&frame), [
+            rect(10.0, 10.0, 50.0, 10.0),
+            rect(60.0, 10.0, 50.0, 10.0),
+            rect(110.0, 10.0, 150.0, 10.0),
+        ]);
+    }
+
+    #[test]
+    fn arrange_spanning_cell() {
+        let layout = GridLayout {
+            column_spacing: 10.0,
+            row_spacing: 0.0,
+            cells: vec![
+                GridPlacement::new(0, 0),
+                GridPlacement::new(0, 1),
+                GridPlacement { column_span: 2, ..GridPlacement::new(1, 0) },
+            ],
+            ..GridLayout::default()
+        };
+        let grid = LayoutNode::Grid(layout, vec![leaf(20.0, 10.0), leaf(30.0, 10.0), leaf(100.0, 10.0)]);
+
+        // The spanning cell needs 40.0 more, which is shared by both columns.
+        assert_eq!(grid.measure(), LayoutSize::new(100.0, 20.0));
+
+        let frame = grid.arrange(rect(0.0, 0.0, 100.0, 20.0));
+        assert_eq!(rects(&frame), [
+            rect(0.0, 0.0, 40.0, 10.0),
+            rect(50.0, 0.0, 50.0, 10.0),
+            rect(0.0, 10.0, 100.0, 10.0),
+        ]);
+    }
+
+    #[test]
+    fn arrange_cell_alignment() {
+        let expected = [
+            (CellAlignment::Start, CellAlignment::End, rect(0.0, 40.0, 20.0, 10.0)),
+            (CellAlignment::Center, CellAlignment::Center, rect(40.0, 20.0, 20.0, 10.0)),
+            (CellAlignment::End, CellAlignment::Start, rect(80.0, 0.0, 20.0, 10.0)),
+            (CellAlignment::Fill, CellAlignment::Fill, rect(0.0, 0.0, 100.0, 50.0)),
+        ];
+
+        for (horizontal_alignment, vertical_alignment, expected) in expected {
+            let layout = GridLayout {
+                columns: vec![GridTrack::Fraction(1.0)],
+                rows: vec![GridTrack::Fraction(1.0)],
+                cells: vec![GridPlacement {
+                    horizontal_alignment,
+                    vertical_alignment,
+                    ..GridPlacement::new(0, 0)
+                }],
+                ..GridLayout::default()
+            };
+            let grid = LayoutNode::Grid(layout, vec![leaf(20.0, 10.0)]);
+
+            let frame = grid.arrange(rect(0.0, 0.0, 100.0, 50.0));
+            assert_eq!(rects(&frame), [expected], "{horizontal_alignment:?} {vertical_alignment:?}");
+        }
+    }
+
+    #[test]
+    fn implicit_tracks_are_auto() {
+        let layout = GridLayout {
+            columns: vec![GridTrack::Fixed(50.0)],
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            cells: vec![GridPlacement::new(2, 1)],
+            ..GridLayout::default()
+        };
+        let grid = LayoutNode::Grid(layout, vec![leaf(20.0, 10.0)]);
+
+        assert_eq!(grid.measure(), LayoutSize::new(70.0, 10.0));
+        assert_eq!(rects(&grid.arrange(rect(0.0, 0.0, 70.0, 10.0))), [rect(50.0, 0.0, 20.0, 10.0)]);
+    }
+}
@@ -1,6 +1,20 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+//! The cross-platform layout pass: the backends measure the views, after which
+//! the frames of the views are computed here. This ensures that apps are laid
+//! out identically on every backend.
+
+#[cfg(target_os = "macos")]
 mod constraints;
+mod grid;
+mod node;
+mod scroll;
+mod stack;
 
+#[cfg(target_os = "macos")]
 pub(crate) use self::constraints::*;
+pub(crate) use self::grid::*;
+pub(crate) use self::node::*;
+pub(crate) use self::scroll::*;
+pub(crate) use self::stack::*;
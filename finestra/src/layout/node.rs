@@ -0,0 +1,111 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::{Point2D, Rect, Size2D};
+
+use crate::{Number, Padding};
+
+use super::{arrange_grid, arrange_scroll, arrange_stack, measure_grid, measure_stack, GridLayout, ScrollLayout, StackLayout};
+
+pub(crate) type LayoutSize = Size2D<Number>;
+pub(crate) type LayoutRect = Rect<Number>;
+
+/// The input of the layout pass: a tree of the views, where the leaves are
+/// measured by the backend.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum LayoutNode {
+    /// A view that isn't a container, with its preferred size.
+    Leaf(LayoutSize),
+
+    Stack(StackLayout, Vec<LayoutNode>),
+
+    Grid(GridLayout, Vec<LayoutNode>),
+
+    /// A scroll view with its content, which is measured at its preferred
+    /// size, but is clipped to the frame of the scroll view.
+    Scroll(ScrollLayout, Box<LayoutNode>),
+}
+
+/// The output of the layout pass: the frame of every node of the
+/// [`LayoutNode`] tree, in the coordinate space of the window.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LayoutFrame {
+    pub(crate) rect: LayoutRect,
+    pub(crate) children: Vec<LayoutFrame>,
+}
+
+impl LayoutNode {
+    /// Computes the preferred size of the node.
+    #[must_use]
+    pub(crate) fn measure(&self) -> LayoutSize {
+        match self {
+            Self::Leaf(size) => *size,
+            Self::Stack(layout, children) => measure_stack(layout, children),
+            Self::Grid(layout, children) => measure_grid(layout, children),
+            Self::Scroll(_, child) => child.measure(),
+        }
+    }
+
+    /// Computes the frames of this node and its descendants, when this node is
+    /// placed at `rect`.
+    #[must_use]
+    pub(crate) fn arrange(&self, rect: LayoutRect) -> LayoutFrame {
+        match self {
+            Self::Leaf(..) => LayoutFrame {
+                rect,
+                children: Vec::new(),
+            },
+
+            Self::Stack(layout, children) => LayoutFrame {
+                rect,
+                children: arrange_stack(layout, children, rect),
+            },
+
+            Self::Grid(layout, children) => LayoutFrame {
+                rect,
+                children: arrange_grid(layout, children, rect),
+            },
+
+            Self::Scroll(layout, child) => LayoutFrame {
+                rect,
+                children: vec![arrange_scroll(layout, child, rect)],
+            },
+        }
+    }
+}
+
+/// The layout properties of a container view, which are stored by the backends
+/// that use the layout pass.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ContainerLayout {
+    Stack(StackLayout),
+    Grid(GridLayout),
+    Scroll(ScrollLayout),
+}
+
+impl ContainerLayout {
+    /// Creates the [`LayoutNode`] of the container with the given children.
+    #[must_use]
+    pub(crate) fn node(&self, children: Vec<LayoutNode>) -> LayoutNode {
+        match self {
+            Self::Stack(layout) => LayoutNode::Stack(*layout, children),
+            Self::Grid(layout) => LayoutNode::Grid(layout.clone(), children),
+            Self::Scroll(layout) => {
+                let child = children.into_iter().next()
+                    .unwrap_or(LayoutNode::Leaf(LayoutSize::zero()));
+                LayoutNode::Scroll(*layout, Box::new(child))
+            }
+        }
+    }
+}
+
+/// Removes the padding from the edges of the rectangle.
+pub(super) fn inset(rect: LayoutRect, padding: Padding) -> LayoutRect {
+    LayoutRect::new(
+        Point2D::new(rect.origin.x + padding.left, rect.origin.y + padding.top),
+        LayoutSize::new(
+            (rect.size.width - padding.horizontal()).max(0.0),
+            (rect.size.height - padding.vertical()).max(0.0),
+        ),
+    )
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::Vector2D;
+
+use crate::Number;
+
+use super::{LayoutFrame, LayoutNode, LayoutRect, LayoutSize};
+
+/// The layout properties of a [`ScrollView`](crate::ScrollView).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ScrollLayout {
+    pub(crate) horizontal: bool,
+    pub(crate) vertical: bool,
+
+    /// The distance the content is scrolled from the top left corner.
+    pub(crate) offset: Vector2D<Number>,
+}
+
+impl Default for ScrollLayout {
+    fn default() -> Self {
+        Self {
+            horizontal: false,
+            vertical: true,
+            offset: Vector2D::zero(),
+        }
+    }
+}
+
+impl ScrollLayout {
+    /// Computes the size of the content within a viewport of the given size.
+    /// Along the scrolling axes, the content can be larger than the viewport,
+    /// and along the other axes, the content is resized to fit.
+    #[must_use]
+    pub(crate) fn content_size(&self, content: LayoutSize, viewport: LayoutSize) -> LayoutSize {
+        LayoutSize::new(
+            if self.horizontal { content.width.max(viewport.width) } else { viewport.width },
+            if self.vertical { content.height.max(viewport.height) } else { viewport.height },
+        )
+    }
+
+    /// Clamps the offset, such that the content always covers the viewport.
+    #[must_use]
+    pub(crate) fn clamped_offset(&self, content: LayoutSize, viewport: LayoutSize) -> Vector2D<Number> {
+        let max = content - viewport;
+        Vector2D::new(
+            if self.horizontal { self.offset.x.min(max.width).max(0.0) } else { 0.0 },
+            if self.vertical { self.offset.y.min(max.height).max(0.0) } else { 0.0 },
+        )
+    }
+}
+
+pub(super) fn arrange_scroll(layout: &ScrollLayout, child: &LayoutNode, rect: LayoutRect) -> LayoutFrame {
+    let size = layout.content_size(child.measure(), rect.size);
+    let offset = layout.clamped_offset(size, rect.size);
+    child.arrange(LayoutRect::new(rect.origin - offset, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use euclid::default::Point2D;
+
+    use super::*;
+
+    fn rect(x: Number, y: Number, width: Number, height: Number) -> LayoutRect {
+        LayoutRect::new(Point2D::new(x, y), LayoutSize::new(width, height))
+    }
+
+    fn scroll(layout: ScrollLayout) -> LayoutNode {
+        LayoutNode::Scroll(layout, Box::new(LayoutNode::Leaf(LayoutSize::new(300.0, 500.0))))
+    }
+
+    #[test]
+    fn measure_scroll_view() {
+        assert_eq!(scroll(ScrollLayout::default()).measure(), LayoutSize::new(300.0, 500.0));
+    }
+
+    #[test]
+    fn arrange_vertical_scroll_view() {
+        let layout = ScrollLayout {
+            offset: Vector2D::new(50.0, 120.0),
+            ..Default::default()
+        };
+
+        let frame = scroll(layout).arrange(rect(10.0, 10.0, 200.0, 100.0));
+        assert_eq!(frame.rect, rect(10.0, 10.0, 200.0, 100.0));
+        assert_eq!(frame.children[0].rect, rect(10.0, -110.0, 200.0, 500.0));
+    }
+
+    #[test]
+    fn arrange_scroll_view_in_both_directions() {
+        let layout = ScrollLayout {
+            horizontal: true,
+            offset: Vector2D::new(50.0, 120.0),
+            ..Default::default()
+        };
+
+        let frame = scroll(layout).arrange(rect(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(frame.children[0].rect, rect(-50.0, -120.0, 300.0, 500.0));
+    }
+
+    #[test]
+    fn arrange_clamps_offset() {
+        let layout = ScrollLayout {
+            horizontal: true,
+            offset: Vector2D::new(-20.0, 1000.0),
+            ..Default::default()
+        };
+
+        let frame = scroll(layout).arrange(rect(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(frame.children[0].rect, rect(0.0, -400.0, 300.0, 500.0));
+    }
+
+    #[test]
+    fn arrange_content_smaller_than_viewport() {
+        let layout = ScrollLayout {
+            horizontal: true,
+            offset: Vector2D::new(10.0, 10.0),
+            ..Default::default()
+        };
+
+        let frame = scroll(layout).arrange(rect(0.0, 0.0, 400.0, 600.0));
+        assert_eq!(frame.children[0].rect, rect(0.0, 0.0, 400.0, 600.0));
+    }
+}
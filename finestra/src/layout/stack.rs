@@ -0,0 +1,289 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::Point2D;
+
+use crate::{Number, Padding, StackAlignment, StackDirection, StackDistribution};
+
+use super::{inset, LayoutFrame, LayoutNode, LayoutRect, LayoutSize};
+
+/// The layout properties of a [`Stack`](crate::Stack).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct StackLayout {
+    pub(crate) direction: StackDirection,
+    pub(crate) spacing: Number,
+    pub(crate) padding: Padding,
+    pub(crate) alignment: StackAlignment,
+    pub(crate) distribution: StackDistribution,
+}
+
+impl StackLayout {
+    pub(crate) const DEFAULT_SPACING: Number = 8.0;
+
+    pub(crate) fn new(direction: StackDirection) -> Self {
+        Self {
+            direction,
+            spacing: Self::DEFAULT_SPACING,
+            padding: Padding::default(),
+            alignment: StackAlignment::default(),
+            distribution: StackDistribution::default(),
+        }
+    }
+}
+
+/// The sizes of a stack are computed along the main axis (the direction of the
+/// stack), and the cross axis (perpendicular to the direction). These functions
+/// convert between the two coordinate systems.
+fn main_and_cross(direction: StackDirection, size: LayoutSize) -> (Number, Number) {
+    match direction {
+        StackDirection::Horizontal => (size.width, size.height),
+        StackDirection::Vertical => (size.height, size.width),
+    }
+}
+
+fn from_main_and_cross(direction: StackDirection, main: Number, cross: Number) -> LayoutSize {
+    match direction {
+        StackDirection::Horizontal => LayoutSize::new(main, cross),
+        StackDirection::Vertical => LayoutSize::new(cross, main),
+    }
+}
+
+fn total_spacing(layout: &StackLayout, count: usize) -> Number {
+    layout.spacing * count.saturating_sub(1) as Number
+}
+
+pub(super) fn measure_stack(layout: &StackLayout, children: &[LayoutNode]) -> LayoutSize {
+    let sizes = children.iter()
+        .map(|child| main_and_cross(layout.direction, child.measure()));
+
+    let mut main: Number = 0.0;
+    let mut largest_main: Number = 0.0;
+    let mut cross: Number = 0.0;
+    for (child_main, child_cross) in sizes {
+        main += child_main;
+        largest_main = largest_main.max(child_main);
+        cross = cross.max(child_cross);
+    }
+
+    if layout.distribution == StackDistribution::FillEqually {
+        main = largest_main * children.len() as Number;
+    }
+
+    main += total_spacing(layout, children.len());
+
+    let size = from_main_and_cross(layout.direction, main, cross);
+    LayoutSize::new(size.width + layout.padding.horizontal(), size.height + layout.padding.vertical())
+}
+
+pub(super) fn arrange_stack(layout: &StackLayout, children: &[LayoutNode], rect: LayoutRect) -> Vec<LayoutFrame> {
+    if children.is_empty() {
+        return Vec::new();
+    }
+
+    let direction = layout.direction;
+    let inner = inset(rect, layout.padding);
+    let (inner_main, inner_cross) = main_and_cross(direction, inner.size);
+
+    let count = children.len() as Number;
+    let spacing = total_spacing(layout, children.len());
+
+    let mut sizes: Vec<(Number, Number)> = children.iter()
+        .map(|child| main_and_cross(direction, child.measure()))
+        .collect();
+
+    if layout.distribution == StackDistribution::FillEqually {
+        let main = ((inner_main - spacing) / count).max(0.0);
+        for size in &mut sizes {
+            size.0 = main;
+        }
+    }
+
+    let used: Number = sizes.iter().map(|(main, _)| main).sum::<Number>() + spacing;
+    let free = (inner_main - used).max(0.0);
+
+    let (mut position, gap) = match layout.distribution {
+        StackDistribution::Start | StackDistribution::FillEqually => (0.0, layout.spacing),
+        StackDistribution::Center => (free / 2.0, layout.spacing),
+        StackDistribution::End => (free, layout.spacing),
+        StackDistribution::SpaceBetween if children.len() == 1 => (0.0, layout.spacing),
+        StackDistribution::SpaceBetween => (0.0, layout.spacing + free / (count - 1.0)),
+    };
+
+    children.iter()
+        .zip(sizes)
+        .map(|(child, (main, cross))| {
+            let (cross_offset, cross) = match layout.alignment {
+                StackAlignment::Start => (0.0, cross),
+                StackAlignment::Center => ((inner_cross - cross) / 2.0, cross),
+                StackAlignment::End => (inner_cross - cross, cross),
+                StackAlignment::Fill => (0.0, inner_cross),
+            };
+
+            let origin = match direction {
+                StackDirection::Horizontal => Point2D::new(inner.origin.x + position, inner.origin.y + cross_offset),
+                StackDirection::Vertical => Point2D::new(inner.origin.x + cross_offset, inner.origin.y + position),
+            };
+
+            position += main + gap;
+
+            let size = from_main_and_cross(direction, main, cross);
+            child.arrange(LayoutRect::new(origin, size))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(width: Number, height: Number) -> LayoutNode {
+        LayoutNode::Leaf(LayoutSize::new(width, height))
+    }
+
+    fn rect(x: Number, y: Number, width: Number, height: Number) -> LayoutRect {
+        LayoutRect::new(Point2D::new(x, y), LayoutSize::new(width, height))
+    }
+
+    fn rects(frame: &LayoutFrame) -> Vec<LayoutRect> {
+        frame.children.iter().map(|child| child.rect).collect()
+    }
+
+    fn stack(layout: StackLayout) -> LayoutNode {
+        LayoutNode::Stack(layout, vec![leaf(50.0, 20.0), leaf(100.0, 10.0)])
+    }
+
+    #[test]
+    fn measure_vertical_stack() {
+        let layout = StackLayout {
+            padding: Padding::new(1.0, 2.0, 3.0, 4.0),
+            ..StackLayout::new(StackDirection::Vertical)
+        };
+
+        assert_eq!(stack(layout).measure(), LayoutSize::new(106.0, 42.0));
+    }
+
+    #[test]
+    fn measure_horizontal_stack() {
+        let layout = StackLayout {
+            spacing: 5.0,
+            ..StackLayout::new(StackDirection::Horizontal)
+        };
+
+        assert_eq!(stack(layout).measure(), LayoutSize::new(155.0, 20.0));
+    }
+
+    #[test]
+    fn measure_fill_equally() {
+        let layout = StackLayout {
+            spacing: 0.0,
+            distribution: StackDistribution::FillEqually,
+            ..StackLayout::new(StackDirection::Horizontal)
+        };
+
+        assert_eq!(stack(layout).measure(), LayoutSize::new(200.0, 20.0));
+    }
+
+    #[test]
+    fn measure_empty_stack() {
+        let layout = StackLayout {
+            padding: Padding::uniform(10.0),
+            ..StackLayout::new(StackDirection::Vertical)
+        };
+
+        assert_eq!(LayoutNode::Stack(layout, Vec::new()).measure(), LayoutSize::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn arrange_with_padding_and_center_alignment() {
+        let layout = StackLayout {
+            padding: Padding::uniform(10.0),
+            ..StackLayout::new(StackDirection::Vertical)
+        };
+
+        let frame = stack(layout).arrange(rect(0.0, 0.0, 120.0, 200.0));
+        assert_eq!(rects(&frame), [
+            rect(35.0, 10.0, 50.0, 20.0),
+            rect(10.0, 38.0, 100.0, 10.0),
+        ]);
+    }
+
+    #[test]
+    fn arrange_cross_axis_alignment() {
+        let expected = [
+            (StackAlignment::Start, [rect(0.0, 0.0, 50.0, 20.0), rect(58.0, 0.0, 100.0, 10.0)]),
+            (StackAlignment::Center, [rect(0.0, 20.0, 50.0, 20.0), rect(58.0, 25.0, 100.0, 10.0)]),
+            (StackAlignment::End, [rect(0.0, 40.0, 50.0, 20.0), rect(58.0, 50.0, 100.0, 10.0)]),
+            (StackAlignment::Fill, [rect(0.0, 0.0, 50.0, 60.0), rect(58.0, 0.0, 100.0, 60.0)]),
+        ];
+
+        for (alignment, expected) in expected {
+            let layout = StackLayout {
+                alignment,
+                ..StackLayout::new(StackDirection::Horizontal)
+            };
+
+            let frame = stack(layout).arrange(rect(0.0, 0.0, 158.0, 60.0));
+            assert_eq!(rects(&frame), expected, "{alignment:?}");
+        }
+    }
+
+    #[test]
+    fn arrange_main_axis_distribution() {
+        let expected = [
+            (StackDistribution::Start, [rect(0.0, 0.0, 50.0, 20.0), rect(50.0, 0.0, 100.0, 10.0)]),
+            (StackDistribution::Center, [rect(25.0, 0.0, 50.0, 20.0), rect(75.0, 0.0, 100.0, 10.0)]),
+            (StackDistribution::End, [rect(50.0, 0.0, 50.0, 20.0), rect(100.0, 0.0, 100.0, 10.0)]),
+            (StackDistribution::SpaceBetween, [rect(0.0, 0.0, 50.0, 20.0), rect(100.0, 0.0, 100.0, 10.0)]),
+            (StackDistribution::FillEqually, [rect(0.0, 0.0, 100.0, 20.0), rect(100.0, 0.0, 100.0, 10.0)]),
+        ];
+
+        for (distribution, expected) in expected {
+            let layout = StackLayout {
+                spacing: 0.0,
+                alignment: StackAlignment::Start,
+                distribution,
+                ..StackLayout::new(StackDirection::Horizontal)
+            };
+
+            let frame = stack(layout).arrange(rect(0.0, 0.0, 200.0, 20.0));
+            assert_eq!(rects(&frame), expected, "{distribution:?}");
+        }
+    }
+
+    #[test]
+    fn arrange_nested_stacks_in_window_coordinates() {
+        let inner = LayoutNode::Stack(StackLayout {
+            spacing: 0.0,
+            alignment: StackAlignment::Start,
+            ..StackLayout::new(StackDirection::Horizontal)
+        }, vec![leaf(10.0, 10.0), leaf(20.0, 10.0)]);
+
+        let outer = LayoutNode::Stack(StackLayout {
+            padding: Padding::uniform(5.0),
+            alignment: StackAlignment::Start,
+            ..StackLayout::new(StackDirection::Vertical)
+        }, vec![leaf(30.0, 30.0), inner]);
+
+        let frame = outer.arrange(rect(100.0, 100.0, 40.0, 58.0));
+        assert_eq!(rects(&frame.children[1]), [
+            rect(105.0, 143.0, 10.0, 10.0),
+            rect(115.0, 143.0, 20.0, 10.0),
+        ]);
+    }
+
+    #[test]
+    fn arrange_overflowing_stack() {
+        let layout = StackLayout {
+            spacing: 0.0,
+            alignment: StackAlignment::Start,
+            distribution: StackDistribution::End,
+            ..StackLayout::new(StackDirection::Horizontal)
+        };
+
+        let frame = stack(layout).arrange(rect(0.0, 0.0, 100.0, 20.0));
+        assert_eq!(rects(&frame), [
+            rect(0.0, 0.0, 50.0, 20.0),
+            rect(50.0, 0.0, 100.0, 10.0),
+        ]);
+    }
+}
@@ -9,9 +9,12 @@
 
 pub(crate) type Number = f32;
 pub use euclid;
+#[cfg(feature = "regex")]
+pub use regex;
 
 mod app;
 mod event;
+mod graphics;
 mod layout;
 mod platform;
 mod property;
@@ -20,7 +23,10 @@ mod state;
 mod views;
 mod window;
 
+pub mod testing;
+
 pub use self::app::*;
+pub use self::graphics::*;
 pub(crate) use self::layout::*;
 pub use self::property::*;
 pub use self::resources::*;
@@ -0,0 +1,106 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::event::{EventHandlerMapRegistry, ViewTree};
+use crate::{AppDelegate, StateChangeOrigin, View, Window};
+
+use super::dispatcher::StatefulEventDispatcher;
+use super::window::{set_theme, GtkWindowDelegator};
+
+pub(crate) struct GtkAppDelegate<Delegate, State>
+        where State: 'static {
+    delegate: Rc<RefCell<Delegate>>,
+    state: Rc<RefCell<State>>,
+    event_registry: EventHandlerMapRegistry<State>,
+}
+
+impl<Delegate, State> GtkAppDelegate<Delegate, State>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    pub(crate) fn new(delegate: Delegate, state: State) -> Self {
+        Self {
+            delegate: Rc::new(RefCell::new(delegate)),
+            state: Rc::new(RefCell::new(state)),
+            event_registry: EventHandlerMapRegistry::default(),
+        }
+    }
+
+    pub(crate) fn did_activate(&self, app: &gtk::Application) {
+        let config = {
+            let mut state = self.state.borrow_mut();
+
+            self.delegate.borrow_mut().did_launch(&mut state);
+            self.delegate.borrow_mut().configure_main_window(&mut state)
+        };
+
+        let window = gtk::ApplicationWindow::new(app);
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let user_delegator = Window::new(Arc::new(GtkWindowDelegator {
+            window: window.clone(),
+        }));
+
+        let dispatcher = StatefulEventDispatcher {
+            delegate: Rc::clone(&self.delegate),
+            state: Rc::clone(&self.state),
+            event_registry: self.event_registry.clone(),
+            window: user_delegator.clone(),
+        };
+
+        super::menu::set_menu_bar(app, &window, config.menubar, &dispatcher);
+
+        config.title.as_ref().with(|title| {
+            window.set_title(Some(title));
+        });
+
+        if let Some(state) = config.title.as_ref().as_state() {
+            let window = window.clone();
+            state.add_listener_with_origin(move |value| {
+                window.set_title(Some(value));
+            }, StateChangeOrigin::System);
+        }
+
+        config.theme.with(|theme| {
+            set_theme(*theme);
+        });
+
+        if let Some(state) = config.theme.as_state() {
+            state.add_listener_with_origin(move |theme| {
+                set_theme(*theme);
+            }, StateChangeOrigin::System);
+        }
+
+        if config.width != 0.0 && config.height != 0.0 {
+            window.set_default_size(config.width as i32, config.height as i32);
+        }
+
+        let content_view = {
+            let mut state = self.state.borrow_mut();
+            let mut delegate = self.delegate.borrow_mut();
+            let mut content_view = delegate.make_content_view(&mut state, user_delegator.clone());
+
+            let mut tree = ViewTree::new(self.event_registry.clone(), Rc::new(dispatcher));
+            let parent_id = tree.exchange_events_for_id(Default::default());
+            tree.set_parent_id(parent_id);
+            content_view.build_native(&mut tree)
+        };
+
+        let widget = content_view.widget();
+        widget.set_halign(gtk::Align::Center);
+        widget.set_valign(gtk::Align::Center);
+        window.set_child(Some(widget));
+
+        {
+            let mut state = self.state.borrow_mut();
+            self.delegate.borrow_mut().will_show_window(user_delegator, &mut state);
+        }
+
+        window.present();
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use euclid::default::Transform2D;
+use gtk4 as gtk;
+use gtk::cairo;
+use gtk::gdk::prelude::GdkCairoContextExt;
+
+use crate::graphics::raster::components;
+use crate::{Color, DrawCommand, ImageKind, Path, PathElement};
+
+/// Replays the commands drawn on a [`DrawingContext`](crate::DrawingContext)
+/// using cairo. Errors of cairo are ignored, since these only occur when the
+/// context can't be drawn on anymore.
+pub(crate) fn draw_commands(cairo: &cairo::Context, commands: &[DrawCommand]) {
+    for command in commands {
+        _ = cairo.save();
+
+        match command {
+            DrawCommand::Fill { path, color, transform } => {
+                cairo.transform(to_cairo_matrix(transform));
+                add_path(cairo, path);
+                set_source_color(cairo, color);
+                _ = cairo.fill();
+            }
+
+            DrawCommand::Stroke { path, color, width, transform } => {
+                cairo.transform(to_cairo_matrix(transform));
+                add_path(cairo, path);
+                set_source_color(cairo, color);
+                cairo.set_line_width(f64::from(*width));
+                cairo.set_line_join(cairo::LineJoin::Round);
+                _ = cairo.stroke();
+            }
+
+            DrawCommand::Text { text, position, font_size, color, transform } => {
+                cairo.transform(to_cairo_matrix(transform));
+                cairo.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Normal);
+                cairo.set_font_size(f64::from(*font_size));
+                set_source_color(cairo, color);
+                cairo.move_to(f64::from(position.x), f64::from(position.y));
+                _ = cairo.show_text(text);
+            }
+
+            DrawCommand::Image { image, rect, transform } => {
+                let ImageKind::File(file) = image.kind() else {
+                    continue;
+                };
+
+                let Ok(pixbuf) = gtk::gdk_pixbuf::Pixbuf::from_file(file) else {
+                    continue;
+                };
+
+                cairo.transform(to_cairo_matrix(transform));
+                cairo.translate(f64::from(rect.origin.x), f64::from(rect.origin.y));
+                cairo.scale(
+                    f64::from(rect.width()) / f64::from(pixbuf.width()),
+                    f64::from(rect.height()) / f64::from(pixbuf.height()),
+                );
+                cairo.set_source_pixbuf(&pixbuf, 0.0, 0.0);
+                _ = cairo.paint();
+            }
+        }
+
+        _ = cairo.restore();
+    }
+}
+
+fn to_cairo_matrix(transform: &Transform2D<f32>) -> cairo::Matrix {
+    let [xx, yx, xy, yy, x0, y0] = transform.to_array().map(f64::from);
+    cairo::Matrix::new(xx, yx, xy, yy, x0, y0)
+}
+
+/// Uses the same colors as the software renderer of the headless backend,
+/// since the colors of the theme can't be used to draw.
+fn set_source_color(cairo: &cairo::Context, color: &Color) {
+    let [red, green, blue, alpha] = components(color).map(f64::from);
+    cairo.set_source_rgba(red, green, blue, alpha);
+}
+
+fn add_path(cairo: &cairo::Context, path: &Path) {
+    cairo.new_path();
+
+    for element in path.elements() {
+        match *element {
+            PathElement::MoveTo(point) => cairo.move_to(point.x.into(), point.y.into()),
+            PathElement::LineTo(point) => cairo.line_to(point.x.into(), point.y.into()),
+
+            // Cairo doesn't have quadratic curves, but these can be drawn as
+            // cubic curves.
+            PathElement::QuadraticTo(control, point) => {
+                let (x, y) = cairo.current_point().unwrap_or((control.x.into(), control.y.into()));
+                let (cx, cy) = (f64::from(control.x), f64::from(control.y));
+                let (px, py) = (f64::from(point.x), f64::from(point.y));
+                cairo.curve_to(
+                    x + 2.0 / 3.0 * (cx - x), y + 2.0 / 3.0 * (cy - y),
+                    px + 2.0 / 3.0 * (cx - px), py + 2.0 / 3.0 * (cy - py),
+                    px, py,
+                );
+            }
+
+            PathElement::CubicTo(control1, control2, point) => cairo.curve_to(
+                control1.x.into(), control1.y.into(),
+                control2.x.into(), control2.y.into(),
+                point.x.into(), point.y.into(),
+            ),
+
+            PathElement::Close => cairo.close_path(),
+        }
+    }
+}
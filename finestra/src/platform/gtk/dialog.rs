@@ -0,0 +1,118 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::{Color, ColorDialogApi, DialogApi, DialogKind};
+
+use super::{add_swatches, color_from_gtk, ToGtk};
+
+pub(crate) struct GtkDialog {
+    window: gtk::ApplicationWindow,
+    kind: RefCell<DialogKind>,
+    title: RefCell<String>,
+    text: RefCell<String>,
+}
+
+impl GtkDialog {
+    pub fn new(window: gtk::ApplicationWindow, text: String, title: String) -> Self {
+        Self {
+            window,
+            kind: RefCell::new(DialogKind::default()),
+            title: RefCell::new(title),
+            text: RefCell::new(text),
+        }
+    }
+}
+
+impl DialogApi for GtkDialog {
+    fn set_kind(&self, kind: DialogKind) {
+        *self.kind.borrow_mut() = kind;
+    }
+
+    fn set_text(&self, text: Cow<'static, str>) {
+        *self.text.borrow_mut() = text.into_owned();
+    }
+
+    fn set_title(&self, title: Cow<'static, str>) {
+        *self.title.borrow_mut() = title.into_owned();
+    }
+
+    fn show(&self) {
+        let message_type = match *self.kind.borrow() {
+            DialogKind::Normal => gtk::MessageType::Other,
+            DialogKind::Informational => gtk::MessageType::Info,
+            DialogKind::Warning => gtk::MessageType::Warning,
+            DialogKind::Error => gtk::MessageType::Error,
+        };
+
+        let dialog = gtk::MessageDialog::builder()
+            .transient_for(&self.window)
+            .modal(true)
+            .message_type(message_type)
+            .buttons(gtk::ButtonsType::Ok)
+            .title(self.title.borrow().as_str())
+            .text(self.text.borrow().as_str())
+            .build();
+
+        dialog.connect_response(|dialog, _| {
+            dialog.close();
+        });
+
+        dialog.present();
+    }
+}
+
+pub(crate) struct GtkColorDialog {
+    window: gtk::ApplicationWindow,
+    title: RefCell<String>,
+    color: RefCell<Color>,
+    alpha: RefCell<bool>,
+}
+
+impl GtkColorDialog {
+    pub fn new(window: gtk::ApplicationWindow, title: String) -> Self {
+        Self {
+            window,
+            title: RefCell::new(title),
+            color: RefCell::new(Color::default()),
+            alpha: RefCell::new(true),
+        }
+    }
+}
+
+impl ColorDialogApi for GtkColorDialog {
+    fn set_alpha(&self, alpha: bool) {
+        *self.alpha.borrow_mut() = alpha;
+    }
+
+    fn set_color(&self, color: Color) {
+        *self.color.borrow_mut() = color;
+    }
+
+    fn set_title(&self, title: Cow<'static, str>) {
+        *self.title.borrow_mut() = title.into_owned();
+    }
+
+    fn show(&self, on_pick: Box<dyn Fn(Color)>) {
+        let dialog = gtk::ColorChooserDialog::new(Some(self.title.borrow().as_str()), Some(&self.window));
+        dialog.set_modal(true);
+        dialog.set_use_alpha(*self.alpha.borrow());
+        dialog.set_rgba(&self.color.borrow().to_gtk());
+        add_swatches(&dialog);
+
+        dialog.connect_response(move |dialog, response| {
+            if response == gtk::ResponseType::Ok {
+                on_pick(color_from_gtk(&dialog.rgba()));
+            }
+
+            dialog.close();
+        });
+
+        dialog.present();
+    }
+}
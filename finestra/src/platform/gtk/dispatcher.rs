@@ -0,0 +1,118 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::glib;
+
+use crate::{AppDelegate, EventHandlerMapRegistry, Window};
+
+use super::state::Event;
+
+pub(crate) trait EventDispatcher {
+    fn dispatch_event(&self, event: Event);
+}
+
+/// Dispatches the events of the GTK widgets to the [`AppDelegate`] and the
+/// event handlers of the views.
+///
+/// GTK emits signals synchronously, even when the change was made by a
+/// [`State`](crate::State) listener. The events are therefore handled when the
+/// main loop is idle, to ensure the app state isn't borrowed twice.
+pub(crate) struct StatefulEventDispatcher<Delegate, State> {
+    pub(crate) delegate: Rc<RefCell<Delegate>>,
+    pub(crate) state: Rc<RefCell<State>>,
+    pub(crate) event_registry: EventHandlerMapRegistry<State>,
+    pub(crate) window: Window,
+}
+
+impl<Delegate, State> Clone for StatefulEventDispatcher<Delegate, State> {
+    fn clone(&self) -> Self {
+        Self {
+            delegate: Rc::clone(&self.delegate),
+            state: Rc::clone(&self.state),
+            event_registry: self.event_registry.clone(),
+            window: self.window.clone(),
+        }
+    }
+}
+
+impl<Delegate, State> StatefulEventDispatcher<Delegate, State>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn handle_event(&self, event: Event) {
+        let mut state = self.state.borrow_mut();
+
+        match event {
+            Event::ButtonClicked(view_id) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.click.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, self.window.clone());
+            }
+
+            Event::CheckboxChanged(view_id, is_checked) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.checked.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, is_checked, self.window.clone());
+            }
+
+            Event::ListRowActivated(view_id, row) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.activate.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, row, self.window.clone());
+            }
+
+            Event::SelectionChanged(view_id, index) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.selection_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, index, self.window.clone());
+            }
+
+            Event::ValueChanged(view_id, value) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.value_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, value, self.window.clone());
+            }
+
+            Event::ColorChanged(view_id, color) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.color_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, color, self.window.clone());
+            }
+
+            Event::MenuAction { item } => {
+                self.delegate.borrow_mut().did_invoke_menu_action(item, &mut state, self.window.clone());
+            }
+
+            Event::TextFieldChanged(view_id, text) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.text_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, text.text, self.window.clone());
+            }
+        }
+    }
+}
+
+impl<Delegate, State> EventDispatcher for StatefulEventDispatcher<Delegate, State>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn dispatch_event(&self, event: Event) {
+        let this = self.clone();
+        glib::idle_add_local_once(move || {
+            this.handle_event(event);
+        });
+    }
+}
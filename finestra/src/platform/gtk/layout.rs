@@ -0,0 +1,98 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use gtk4 as gtk;
+use gtk::{glib, prelude::*, subclass::prelude::*};
+
+use crate::{ContainerLayout, LayoutNode, LayoutSize};
+
+glib::wrapper! {
+    /// Lays out the children of a widget using the layout pass, for
+    /// containers that GTK doesn't provide, such as a [`Grid`](crate::Grid)
+    /// with fractional tracks. The children are measured by GTK, and are
+    /// treated as leaves of the layout pass.
+    pub(crate) struct ContainerLayoutManager(ObjectSubclass<imp::ContainerLayoutManager>)
+        @extends gtk::LayoutManager;
+}
+
+impl ContainerLayoutManager {
+    pub(crate) fn new(layout: ContainerLayout) -> Self {
+        let this: Self = glib::Object::new();
+        this.imp().layout.replace(Some(layout));
+        this
+    }
+}
+
+fn children(widget: &gtk::Widget) -> impl Iterator<Item = gtk::Widget> {
+    std::iter::successors(widget.first_child(), |child| child.next_sibling())
+        .filter(|child| child.should_layout())
+}
+
+mod imp {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[derive(Default)]
+    pub(crate) struct ContainerLayoutManager {
+        pub(super) layout: RefCell<Option<ContainerLayout>>,
+    }
+
+    impl ContainerLayoutManager {
+        fn node(&self, widget: &gtk::Widget) -> LayoutNode {
+            let children = children(widget)
+                .map(|child| {
+                    let (_, natural) = child.preferred_size();
+                    LayoutNode::Leaf(LayoutSize::new(natural.width() as f32, natural.height() as f32))
+                })
+                .collect();
+
+            match &*self.layout.borrow() {
+                Some(layout) => layout.node(children),
+                None => LayoutNode::Leaf(LayoutSize::zero()),
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerLayoutManager {
+        const NAME: &'static str = "FinestraContainerLayoutManager";
+        type Type = super::ContainerLayoutManager;
+        type ParentType = gtk::LayoutManager;
+    }
+
+    impl ObjectImpl for ContainerLayoutManager {}
+
+    impl LayoutManagerImpl for ContainerLayoutManager {
+        fn request_mode(&self, _widget: &gtk::Widget) -> gtk::SizeRequestMode {
+            gtk::SizeRequestMode::ConstantSize
+        }
+
+        fn measure(&self, widget: &gtk::Widget, orientation: gtk::Orientation, _for_size: i32) -> (i32, i32, i32, i32) {
+            let size = self.node(widget).measure();
+            let size = match orientation {
+                gtk::Orientation::Horizontal => size.width,
+                _ => size.height,
+            };
+
+            let size = size.ceil() as i32;
+            (size, size, -1, -1)
+        }
+
+        fn allocate(&self, widget: &gtk::Widget, width: i32, height: i32, _baseline: i32) {
+            let size = LayoutSize::new(width as f32, height as f32);
+            let frame = self.node(widget).arrange(crate::LayoutRect::from_size(size));
+
+            for (child, frame) in children(widget).zip(&frame.children) {
+                let rect = frame.rect.round();
+                let allocation = gtk::Allocation::new(
+                    rect.origin.x as i32,
+                    rect.origin.y as i32,
+                    rect.size.width as i32,
+                    rect.size.height as i32,
+                );
+                child.size_allocate(&allocation, -1);
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use gtk4 as gtk;
+use gtk::{gio, prelude::*};
+
+use crate::{Menu, MenuBar, MenuItemKind};
+
+use super::{Event, EventDispatcher};
+
+pub(crate) fn set_menu_bar(app: &gtk::Application, window: &gtk::ApplicationWindow, menubar: MenuBar, dispatcher: &(impl EventDispatcher + Clone + 'static)) {
+    if menubar.menus.is_empty() {
+        return;
+    }
+
+    let bar = gio::Menu::new();
+
+    for (menu_index, menu) in menubar.menus.into_iter().enumerate() {
+        let name = menu.name.clone();
+        let submenu = convert_menu(app, menu_index, menu, dispatcher);
+        bar.append_submenu(Some(&name), &submenu);
+    }
+
+    app.set_menubar(Some(&bar));
+    window.set_show_menubar(true);
+}
+
+/// Separators are expressed in GIO menus by splitting the items into sections.
+fn convert_menu(app: &gtk::Application, menu_index: usize, menu: Menu, dispatcher: &(impl EventDispatcher + Clone + 'static)) -> gio::Menu {
+    let menu_model = gio::Menu::new();
+    let mut section = gio::Menu::new();
+
+    for (item_index, item) in menu.items.into_iter().enumerate() {
+        let MenuItemKind::Titled(title) = &item.kind else {
+            menu_model.append_section(None, &section);
+            section = gio::Menu::new();
+            continue;
+        };
+
+        let action_name = format!("menu-{menu_index}-{item_index}");
+        section.append(Some(title), Some(&format!("app.{action_name}")));
+
+        let action = gio::SimpleAction::new(&action_name, None);
+        let dispatcher = dispatcher.clone();
+        action.connect_activate(move |_, _| {
+            dispatcher.dispatch_event(Event::MenuAction { item: item.clone() });
+        });
+        app.add_action(&action);
+    }
+
+    menu_model.append_section(None, &section);
+    menu_model
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+mod app;
+mod canvas;
+mod dialog;
+pub(crate) mod dispatcher;
+mod layout;
+mod menu;
+mod resources;
+mod rows;
+pub(crate) mod state;
+mod view;
+mod window;
+
+pub(crate) use self::canvas::draw_commands;
+pub(crate) use self::dispatcher::EventDispatcher;
+pub(crate) use self::layout::ContainerLayoutManager;
+pub(crate) use self::resources::*;
+pub(crate) use self::rows::create_row_model;
+pub(crate) use self::state::{Event, SignalGuard};
+pub(crate) use self::view::GtkView;
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::{App, AppDelegate, Timer};
+
+use self::app::GtkAppDelegate;
+
+const APPLICATION_ID: &str = "com.tristangerritsen.finestra";
+
+pub(crate) fn run_app<Delegate, State>(app: App<Delegate, State>) -> !
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    let code = run_app_until_exit(app);
+    std::process::exit(code);
+}
+
+pub(crate) fn run_app_until_exit<Delegate, State>(app: App<Delegate, State>) -> i32
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    let gtk_app = gtk::Application::builder()
+        .application_id(APPLICATION_ID)
+        .flags(gtk::gio::ApplicationFlags::NON_UNIQUE)
+        .build();
+
+    let delegate = GtkAppDelegate::new(app.delegate, app.state);
+    gtk_app.connect_activate(move |gtk_app| {
+        delegate.did_activate(gtk_app);
+    });
+
+    // Don't pass the arguments of the process to GTK, since they are meant
+    // for the app (or the test harness).
+    let code = gtk_app.run_with_args::<&str>(&[]);
+    code.value()
+}
+
+pub(crate) fn schedule_timer<F: FnOnce() + Send + 'static>(timer: Timer<F>) {
+    gtk::glib::timeout_add_once(timer.delay, move || {
+        (timer.action)();
+    });
+}
+
+/// Like [`schedule_timer()`], but the action runs on the main thread, so it
+/// doesn't have to be `Send`.
+pub(crate) fn schedule_local_timer<F: FnOnce() + 'static>(timer: Timer<F>) {
+    gtk::glib::timeout_add_local_once(timer.delay, move || {
+        (timer.action)();
+    });
+}
+
+// These tests require a display, e.g. by running them under `xvfb-run`, or by
+// using the Broadway backend of GTK (`GDK_BACKEND=broadway`).
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct ClosingApp;
+
+    impl AppDelegate for ClosingApp {
+        fn make_content_view(&mut self, _: &mut (), _: Window) -> impl View<Self> {
+            Stack::vertical()
+                .with(Label::new("Hello"))
+                .with(Button::new("Click Me"))
+                .with(Checkbox::new("Check Me"))
+                .with(TextField::new(""))
+        }
+
+        fn will_show_window(&mut self, window: Window, _: &mut ()) {
+            window.close();
+        }
+    }
+
+    #[test]
+    fn app_runs_until_window_is_closed() {
+        let code = App::new(ClosingApp)
+            .with_backend(UIBackend::Gtk)
+            .run_until_exit();
+
+        assert_eq!(code, 0);
+    }
+}
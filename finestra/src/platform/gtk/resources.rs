@@ -0,0 +1,207 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::{Color, ColorKind, Cursor, CursorKind, Orientation, SystemColor, SystemCursor, TextAlignment, UnstableCursor, ViewId};
+
+pub(crate) trait ToGtk<GtkType> {
+    fn to_gtk(&self) -> GtkType;
+}
+
+impl ToGtk<Option<String>> for Color {
+    /// Converts the color to a CSS color, or [`None`] if the theme should
+    /// decide.
+    fn to_gtk(&self) -> Option<String> {
+        match *self.kind() {
+            ColorKind::Default => None,
+            ColorKind::System(system) => Some(system.to_gtk().to_owned()),
+            ColorKind::Transparent => Some("transparent".to_owned()),
+
+            ColorKind::Rgb { red, green, blue } => {
+                Some(format!("rgb({red}, {green}, {blue})"))
+            }
+
+            ColorKind::Rgba { red, green, blue, alpha } => {
+                Some(format!("rgba({red}, {green}, {blue}, {:.3})", f32::from(alpha) / 255.0))
+            }
+        }
+    }
+}
+
+impl ToGtk<gtk::gdk::RGBA> for Color {
+    /// Converts the color for a color chooser. The colors of the theme can't
+    /// be chosen, so these are black.
+    fn to_gtk(&self) -> gtk::gdk::RGBA {
+        let css: Option<String> = self.to_gtk();
+        css.and_then(|css| gtk::gdk::RGBA::parse(css.as_str()).ok())
+            .unwrap_or(gtk::gdk::RGBA::BLACK)
+    }
+}
+
+pub(crate) fn color_from_gtk(color: &gtk::gdk::RGBA) -> Color {
+    Color::from_components(color.red().into(), color.green().into(), color.blue().into(), color.alpha().into())
+}
+
+/// Offers the named [`SystemColor`]s as swatches.
+pub(crate) fn add_swatches(chooser: &impl IsA<gtk::ColorChooser>) {
+    let swatches: Vec<_> = SystemColor::SWATCHES.iter()
+        .map(|color| Color::system(*color).to_gtk())
+        .collect();
+
+    chooser.add_palette(gtk::Orientation::Horizontal, swatches.len() as i32, &swatches);
+}
+
+impl ToGtk<&'static str> for SystemColor {
+    /// Uses the colors of the theme, or the
+    /// [GNOME palette](https://developer.gnome.org/hig/reference/palette.html)
+    /// when the theme doesn't define the color.
+    fn to_gtk(&self) -> &'static str {
+        match self {
+            SystemColor::Background => "@theme_bg_color",
+            SystemColor::Label => "@theme_fg_color",
+            SystemColor::Link => "@link_color",
+
+            SystemColor::Black => "#000000",
+            SystemColor::Blue => "#3584e4",
+            SystemColor::Brown => "#986a44",
+            SystemColor::Gray => "#9a9996",
+            SystemColor::Green => "#33d17a",
+            SystemColor::Indigo => "#1c71d8",
+            SystemColor::Orange => "#ff7800",
+            SystemColor::Pink => "#dc8add",
+            SystemColor::Purple => "#9141ac",
+            SystemColor::Red => "#e01b24",
+            SystemColor::Teal => "#2190a4",
+            SystemColor::White => "#ffffff",
+            SystemColor::Yellow => "#f6d32d",
+        }
+    }
+}
+
+impl ToGtk<gtk::Justification> for TextAlignment {
+    fn to_gtk(&self) -> gtk::Justification {
+        match self {
+            Self::Default => gtk::Justification::Left,
+            Self::Left => gtk::Justification::Left,
+            Self::Center => gtk::Justification::Center,
+            Self::Right => gtk::Justification::Right,
+        }
+    }
+}
+
+impl ToGtk<gtk::Orientation> for Orientation {
+    fn to_gtk(&self) -> gtk::Orientation {
+        match self {
+            Self::Horizontal => gtk::Orientation::Horizontal,
+            Self::Vertical => gtk::Orientation::Vertical,
+        }
+    }
+}
+
+impl ToGtk<&'static str> for Cursor {
+    /// Returns the [CSS cursor name](https://docs.gtk.org/gdk4/ctor.Cursor.new_from_name.html).
+    fn to_gtk(&self) -> &'static str {
+        match self.kind {
+            CursorKind::Unstable { cursor, .. } => match cursor {
+                UnstableCursor::Busy => "wait",
+                UnstableCursor::BusyInBackground => "progress",
+                UnstableCursor::DisappearingItem => "no-drop",
+                UnstableCursor::Help => "help",
+            },
+            CursorKind::System(system) => match system {
+                SystemCursor::Default => "default",
+
+                SystemCursor::Arrow => "default",
+                SystemCursor::CrossHair => "crosshair",
+                SystemCursor::Hand => "pointer",
+                SystemCursor::IBeam => "text",
+                SystemCursor::NotAllowed => "not-allowed",
+                SystemCursor::ResizeDown => "s-resize",
+                SystemCursor::ResizeLeft => "w-resize",
+                SystemCursor::ResizeRight => "e-resize",
+                SystemCursor::ResizeUp => "n-resize",
+            },
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToGtk<Option<gtk::glib::DateTime>> for chrono::NaiveDate {
+    /// Returns `None` when the date is outside the range of `GDateTime`.
+    fn to_gtk(&self) -> Option<gtk::glib::DateTime> {
+        use chrono::Datelike;
+
+        gtk::glib::DateTime::from_utc(self.year(), self.month() as i32, self.day() as i32, 0, 0, 0.0).ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn date_from_gtk(date: &gtk::glib::DateTime) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::from_ymd_opt(date.year(), date.month() as u32, date.day_of_month() as u32)
+}
+
+/// GTK widgets are styled using CSS, which means that colors can't be set on
+/// the widget directly. Every styled widget therefore gets its own name and
+/// style sheet.
+#[derive(Clone)]
+pub(crate) struct WidgetStyle {
+    inner: Rc<WidgetStyleData>,
+}
+
+struct WidgetStyleData {
+    name: String,
+    provider: gtk::CssProvider,
+    background_color: RefCell<Option<String>>,
+    text_color: RefCell<Option<String>>,
+}
+
+impl WidgetStyle {
+    pub(crate) fn new(view_id: ViewId, widget: &impl IsA<gtk::Widget>) -> Self {
+        let name = format!("finestra-{}", view_id.0);
+        widget.set_widget_name(&name);
+
+        let provider = gtk::CssProvider::new();
+        if let Some(display) = gtk::gdk::Display::default() {
+            gtk::style_context_add_provider_for_display(&display, &provider, gtk::STYLE_PROVIDER_PRIORITY_APPLICATION);
+        }
+
+        Self {
+            inner: Rc::new(WidgetStyleData {
+                name,
+                provider,
+                background_color: RefCell::new(None),
+                text_color: RefCell::new(None),
+            }),
+        }
+    }
+
+    pub(crate) fn set_background_color(&self, color: &Color) {
+        *self.inner.background_color.borrow_mut() = color.to_gtk();
+        self.reload();
+    }
+
+    pub(crate) fn set_text_color(&self, color: &Color) {
+        *self.inner.text_color.borrow_mut() = color.to_gtk();
+        self.reload();
+    }
+
+    fn reload(&self) {
+        let mut properties = String::new();
+
+        if let Some(color) = self.inner.background_color.borrow().as_ref() {
+            properties += &format!("background: {color}; ");
+        }
+
+        if let Some(color) = self.inner.text_color.borrow().as_ref() {
+            properties += &format!("color: {color}; ");
+        }
+
+        let name = &self.inner.name;
+        self.inner.provider.load_from_data(&format!("#{name}, #{name} label {{ {properties}}}"));
+    }
+}
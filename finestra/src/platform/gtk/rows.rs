@@ -0,0 +1,55 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::{ListSelection, Rows, SelectionMode, State, StateChangeOrigin, ViewId};
+
+use super::SignalGuard;
+
+/// Creates the model of a view that displays a row per item, such as a
+/// [`List`](crate::List). The model only contains the number of rows, as the
+/// rows are built from the items in `rows`, which are kept up to date.
+pub(crate) fn create_row_model<T>(
+    view_id: ViewId,
+    rows: &Rows<T>,
+    items: &State<Vec<T>>,
+    selection: &ListSelection,
+    guard: &SignalGuard,
+) -> gtk::SelectionModel
+        where T: Clone + PartialEq + 'static {
+    let model = gtk::StringList::new(&vec![""; rows.len()]);
+
+    let selection_model: gtk::SelectionModel = match selection.mode() {
+        SelectionMode::None => gtk::NoSelection::new(Some(model.clone())).upcast(),
+        SelectionMode::Single => {
+            let selection = gtk::SingleSelection::new(Some(model.clone()));
+            selection.set_autoselect(false);
+            selection.set_can_unselect(true);
+            selection.upcast()
+        }
+        SelectionMode::Multiple => gtk::MultiSelection::new(Some(model.clone())).upcast(),
+    };
+
+    let selection_state = selection.clone();
+    let selection_guard = guard.clone();
+    selection_model.connect_selection_changed(move |model, _, _| {
+        if selection_guard.is_active() {
+            return;
+        }
+
+        let selected = model.selection();
+        let rows = (0..selected.size()).map(|index| selected.nth(index as u32) as usize).collect();
+        selection_state.set_rows(rows, StateChangeOrigin::Owner(view_id));
+    });
+
+    let guard = guard.clone();
+    rows.observe(items, selection, move |change| {
+        guard.run(|| {
+            model.splice(change.start as u32, change.removed as u32, &vec![""; change.inserted]);
+        });
+    });
+
+    selection_model
+}
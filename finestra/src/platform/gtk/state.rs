@@ -0,0 +1,650 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+use crate::{BaseView, Button, Canvas, ChangedText, Checkbox, Color, ColorWell, ImageView, Label, Link, List, ListSelection, MenuItem, ProgressBar, RadioGroup, ScrollView, SearchField, Select, Slider, SortOrder, State, StateChangeOrigin, StateOrRaw, Stepper, StepperValue, Switch, TabView, Table, TableSort, TextAlignment, TextBlock, TextEditor, TextField, TextSelection, ViewId};
+
+#[cfg(feature = "chrono")]
+use crate::{views::format_date, Calendar, DatePicker, DateValue};
+
+use super::resources::{ToGtk, WidgetStyle};
+use super::GtkView;
+
+#[derive(Debug)]
+pub(crate) enum Event {
+    ButtonClicked(ViewId),
+    MenuAction {
+        item: MenuItem,
+    },
+    TextFieldChanged(ViewId, ChangedText),
+    CheckboxChanged(ViewId, bool),
+    ListRowActivated(ViewId, usize),
+    SelectionChanged(ViewId, Option<usize>),
+    ValueChanged(ViewId, f64),
+    ColorChanged(ViewId, Color),
+}
+
+/// GTK emits signals for changes made by the program as well, which would make
+/// the listeners of a [`State`](crate::State) notify themselves. This guard is
+/// used to ignore the signals while a listener updates the widget.
+#[derive(Clone, Default)]
+pub(crate) struct SignalGuard {
+    active: Rc<Cell<bool>>,
+}
+
+impl SignalGuard {
+    pub(crate) fn run(&self, f: impl FnOnce()) {
+        self.active.set(true);
+        f();
+        self.active.set(false);
+    }
+
+    #[must_use]
+    pub(crate) fn is_active(&self) -> bool {
+        self.active.get()
+    }
+}
+
+pub fn attach_base_state(finestra: &dyn BaseView, widget: &impl IsA<gtk::Widget>) {
+    finestra.base().register_handle(|| GtkView::from(widget.clone()));
+    hook_tooltip_state(widget, &finestra.base().tooltip);
+}
+
+pub fn attach_button_state<S>(view_id: ViewId, finestra: &Button<S>, gtk: &gtk::Button) {
+    attach_base_state(finestra, gtk);
+
+    let style = WidgetStyle::new(view_id, gtk);
+    hook_background_color_state(&style, &finestra.background_color);
+    hook_text_color_state(&style, &finestra.text_color);
+
+    let button = gtk.clone();
+    hook_text_state(view_id, &finestra.text, move |text| button.set_label(text));
+}
+
+/// Returns the function that shows a date, which is also used to move the
+/// selection back into the bounds.
+#[cfg(feature = "chrono")]
+pub fn attach_calendar_state<S>(view_id: ViewId, finestra: &Calendar<S>, gtk: &gtk::Calendar, guard: &SignalGuard) -> impl Fn(&NaiveDate) + Clone + 'static {
+    attach_base_state(finestra, gtk);
+
+    let calendar = gtk.clone();
+    let guard = guard.clone();
+    let select = move |date: &NaiveDate| {
+        if let Some(date) = date.to_gtk() {
+            guard.run(|| calendar.select_day(&date));
+        }
+    };
+
+    finestra.value.with(&select);
+    finestra.value.add_listener_with_origin(select.clone(), StateChangeOrigin::Owner(view_id));
+    select
+}
+
+pub fn attach_checkbox_state<S>(view_id: ViewId, finestra: &Checkbox<S>, gtk: &gtk::CheckButton, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let style = WidgetStyle::new(view_id, gtk);
+    hook_background_color_state(&style, &finestra.background_color);
+    hook_text_color_state(&style, &finestra.text_color);
+
+    let checkbox = gtk.clone();
+    hook_text_state(view_id, &finestra.text, move |text| checkbox.set_label(Some(text)));
+
+    if let StateOrRaw::State(checked_state) = &finestra.checked {
+        let checkbox = gtk.clone();
+        let guard = guard.clone();
+        checked_state.add_listener_with_origin(move |val| {
+            guard.run(|| checkbox.set_active(*val));
+        }, StateChangeOrigin::Owner(view_id));
+    }
+}
+
+pub fn attach_canvas_state<S>(finestra: &Canvas<S>, gtk: &gtk::DrawingArea) {
+    attach_base_state(finestra, gtk);
+}
+
+pub fn attach_color_well_state<S>(view_id: ViewId, finestra: &ColorWell<S>, gtk: &gtk::ColorButton) {
+    attach_base_state(finestra, gtk);
+
+    let button = gtk.clone();
+    let set_color = move |color: &Color| button.set_rgba(&color.to_gtk());
+
+    finestra.value.with(&set_color);
+    finestra.value.add_listener_with_origin(set_color, StateChangeOrigin::Owner(view_id));
+}
+
+/// Returns the function that shows a moment, which is also used to restore the
+/// moment after the user typed invalid text.
+#[cfg(feature = "chrono")]
+pub fn attach_date_picker_state<T: DateValue, S>(
+    view_id: ViewId,
+    finestra: &DatePicker<T, S>,
+    gtk: &gtk::Box,
+    entry: &gtk::Entry,
+    calendar: Option<&gtk::Calendar>,
+    guard: &SignalGuard,
+) -> impl Fn(&T) + Clone + 'static {
+    attach_base_state(finestra, gtk);
+
+    let entry = entry.clone();
+    let calendar = calendar.cloned();
+    let guard = guard.clone();
+    let show = move |value: &T| {
+        entry.set_text(&format_date(*value));
+
+        let date = calendar.as_ref().zip(value.to_date_time().date().to_gtk());
+        if let Some((calendar, date)) = date {
+            guard.run(|| calendar.select_day(&date));
+        }
+    };
+
+    finestra.value.with(&show);
+    finestra.value.add_listener_with_origin(show.clone(), StateChangeOrigin::Owner(view_id));
+    show
+}
+
+pub fn attach_image_view_state<S>(finestra: &ImageView<S>, gtk: &gtk::Picture) {
+    attach_base_state(finestra, gtk);
+}
+
+pub fn attach_label_state<S>(view_id: ViewId, finestra: &Label<S>, gtk: &gtk::Label) {
+    attach_base_state(finestra, gtk);
+
+    let style = WidgetStyle::new(view_id, gtk);
+    hook_background_color_state(&style, &finestra.background_color);
+    hook_text_color_state(&style, &finestra.text_color);
+
+    let label = gtk.clone();
+    hook_text_state(view_id, &finestra.text, move |text| label.set_label(text));
+}
+
+pub fn attach_link_state<S>(view_id: ViewId, finestra: &Link<S>, gtk: &gtk::LinkButton) {
+    attach_base_state(finestra, gtk);
+
+    finestra.text.with(|text| gtk.set_label(text));
+    let link = gtk.clone();
+    hook_text_state(view_id, &finestra.text, move |text| link.set_label(text));
+
+    let Some(url) = &finestra.url else {
+        return;
+    };
+
+    url.with(|url| gtk.set_uri(url));
+    let link = gtk.clone();
+    hook_text_state(view_id, url, move |url| link.set_uri(url));
+}
+
+pub fn attach_text_block_state<S>(view_id: ViewId, finestra: &TextBlock<S>, gtk: &gtk::Label) {
+    attach_base_state(finestra, gtk);
+
+    let style = WidgetStyle::new(view_id, gtk);
+    hook_background_color_state(&style, &finestra.background_color);
+    hook_text_color_state(&style, &finestra.text_color);
+    hook_text_alignment_state(gtk, &finestra.alignment);
+
+    let label = gtk.clone();
+    hook_text_state(view_id, &finestra.text, move |text| label.set_label(text));
+}
+
+pub fn attach_list_state<T, S, D>(view_id: ViewId, finestra: &List<T, S, D>, gtk: &gtk::ListView, model: &gtk::SelectionModel, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+    hook_selection_state(view_id, model, &finestra.selection, guard);
+}
+
+/// The `spinner` is shown instead of the bar while the progress is unknown.
+pub fn attach_progress_bar_state<S>(finestra: &ProgressBar<S>, gtk: &gtk::ProgressBar, spinner: Option<&gtk::Spinner>) {
+    attach_base_state(finestra, gtk);
+
+    let bar = gtk.clone();
+    let spinner = spinner.cloned();
+    let show = move |value: Option<f64>| {
+        if let Some(value) = value {
+            bar.set_fraction(value.clamp(0.0, 1.0));
+        }
+
+        if let Some(spinner) = &spinner {
+            spinner.set_spinning(value.is_none());
+            spinner.set_visible(value.is_none());
+            bar.set_visible(value.is_some());
+        }
+    };
+
+    show(finestra.value.clone_inner());
+
+    let StateOrRaw::State(value_state) = &finestra.value else {
+        return;
+    };
+
+    value_state.add_listener(move |val| show(*val));
+}
+
+pub fn attach_scroll_view_state<S, D>(view_id: ViewId, finestra: &ScrollView<S, D>, gtk: &gtk::ScrolledWindow, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+    hook_scroll_offset_state(view_id, &gtk.hadjustment(), &finestra.horizontal_offset, guard);
+    hook_scroll_offset_state(view_id, &gtk.vadjustment(), &finestra.vertical_offset, guard);
+}
+
+/// The text of the entry is bound to the state of the editable select.
+pub fn attach_combo_box_state<T, S>(view_id: ViewId, finestra: &Select<T, S>, gtk: &gtk::ComboBoxText, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let Some(entry) = gtk.child().and_downcast::<gtk::Entry>() else {
+        return;
+    };
+
+    let text = StateOrRaw::State(finestra.text.clone().unwrap_or_default());
+    text.with(|text| entry.set_text(text));
+
+    let guard = guard.clone();
+    hook_text_state(view_id, &text, move |text| {
+        guard.run(|| entry.set_text(text));
+    });
+}
+
+pub fn attach_select_state<T, S>(view_id: ViewId, finestra: &Select<T, S>, gtk: &gtk::DropDown, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let drop_down = gtk.clone();
+    let guard = guard.clone();
+    let select = move |index: Option<usize>| {
+        guard.run(|| drop_down.set_selected(index.map_or(gtk::INVALID_LIST_POSITION, |index| index as u32)));
+    };
+
+    select(finestra.selection.clone_inner());
+    finestra.selection.add_listener_with_origin(move |index| select(*index), StateChangeOrigin::Owner(view_id));
+}
+
+/// The `buttons` are the radio buttons of the options, in the same order.
+pub fn attach_radio_group_state<T, S>(view_id: ViewId, finestra: &RadioGroup<T, S>, gtk: &gtk::Box, buttons: &[gtk::CheckButton], guard: &SignalGuard)
+        where T: PartialEq + Clone + 'static {
+    attach_base_state(finestra, gtk);
+
+    for (button, option) in buttons.iter().zip(&finestra.options) {
+        button.set_sensitive(option.enabled.clone_inner());
+        if let StateOrRaw::State(enabled_state) = &option.enabled {
+            let button = button.clone();
+            enabled_state.add_listener(move |val| button.set_sensitive(*val));
+        }
+    }
+
+    let buttons = buttons.to_vec();
+    let values: Vec<_> = finestra.options.iter().map(|option| option.value.clone()).collect();
+    let guard = guard.clone();
+    let show = move |selected: &T| {
+        guard.run(|| {
+            for (button, value) in buttons.iter().zip(&values) {
+                button.set_active(value == selected);
+            }
+        });
+    };
+
+    finestra.selection.with(&show);
+    finestra.selection.add_listener_with_origin(show, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_slider_state<S>(view_id: ViewId, finestra: &Slider<S>, gtk: &gtk::Scale, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let slider = gtk.clone();
+    let guard = guard.clone();
+    let set_value = move |value: f64| {
+        guard.run(|| slider.set_value(value));
+    };
+
+    set_value(finestra.value.clone_inner());
+    finestra.value.add_listener_with_origin(move |val| set_value(*val), StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_stepper_state<T: StepperValue, S>(view_id: ViewId, finestra: &Stepper<T, S>, gtk: &gtk::SpinButton, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let spin_button = gtk.clone();
+    let guard = guard.clone();
+    let set_value = move |value: &T| {
+        guard.run(|| spin_button.set_value(value.to_f64()));
+    };
+
+    finestra.value.with(&set_value);
+    finestra.value.add_listener_with_origin(set_value, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_switch_state<S>(view_id: ViewId, finestra: &Switch<S>, gtk: &gtk::Box, label: &gtk::Label, switch: &gtk::Switch, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let label = label.clone();
+    hook_text_state(view_id, &finestra.text, move |text| label.set_label(text));
+
+    let switch = switch.clone();
+    let guard = guard.clone();
+    let set_active = move |is_active: bool| {
+        guard.run(|| switch.set_active(is_active));
+    };
+
+    set_active(finestra.checked.clone_inner());
+    finestra.checked.add_listener_with_origin(move |val| set_active(*val), StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_tab_view_state<S, D>(view_id: ViewId, finestra: &TabView<S, D>, gtk: &gtk::Notebook, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+
+    let notebook = gtk.clone();
+    let guard = guard.clone();
+    let select = move |index: usize| {
+        guard.run(|| notebook.set_current_page(Some(index as u32)));
+    };
+
+    select(finestra.selection.clone_inner());
+    finestra.selection.add_listener_with_origin(move |index| select(*index), StateChangeOrigin::Owner(view_id));
+}
+
+/// The `selection` contains the rows in the order of the sort, instead of the
+/// selection of the `finestra` table, which contains the indices of the items.
+pub fn attach_table_state<T, S, D>(
+    view_id: ViewId,
+    finestra: &Table<T, S, D>,
+    selection: &ListSelection,
+    gtk: &gtk::ColumnView,
+    columns: &[gtk::ColumnViewColumn],
+    model: &gtk::SelectionModel,
+    guard: &SignalGuard,
+) {
+    attach_base_state(finestra, gtk);
+    hook_selection_state(view_id, model, selection, guard);
+    hook_sort_state(view_id, gtk, columns, &finestra.sort, guard);
+}
+
+/// The caret is the "insert" mark of the buffer, and the anchor is the
+/// "selection_bound" mark.
+pub fn attach_text_editor_state<S>(view_id: ViewId, finestra: &TextEditor<S>, gtk: &gtk::TextView, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+    hook_text_view_editable_state(gtk, &finestra.editable);
+
+    let buffer = gtk.buffer();
+    finestra.text.with(|text| buffer.set_text(text));
+
+    if let Some(selection) = &finestra.selection {
+        let buffer = buffer.clone();
+        let select = move |selection: &TextSelection| {
+            let iter = |offset: usize| buffer.iter_at_offset(offset.try_into().unwrap_or(i32::MAX));
+            buffer.select_range(&iter(selection.caret), &iter(selection.anchor));
+        };
+
+        select(&selection.clone_inner());
+
+        let guard = guard.clone();
+        selection.add_listener_with_origin(move |val| {
+            guard.run(|| select(val));
+        }, StateChangeOrigin::Owner(view_id));
+    }
+
+    let guard = guard.clone();
+    hook_text_state(view_id, &finestra.text, move |text| {
+        guard.run(|| buffer.set_text(text));
+    });
+}
+
+pub fn attach_text_field_state<S>(view_id: ViewId, finestra: &TextField<S>, gtk: &gtk::Entry, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+    hook_placeholder_state(gtk, &finestra.placeholder_text);
+
+    // Input methods don't suggest or learn the text of a password, and the
+    // text of an invisible entry can't be copied.
+    if finestra.secure {
+        gtk.set_input_purpose(gtk::InputPurpose::Password);
+        gtk.set_input_hints(gtk::InputHints::NO_SPELLCHECK | gtk::InputHints::PRIVATE);
+        hook_reveal_state(gtk, &finestra.reveal);
+    }
+
+    if let Some(validation) = &finestra.validation {
+        hook_validation_error_state(gtk, &validation.error, &finestra.base().tooltip);
+    }
+
+    let entry = gtk.clone();
+    let guard = guard.clone();
+    hook_text_state(view_id, &finestra.text, move |text| {
+        guard.run(|| entry.set_text(text));
+    });
+}
+
+/// The clear button is only shown when there is text to clear, and the recent
+/// searches are suggested using a completion.
+pub fn attach_search_field_state<S>(view_id: ViewId, finestra: &SearchField<S>, gtk: &gtk::Entry, guard: &SignalGuard) {
+    attach_base_state(finestra, gtk);
+    hook_placeholder_state(gtk, &finestra.placeholder_text);
+
+    show_clear_icon(gtk);
+    gtk.connect_changed(show_clear_icon);
+
+    if let Some(history) = &finestra.history {
+        hook_history_state(gtk, history);
+    }
+
+    let entry = gtk.clone();
+    let guard = guard.clone();
+    hook_text_state(view_id, &finestra.text, move |text| {
+        guard.run(|| entry.set_text(text));
+    });
+}
+
+fn hook_background_color_state(style: &WidgetStyle, color: &StateOrRaw<Color>) {
+    style.set_background_color(&color.clone_inner());
+
+    let StateOrRaw::State(color_state) = &color else {
+        return;
+    };
+
+    let style = style.clone();
+    color_state.add_listener(move |val| {
+        style.set_background_color(val);
+    });
+}
+
+fn hook_history_state(entry: &gtk::Entry, history: &State<Vec<String>>) {
+    let store = gtk::ListStore::new(&[gtk::glib::Type::STRING]);
+    let completion = gtk::EntryCompletion::new();
+    completion.set_text_column(0);
+    completion.set_minimum_key_length(0);
+    completion.set_model(Some(&store));
+    entry.set_completion(Some(&completion));
+
+    let fill = move |recent: &Vec<String>| {
+        store.clear();
+        for search in recent {
+            store.insert_with_values(None, &[(0, search)]);
+        }
+    };
+
+    history.with(&fill);
+    history.add_listener(fill);
+}
+
+fn hook_placeholder_state(entry: &gtk::Entry, text: &StateOrRaw<String>) {
+    text.with(|val| entry.set_placeholder_text(Some(val)));
+
+    let StateOrRaw::State(text_state) = &text else {
+        return;
+    };
+
+    let entry = entry.clone();
+    text_state.add_listener(move |val| {
+        entry.set_placeholder_text(Some(val));
+    });
+}
+
+fn hook_reveal_state(entry: &gtk::Entry, reveal: &StateOrRaw<bool>) {
+    entry.set_visibility(reveal.clone_inner());
+
+    let StateOrRaw::State(reveal_state) = &reveal else {
+        return;
+    };
+
+    let entry = entry.clone();
+    reveal_state.add_listener(move |val| {
+        entry.set_visibility(*val);
+    });
+}
+
+fn hook_text_alignment_state(label: &gtk::Label, alignment: &StateOrRaw<TextAlignment>) {
+    label.set_justify(alignment.clone_inner().to_gtk());
+
+    let StateOrRaw::State(alignment_state) = &alignment else {
+        return;
+    };
+
+    let label = label.clone();
+    alignment_state.add_listener(move |val| {
+        label.set_justify(val.to_gtk());
+    });
+}
+
+fn hook_text_color_state(style: &WidgetStyle, color: &StateOrRaw<Color>) {
+    style.set_text_color(&color.clone_inner());
+
+    let StateOrRaw::State(color_state) = &color else {
+        return;
+    };
+
+    let style = style.clone();
+    color_state.add_listener(move |val| {
+        style.set_text_color(val);
+    });
+}
+
+fn hook_selection_state(view_id: ViewId, model: &gtk::SelectionModel, selection: &ListSelection, guard: &SignalGuard) {
+    let model = model.clone();
+    let guard = guard.clone();
+    let select = move |rows: Vec<usize>| {
+        guard.run(|| {
+            model.unselect_all();
+            for row in rows {
+                model.select_item(row as u32, false);
+            }
+        });
+    };
+
+    select(selection.rows());
+    selection.add_listener(select, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_sort_state(view_id: ViewId, gtk: &gtk::ColumnView, columns: &[gtk::ColumnViewColumn], sort: &State<Option<TableSort>>, guard: &SignalGuard) {
+    let column_view = gtk.clone();
+    let columns = columns.to_vec();
+    let guard = guard.clone();
+    let show_sort = move |sort: Option<TableSort>| {
+        let column = sort.and_then(|sort| columns.get(sort.column));
+        let order = match sort.map(|sort| sort.order) {
+            Some(SortOrder::Descending) => gtk::SortType::Descending,
+            _ => gtk::SortType::Ascending,
+        };
+
+        guard.run(|| column_view.sort_by_column(column, order));
+    };
+
+    show_sort(sort.clone_inner());
+    sort.add_listener_with_origin(move |sort| show_sort(*sort), StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_scroll_offset_state(view_id: ViewId, adjustment: &gtk::Adjustment, offset: &StateOrRaw<f32>, guard: &SignalGuard) {
+    // The adjustment clamps the value to the size of the content, which isn't
+    // known until the content is allocated, so the offset is applied again
+    // when the size changes.
+    let guard = guard.clone();
+    let set_value = move |adjustment: &gtk::Adjustment, value: f32| {
+        guard.run(|| adjustment.set_value(f64::from(value)));
+    };
+
+    set_value(adjustment, offset.clone_inner());
+
+    let initial = offset.clone_inner();
+    let state = offset.as_state();
+    let set = set_value.clone();
+    adjustment.connect_changed(move |adjustment| {
+        set(adjustment, state.as_ref().map_or(initial, State::clone_inner));
+    });
+
+    let StateOrRaw::State(offset_state) = &offset else {
+        return;
+    };
+
+    let adjustment = adjustment.clone();
+    offset_state.add_listener_with_origin(move |val| {
+        set_value(&adjustment, *val);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+/// A read-only text view doesn't show the caret, but the text can still be
+/// selected.
+fn hook_text_view_editable_state(text_view: &gtk::TextView, editable: &StateOrRaw<bool>) {
+    let set_editable = {
+        let text_view = text_view.clone();
+        move |editable: bool| {
+            text_view.set_editable(editable);
+            text_view.set_cursor_visible(editable);
+        }
+    };
+
+    set_editable(editable.clone_inner());
+
+    let StateOrRaw::State(editable_state) = &editable else {
+        return;
+    };
+
+    editable_state.add_listener(move |val| set_editable(*val));
+}
+
+/// Invalid text is marked using the `error` style class of the theme, and the
+/// message replaces the tooltip until the text is valid again.
+fn hook_validation_error_state(entry: &gtk::Entry, error: &State<Option<String>>, tooltip: &StateOrRaw<String>) {
+    let entry = entry.clone();
+    let tooltip = tooltip.clone();
+    error.add_listener(move |error| {
+        if let Some(message) = error {
+            entry.add_css_class("error");
+            entry.set_tooltip_text(Some(message));
+        } else {
+            entry.remove_css_class("error");
+            let tooltip = tooltip.clone_inner();
+            entry.set_tooltip_text(Some(&tooltip).filter(|tooltip| !tooltip.is_empty()).map(String::as_str));
+        }
+    });
+}
+
+fn show_clear_icon(entry: &gtk::Entry) {
+    let icon = if entry.text().is_empty() { None } else { Some("edit-clear-symbolic") };
+    entry.set_icon_from_icon_name(gtk::EntryIconPosition::Secondary, icon);
+}
+
+fn hook_text_state(view_id: ViewId, text: &StateOrRaw<String>, set_text: impl Fn(&str) + 'static) {
+    let StateOrRaw::State(text_state) = &text else {
+        return;
+    };
+
+    text_state.add_listener_with_origin(move |val| {
+        set_text(val);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_tooltip_state(widget: &impl IsA<gtk::Widget>, text: &StateOrRaw<String>) {
+    text.with(|val| {
+        if !val.is_empty() {
+            widget.set_tooltip_text(Some(val));
+        }
+    });
+
+    let StateOrRaw::State(text_state) = &text else {
+        return;
+    };
+
+    let widget = widget.clone().upcast::<gtk::Widget>();
+    text_state.add_listener(move |val| {
+        widget.set_tooltip_text(Some(val));
+    });
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::ViewHandleApi;
+
+/// The native widget of a [`View`](crate::View) on the
+/// [`UIBackend::Gtk`](crate::UIBackend::Gtk).
+#[derive(Clone, Debug)]
+pub struct GtkView {
+    widget: gtk::Widget,
+}
+
+impl GtkView {
+    #[must_use]
+    pub(crate) fn widget(&self) -> &gtk::Widget {
+        &self.widget
+    }
+}
+
+impl<W: IsA<gtk::Widget>> From<W> for GtkView {
+    fn from(value: W) -> Self {
+        Self {
+            widget: value.upcast(),
+        }
+    }
+}
+
+impl ViewHandleApi for GtkView {
+    fn text(&self) -> String {
+        let widget = &self.widget;
+
+        if let Some(label) = widget.downcast_ref::<gtk::Label>() {
+            label.label().to_string()
+        } else if let Some(button) = widget.downcast_ref::<gtk::Button>() {
+            button.label().map(|label| label.to_string()).unwrap_or_default()
+        } else if let Some(checkbox) = widget.downcast_ref::<gtk::CheckButton>() {
+            checkbox.label().map(|label| label.to_string()).unwrap_or_default()
+        } else if let Some(editable) = widget.dynamic_cast_ref::<gtk::Editable>() {
+            editable.text().to_string()
+        } else if let Some(text_view) = widget.downcast_ref::<gtk::TextView>() {
+            let buffer = text_view.buffer();
+            buffer.text(&buffer.start_iter(), &buffer.end_iter(), false).to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    fn set_text(&self, text: &str) {
+        let widget = &self.widget;
+
+        if let Some(label) = widget.downcast_ref::<gtk::Label>() {
+            label.set_label(text);
+        } else if let Some(button) = widget.downcast_ref::<gtk::Button>() {
+            button.set_label(text);
+        } else if let Some(checkbox) = widget.downcast_ref::<gtk::CheckButton>() {
+            checkbox.set_label(Some(text));
+        } else if let Some(editable) = widget.dynamic_cast_ref::<gtk::Editable>() {
+            editable.set_text(text);
+        } else if let Some(text_view) = widget.downcast_ref::<gtk::TextView>() {
+            text_view.buffer().set_text(text);
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.widget.is_sensitive()
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        self.widget.set_sensitive(enabled);
+    }
+
+    fn focus(&self) {
+        self.widget.grab_focus();
+    }
+
+    fn scroll_into_view(&self) {
+        let Some(scrolled_window) = self.widget.ancestor(gtk::ScrolledWindow::static_type()) else {
+            return;
+        };
+        let scrolled_window = scrolled_window.downcast::<gtk::ScrolledWindow>().unwrap();
+
+        let Some((x, y)) = self.widget.translate_coordinates(&scrolled_window, 0.0, 0.0) else {
+            return;
+        };
+
+        let width = f64::from(self.widget.width());
+        let height = f64::from(self.widget.height());
+        scroll_adjustment(&scrolled_window.hadjustment(), x, x + width);
+        scroll_adjustment(&scrolled_window.vadjustment(), y, y + height);
+    }
+
+    fn invalidate(&self) {
+        self.widget.queue_draw();
+    }
+}
+
+/// Scroll by the smallest distance that makes the range `start..end`, which is
+/// relative to the visible area, visible.
+fn scroll_adjustment(adjustment: &gtk::Adjustment, start: f64, end: f64) {
+    let page_size = adjustment.page_size();
+
+    let delta = if start < 0.0 {
+        start
+    } else if end > page_size {
+        (end - page_size).min(start)
+    } else {
+        return;
+    };
+
+    adjustment.set_value(adjustment.value() + delta);
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::borrow::Cow;
+
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::{ColorDialogBuilder, DialogBuilder, Theme, WindowDelegator};
+
+use super::dialog::{GtkColorDialog, GtkDialog};
+
+pub(crate) struct GtkWindowDelegator {
+    pub(crate) window: gtk::ApplicationWindow,
+}
+
+impl WindowDelegator for GtkWindowDelegator {
+    fn create_dialog(&self, text: Cow<'static, str>) -> DialogBuilder {
+        let title = self.window.title().map(|title| title.to_string()).unwrap_or_default();
+        DialogBuilder::new(Box::new(GtkDialog::new(self.window.clone(), text.into_owned(), title)))
+    }
+
+    fn pick_color(&self) -> ColorDialogBuilder {
+        let title = self.window.title().map(|title| title.to_string()).unwrap_or_default();
+        ColorDialogBuilder::new(Box::new(GtkColorDialog::new(self.window.clone(), title)))
+    }
+
+    fn close(&self) {
+        self.window.close();
+    }
+}
+
+/// GTK doesn't have a per-window theme, so the preference is applied to the
+/// whole application. The system theme is used unless dark mode is requested.
+pub(crate) fn set_theme(theme: Theme) {
+    let Some(settings) = gtk::Settings::default() else {
+        return;
+    };
+
+    settings.set_gtk_application_prefer_dark_theme(theme == Theme::Dark);
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Arc;
+
+use crate::event::{EventHandlerMapRegistry, ViewTree};
+use crate::{App, AppContext, AppDelegate, LayoutSize, View, Window};
+
+use super::context::HeadlessContext;
+use super::state::Event;
+use super::view::HeadlessView;
+use super::window::{HeadlessWindow, HeadlessWindowDelegator};
+
+/// Drives an [`App`] without a window system. The lifecycle of the
+/// [`AppDelegate`] is invoked in the same order as on the native backends, and
+/// the content view is built as a tree of [`HeadlessViews`](HeadlessView).
+pub(crate) struct HeadlessApp<Delegate, State: 'static> {
+    pub(super) delegate: Delegate,
+    state: State,
+    event_registry: EventHandlerMapRegistry<State>,
+    window: HeadlessWindow,
+    delegator: Window,
+    root: HeadlessView,
+}
+
+impl<Delegate, State: 'static> HeadlessApp<Delegate, State>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Launch the app: this invokes the [`AppDelegate`] up until (and
+    /// including) [`AppDelegate::will_show_window()`].
+    pub(crate) fn launch(app: App<Delegate, State>) -> Self {
+        AppContext::initialize(&app);
+        HeadlessContext::reset();
+
+        let App { mut delegate, mut state, .. } = app;
+
+        delegate.did_launch(&mut state);
+        let config = delegate.configure_main_window(&mut state);
+
+        let window = HeadlessWindow::new(config);
+        #[allow(clippy::arc_with_non_send_sync)]
+        let delegator = Window::new(Arc::new(HeadlessWindowDelegator {
+            window: window.clone(),
+        }));
+
+        let event_registry = EventHandlerMapRegistry::default();
+        let root = {
+            let mut content_view = delegate.make_content_view(&mut state, delegator.clone());
+
+            let mut tree = ViewTree::headless(event_registry.clone());
+            let parent_id = tree.exchange_events_for_id(Default::default());
+            tree.set_parent_id(parent_id);
+            content_view.build_headless(&mut tree)
+        };
+
+        delegate.will_show_window(delegator.clone(), &mut state);
+
+        let this = Self {
+            delegate,
+            state,
+            event_registry,
+            window,
+            delegator,
+            root,
+        };
+        this.layout();
+        this
+    }
+
+    /// Runs the event loop, until the app requested to exit (e.g. by using
+    /// [`Window::close()`]), or when there is no work left. Returns the exit
+    /// code.
+    pub(crate) fn run(&mut self) -> i32 {
+        loop {
+            self.process_events();
+
+            if let Some(code) = HeadlessContext::exit_code() {
+                return code;
+            }
+
+            if !HeadlessContext::fire_next_timer() {
+                return 0;
+            }
+        }
+    }
+
+    /// Handles the events that are queued, without advancing the clock.
+    pub(crate) fn process_events(&mut self) {
+        while HeadlessContext::exit_code().is_none() {
+            let Some(event) = HeadlessContext::next_event() else {
+                break;
+            };
+
+            self.handle_event(event);
+        }
+
+        // The events could have changed the contents of the views.
+        self.layout();
+    }
+
+    /// Computes the frames of the views, using the size of the window.
+    pub(crate) fn layout(&self) {
+        let size = self.window.with(|data| LayoutSize::new(data.width, data.height));
+        self.root.layout(size);
+    }
+
+    #[must_use]
+    pub(crate) fn delegate(&self) -> &Delegate {
+        &self.delegate
+    }
+
+    #[must_use]
+    pub(crate) fn root(&self) -> &HeadlessView {
+        &self.root
+    }
+
+    /// The number of views that have event handlers registered.
+    #[cfg(test)]
+    pub(crate) fn event_handler_count(&self) -> usize {
+        self.event_registry.map.len()
+    }
+
+    #[must_use]
+    pub(crate) fn window(&self) -> &HeadlessWindow {
+        &self.window
+    }
+
+    #[must_use]
+    pub(crate) fn state(&self) -> &State {
+        &self.state
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        let window = self.delegator.clone();
+
+        match event {
+            Event::ButtonClicked(view_id) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.click.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, window);
+            }
+
+            Event::CheckboxChanged(view_id, is_checked) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.checked.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, is_checked, window);
+            }
+
+            Event::ListRowActivated(view_id, row) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.activate.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, row, window);
+            }
+
+            Event::SelectionChanged(view_id, index) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.selection_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, index, window);
+            }
+
+            Event::ValueChanged(view_id, value) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.value_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, value, window);
+            }
+
+            Event::ColorChanged(view_id, color) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.color_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, color, window);
+            }
+
+            Event::MenuAction { item } => {
+                self.delegate.did_invoke_menu_action(item, &mut self.state, window);
+            }
+
+            Event::TextFieldChanged(view_id, text) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.text_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut self.state, text.text, window);
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::{Cursor, ViewId};
+
+use super::state::Event;
+
+thread_local! {
+    static CURRENT: RefCell<HeadlessContext> = RefCell::new(HeadlessContext::default());
+}
+
+type TimerAction = Box<dyn FnOnce()>;
+
+/// The event loop state of the headless backend. Since there is no platform
+/// that owns the event loop, the events and timers are queued on the thread
+/// that runs the app.
+///
+/// Time is virtual: when the event queue is drained, the clock jumps to the
+/// next timer, which makes the timers deterministic and instant.
+#[derive(Default)]
+pub(crate) struct HeadlessContext {
+    events: VecDeque<Event>,
+    timers: Vec<ScheduledTimer>,
+    timer_sequence: usize,
+    now: Duration,
+    exit_code: Option<i32>,
+    cursors: Vec<Cursor>,
+    focused: Option<ViewId>,
+    opened_urls: Vec<String>,
+}
+
+impl HeadlessContext {
+    fn with_current<F: FnOnce(&mut HeadlessContext) -> R, R>(f: F) -> R {
+        CURRENT.with_borrow_mut(f)
+    }
+
+    /// Clears all the state of a previous app that ran on this thread.
+    pub(crate) fn reset() {
+        Self::with_current(|ctx| *ctx = Self::default());
+    }
+
+    pub(crate) fn dispatch(event: Event) {
+        Self::with_current(|ctx| ctx.events.push_back(event));
+    }
+
+    pub(crate) fn next_event() -> Option<Event> {
+        Self::with_current(|ctx| ctx.events.pop_front())
+    }
+
+    pub(crate) fn request_exit(code: i32) {
+        Self::with_current(|ctx| {
+            ctx.exit_code.get_or_insert(code);
+        });
+    }
+
+    #[must_use]
+    pub(crate) fn exit_code() -> Option<i32> {
+        Self::with_current(|ctx| ctx.exit_code)
+    }
+
+    pub(crate) fn push_cursor(cursor: Cursor) {
+        Self::with_current(|ctx| ctx.cursors.push(cursor));
+    }
+
+    pub(crate) fn pop_cursor() {
+        Self::with_current(|ctx| ctx.cursors.pop());
+    }
+
+    /// Remembers the `url` instead of opening it, as there might not be a
+    /// browser.
+    pub(crate) fn open_url(url: &str) {
+        Self::with_current(|ctx| ctx.opened_urls.push(url.to_owned()));
+    }
+
+    #[must_use]
+    pub(crate) fn opened_urls() -> Vec<String> {
+        Self::with_current(|ctx| ctx.opened_urls.clone())
+    }
+
+    /// Set the view that has the keyboard focus.
+    pub(crate) fn set_focused(view_id: ViewId) {
+        Self::with_current(|ctx| ctx.focused = Some(view_id));
+    }
+
+    #[must_use]
+    pub(crate) fn focused() -> Option<ViewId> {
+        Self::with_current(|ctx| ctx.focused)
+    }
+
+    pub(crate) fn schedule(delay: Duration, action: TimerAction) {
+        Self::with_current(|ctx| {
+            ctx.timer_sequence += 1;
+            let timer = ScheduledTimer {
+                due: ctx.now + delay,
+                sequence: ctx.timer_sequence,
+                action,
+            };
+            ctx.timers.push(timer);
+        });
+    }
+
+    #[must_use]
+    pub(crate) fn now() -> Duration {
+        Self::with_current(|ctx| ctx.now)
+    }
+
+    /// Advances the clock to `time`, without invoking the timers that are due
+    /// by then, see [`fire_next_timer_due_by()`](Self::fire_next_timer_due_by).
+    pub(crate) fn advance_clock_to(time: Duration) {
+        Self::with_current(|ctx| ctx.now = ctx.now.max(time));
+    }
+
+    /// Advances the clock to the first timer that is due, and invokes it.
+    /// Returns `false` if there are no timers scheduled.
+    pub(crate) fn fire_next_timer() -> bool {
+        Self::fire_next_timer_due_by(Duration::MAX)
+    }
+
+    /// Like [`fire_next_timer()`](Self::fire_next_timer), but only if the
+    /// timer is due by the `deadline`.
+    pub(crate) fn fire_next_timer_due_by(deadline: Duration) -> bool {
+        let timer = Self::with_current(|ctx| {
+            let idx = ctx.timers.iter()
+                .enumerate()
+                .filter(|(_, timer)| timer.due <= deadline)
+                .min_by_key(|(_, timer)| (timer.due, timer.sequence))
+                .map(|(idx, _)| idx)?;
+
+            let timer = ctx.timers.remove(idx);
+            ctx.now = ctx.now.max(timer.due);
+            Some(timer)
+        });
+
+        let Some(timer) = timer else {
+            return false;
+        };
+
+        // Invoke the action outside of the borrow, as it can schedule other
+        // timers or dispatch events.
+        (timer.action)();
+        true
+    }
+}
+
+struct ScheduledTimer {
+    due: Duration,
+    sequence: usize,
+    action: TimerAction,
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+use crate::{Color, ColorDialogApi, DialogApi, DialogKind};
+
+use super::window::{HeadlessColorDialogRecord, HeadlessDialogRecord, HeadlessWindow};
+
+/// A dialog that isn't presented, but is recorded in the [`HeadlessWindow`]
+/// when shown.
+pub(crate) struct HeadlessDialog {
+    window: HeadlessWindow,
+    record: RefCell<HeadlessDialogRecord>,
+}
+
+impl HeadlessDialog {
+    pub fn new(window: HeadlessWindow, text: String, title: String) -> Self {
+        Self {
+            window,
+            record: RefCell::new(HeadlessDialogRecord {
+                kind: DialogKind::Informational,
+                title,
+                text,
+            }),
+        }
+    }
+}
+
+impl DialogApi for HeadlessDialog {
+    fn set_kind(&self, kind: DialogKind) {
+        self.record.borrow_mut().kind = kind;
+    }
+
+    fn set_text(&self, text: Cow<'static, str>) {
+        self.record.borrow_mut().text = text.into_owned();
+    }
+
+    fn set_title(&self, title: Cow<'static, str>) {
+        self.record.borrow_mut().title = title.into_owned();
+    }
+
+    fn show(&self) {
+        let record = self.record.borrow().clone();
+        self.window.with_mut(|data| data.dialogs.push(record));
+    }
+}
+
+/// A color dialog that isn't presented, but is kept open in the
+/// [`HeadlessWindow`] until the test picks a color or cancels it.
+pub(crate) struct HeadlessColorDialog {
+    window: HeadlessWindow,
+    title: RefCell<String>,
+    color: RefCell<Color>,
+    alpha: RefCell<bool>,
+}
+
+impl HeadlessColorDialog {
+    pub fn new(window: HeadlessWindow, title: String) -> Self {
+        Self {
+            window,
+            title: RefCell::new(title),
+            color: RefCell::new(Color::default()),
+            alpha: RefCell::new(true),
+        }
+    }
+}
+
+impl ColorDialogApi for HeadlessColorDialog {
+    fn set_alpha(&self, alpha: bool) {
+        *self.alpha.borrow_mut() = alpha;
+    }
+
+    fn set_color(&self, color: Color) {
+        *self.color.borrow_mut() = color;
+    }
+
+    fn set_title(&self, title: Cow<'static, str>) {
+        *self.title.borrow_mut() = title.into_owned();
+    }
+
+    fn show(&self, on_pick: Box<dyn Fn(Color)>) {
+        let record = HeadlessColorDialogRecord {
+            title: self.title.borrow().clone(),
+            color: self.color.borrow().clone(),
+            alpha: *self.alpha.borrow(),
+            on_pick: on_pick.into(),
+        };
+        self.window.with_mut(|data| data.color_dialog = Some(record));
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+mod app;
+mod context;
+mod dialog;
+pub(crate) mod state;
+mod view;
+mod window;
+
+pub(crate) use self::app::HeadlessApp;
+pub(crate) use self::context::HeadlessContext;
+pub(crate) use self::state::Event;
+pub(crate) use self::view::{HeadlessColumn, HeadlessTab, HeadlessView, HeadlessViewKind};
+
+use crate::{App, AppDelegate, Timer};
+
+pub(crate) fn run_app<Delegate, State>(app: App<Delegate, State>) -> !
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    let code = run_app_until_exit(app);
+    std::process::exit(code);
+}
+
+pub(crate) fn run_app_until_exit<Delegate, State>(app: App<Delegate, State>) -> i32
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    HeadlessApp::launch(app).run()
+}
+
+pub(crate) fn schedule_timer<F: FnOnce() + 'static>(timer: Timer<F>) {
+    HeadlessContext::schedule(timer.delay, timer.action);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::*;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct LifecycleApp {
+        calls: Vec<&'static str>,
+    }
+
+    impl AppDelegate for LifecycleApp {
+        fn did_launch(&mut self, _: &mut ()) {
+            self.calls.push("did_launch");
+        }
+
+        fn configure_main_window(&mut self, _: &mut ()) -> WindowConfiguration {
+            self.calls.push("configure_main_window");
+            WindowConfiguration::new()
+                .with_title("Headless")
+        }
+
+        fn make_content_view(&mut self, _: &mut (), _: Window) -> impl View<Self> {
+            self.calls.push("make_content_view");
+            Label::new("Hello, world!")
+        }
+
+        fn will_show_window(&mut self, _: Window, _: &mut ()) {
+            self.calls.push("will_show_window");
+        }
+    }
+
+    #[derive(Default)]
+    struct CounterApp;
+
+    #[derive(Default)]
+    struct CounterState {
+        count: usize,
+        label: TextValue,
+        checked: Option<bool>,
+        text: TextValue,
+        changed_text: Option<String>,
+    }
+
+    impl AppDelegate<CounterState> for CounterApp {
+        fn make_content_view(&mut self, state: &mut CounterState, _: Window) -> impl View<Self, CounterState> {
+            state.label.set("Count: 0");
+
+            Stack::vertical()
+                .with(Label::new(&state.label))
+                .with(Button::new("Increment")
+                    .with_on_click(|state: &mut CounterState, _| {
+                        state.count += 1;
+                        state.label.set(format!("Count: {}", state.count));
+                    }))
+                .with(Checkbox::new("Check")
+                    .with_on_checked(|state: &mut CounterState, checked, _| {
+                        state.checked = Some(checked);
+                    }))
+                .with(TextField::new(&state.text)
+                    .with_on_change(|state: &mut CounterState, text, _| {
+                        state.changed_text = Some(text);
+                    }))
+        }
+    }
+
+    fn find_kind(root: &HeadlessView, kind: HeadlessViewKind) -> HeadlessView {
+        root.find(&|view| view.kind() == kind).unwrap()
+    }
+
+    #[test]
+    fn delegate_is_invoked_in_order() {
+        let app = HeadlessApp::launch(App::new(LifecycleApp::default()));
+
+        assert_eq!(app.delegate.calls, [
+            "did_launch",
+            "configure_main_window",
+            "make_content_view",
+            "will_show_window",
+        ]);
+
+        assert_eq!(app.window().with(|data| data.title.clone()), "Headless");
+        assert_eq!(app.root().kind(), HeadlessViewKind::Label);
+        assert_eq!(app.root().with(|data| data.text.clone()), "Hello, world!");
+    }
+
+    #[test]
+    fn state_changes_are_applied_to_tree() {
+        let app = HeadlessApp::launch(App::with_state(CounterApp, CounterState::default()));
+        let label = find_kind(app.root(), HeadlessViewKind::Label);
+        assert_eq!(label.with(|data| data.text.clone()), "Count: 0");
+
+        app.state().label.set("Changed");
+        assert_eq!(label.with(|data| data.text.clone()), "Changed");
+    }
+
+    #[test]
+    fn click_invokes_event_handler() {
+        let mut app = HeadlessApp::launch(App::with_state(CounterApp, CounterState::default()));
+        let button = find_kind(app.root(), HeadlessViewKind::Button);
+
+        assert!(button.perform_action());
+        assert!(button.perform_action());
+        app.process_events();
+
+        assert_eq!(app.state().count, 2);
+        let label = find_kind(app.root(), HeadlessViewKind::Label);
+        assert_eq!(label.with(|data| data.text.clone()), "Count: 2");
+    }
+
+    #[test]
+    fn checkbox_toggles() {
+        let mut app = HeadlessApp::launch(App::with_state(CounterApp, CounterState::default()));
+        let checkbox = find_kind(app.root(), HeadlessViewKind::Checkbox);
+
+        checkbox.perform_action();
+        app.process_events();
+
+        assert_eq!(app.state().checked, Some(true));
+        assert!(checkbox.with(|data| data.checked));
+    }
+
+    #[test]
+    fn text_field_change_updates_state() {
+        let mut app = HeadlessApp::launch(App::with_state(CounterApp, CounterState::default()));
+        let text_field = find_kind(app.root(), HeadlessViewKind::TextField);
+
+        text_field.change_text("Finestra");
+        app.process_events();
+
+        assert_eq!(app.state().text.clone_inner(), "Finestra");
+        assert_eq!(app.state().changed_text.as_deref(), Some("Finestra"));
+    }
+
+    #[test]
+    fn menu_action_invokes_delegate() {
+        struct MenuApp;
+
+        impl AppDelegate<Vec<MenuItem>> for MenuApp {
+            fn did_invoke_menu_action(&mut self, item: MenuItem, state: &mut Vec<MenuItem>, _: Window) {
+                state.push(item);
+            }
+        }
+
+        let mut app = HeadlessApp::launch(App::with_state(MenuApp, Vec::new()));
+        HeadlessContext::dispatch(Event::MenuAction { item: MenuItem::titled("Open") });
+        app.process_events();
+
+        assert_eq!(app.state(), &[MenuItem::titled("Open")]);
+    }
+
+    #[test]
+    fn timers_use_virtual_clock() {
+        struct TimerApp;
+
+        impl AppDelegate for TimerApp {
+            fn will_show_window(&mut self, window: Window, _: &mut ()) {
+                let inner = window.clone();
+                Timer::delayed_action(Duration::from_secs(3600), move || {
+                    inner.create_dialog("An hour later").show();
+                    inner.close();
+                }).schedule_once();
+            }
+        }
+
+        let mut app = HeadlessApp::launch(App::new(TimerApp).with_backend(UIBackend::Headless));
+        assert_eq!(app.run(), 0);
+
+        let dialogs = app.window().with(|data| data.dialogs.clone());
+        assert_eq!(dialogs.len(), 1);
+        assert_eq!(dialogs[0].text, "An hour later");
+    }
+
+    #[test]
+    fn run_until_exit_returns() {
+        struct ClosingApp;
+
+        impl AppDelegate for ClosingApp {
+            fn will_show_window(&mut self, window: Window, _: &mut ()) {
+                window.close();
+            }
+        }
+
+        let code = App::new(ClosingApp)
+            .with_backend(UIBackend::Headless)
+            .run_until_exit();
+        assert_eq!(code, 0);
+    }
+}
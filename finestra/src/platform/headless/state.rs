@@ -0,0 +1,492 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use euclid::default::Vector2D;
+
+use crate::{BaseView, Button, Canvas, ChangedText, Checkbox, Color, ColorWell, Cursor, ImageView, Label, Link, List, ListSelection, MenuItem, ProgressBar, RadioGroup, ScrollView, SearchField, Select, SelectionMode, Slider, State, StateChangeOrigin, StateOrRaw, Stepper, StepperValue, Switch, SystemColor, SystemCursor, TabView, Table, TextAlignment, TextBlock, TextEditor, TextField, TextSelection, ViewId};
+
+#[cfg(feature = "chrono")]
+use crate::{views::{format_date, to_timestamp}, Calendar, DatePicker, DateValue};
+
+use super::view::HeadlessViewData;
+use super::HeadlessView;
+
+#[derive(Debug)]
+pub(crate) enum Event {
+    ButtonClicked(ViewId),
+    MenuAction {
+        item: MenuItem,
+    },
+    TextFieldChanged(ViewId, ChangedText),
+    CheckboxChanged(ViewId, bool),
+    ListRowActivated(ViewId, usize),
+    SelectionChanged(ViewId, Option<usize>),
+    ValueChanged(ViewId, f64),
+    ColorChanged(ViewId, Color),
+}
+
+pub fn attach_base_state(finestra: &dyn BaseView, view: &HeadlessView) {
+    view.with_mut(|data| data.user_id.clone_from(&finestra.base().id));
+    finestra.base().register_handle(|| view.clone());
+    hook_tooltip_state(view, &finestra.base().tooltip);
+}
+
+pub fn attach_button_state<S>(finestra: &Button<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_background_color_state(view, &finestra.background_color);
+    hook_text_color_state(view, &finestra.text_color);
+    hook_text_state(view, &finestra.text);
+}
+
+#[cfg(feature = "chrono")]
+pub fn attach_calendar_state<S>(finestra: &Calendar<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_date_state(view, &finestra.value);
+}
+
+pub fn attach_checkbox_state<S>(finestra: &Checkbox<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_background_color_state(view, &finestra.background_color);
+    hook_text_color_state(view, &finestra.text_color);
+    hook_text_state(view, &finestra.text);
+    hook_checked_state(view, &finestra.checked);
+}
+
+pub fn attach_canvas_state<S>(finestra: &Canvas<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+}
+
+pub fn attach_color_well_state<S>(finestra: &ColorWell<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    view.with_mut(|data| data.background_color = finestra.value.clone_inner());
+
+    let view_id = view.id();
+    let view = view.clone();
+    finestra.value.add_listener_with_origin(move |color| {
+        view.with_mut(|data| data.background_color = color.clone());
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+#[cfg(feature = "chrono")]
+pub fn attach_date_picker_state<T: DateValue, S>(finestra: &DatePicker<T, S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_date_state(view, &finestra.value);
+}
+
+pub fn attach_image_view_state<S>(finestra: &ImageView<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+}
+
+pub fn attach_label_state<S>(finestra: &Label<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_background_color_state(view, &finestra.background_color);
+    hook_text_color_state(view, &finestra.text_color);
+    hook_text_state(view, &finestra.text);
+}
+
+pub fn attach_link_state<S>(finestra: &Link<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_text_state(view, &finestra.text);
+    view.with_mut(|data| {
+        data.text_color = Color::system(SystemColor::Link);
+        data.cursor = Some(Cursor::system(SystemCursor::Hand));
+    });
+}
+
+pub fn attach_list_state<T, S, D>(finestra: &List<T, S, D>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_selection_state(view, &finestra.selection);
+}
+
+pub fn attach_progress_bar_state<S>(finestra: &ProgressBar<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    view.with_mut(|data| data.progress = finestra.value.clone_inner());
+
+    let StateOrRaw::State(value_state) = &finestra.value else {
+        return;
+    };
+
+    let view = view.clone();
+    value_state.add_listener(move |val| {
+        view.with_mut(|data| data.progress = *val);
+    });
+}
+
+/// The `buttons` are the radio buttons of the options, in the same order.
+pub fn attach_radio_group_state<T, S>(finestra: &RadioGroup<T, S>, view: &HeadlessView, buttons: &[HeadlessView])
+        where T: PartialEq + Clone + 'static {
+    attach_base_state(finestra, view);
+
+    for (button, option) in buttons.iter().zip(&finestra.options) {
+        button.with_mut(|data| data.text.clone_from(&option.title));
+        hook_enabled_state(button, &option.enabled);
+    }
+
+    let show = |buttons: &[HeadlessView], values: &[T], selected: &T| {
+        for (button, value) in buttons.iter().zip(values) {
+            button.with_mut(|data| data.checked = value == selected);
+        }
+    };
+
+    let values: Vec<_> = finestra.options.iter().map(|option| option.value.clone()).collect();
+    finestra.selection.with(|selected| show(buttons, &values, selected));
+
+    let buttons = buttons.to_vec();
+    finestra.selection.add_listener_with_origin(move |selected| {
+        show(&buttons, &values, selected);
+    }, StateChangeOrigin::Owner(view.id()));
+}
+
+pub fn attach_scroll_view_state<S, D>(finestra: &ScrollView<S, D>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_scroll_offset_state(view, &finestra.horizontal_offset, |offset, val| offset.x = val);
+    hook_scroll_offset_state(view, &finestra.vertical_offset, |offset, val| offset.y = val);
+}
+
+/// The text of an editable select is bound to its state, otherwise the title
+/// of the selected option is displayed.
+pub fn attach_select_state<T, S>(finestra: &Select<T, S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+
+    if let Some(text) = &finestra.text {
+        hook_selection_state(view, &ListSelection::Single(finestra.selection.clone()));
+        hook_text_state(view, &StateOrRaw::State(text.clone()));
+    } else {
+        hook_selected_option_state(view, &finestra.selection);
+    }
+}
+
+pub fn attach_slider_state<S>(finestra: &Slider<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    view.with_mut(|data| data.value = finestra.value.clone_inner());
+
+    let view_id = view.id();
+    let view = view.clone();
+    finestra.value.add_listener_with_origin(move |val| {
+        view.with_mut(|data| data.value = *val);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_stepper_state<T: StepperValue, S>(finestra: &Stepper<T, S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+
+    let format = Rc::clone(&finestra.format);
+    let show = move |data: &mut HeadlessViewData, value: T| {
+        data.value = value.to_f64();
+        data.text = format(value);
+    };
+
+    let value = finestra.value.clone_inner();
+    view.with_mut(|data| show(data, value));
+
+    let view_id = view.id();
+    let view = view.clone();
+    finestra.value.add_listener_with_origin(move |value| {
+        view.with_mut(|data| show(data, *value));
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_switch_state<S>(finestra: &Switch<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_text_state(view, &finestra.text);
+    view.with_mut(|data| data.checked = finestra.checked.clone_inner());
+
+    let view_id = view.id();
+    let view = view.clone();
+    finestra.checked.add_listener_with_origin(move |val| {
+        view.with_mut(|data| data.checked = *val);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+/// The tab is shown by `show`, which builds its content when the tab is
+/// selected for the first time.
+pub fn attach_tab_view_state<S, D>(finestra: &TabView<S, D>, view: &HeadlessView, show: impl Fn(usize) + 'static) {
+    attach_base_state(finestra, view);
+    finestra.selection.add_listener_with_origin(move |index| show(*index), StateChangeOrigin::Owner(view.id()));
+}
+
+/// The recent searches are the options of the view.
+pub fn attach_search_field_state<S>(finestra: &SearchField<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_text_state(view, &finestra.text);
+    hook_placeholder_state(view, &finestra.placeholder_text);
+
+    if let Some(history) = &finestra.history {
+        view.with_mut(|data| data.options = history.clone_inner());
+
+        let view = view.clone();
+        history.add_listener(move |recent| {
+            view.with_mut(|data| data.options.clone_from(recent));
+        });
+    }
+}
+
+/// The `selection` contains the rows in the order of the sort, instead of the
+/// selection of the `finestra` table, which contains the indices of the items.
+pub fn attach_table_state<T, S, D>(finestra: &Table<T, S, D>, selection: &ListSelection, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_selection_state(view, selection);
+}
+
+pub fn attach_text_block_state<S>(finestra: &TextBlock<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_background_color_state(view, &finestra.background_color);
+    hook_text_color_state(view, &finestra.text_color);
+    hook_text_alignment_state(view, &finestra.alignment);
+    hook_text_state(view, &finestra.text);
+}
+
+/// The selection is clamped when the program replaces the text by a shorter
+/// one.
+pub fn attach_text_editor_state<S>(finestra: &TextEditor<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_text_state(view, &finestra.text);
+    hook_editable_state(view, &finestra.editable);
+
+    if let Some(selection) = &finestra.selection {
+        hook_text_selection_state(view, selection);
+    }
+
+    if let StateOrRaw::State(text_state) = &finestra.text {
+        let view = view.clone();
+        text_state.add_listener(move |_| view.clamp_text_selection());
+    }
+}
+
+pub fn attach_text_field_state<S>(finestra: &TextField<S>, view: &HeadlessView) {
+    attach_base_state(finestra, view);
+    hook_text_state(view, &finestra.text);
+    hook_placeholder_state(view, &finestra.placeholder_text);
+
+    if finestra.secure {
+        view.with_mut(|data| data.secure = true);
+        hook_reveal_state(view, &finestra.reveal);
+    }
+
+    if let Some(validation) = &finestra.validation {
+        hook_validation_error_state(view, &validation.error, &finestra.base().tooltip);
+    }
+}
+
+fn hook_background_color_state(view: &HeadlessView, color: &StateOrRaw<Color>) {
+    view.with_mut(|data| data.background_color = color.clone_inner());
+
+    let StateOrRaw::State(color_state) = &color else {
+        return;
+    };
+
+    let view = view.clone();
+    color_state.add_listener(move |val| {
+        view.with_mut(|data| data.background_color = val.clone());
+    });
+}
+
+fn hook_checked_state(view: &HeadlessView, checked: &StateOrRaw<bool>) {
+    view.with_mut(|data| data.checked = checked.clone_inner());
+
+    let StateOrRaw::State(checked_state) = &checked else {
+        return;
+    };
+
+    let view = view.clone();
+    checked_state.add_listener(move |val| {
+        view.with_mut(|data| data.checked = *val);
+    });
+}
+
+#[cfg(feature = "chrono")]
+fn hook_date_state<T: DateValue>(view: &HeadlessView, value: &State<T>) {
+    show_date(view, value.clone_inner());
+
+    let view_id = view.id();
+    let view = view.clone();
+    value.add_listener_with_origin(move |value| show_date(&view, *value), StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_editable_state(view: &HeadlessView, editable: &StateOrRaw<bool>) {
+    view.with_mut(|data| data.editable = editable.clone_inner());
+
+    let StateOrRaw::State(editable_state) = &editable else {
+        return;
+    };
+
+    let view = view.clone();
+    editable_state.add_listener(move |val| {
+        view.with_mut(|data| data.editable = *val);
+    });
+}
+
+fn hook_enabled_state(view: &HeadlessView, enabled: &StateOrRaw<bool>) {
+    view.with_mut(|data| data.enabled = enabled.clone_inner());
+
+    let StateOrRaw::State(enabled_state) = &enabled else {
+        return;
+    };
+
+    let view = view.clone();
+    enabled_state.add_listener(move |val| {
+        view.with_mut(|data| data.enabled = *val);
+    });
+}
+
+fn hook_placeholder_state(view: &HeadlessView, text: &StateOrRaw<String>) {
+    view.with_mut(|data| data.placeholder = text.clone_inner());
+
+    let StateOrRaw::State(text_state) = &text else {
+        return;
+    };
+
+    let view = view.clone();
+    text_state.add_listener(move |val| {
+        view.with_mut(|data| data.placeholder = val.clone());
+    });
+}
+
+fn hook_text_alignment_state(view: &HeadlessView, alignment: &StateOrRaw<TextAlignment>) {
+    view.with_mut(|data| data.alignment = alignment.clone_inner());
+
+    let StateOrRaw::State(alignment_state) = &alignment else {
+        return;
+    };
+
+    let view = view.clone();
+    alignment_state.add_listener(move |val| {
+        view.with_mut(|data| data.alignment = *val);
+    });
+}
+
+fn hook_text_color_state(view: &HeadlessView, color: &StateOrRaw<Color>) {
+    view.with_mut(|data| data.text_color = color.clone_inner());
+
+    let StateOrRaw::State(color_state) = &color else {
+        return;
+    };
+
+    let view = view.clone();
+    color_state.add_listener(move |val| {
+        view.with_mut(|data| data.text_color = val.clone());
+    });
+}
+
+fn hook_selection_state(view: &HeadlessView, selection: &ListSelection) {
+    view.with_mut(|data| {
+        data.selection = selection.rows();
+        data.selection_mode = selection.mode();
+    });
+
+    let view_id = view.id();
+    let view = view.clone();
+    selection.add_listener(move |rows| {
+        view.with_mut(|data| data.selection = rows);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_selected_option_state(view: &HeadlessView, selection: &State<Option<usize>>) {
+    let show = |data: &mut HeadlessViewData, index: Option<usize>| {
+        data.selection = index.into_iter().collect();
+        data.text = index.and_then(|index| data.options.get(index)).cloned().unwrap_or_default();
+    };
+
+    view.with_mut(|data| {
+        data.selection_mode = SelectionMode::Single;
+        show(data, selection.clone_inner());
+    });
+
+    let view_id = view.id();
+    let view = view.clone();
+    selection.add_listener_with_origin(move |index| {
+        view.with_mut(|data| show(data, *index));
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_reveal_state(view: &HeadlessView, reveal: &StateOrRaw<bool>) {
+    view.with_mut(|data| data.revealed = reveal.clone_inner());
+
+    let StateOrRaw::State(reveal_state) = &reveal else {
+        return;
+    };
+
+    let view = view.clone();
+    reveal_state.add_listener(move |val| {
+        view.with_mut(|data| data.revealed = *val);
+    });
+}
+
+fn hook_scroll_offset_state(view: &HeadlessView, offset: &StateOrRaw<f32>, set: fn(&mut Vector2D<f32>, f32)) {
+    let mut value = view.scroll_offset();
+    set(&mut value, offset.clone_inner());
+    view.set_scroll_offset(value);
+
+    let StateOrRaw::State(offset_state) = &offset else {
+        return;
+    };
+
+    let view_id = view.id();
+    let view = view.clone();
+    offset_state.add_listener_with_origin(move |val| {
+        let mut value = view.scroll_offset();
+        set(&mut value, *val);
+        view.set_scroll_offset(value);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_text_selection_state(view: &HeadlessView, selection: &State<TextSelection>) {
+    view.with_mut(|data| data.text_selection = selection.clone_inner().clamped(data.text.chars().count()));
+
+    let view_id = view.id();
+    let view = view.clone();
+    selection.add_listener_with_origin(move |val| {
+        view.with_mut(|data| data.text_selection = val.clamped(data.text.chars().count()));
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_text_state(view: &HeadlessView, text: &StateOrRaw<String>) {
+    view.with_mut(|data| data.text = text.clone_inner());
+
+    let StateOrRaw::State(text_state) = &text else {
+        return;
+    };
+
+    let view_id = view.id();
+    let view = view.clone();
+    text_state.add_listener_with_origin(move |val| {
+        view.with_mut(|data| data.text = val.clone());
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_tooltip_state(view: &HeadlessView, text: &StateOrRaw<String>) {
+    view.with_mut(|data| data.tooltip = text.clone_inner());
+
+    let StateOrRaw::State(text_state) = &text else {
+        return;
+    };
+
+    let view = view.clone();
+    text_state.add_listener(move |val| {
+        view.with_mut(|data| data.tooltip = val.clone());
+    });
+}
+
+/// The message of invalid text replaces the tooltip, until the text is valid
+/// again.
+fn hook_validation_error_state(view: &HeadlessView, error: &State<Option<String>>, tooltip: &StateOrRaw<String>) {
+    let view = view.clone();
+    let tooltip = tooltip.clone();
+    error.add_listener(move |error| {
+        view.with_mut(|data| {
+            data.tooltip = error.clone().unwrap_or_else(|| tooltip.clone_inner());
+            data.validation_error.clone_from(error);
+        });
+    });
+}
+
+/// The moment is shown as text, and stored as a timestamp.
+#[cfg(feature = "chrono")]
+pub(crate) fn show_date<T: DateValue>(view: &HeadlessView, value: T) {
+    view.with_mut(|data| {
+        data.value = to_timestamp(value);
+        data.text = format_date(value);
+    });
+}
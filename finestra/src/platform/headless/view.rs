@@ -0,0 +1,861 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
+
+use euclid::default::Vector2D;
+
+use crate::{Color, ContainerLayout, Cursor, DrawCommand, DrawingContext, Key, Orientation, ProgressStyle, LayoutFrame, LayoutNode, LayoutRect, LayoutSize, SelectionMode, StackDirection, TextAlignment, TextSelection, ViewHandleApi, ViewId};
+
+use super::HeadlessContext;
+
+type Action = dyn Fn();
+type TextAction = dyn Fn(&str);
+type ScrollAction = dyn Fn(Vector2D<f32>);
+type SelectionAction = dyn Fn(Vec<usize>);
+type RowAction = dyn Fn(usize);
+type ColumnAction = dyn Fn(usize);
+type KeyAction = dyn Fn(Key) -> bool;
+type TabAction = dyn Fn(usize);
+type ValueAction = dyn Fn(f64);
+type StepAction = dyn Fn(bool);
+type ColorAction = dyn Fn(Color);
+type CellAction = dyn Fn(usize, usize, &str);
+type Painter = dyn Fn(&mut DrawingContext);
+type TextSelectionAction = dyn Fn(TextSelection);
+
+/// An in-memory replacement of a native view, used by the
+/// [`UIBackend::Headless`](crate::UIBackend::Headless). It behaves like a
+/// native control: it has properties that are updated by [`State`](crate::State)
+/// listeners, and actions that are invoked when the "user" interacts with it.
+#[derive(Clone)]
+pub struct HeadlessView {
+    inner: Rc<RefCell<HeadlessViewData>>,
+}
+
+impl HeadlessView {
+    pub(crate) fn new(id: ViewId, kind: HeadlessViewKind) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(HeadlessViewData {
+                id,
+                kind,
+                text: String::new(),
+                placeholder: String::new(),
+                tooltip: String::new(),
+                user_id: None,
+                text_color: Color::default(),
+                cursor: None,
+                background_color: Color::default(),
+                alignment: TextAlignment::default(),
+                checked: false,
+                value: 0.0,
+                progress: None,
+                enabled: true,
+                image: None,
+                selection: Vec::new(),
+                selection_mode: SelectionMode::None,
+                options: Vec::new(),
+                editable: false,
+                secure: false,
+                revealed: false,
+                validation_error: None,
+                text_selection: TextSelection::default(),
+                columns: Vec::new(),
+                tabs: Vec::new(),
+                selected_tab: 0,
+                container: None,
+                canvas: None,
+                frame: LayoutRect::zero(),
+                children: Vec::new(),
+                parent: Weak::new(),
+                action: None,
+                text_did_change: None,
+                scroll_did_change: None,
+                selection_did_change: None,
+                row_activated: None,
+                column_clicked: None,
+                cell_edited: None,
+                key_pressed: None,
+                tab_selected: None,
+                value_did_change: None,
+                stepped: None,
+                color_did_change: None,
+                text_selection_did_change: None,
+            })),
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn id(&self) -> ViewId {
+        self.inner.borrow().id
+    }
+
+    /// Modify the properties of this view.
+    pub(crate) fn with_mut<F: FnOnce(&mut HeadlessViewData) -> R, R>(&self, f: F) -> R {
+        f(&mut self.inner.borrow_mut())
+    }
+
+    pub(crate) fn add_child(&self, child: HeadlessView) {
+        child.inner.borrow_mut().parent = Rc::downgrade(&self.inner);
+        self.inner.borrow_mut().children.push(child);
+    }
+
+    /// Replace the children in `range` with the `children`, e.g. when the items
+    /// of a [`List`](crate::List) changed.
+    pub(crate) fn splice_children(&self, range: std::ops::Range<usize>, children: Vec<HeadlessView>) {
+        for child in &children {
+            child.inner.borrow_mut().parent = Rc::downgrade(&self.inner);
+        }
+
+        self.inner.borrow_mut().children.splice(range, children);
+    }
+
+    /// Set the action that is invoked when the view is activated, e.g. when a
+    /// button is clicked.
+    pub(crate) fn set_action(&self, action: impl Fn() + 'static) {
+        self.inner.borrow_mut().action = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user changed the text of the
+    /// view, e.g. by typing in a text field.
+    pub(crate) fn set_text_did_change(&self, action: impl Fn(&str) + 'static) {
+        self.inner.borrow_mut().text_did_change = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user scrolled the view.
+    pub(crate) fn set_scroll_did_change(&self, action: impl Fn(Vector2D<f32>) + 'static) {
+        self.inner.borrow_mut().scroll_did_change = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user selected rows.
+    pub(crate) fn set_selection_did_change(&self, action: impl Fn(Vec<usize>) + 'static) {
+        self.inner.borrow_mut().selection_did_change = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user activated a row, e.g. by
+    /// double clicking it.
+    pub(crate) fn set_row_activated(&self, action: impl Fn(usize) + 'static) {
+        self.inner.borrow_mut().row_activated = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user clicked the header of a
+    /// column.
+    pub(crate) fn set_column_clicked(&self, action: impl Fn(usize) + 'static) {
+        self.inner.borrow_mut().column_clicked = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user finished editing a cell,
+    /// with the row, the column and the new text.
+    pub(crate) fn set_cell_edited(&self, action: impl Fn(usize, usize, &str) + 'static) {
+        self.inner.borrow_mut().cell_edited = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user selected a tab.
+    pub(crate) fn set_tab_selected(&self, action: impl Fn(usize) + 'static) {
+        self.inner.borrow_mut().tab_selected = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user changed the value of the
+    /// view, e.g. by dragging the knob of a slider.
+    pub(crate) fn set_value_did_change(&self, action: impl Fn(f64) + 'static) {
+        self.inner.borrow_mut().value_did_change = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user incremented (`true`) or
+    /// decremented (`false`) the value of the view, e.g. of a stepper.
+    pub(crate) fn set_stepped(&self, action: impl Fn(bool) + 'static) {
+        self.inner.borrow_mut().stepped = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user picked a color, e.g. in a
+    /// color well.
+    pub(crate) fn set_color_did_change(&self, action: impl Fn(Color) + 'static) {
+        self.inner.borrow_mut().color_did_change = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user selected text, or moved
+    /// the caret.
+    pub(crate) fn set_text_selection_did_change(&self, action: impl Fn(TextSelection) + 'static) {
+        self.inner.borrow_mut().text_selection_did_change = Some(Rc::new(action));
+    }
+
+    /// Set the action that is invoked when the user pressed a key. The action
+    /// returns `false` if the key didn't do anything.
+    pub(crate) fn set_key_pressed(&self, action: impl Fn(Key) -> bool + 'static) {
+        self.inner.borrow_mut().key_pressed = Some(Rc::new(action));
+    }
+
+    /// Get the scroll offset of a [`ScrollView`](crate::ScrollView).
+    #[must_use]
+    pub(crate) fn scroll_offset(&self) -> Vector2D<f32> {
+        self.with(|data| match &data.container {
+            Some(ContainerLayout::Scroll(layout)) => layout.offset,
+            _ => Vector2D::zero(),
+        })
+    }
+
+    /// Set the scroll offset of a [`ScrollView`](crate::ScrollView), without
+    /// invoking the action.
+    pub(crate) fn set_scroll_offset(&self, offset: Vector2D<f32>) {
+        self.with_mut(|data| {
+            if let Some(ContainerLayout::Scroll(layout)) = &mut data.container {
+                layout.offset = offset;
+            }
+        });
+    }
+
+    /// Makes this view a [`Canvas`](crate::Canvas) of the given preferred
+    /// `size`, which is painted by the `painter`.
+    pub(crate) fn set_painter(&self, size: LayoutSize, painter: Rc<Painter>) {
+        self.inner.borrow_mut().canvas = Some(HeadlessCanvas {
+            size,
+            painter,
+            painted_size: None,
+            commands: Vec::new(),
+        });
+    }
+
+    /// Paints the canvas at the size of its frame, and stores the commands
+    /// that were drawn.
+    pub(crate) fn paint(&self) {
+        let Some((painter, size)) = self.with(|data| {
+            data.canvas.as_ref().map(|canvas| (Rc::clone(&canvas.painter), data.frame.size))
+        }) else {
+            return;
+        };
+
+        // Don't hold the borrow, as the painter can inspect the state.
+        let mut context = DrawingContext::new(size);
+        painter(&mut context);
+
+        self.with_mut(|data| {
+            if let Some(canvas) = &mut data.canvas {
+                canvas.painted_size = Some(size);
+                canvas.commands = context.into_commands();
+            }
+        });
+    }
+
+    /// Get the commands drawn on the canvas. Like a native view, the canvas
+    /// is painted when it is first shown, and when its size changed.
+    pub(crate) fn drawing(&self) -> Vec<DrawCommand> {
+        let outdated = self.with(|data| {
+            data.canvas.as_ref().is_some_and(|canvas| canvas.painted_size != Some(data.frame.size))
+        });
+
+        if outdated {
+            self.paint();
+        }
+
+        self.with(|data| data.canvas.as_ref().map(|canvas| canvas.commands.clone()).unwrap_or_default())
+    }
+
+    #[must_use]
+    fn parent(&self) -> Option<HeadlessView> {
+        let inner = self.inner.borrow().parent.upgrade()?;
+        Some(Self { inner })
+    }
+}
+
+// These functions are used to inspect the tree, and to simulate the user.
+impl HeadlessView {
+    #[must_use]
+    pub(crate) fn kind(&self) -> HeadlessViewKind {
+        self.inner.borrow().kind
+    }
+
+    /// Inspect the properties of this view.
+    pub(crate) fn with<F: FnOnce(&HeadlessViewData) -> R, R>(&self, f: F) -> R {
+        f(&self.inner.borrow())
+    }
+
+    #[must_use]
+    pub(crate) fn children(&self) -> Vec<HeadlessView> {
+        self.inner.borrow().children.clone()
+    }
+
+    /// Simulates the user activating the view. Returns `false` if the view
+    /// doesn't have an action, or is disabled.
+    pub(crate) fn perform_action(&self) -> bool {
+        // Don't hold the borrow, as the action can modify this view.
+        let action = self.with(|data| data.action.clone().filter(|_| data.enabled));
+        let Some(action) = action else {
+            return false;
+        };
+
+        action();
+        true
+    }
+
+    /// Simulates the user replacing the text of the view.
+    pub(crate) fn change_text(&self, text: &str) {
+        let action = {
+            let mut data = self.inner.borrow_mut();
+            data.text = text.to_owned();
+            data.text_did_change.clone()
+        };
+
+        if let Some(action) = action {
+            action(text);
+        }
+    }
+
+    /// Simulates the user selecting text, or moving the caret, in a
+    /// [`TextEditor`](crate::TextEditor). The selection is clamped to the
+    /// text. Returns `false` if the view is disabled.
+    pub(crate) fn select_text(&self, selection: TextSelection) -> bool {
+        let action = self.with_mut(|data| {
+            if !data.enabled {
+                return None;
+            }
+
+            data.text_selection = selection.clamped(data.text.chars().count());
+            Some((data.text_selection, data.text_selection_did_change.clone()))
+        });
+
+        let Some((selection, action)) = action else {
+            return false;
+        };
+
+        if let Some(action) = action {
+            action(selection);
+        }
+        true
+    }
+
+    /// Simulates the user typing `text` in a [`TextEditor`](crate::TextEditor),
+    /// which replaces the selected text and moves the caret after it. Returns
+    /// `false` if the text can't be edited.
+    pub(crate) fn insert_text(&self, text: &str) -> bool {
+        let edit = self.with(|data| {
+            if !data.enabled || !data.editable {
+                return None;
+            }
+
+            let range = data.text_selection.clamped(data.text.chars().count()).range();
+            let byte_offset = |offset| data.text.char_indices().nth(offset).map_or(data.text.len(), |(index, _)| index);
+
+            let mut result = data.text.clone();
+            result.replace_range(byte_offset(range.start)..byte_offset(range.end), text);
+            Some((result, range.start + text.chars().count()))
+        });
+
+        let Some((result, caret)) = edit else {
+            return false;
+        };
+
+        self.change_text(&result);
+        self.select_text(TextSelection::caret_at(caret))
+    }
+
+    /// Limits the selection of a [`TextEditor`](crate::TextEditor) to its
+    /// text, e.g. after the program replaced the text. Like a native editor,
+    /// the change of the selection is reported.
+    pub(crate) fn clamp_text_selection(&self) {
+        let action = self.with_mut(|data| {
+            let selection = data.text_selection.clamped(data.text.chars().count());
+            if selection == data.text_selection {
+                return None;
+            }
+
+            data.text_selection = selection;
+            Some((selection, data.text_selection_did_change.clone()?))
+        });
+
+        if let Some((selection, action)) = action {
+            action(selection);
+        }
+    }
+
+    /// Simulates the user scrolling the view to the given offset, which is
+    /// clamped to the size of the content, as computed by the last layout
+    /// pass.
+    pub(crate) fn scroll_to(&self, offset: Vector2D<f32>) {
+        let action = self.with_mut(|data| {
+            let Some(ContainerLayout::Scroll(layout)) = &mut data.container else {
+                return None;
+            };
+
+            let content = data.children.first()
+                .map(|child| child.with(|child| child.frame.size))
+                .unwrap_or_default();
+
+            layout.offset = offset;
+            layout.offset = layout.clamped_offset(content, data.frame.size);
+            Some((layout.offset, data.scroll_did_change.clone()))
+        });
+
+        if let Some((offset, Some(action))) = action {
+            action(offset);
+        }
+    }
+
+    /// Simulates the user selecting the given rows. Returns `false` if the rows
+    /// can't be selected, e.g. when multiple rows are given, but only a single
+    /// row can be selected.
+    pub(crate) fn select_rows(&self, mut rows: Vec<usize>) -> bool {
+        rows.sort_unstable();
+        rows.dedup();
+
+        let action = self.with_mut(|data| {
+            let allowed = match data.selection_mode {
+                SelectionMode::None => false,
+                SelectionMode::Single => rows.len() <= 1,
+                SelectionMode::Multiple => true,
+            };
+
+            if !allowed || !data.enabled || rows.iter().any(|row| *row >= data.children.len()) {
+                return None;
+            }
+
+            data.selection.clone_from(&rows);
+            Some(data.selection_did_change.clone())
+        });
+
+        let Some(action) = action else {
+            return false;
+        };
+
+        if let Some(action) = action {
+            action(rows);
+        }
+        true
+    }
+
+    /// Simulates the user activating a row, e.g. by double clicking it.
+    /// Returns `false` if the row doesn't exist.
+    pub(crate) fn activate_row(&self, row: usize) -> bool {
+        let action = self.with(|data| {
+            data.row_activated.clone().filter(|_| data.enabled && row < data.children.len())
+        });
+
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(row);
+        true
+    }
+
+    /// Simulates the user clicking the header of a column. Returns `false` if
+    /// the column doesn't exist, or can't be sorted.
+    pub(crate) fn click_column(&self, column: usize) -> bool {
+        let action = self.with(|data| {
+            let sortable = data.columns.get(column).is_some_and(|column| column.sortable);
+            data.column_clicked.clone().filter(|_| data.enabled && sortable)
+        });
+
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(column);
+        true
+    }
+
+    /// Simulates the user editing a cell, and pressing the return key. Returns
+    /// `false` if the cell doesn't exist, or can't be edited.
+    pub(crate) fn edit_cell(&self, row: usize, column: usize, text: &str) -> bool {
+        let action = self.with(|data| {
+            let editable = data.columns.get(column).is_some_and(|column| column.editable);
+            data.cell_edited.clone().filter(|_| data.enabled && editable && row < data.children.len())
+        });
+
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(row, column, text);
+        true
+    }
+
+    /// Simulates the user changing the value of the view, e.g. by dragging the
+    /// knob of a slider. Returns `false` if the value can't be changed.
+    pub(crate) fn change_value(&self, value: f64) -> bool {
+        let action = self.with(|data| data.value_did_change.clone().filter(|_| data.enabled));
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(value);
+        true
+    }
+
+    /// Simulates the user clicking the increment (`up`) or decrement button
+    /// of the view. Returns `false` if the view can't be stepped.
+    pub(crate) fn step(&self, up: bool) -> bool {
+        let action = self.with(|data| data.stepped.clone().filter(|_| data.enabled));
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(up);
+        true
+    }
+
+    /// Simulates the user picking a color, e.g. in a color well. Returns
+    /// `false` if the view doesn't have a color.
+    pub(crate) fn change_color(&self, color: Color) -> bool {
+        let action = self.with(|data| data.color_did_change.clone().filter(|_| data.enabled));
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(color);
+        true
+    }
+
+    /// Simulates the user selecting an option of a [`Select`](crate::Select).
+    /// Returns `false` if the option doesn't exist.
+    pub(crate) fn select_option(&self, index: usize) -> bool {
+        let action = self.with_mut(|data| {
+            let title = data.options.get(index).filter(|_| data.enabled)?.clone();
+            data.selection = vec![index];
+            data.text = title;
+            Some(data.selection_did_change.clone())
+        });
+
+        let Some(action) = action else {
+            return false;
+        };
+
+        if let Some(action) = action {
+            action(vec![index]);
+        }
+        true
+    }
+
+    /// Simulates the user selecting a tab of a [`TabView`](crate::TabView).
+    /// Returns `false` if the tab doesn't exist.
+    pub(crate) fn select_tab(&self, index: usize) -> bool {
+        let action = self.with(|data| {
+            data.tab_selected.clone().filter(|_| data.enabled && index < data.tabs.len())
+        });
+
+        let Some(action) = action else {
+            return false;
+        };
+
+        action(index);
+        true
+    }
+
+    /// Simulates the user pressing a key while this view has the focus.
+    /// Returns `false` if the key didn't do anything.
+    pub(crate) fn press_key(&self, key: Key) -> bool {
+        let action = self.with(|data| data.key_pressed.clone().filter(|_| data.enabled));
+
+        action.is_some_and(|action| action(key))
+    }
+
+    /// Find the first view (including `self`) that matches the predicate, in
+    /// depth-first order.
+    pub(crate) fn find(&self, predicate: &impl Fn(&HeadlessView) -> bool) -> Option<HeadlessView> {
+        if predicate(self) {
+            return Some(self.clone());
+        }
+
+        self.children()
+            .iter()
+            .find_map(|child| child.find(predicate))
+    }
+}
+
+// The layout pass. As there is no font rendering, the views are measured using
+// fixed metrics, so that the frames are the same on every machine.
+impl HeadlessView {
+    const CHARACTER_WIDTH: f32 = 8.0;
+    const LINE_HEIGHT: f32 = 20.0;
+
+    /// Creates the input of the layout pass for this view and its descendants.
+    #[must_use]
+    pub(crate) fn layout_node(&self) -> LayoutNode {
+        self.with(|data| {
+            if let Some(layout) = &data.container {
+                return layout.node(data.children.iter().map(Self::layout_node).collect());
+            }
+
+            LayoutNode::Leaf(data.measure())
+        })
+    }
+
+    /// Stores the frames computed by the layout pass.
+    pub(crate) fn apply_layout(&self, frame: &LayoutFrame) {
+        let children = self.with_mut(|data| {
+            data.frame = frame.rect;
+            data.children.clone()
+        });
+
+        for (child, frame) in children.iter().zip(&frame.children) {
+            child.apply_layout(frame);
+        }
+    }
+
+    /// Lays out this view and its descendants in a window of the given size.
+    /// When the size is empty, the preferred size of the view is used instead.
+    pub(crate) fn layout(&self, size: LayoutSize) {
+        let node = self.layout_node();
+        let size = if size.is_empty() { node.measure() } else { size };
+        self.apply_layout(&node.arrange(LayoutRect::from_size(size)));
+    }
+}
+
+impl HeadlessViewData {
+    fn text_size(text: &str) -> LayoutSize {
+        let columns = text.lines().map(|line| line.chars().count()).max().unwrap_or_default();
+        let lines = text.lines().count().max(1);
+        LayoutSize::new(columns as f32 * HeadlessView::CHARACTER_WIDTH, lines as f32 * HeadlessView::LINE_HEIGHT)
+    }
+
+    fn measure(&self) -> LayoutSize {
+        match self.kind {
+            HeadlessViewKind::Empty | HeadlessViewKind::Grid | HeadlessViewKind::List | HeadlessViewKind::ScrollView | HeadlessViewKind::Stack(..) | HeadlessViewKind::TabView | HeadlessViewKind::Table => {
+                LayoutSize::zero()
+            }
+            HeadlessViewKind::Button => Self::text_size(&self.text) + LayoutSize::new(24.0, 8.0),
+            HeadlessViewKind::Canvas => self.canvas.as_ref().map(|canvas| canvas.size).unwrap_or_default(),
+            #[cfg(feature = "chrono")]
+            HeadlessViewKind::Calendar => LayoutSize::new(7.0 * 32.0, 7.0 * 24.0),
+            HeadlessViewKind::Checkbox | HeadlessViewKind::RadioButton => Self::text_size(&self.text) + LayoutSize::new(24.0, 0.0),
+            HeadlessViewKind::ColorWell => LayoutSize::new(44.0, 24.0),
+            #[cfg(feature = "chrono")]
+            HeadlessViewKind::DatePicker(mode) => match mode {
+                crate::DatePickerMode::Date => LayoutSize::new(120.0, 24.0),
+                crate::DatePickerMode::Time => LayoutSize::new(80.0, 24.0),
+                crate::DatePickerMode::DateTime => LayoutSize::new(160.0, 24.0),
+            },
+            HeadlessViewKind::ImageView => LayoutSize::new(100.0, 100.0),
+            HeadlessViewKind::Label | HeadlessViewKind::Link | HeadlessViewKind::TextBlock => Self::text_size(&self.text),
+            HeadlessViewKind::ProgressBar(ProgressStyle::Bar) => LayoutSize::new(160.0, 16.0),
+            HeadlessViewKind::ProgressBar(ProgressStyle::Circular) => LayoutSize::new(32.0, 32.0),
+            HeadlessViewKind::Select => {
+                let widest = self.options.iter().map(|title| Self::text_size(title).width).fold(0.0, f32::max);
+                LayoutSize::new(widest + 32.0, HeadlessView::LINE_HEIGHT + 8.0)
+            }
+            HeadlessViewKind::Slider(Orientation::Horizontal) => LayoutSize::new(160.0, 24.0),
+            HeadlessViewKind::Slider(Orientation::Vertical) => LayoutSize::new(24.0, 160.0),
+            HeadlessViewKind::Stepper => LayoutSize::new(120.0, 24.0),
+            HeadlessViewKind::Switch => Self::text_size(&self.text) + LayoutSize::new(48.0, 4.0),
+            HeadlessViewKind::TextEditor => LayoutSize::new(200.0, 100.0),
+            HeadlessViewKind::SearchField | HeadlessViewKind::TextField => LayoutSize::new(200.0, 24.0),
+        }
+    }
+}
+
+impl ViewHandleApi for HeadlessView {
+    fn text(&self) -> String {
+        self.with(|data| data.text.clone())
+    }
+
+    fn set_text(&self, text: &str) {
+        self.with_mut(|data| text.clone_into(&mut data.text));
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.with(|data| data.enabled)
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        self.with_mut(|data| data.enabled = enabled);
+    }
+
+    fn focus(&self) {
+        HeadlessContext::set_focused(self.id());
+    }
+
+    fn scroll_into_view(&self) {
+        let frame = self.with(|data| data.frame);
+
+        let mut ancestor = self.parent();
+        while let Some(view) = ancestor {
+            if view.kind() != HeadlessViewKind::ScrollView {
+                ancestor = view.parent();
+                continue;
+            }
+
+            // Scroll by the smallest distance that makes the view visible,
+            // preferring to show the top left corner of the view.
+            let viewport = view.with(|data| data.frame);
+            let delta = Vector2D::new(
+                scroll_delta(frame.min_x(), frame.max_x(), viewport.min_x(), viewport.max_x()),
+                scroll_delta(frame.min_y(), frame.max_y(), viewport.min_y(), viewport.max_y()),
+            );
+            view.scroll_to(view.scroll_offset() + delta);
+            return;
+        }
+    }
+
+    fn invalidate(&self) {
+        self.paint();
+    }
+}
+
+/// The distance to scroll along an axis, to move the range `start..end` into
+/// the visible range `visible_start..visible_end`.
+fn scroll_delta(start: f32, end: f32, visible_start: f32, visible_end: f32) -> f32 {
+    if start < visible_start {
+        start - visible_start
+    } else if end > visible_end {
+        (end - visible_end).min(start - visible_start)
+    } else {
+        0.0
+    }
+}
+
+impl Debug for HeadlessView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.borrow().fmt(f)
+    }
+}
+
+/// The properties of a [`HeadlessView`].
+pub(crate) struct HeadlessViewData {
+    pub(crate) id: ViewId,
+    pub(crate) kind: HeadlessViewKind,
+    pub(crate) text: String,
+    pub(crate) placeholder: String,
+    pub(crate) tooltip: String,
+    /// The identifier given by [`BaseViewExt::with_id()`](crate::BaseViewExt::with_id).
+    pub(crate) user_id: Option<String>,
+    pub(crate) text_color: Color,
+    /// The cursor that is shown when the mouse hovers over the view, e.g. over
+    /// a [`Link`](crate::Link).
+    pub(crate) cursor: Option<Cursor>,
+    /// The background color, or the color of a [`ColorWell`](crate::ColorWell).
+    pub(crate) background_color: Color,
+    pub(crate) alignment: TextAlignment,
+    pub(crate) checked: bool,
+    /// The value of a [`Slider`](crate::Slider).
+    pub(crate) value: f64,
+    /// The progress of a [`ProgressBar`](crate::ProgressBar).
+    pub(crate) progress: Option<f64>,
+    pub(crate) enabled: bool,
+    pub(crate) image: Option<PathBuf>,
+    /// The selected rows of a [`List`](crate::List) or a [`Table`](crate::Table).
+    pub(crate) selection: Vec<usize>,
+    pub(crate) selection_mode: SelectionMode,
+    /// The titles of the options of a [`Select`](crate::Select), or the recent
+    /// searches of a [`SearchField`](crate::SearchField).
+    pub(crate) options: Vec<String>,
+    /// Whether the text of a [`Select`](crate::Select) or a
+    /// [`TextEditor`](crate::TextEditor) can be edited.
+    pub(crate) editable: bool,
+    /// Whether the text of a [`TextField`](crate::TextField) is secret, and
+    /// whether it is shown instead of masked.
+    pub(crate) secure: bool,
+    pub(crate) revealed: bool,
+    /// The message of the validator of a [`TextField`](crate::TextField),
+    /// when the text isn't valid.
+    pub(crate) validation_error: Option<String>,
+    /// The selected text of a [`TextEditor`](crate::TextEditor).
+    pub(crate) text_selection: TextSelection,
+    /// The columns of a [`Table`](crate::Table).
+    pub(crate) columns: Vec<HeadlessColumn>,
+    /// The tabs of a [`TabView`](crate::TabView), and the selected tab.
+    pub(crate) tabs: Vec<HeadlessTab>,
+    pub(crate) selected_tab: usize,
+    /// The layout of a container view, such as a [`Stack`](crate::Stack).
+    pub(crate) container: Option<ContainerLayout>,
+    /// The painter and the drawing of a [`Canvas`](crate::Canvas).
+    canvas: Option<HeadlessCanvas>,
+    /// The frame computed by the layout pass, in window coordinates.
+    pub(crate) frame: LayoutRect,
+    pub(crate) children: Vec<HeadlessView>,
+    parent: Weak<RefCell<HeadlessViewData>>,
+    action: Option<Rc<Action>>,
+    text_did_change: Option<Rc<TextAction>>,
+    scroll_did_change: Option<Rc<ScrollAction>>,
+    selection_did_change: Option<Rc<SelectionAction>>,
+    row_activated: Option<Rc<RowAction>>,
+    column_clicked: Option<Rc<ColumnAction>>,
+    cell_edited: Option<Rc<CellAction>>,
+    key_pressed: Option<Rc<KeyAction>>,
+    tab_selected: Option<Rc<TabAction>>,
+    value_did_change: Option<Rc<ValueAction>>,
+    stepped: Option<Rc<StepAction>>,
+    color_did_change: Option<Rc<ColorAction>>,
+    text_selection_did_change: Option<Rc<TextSelectionAction>>,
+}
+
+impl Debug for HeadlessViewData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HeadlessView")
+            .field("id", &self.id)
+            .field("kind", &self.kind)
+            .field("text", if self.secure { &"<redacted>" } else { &self.text })
+            .field("tooltip", &self.tooltip)
+            .field("validation_error", &self.validation_error)
+            .field("user_id", &self.user_id)
+            .field("checked", &self.checked)
+            .field("value", &self.value)
+            .field("progress", &self.progress)
+            .field("enabled", &self.enabled)
+            .field("selection", &self.selection)
+            .field("text_selection", &self.text_selection)
+            .field("options", &self.options)
+            .field("columns", &self.columns)
+            .field("tabs", &self.tabs)
+            .field("frame", &self.frame)
+            .field("children", &self.children)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A column of a [`Table`](crate::Table).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct HeadlessColumn {
+    pub(crate) title: String,
+    pub(crate) sortable: bool,
+    pub(crate) editable: bool,
+}
+
+/// The painter of a [`Canvas`](crate::Canvas), and what it drew the last
+/// time it was painted.
+struct HeadlessCanvas {
+    size: LayoutSize,
+    painter: Rc<Painter>,
+    painted_size: Option<LayoutSize>,
+    commands: Vec<DrawCommand>,
+}
+
+/// A tab of a [`TabView`](crate::TabView).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct HeadlessTab {
+    pub(crate) title: String,
+    pub(crate) icon: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum HeadlessViewKind {
+    Empty,
+    Button,
+    #[cfg(feature = "chrono")]
+    Calendar,
+    Canvas,
+    Checkbox,
+    ColorWell,
+    #[cfg(feature = "chrono")]
+    DatePicker(crate::DatePickerMode),
+    Grid,
+    ImageView,
+    Label,
+    Link,
+    List,
+    ProgressBar(ProgressStyle),
+    RadioButton,
+    ScrollView,
+    SearchField,
+    Select,
+    Slider(Orientation),
+    Stack(StackDirection),
+    Stepper,
+    Switch,
+    TabView,
+    Table,
+    TextBlock,
+    TextEditor,
+    TextField,
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{Color, ColorDialogBuilder, DialogBuilder, DialogKind, MenuBar, Number, StateChangeOrigin, Theme, WindowConfiguration, WindowDelegator};
+
+use super::context::HeadlessContext;
+use super::dialog::{HeadlessColorDialog, HeadlessDialog};
+
+/// The in-memory counterpart of a native window.
+#[derive(Clone)]
+pub(crate) struct HeadlessWindow {
+    inner: Rc<RefCell<HeadlessWindowData>>,
+}
+
+impl HeadlessWindow {
+    pub(crate) fn new(config: WindowConfiguration) -> Self {
+        let this = Self {
+            inner: Rc::new(RefCell::new(HeadlessWindowData {
+                title: config.title.as_ref().clone_inner(),
+                width: config.width,
+                height: config.height,
+                theme: config.theme.clone_inner(),
+                menubar: config.menubar,
+                dialogs: Vec::new(),
+                color_dialog: None,
+            })),
+        };
+
+        if let Some(state) = config.title.as_ref().as_state() {
+            let window = this.clone();
+            state.add_listener_with_origin(move |value| {
+                window.with_mut(|data| data.title = value.clone());
+            }, StateChangeOrigin::System);
+        }
+
+        if let Some(state) = config.theme.as_state() {
+            let window = this.clone();
+            state.add_listener_with_origin(move |theme| {
+                window.with_mut(|data| data.theme = *theme);
+            }, StateChangeOrigin::System);
+        }
+
+        this
+    }
+
+    pub(crate) fn with<F: FnOnce(&HeadlessWindowData) -> R, R>(&self, f: F) -> R {
+        f(&self.inner.borrow())
+    }
+
+    pub(crate) fn with_mut<F: FnOnce(&mut HeadlessWindowData) -> R, R>(&self, f: F) -> R {
+        f(&mut self.inner.borrow_mut())
+    }
+}
+
+#[allow(unused)]
+pub(crate) struct HeadlessWindowData {
+    pub(crate) title: String,
+    pub(crate) width: Number,
+    pub(crate) height: Number,
+    pub(crate) theme: Theme,
+    pub(crate) menubar: MenuBar,
+    pub(crate) dialogs: Vec<HeadlessDialogRecord>,
+    /// The color dialog that is open, which the test has to answer.
+    pub(crate) color_dialog: Option<HeadlessColorDialogRecord>,
+}
+
+/// A dialog that was shown by the app.
+#[derive(Clone, Debug)]
+#[allow(unused)]
+pub(crate) struct HeadlessDialogRecord {
+    pub(crate) kind: DialogKind,
+    pub(crate) title: String,
+    pub(crate) text: String,
+}
+
+/// A color dialog that was shown by the app, and is still open.
+#[allow(unused)]
+pub(crate) struct HeadlessColorDialogRecord {
+    pub(crate) title: String,
+    pub(crate) color: Color,
+    pub(crate) alpha: bool,
+    pub(crate) on_pick: Rc<dyn Fn(Color)>,
+}
+
+pub(crate) struct HeadlessWindowDelegator {
+    pub(crate) window: HeadlessWindow,
+}
+
+impl WindowDelegator for HeadlessWindowDelegator {
+    fn create_dialog(&self, text: Cow<'static, str>) -> DialogBuilder {
+        let title = self.window.with(|data| data.title.clone());
+        DialogBuilder::new(Box::new(HeadlessDialog::new(self.window.clone(), text.into_owned(), title)))
+    }
+
+    fn pick_color(&self) -> ColorDialogBuilder {
+        let title = self.window.with(|data| data.title.clone());
+        ColorDialogBuilder::new(Box::new(HeadlessColorDialog::new(self.window.clone(), title)))
+    }
+
+    fn close(&self) {
+        HeadlessContext::request_exit(0);
+    }
+}
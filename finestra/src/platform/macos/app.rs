@@ -119,43 +119,63 @@ impl<Delegate, State> Dispatcher for MacOSDelegate<Delegate, State>
 
         match message {
             Event::ButtonClicked(view_id) => {
-                let Some(handler) = self.event_registry.map.get(&view_id) else {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.click.clone()) else {
                     return;
                 };
 
-                let Some(handler) = &handler.click else {
+                (handler)(&mut state, window);
+            }
+
+            Event::CheckboxChanged(view_id, is_checked) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.checked.clone()) else {
                     return;
                 };
 
-                (handler)(&mut state, window);
+                (handler)(&mut state, is_checked, window);
             }
 
-            Event::CheckboxChanged(view_id, is_checked) => {
-                let Some(handler) = self.event_registry.map.get(&view_id) else {
+            Event::ListRowActivated(view_id, row) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.activate.clone()) else {
                     return;
                 };
 
-                let Some(handler) = &handler.checked else {
+                (handler)(&mut state, row, window);
+            }
+
+            Event::SelectionChanged(view_id, index) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.selection_changed.clone()) else {
                     return;
                 };
 
-                (handler)(&mut state, is_checked, window);
+                (handler)(&mut state, index, window);
             }
 
-            Event::MenuAction { item } => {
-                self.delegate.borrow_mut().did_invoke_menu_action(item, &mut state, window);
+            Event::ValueChanged(view_id, value) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.value_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, value, window);
             }
 
-            Event::TextFieldChanged(view_id, text) => {
-                let Some(handler) = self.event_registry.map.get(&view_id) else {
+            Event::ColorChanged(view_id, color) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.color_changed.clone()) else {
                     return;
                 };
 
-                let Some(handler) = &handler.text_changed else {
+                (handler)(&mut state, color, window);
+            }
+
+            Event::MenuAction { item } => {
+                self.delegate.borrow_mut().did_invoke_menu_action(item, &mut state, window);
+            }
+
+            Event::TextFieldChanged(view_id, text) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.text_changed.clone()) else {
                     return;
                 };
 
-                (handler)(&mut state, text, window);
+                (handler)(&mut state, text.text, window);
             }
         }
     }
@@ -0,0 +1,68 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! AppKit doesn't retain the targets and delegates of its views, so they are
+//! kept alive as associated objects of the view instead, and the actions they
+//! store are dropped when they are deallocated.
+
+use std::ffi::c_void;
+
+use cacao::foundation::id;
+use cacao::objc::runtime::Object;
+use cacao::objc::{msg_send, sel, sel_impl};
+
+/// The role of an object that is kept alive by [`associate()`]. A view has
+/// at most one object per role.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Association {
+    Target,
+    Delegate,
+    KeyMonitor,
+}
+
+impl Association {
+    fn key(self) -> *const c_void {
+        static TARGET: u8 = 0;
+        static DELEGATE: u8 = 0;
+        static KEY_MONITOR: u8 = 0;
+
+        let key: &'static u8 = match self {
+            Self::Target => &TARGET,
+            Self::Delegate => &DELEGATE,
+            Self::KeyMonitor => &KEY_MONITOR,
+        };
+        key as *const u8 as *const c_void
+    }
+}
+
+/// <https://developer.apple.com/documentation/objectivec/objc_associationpolicy/objc_association_retain_nonatomic>
+const OBJC_ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+extern "C" {
+    fn objc_setAssociatedObject(object: id, key: *const c_void, value: id, policy: usize);
+}
+
+/// Keeps the `object` alive for as long as the `owner` lives, and releases
+/// the reference of the caller, e.g. of an object created using `new`. The
+/// previous object of the `association` is released.
+///
+/// <https://developer.apple.com/documentation/objectivec/1418509-objc_setassociatedobject>
+pub(crate) unsafe fn associate(owner: id, association: Association, object: id) {
+    objc_setAssociatedObject(owner, association.key(), object, OBJC_ASSOCIATION_RETAIN_NONATOMIC);
+    let _: () = msg_send![object, release];
+}
+
+/// Stores the boxed `value` in the `ivar`, dropping the previous value.
+pub(crate) unsafe fn replace_boxed_ivar<T>(this: &mut Object, ivar: &str, value: T) {
+    drop_boxed_ivar::<T>(this, ivar);
+    this.set_ivar::<usize>(ivar, Box::into_raw(Box::new(value)) as usize);
+}
+
+/// Drops the value stored by [`replace_boxed_ivar()`], for the `dealloc`
+/// method of the class.
+pub(crate) unsafe fn drop_boxed_ivar<T>(this: &Object, ivar: &str) {
+    let value = *this.get_ivar::<usize>(ivar) as *mut T;
+    if !value.is_null() {
+        drop(Box::from_raw(value));
+    }
+}
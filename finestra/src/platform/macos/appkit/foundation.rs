@@ -0,0 +1,80 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The Foundation structures that are passed by value to AppKit.
+
+use cacao::objc::{Encode, Encoding};
+
+/// <https://developer.apple.com/documentation/foundation/nspoint>
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct NSPoint {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+}
+
+unsafe impl Encode for NSPoint {
+    fn encode() -> Encoding {
+        unsafe { Encoding::from_str("{CGPoint=dd}") }
+    }
+}
+
+/// <https://developer.apple.com/documentation/foundation/nssize>
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct NSSize {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
+unsafe impl Encode for NSSize {
+    fn encode() -> Encoding {
+        unsafe { Encoding::from_str("{CGSize=dd}") }
+    }
+}
+
+/// <https://developer.apple.com/documentation/foundation/nsrect>
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct NSRect {
+    pub(crate) origin: NSPoint,
+    pub(crate) size: NSSize,
+}
+
+unsafe impl Encode for NSRect {
+    fn encode() -> Encoding {
+        unsafe { Encoding::from_str("{CGRect={CGPoint=dd}{CGSize=dd}}") }
+    }
+}
+
+/// <https://developer.apple.com/documentation/foundation/nsrange>
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct NSRange {
+    pub(crate) location: usize,
+    pub(crate) length: usize,
+}
+
+unsafe impl Encode for NSRange {
+    fn encode() -> Encoding {
+        unsafe { Encoding::from_str("{_NSRange=QQ}") }
+    }
+}
+
+/// <https://developer.apple.com/documentation/foundation/nsaffinetransformstruct>
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct NSAffineTransformStruct {
+    pub(crate) m11: f64,
+    pub(crate) m12: f64,
+    pub(crate) m21: f64,
+    pub(crate) m22: f64,
+    pub(crate) t_x: f64,
+    pub(crate) t_y: f64,
+}
+
+unsafe impl Encode for NSAffineTransformStruct {
+    fn encode() -> Encoding {
+        unsafe { Encoding::from_str("{_NSAffineTransformStruct=dddddd}") }
+    }
+}
@@ -1,14 +1,52 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+mod associated;
+mod foundation;
 mod nsalert;
+mod nscanvasview;
+mod nscolorpanel;
+mod nscolorwell;
+mod nscombobox;
+mod nscontainerview;
+#[cfg(feature = "chrono")]
+mod nsdatepicker;
+mod nsgridview;
+mod nslinkbutton;
+mod nspopupbutton;
+mod nsscrollview;
+mod nssearchfield;
+mod nsslider;
 mod nsstackview;
+mod nsstepper;
+mod nstabview;
+mod nstableview;
+mod nstextview;
 mod nstimer;
 mod menu;
 
 pub(crate) use self::{
+    foundation::{NSPoint, NSRange, NSRect},
     nsalert::NSAlert,
+    nscanvasview::NSCanvasView,
+    nscolorpanel::NSColorPanel,
+    nscolorwell::NSColorWell,
+    nscombobox::NSComboBox,
+    nscontainerview::NSContainerView,
+    nsgridview::NSGridView,
+    nslinkbutton::NSLinkButton,
+    nspopupbutton::{select_popup_item, set_popup_action, set_popup_items},
+    nsscrollview::{observe_scroll_offset, scroll_offset, set_document_view, set_scroll_offset},
+    nssearchfield::NSSearchField,
+    nsslider::NSSlider,
     nsstackview::NSStackView,
+    nsstepper::NSStepper,
+    nstabview::{is_selecting_tab, set_tab_content, NSTabView},
+    nstableview::{is_changing_rows, reload_rows, select_rows, selected_rows, set_double_action, set_key_action, set_selection_mode},
+    nstextview::NSTextView,
     nstimer::NSTimer,
     menu::set_menu_bar,
 };
+
+#[cfg(feature = "chrono")]
+pub(crate) use self::nsdatepicker::{set_date_value, NSDatePicker};
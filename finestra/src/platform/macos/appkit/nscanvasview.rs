@@ -0,0 +1,253 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+use std::sync::Once;
+
+use cacao::foundation::{id, NSString, BOOL, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use euclid::default::{Point2D, Size2D, Transform2D};
+use objc_id::ShareId;
+
+use crate::graphics::raster::components;
+use crate::{Color, DrawCommand, DrawingContext, ImageKind, Path, PathElement};
+
+use super::associated::{drop_boxed_ivar, replace_boxed_ivar};
+use super::foundation::{NSAffineTransformStruct, NSPoint, NSRect, NSSize};
+
+type Painter = Rc<dyn Fn(&mut DrawingContext)>;
+
+/// The view of a [`Canvas`](crate::Canvas), which replays the commands drawn
+/// by the painter using `NSBezierPath`. The view is flipped, so the
+/// coordinates start at the top left corner, like on the other platforms.
+pub struct NSCanvasView {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSCanvasView {
+    pub fn new(painter: Painter, size: Size2D<f32>) -> Self {
+        let view: id = unsafe { msg_send![view_class(), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            // The painter is dropped when the view is deallocated.
+            replace_boxed_ivar(&mut *view, PAINTER_IVAR, painter);
+            (*view).set_ivar::<f64>(WIDTH_IVAR, f64::from(size.width));
+            (*view).set_ivar::<f64>(HEIGHT_IVAR, f64::from(size.height));
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+const PAINTER_IVAR: &str = "finestraPainter";
+const WIDTH_IVAR: &str = "finestraWidth";
+const HEIGHT_IVAR: &str = "finestraHeight";
+
+fn view_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraCanvasView", class!(NSView)).unwrap();
+        decl.add_ivar::<usize>(PAINTER_IVAR);
+        decl.add_ivar::<f64>(WIDTH_IVAR);
+        decl.add_ivar::<f64>(HEIGHT_IVAR);
+
+        extern "C" fn is_flipped(_: &Object, _: Sel) -> BOOL {
+            YES
+        }
+
+        extern "C" fn intrinsic_content_size(this: &Object, _: Sel) -> NSSize {
+            unsafe {
+                NSSize {
+                    width: *this.get_ivar::<f64>(WIDTH_IVAR),
+                    height: *this.get_ivar::<f64>(HEIGHT_IVAR),
+                }
+            }
+        }
+
+        extern "C" fn draw_rect(this: &Object, _: Sel, _dirty: NSRect) {
+            unsafe {
+                let bounds: NSRect = msg_send![this, bounds];
+                let painter = &*(*this.get_ivar::<usize>(PAINTER_IVAR) as *const Painter);
+
+                let mut context = DrawingContext::new(Size2D::new(bounds.size.width as f32, bounds.size.height as f32));
+                painter(&mut context);
+
+                for command in context.into_commands() {
+                    draw_command(&command);
+                }
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<Painter>(this, PAINTER_IVAR);
+                let _: () = msg_send![super(this, class!(NSView)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(isFlipped), is_flipped as extern "C" fn(&Object, Sel) -> BOOL);
+            decl.add_method(sel!(intrinsicContentSize), intrinsic_content_size as extern "C" fn(&Object, Sel) -> NSSize);
+            decl.add_method(sel!(drawRect:), draw_rect as extern "C" fn(&Object, Sel, NSRect));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraCanvasView)
+}
+
+unsafe fn draw_command(command: &DrawCommand) {
+    let _: () = msg_send![class!(NSGraphicsContext), saveGraphicsState];
+
+    match command {
+        DrawCommand::Fill { path, color, transform } => {
+            concat_transform(transform);
+            set_color(color);
+            let _: () = msg_send![bezier_path(path), fill];
+        }
+
+        DrawCommand::Stroke { path, color, width, transform } => {
+            concat_transform(transform);
+            set_color(color);
+
+            let path = bezier_path(path);
+            let _: () = msg_send![path, setLineWidth:f64::from(*width)];
+            // NSLineJoinStyleRound
+            let _: () = msg_send![path, setLineJoinStyle:1_usize];
+            let _: () = msg_send![path, stroke];
+        }
+
+        DrawCommand::Text { text, position, font_size, color, transform } => {
+            concat_transform(transform);
+
+            let font: id = msg_send![class!(NSFont), systemFontOfSize:f64::from(*font_size)];
+            let ascender: f64 = msg_send![font, ascender];
+
+            let font_key = NSString::new("NSFont");
+            let color_key = NSString::new("NSColor");
+            let keys: [id; 2] = [&*font_key as *const Object as id, &*color_key as *const Object as id];
+            let values: [id; 2] = [font, ns_color(color)];
+            let attributes: id = msg_send![class!(NSDictionary), dictionaryWithObjects:values.as_ptr() forKeys:keys.as_ptr() count:2_usize];
+
+            // The point is the top left corner of the text, not the start of
+            // the baseline.
+            let point = NSPoint { x: f64::from(position.x), y: f64::from(position.y) - ascender };
+            let text = NSString::new(text);
+            let _: () = msg_send![&*text, drawAtPoint:point withAttributes:attributes];
+        }
+
+        DrawCommand::Image { image, rect, transform } => {
+            if let ImageKind::File(path) = image.kind() {
+                concat_transform(transform);
+
+                let path = NSString::new(&path.display().to_string());
+                let image: id = msg_send![class!(NSImage), alloc];
+                let image: id = msg_send![image, initWithContentsOfFile:&*path];
+                if !image.is_null() {
+                    let rect = NSRect {
+                        origin: NSPoint { x: f64::from(rect.origin.x), y: f64::from(rect.origin.y) },
+                        size: NSSize { width: f64::from(rect.width()), height: f64::from(rect.height()) },
+                    };
+                    let _: () = msg_send![image, drawInRect:rect];
+                    let _: () = msg_send![image, release];
+                }
+            }
+        }
+    }
+
+    let _: () = msg_send![class!(NSGraphicsContext), restoreGraphicsState];
+}
+
+unsafe fn concat_transform(transform: &Transform2D<f32>) {
+    let [m11, m12, m21, m22, t_x, t_y] = transform.to_array().map(f64::from);
+
+    let ns_transform: id = msg_send![class!(NSAffineTransform), transform];
+    let _: () = msg_send![ns_transform, setTransformStruct:NSAffineTransformStruct { m11, m12, m21, m22, t_x, t_y }];
+    let _: () = msg_send![ns_transform, concat];
+}
+
+/// Uses the same colors as the software renderer of the headless backend,
+/// like the GTK backend does.
+unsafe fn ns_color(color: &Color) -> id {
+    let [red, green, blue, alpha] = components(color).map(f64::from);
+    msg_send![class!(NSColor), colorWithSRGBRed:red green:green blue:blue alpha:alpha]
+}
+
+unsafe fn set_color(color: &Color) {
+    let _: () = msg_send![ns_color(color), set];
+}
+
+unsafe fn bezier_path(path: &Path) -> id {
+    let ns_path: id = msg_send![class!(NSBezierPath), bezierPath];
+    let to_ns = |point: Point2D<f32>| NSPoint { x: f64::from(point.x), y: f64::from(point.y) };
+
+    let mut current = Point2D::zero();
+    for element in path.elements() {
+        match *element {
+            PathElement::MoveTo(point) => {
+                let _: () = msg_send![ns_path, moveToPoint:to_ns(point)];
+                current = point;
+            }
+
+            PathElement::LineTo(point) => {
+                let _: () = msg_send![ns_path, lineToPoint:to_ns(point)];
+                current = point;
+            }
+
+            // AppKit doesn't have quadratic curves before macOS 14, but these
+            // can be drawn as cubic curves.
+            PathElement::QuadraticTo(control, point) => {
+                let control1 = current.lerp(control, 2.0 / 3.0);
+                let control2 = point.lerp(control, 2.0 / 3.0);
+                let _: () = msg_send![ns_path, curveToPoint:to_ns(point) controlPoint1:to_ns(control1) controlPoint2:to_ns(control2)];
+                current = point;
+            }
+
+            PathElement::CubicTo(control1, control2, point) => {
+                let _: () = msg_send![ns_path, curveToPoint:to_ns(point) controlPoint1:to_ns(control1) controlPoint2:to_ns(control2)];
+                current = point;
+            }
+
+            PathElement::Close => {
+                let _: () = msg_send![ns_path, closePath];
+            }
+        }
+    }
+
+    ns_path
+}
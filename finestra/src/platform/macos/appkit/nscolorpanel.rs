@@ -0,0 +1,169 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::Once;
+
+use cacao::foundation::{id, nil, NSString, NO, YES};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+
+use crate::platform::macos::resources::ToCacao;
+use crate::{Color, ColorDialogApi, SystemColor};
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type ColorAction = Box<dyn Fn(Color)>;
+
+/// The shared color panel, which is used by [`Window::pick_color()`](crate::Window::pick_color).
+/// The panel isn't modal, so the action is invoked for every color the user
+/// picks.
+pub(crate) struct NSColorPanel {
+    title: RefCell<String>,
+    color: RefCell<Color>,
+    alpha: RefCell<bool>,
+}
+
+impl NSColorPanel {
+    pub fn new(title: String) -> Self {
+        Self {
+            title: RefCell::new(title),
+            color: RefCell::new(Color::default()),
+            alpha: RefCell::new(true),
+        }
+    }
+}
+
+impl ColorDialogApi for NSColorPanel {
+    fn set_alpha(&self, alpha: bool) {
+        *self.alpha.borrow_mut() = alpha;
+    }
+
+    fn set_color(&self, color: Color) {
+        *self.color.borrow_mut() = color;
+    }
+
+    fn set_title(&self, title: Cow<'static, str>) {
+        *self.title.borrow_mut() = title.into_owned();
+    }
+
+    fn show(&self, on_pick: Box<dyn Fn(Color)>) {
+        unsafe {
+            let panel = shared_color_panel();
+
+            let title = NSString::new(&self.title.borrow());
+            let _: () = msg_send![panel, setTitle:title];
+            let _: () = msg_send![panel, setShowsAlpha:if *self.alpha.borrow() { YES } else { NO }];
+            let _: () = msg_send![panel, setColor:to_ns_color(&self.color.borrow())];
+
+            // The panel is shared, so the target of the previous dialog is
+            // released when it is replaced.
+            let target = color_target(on_pick);
+            let _: () = msg_send![panel, setTarget:target];
+            let _: () = msg_send![panel, setAction:sel!(pick:)];
+            associate(panel, Association::Target, target);
+            let _: () = msg_send![panel, makeKeyAndOrderFront:nil];
+        }
+    }
+}
+
+/// Returns the shared color panel, which offers the named [`SystemColor`]s as
+/// swatches.
+pub(crate) fn shared_color_panel() -> id {
+    static ATTACH_SWATCHES: Once = Once::new();
+
+    let panel: id = unsafe { msg_send![class!(NSColorPanel), sharedColorPanel] };
+
+    ATTACH_SWATCHES.call_once(|| unsafe {
+        let name = NSString::new("Finestra");
+        let list: id = msg_send![class!(NSColorList), alloc];
+        let list: id = msg_send![list, initWithName:name];
+
+        for color in SystemColor::SWATCHES {
+            let key = NSString::new(&format!("{color:?}"));
+            let _: () = msg_send![list, setColor:to_ns_color(&color.into()) forKey:key];
+        }
+
+        let _: () = msg_send![panel, attachColorList:list];
+    });
+
+    panel
+}
+
+/// The default color isn't a specific color, so it is black.
+pub(crate) fn to_ns_color(color: &Color) -> id {
+    let color = color.to_cacao().unwrap_or(cacao::color::Color::SystemBlack);
+    color.as_ref().into()
+}
+
+/// Returns [`None`] when the color can't be converted to sRGB, e.g. when it's
+/// a pattern.
+fn from_ns_color(color: id) -> Option<Color> {
+    unsafe {
+        let color_space: id = msg_send![class!(NSColorSpace), sRGBColorSpace];
+        let color: id = msg_send![color, colorUsingColorSpace:color_space];
+        if color == nil {
+            return None;
+        }
+
+        let red: f64 = msg_send![color, redComponent];
+        let green: f64 = msg_send![color, greenComponent];
+        let blue: f64 = msg_send![color, blueComponent];
+        let alpha: f64 = msg_send![color, alphaComponent];
+        Some(Color::from_components(red, green, blue, alpha))
+    }
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+/// The action of the color panel, and of the color wells.
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraColorTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn pick(this: &Object, _: Sel, sender: id) {
+            unsafe {
+                let color: id = msg_send![sender, color];
+                let Some(color) = from_ns_color(color) else {
+                    return;
+                };
+
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const ColorAction;
+                (*action)(color);
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<ColorAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(pick:), pick as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraColorTarget)
+}
+
+/// Creates a target that invokes the `action` with the color of the sender.
+/// The caller owns the target, e.g. to [`associate()`] it with the view.
+pub(crate) fn color_target(action: impl Fn(Color) + 'static) -> id {
+    let action: ColorAction = Box::new(action);
+
+    unsafe {
+        let target: id = msg_send![target_class(), new];
+        replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+        target
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use cacao::foundation::{id, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use crate::Color;
+
+use super::associated::{associate, Association};
+use super::nscolorpanel::{color_target, shared_color_panel, to_ns_color};
+
+/// The well of a [`ColorWell`](crate::ColorWell), which opens the shared color
+/// panel when it is clicked.
+pub struct NSColorWell {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSColorWell {
+    pub fn new(alpha: bool) -> Self {
+        let view: id = unsafe { msg_send![class!(NSColorWell), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            // The panel is shared by every well, so the last well that is
+            // created decides whether the opacity can be picked.
+            let _: () = msg_send![shared_color_panel(), setShowsAlpha:if alpha { YES } else { NO }];
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    pub fn set_color(objc: &ObjcProperty, color: &Color) {
+        objc.with_mut(|view| unsafe {
+            let _: () = msg_send![view, setColor:to_ns_color(color)];
+        });
+    }
+
+    /// Invokes the `action` with the color the user picked.
+    pub fn set_action(&self, action: impl Fn(Color) + 'static) {
+        self.objc.with_mut(|view| unsafe {
+            let target = color_target(action);
+            let _: () = msg_send![view, setTarget:target];
+            let _: () = msg_send![view, setAction:sel!(pick:)];
+            associate(view, Association::Target, target);
+        });
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, NSString, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type ComboBoxAction = Box<dyn Fn(String, Option<usize>)>;
+
+/// A text field with a list of suggestions, that can be used to enter any
+/// text.
+pub struct NSComboBox {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSComboBox {
+    pub fn new() -> Self {
+        let view: id = unsafe { msg_send![class!(NSComboBox), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setCompletes: YES];
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    /// Replaces the suggestions.
+    pub fn set_items(objc: &ObjcProperty, items: &[String]) {
+        objc.with_mut(|view| unsafe {
+            let _: () = msg_send![view, removeAllItems];
+            for item in items {
+                let item = NSString::new(item);
+                let _: () = msg_send![view, addItemWithObjectValue:&*item];
+            }
+        });
+    }
+
+    /// Invokes the `action` with the text and the index of the selected
+    /// suggestion, when the user picked a suggestion or finished typing.
+    pub fn set_action(&self, action: impl Fn(String, Option<usize>) + 'static) {
+        let action: ComboBoxAction = Box::new(action);
+
+        self.objc.with_mut(|view| unsafe {
+            let target: id = msg_send![target_class(), new];
+            replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+
+            let _: () = msg_send![view, setTarget:target];
+            let _: () = msg_send![view, setAction:sel!(changeValue:)];
+            associate(view, Association::Target, target);
+        });
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraComboBoxTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn change_value(this: &Object, _: Sel, combo_box: id) {
+            unsafe {
+                let text: id = msg_send![combo_box, stringValue];
+                let text = NSString::retain(text).to_string();
+
+                let index: isize = msg_send![combo_box, indexOfSelectedItem];
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const ComboBoxAction;
+                (*action)(text, usize::try_from(index).ok());
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<ComboBoxAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(changeValue:), change_value as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraComboBoxTarget)
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, BOOL, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use crate::{ContainerLayout, LayoutNode, LayoutRect, LayoutSize};
+
+use crate::platform::macos::DynamicViewWrapper;
+
+use super::foundation::{NSPoint, NSRect, NSSize};
+
+/// Lays out its subviews using the layout pass, instead of the Auto Layout
+/// rules of AppKit, so the frames are the same as on the other backends. The
+/// subviews are measured using their `fittingSize`, and are treated as leaves
+/// of the layout pass. The view is flipped, so the frames start at the top
+/// left corner.
+pub struct NSContainerView {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+
+    pub children: Vec<DynamicViewWrapper>,
+}
+
+impl NSContainerView {
+    pub fn new(layout: ContainerLayout) -> Self {
+        let view: id = unsafe { msg_send![view_class(), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setWantsLayer: YES];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            // The layout is dropped when the view is deallocated.
+            (*view).set_ivar::<usize>(LAYOUT_IVAR, Box::into_raw(Box::new(layout)) as usize);
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+
+            children: Vec::new(),
+        }
+    }
+
+    pub fn add_view(&mut self, subview: DynamicViewWrapper) {
+        self.objc.with_mut(|view| {
+            subview.objc().with_mut(|subview| {
+                unsafe {
+                    // The frame of the subview is set by the container.
+                    let _: () = msg_send![subview, setTranslatesAutoresizingMaskIntoConstraints: YES];
+                    let _: () = msg_send![view, addSubview: subview];
+                    let _: () = msg_send![view, invalidateIntrinsicContentSize];
+                    let _: () = msg_send![view, setNeedsLayout: YES];
+                }
+            });
+        });
+
+        self.children.push(subview);
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+const LAYOUT_IVAR: &str = "finestraLayout";
+
+/// Builds the layout node of the container, with its subviews as leaves.
+unsafe fn node(this: &Object) -> LayoutNode {
+    let layout = &*(*this.get_ivar::<usize>(LAYOUT_IVAR) as *const ContainerLayout);
+
+    let children = subviews(this)
+        .into_iter()
+        .map(|subview| {
+            let size: NSSize = msg_send![subview, fittingSize];
+            LayoutNode::Leaf(LayoutSize::new(size.width as f32, size.height as f32))
+        })
+        .collect();
+
+    layout.node(children)
+}
+
+unsafe fn subviews(this: &Object) -> Vec<id> {
+    let subviews: id = msg_send![this, subviews];
+    let count: usize = msg_send![subviews, count];
+    (0..count)
+        .map(|index| msg_send![subviews, objectAtIndex: index])
+        .collect()
+}
+
+fn view_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraContainerView", class!(NSView)).unwrap();
+        decl.add_ivar::<usize>(LAYOUT_IVAR);
+
+        extern "C" fn is_flipped(_: &Object, _: Sel) -> BOOL {
+            YES
+        }
+
+        extern "C" fn intrinsic_content_size(this: &Object, _: Sel) -> NSSize {
+            let size = unsafe { node(this) }.measure();
+            NSSize {
+                width: size.width.into(),
+                height: size.height.into(),
+            }
+        }
+
+        extern "C" fn layout(this: &Object, _: Sel) {
+            unsafe {
+                let _: () = msg_send![super(this, class!(NSView)), layout];
+
+                let bounds: NSRect = msg_send![this, bounds];
+                let size = LayoutSize::new(bounds.size.width as f32, bounds.size.height as f32);
+                let frame = node(this).arrange(LayoutRect::from_size(size));
+
+                for (subview, frame) in subviews(this).into_iter().zip(&frame.children) {
+                    let rect = frame.rect.round();
+                    let rect = NSRect {
+                        origin: NSPoint { x: rect.origin.x.into(), y: rect.origin.y.into() },
+                        size: NSSize { width: rect.size.width.into(), height: rect.size.height.into() },
+                    };
+                    let _: () = msg_send![subview, setFrame: rect];
+                }
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                let layout = *this.get_ivar::<usize>(LAYOUT_IVAR) as *mut ContainerLayout;
+                if !layout.is_null() {
+                    drop(Box::from_raw(layout));
+                }
+
+                let _: () = msg_send![super(this, class!(NSView)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+            decl.add_method(sel!(isFlipped), is_flipped as extern "C" fn(&Object, Sel) -> BOOL);
+            decl.add_method(sel!(intrinsicContentSize), intrinsic_content_size as extern "C" fn(&Object, Sel) -> NSSize);
+            decl.add_method(sel!(layout), layout as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraContainerView)
+}
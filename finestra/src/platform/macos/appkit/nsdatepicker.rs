@@ -0,0 +1,158 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, nil, NO};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use crate::DatePickerMode;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type DatePickerAction = Box<dyn Fn(f64)>;
+
+/// `NSDatePickerStyleTextFieldAndStepper`
+const STYLE_TEXT_FIELD_AND_STEPPER: usize = 0;
+
+/// `NSDatePickerStyleClockAndCalendar`
+const STYLE_CLOCK_AND_CALENDAR: usize = 1;
+
+/// `NSDatePickerElementFlagHourMinute`
+const ELEMENTS_HOUR_MINUTE: usize = 0x000c;
+
+/// `NSDatePickerElementFlagYearMonthDay`
+const ELEMENTS_YEAR_MONTH_DAY: usize = 0x00e0;
+
+/// A date picker for the [`DatePicker`](crate::DatePicker), or the calendar
+/// grid for the [`Calendar`](crate::Calendar). The moments are exchanged as
+/// seconds since the Unix epoch, and shown in UTC, just like the `chrono`
+/// naive types.
+pub struct NSDatePicker {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSDatePicker {
+    pub fn new(mode: DatePickerMode, calendar: bool) -> Self {
+        let view: id = unsafe { msg_send![class!(NSDatePicker), new] };
+
+        let style = if calendar { STYLE_CLOCK_AND_CALENDAR } else { STYLE_TEXT_FIELD_AND_STEPPER };
+        let elements = match mode {
+            DatePickerMode::Date => ELEMENTS_YEAR_MONTH_DAY,
+            DatePickerMode::Time => ELEMENTS_HOUR_MINUTE,
+            DatePickerMode::DateTime => ELEMENTS_YEAR_MONTH_DAY | ELEMENTS_HOUR_MINUTE,
+        };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setDatePickerStyle:style];
+            let _: () = msg_send![view, setDatePickerElements:elements];
+
+            let utc: id = msg_send![class!(NSTimeZone), timeZoneForSecondsFromGMT:0_isize];
+            let _: () = msg_send![view, setTimeZone:utc];
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    /// Don't let the user pick a moment outside the bounds.
+    pub fn set_bounds(&self, min: Option<f64>, max: Option<f64>) {
+        self.objc.with_mut(|view| unsafe {
+            let _: () = msg_send![view, setMinDate:min.map_or(nil, to_ns_date)];
+            let _: () = msg_send![view, setMaxDate:max.map_or(nil, to_ns_date)];
+        });
+    }
+
+    /// Invokes the `action` with the moment the user picked.
+    pub fn set_action(&self, action: impl Fn(f64) + 'static) {
+        let action: DatePickerAction = Box::new(action);
+
+        self.objc.with_mut(|view| unsafe {
+            let target: id = msg_send![target_class(), new];
+            replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+
+            let _: () = msg_send![view, setTarget:target];
+            let _: () = msg_send![view, setAction:sel!(pick:)];
+            associate(view, Association::Target, target);
+        });
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+/// Shows the moment, given as seconds since the Unix epoch.
+pub(crate) fn set_date_value(objc: &ObjcProperty, timestamp: f64) {
+    objc.with_mut(|view| unsafe {
+        let _: () = msg_send![view, setDateValue:to_ns_date(timestamp)];
+    });
+}
+
+fn to_ns_date(timestamp: f64) -> id {
+    unsafe { msg_send![class!(NSDate), dateWithTimeIntervalSince1970:timestamp] }
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraDatePickerTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn pick(this: &Object, _: Sel, date_picker: id) {
+            unsafe {
+                let date: id = msg_send![date_picker, dateValue];
+                let timestamp: f64 = msg_send![date, timeIntervalSince1970];
+
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const DatePickerAction;
+                (*action)(timestamp);
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<DatePickerAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(pick:), pick as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraDatePickerTarget)
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use cacao::foundation::{id, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+use cacao::objc::{class, msg_send, sel, sel_impl};
+
+use crate::{CellAlignment, GridLayout, GridPlacement, GridTrack};
+
+use crate::platform::macos::DynamicViewWrapper;
+
+use super::NSRange;
+
+/// <https://developer.apple.com/documentation/appkit/nsgridcell/placement>
+fn placement(alignment: CellAlignment) -> isize {
+    match alignment {
+        CellAlignment::Start => 2, // .leading, .top
+        CellAlignment::Center => 4, // .center
+        CellAlignment::End => 3, // .trailing, .bottom
+        CellAlignment::Fill => 5, // .fill
+    }
+}
+
+pub struct NSGridView {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+
+    pub children: Vec<DynamicViewWrapper>,
+}
+
+impl NSGridView {
+    pub fn new(layout: &GridLayout) -> Self {
+        let columns = layout.cells.iter()
+            .map(|cell| cell.column + cell.column_span)
+            .chain(std::iter::once(layout.columns.len()))
+            .max()
+            .unwrap_or_default();
+
+        let rows = layout.cells.iter()
+            .map(|cell| cell.row + cell.row_span)
+            .chain(std::iter::once(layout.rows.len()))
+            .max()
+            .unwrap_or_default();
+
+        let view: id = unsafe {
+            msg_send![
+                class!(NSGridView), gridViewWithNumberOfColumns:columns as isize rows:rows as isize
+            ]
+        };
+
+        unsafe {
+            let _: () = msg_send![view, setWantsLayer: YES];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setColumnSpacing: f64::from(layout.column_spacing)];
+            let _: () = msg_send![view, setRowSpacing: f64::from(layout.row_spacing)];
+
+            for (index, track) in layout.columns.iter().enumerate() {
+                if let GridTrack::Fixed(width) = track {
+                    let column: id = msg_send![view, columnAtIndex: index as isize];
+                    let _: () = msg_send![column, setWidth: f64::from(*width)];
+                }
+            }
+
+            for (index, track) in layout.rows.iter().enumerate() {
+                if let GridTrack::Fixed(height) = track {
+                    let row: id = msg_send![view, rowAtIndex: index as isize];
+                    let _: () = msg_send![row, setHeight: f64::from(*height)];
+                }
+            }
+
+            // NSGridView doesn't have insets, so the padding is applied to
+            // the outer rows and columns.
+            if columns != 0 {
+                let first: id = msg_send![view, columnAtIndex: 0isize];
+                let _: () = msg_send![first, setLeadingPadding: f64::from(layout.padding.left)];
+                let last: id = msg_send![view, columnAtIndex: columns as isize - 1];
+                let _: () = msg_send![last, setTrailingPadding: f64::from(layout.padding.right)];
+            }
+
+            if rows != 0 {
+                let first: id = msg_send![view, rowAtIndex: 0isize];
+                let _: () = msg_send![first, setTopPadding: f64::from(layout.padding.top)];
+                let last: id = msg_send![view, rowAtIndex: rows as isize - 1];
+                let _: () = msg_send![last, setBottomPadding: f64::from(layout.padding.bottom)];
+            }
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+
+            children: Vec::new(),
+        }
+    }
+
+    pub fn add_view(&mut self, cell_placement: &GridPlacement, subview: DynamicViewWrapper) {
+        let GridPlacement { row, column, row_span, column_span, .. } = *cell_placement;
+
+        self.objc.with_mut(|view| {
+            subview.objc().with_mut(|subview| {
+                unsafe {
+                    if row_span > 1 || column_span > 1 {
+                        let horizontal = NSRange { location: column, length: column_span };
+                        let vertical = NSRange { location: row, length: row_span };
+                        let _: () = msg_send![view, mergeCellsInHorizontalRange:horizontal verticalRange:vertical];
+                    }
+
+                    let cell: id = msg_send![view, cellAtColumnIndex:column as isize rowIndex:row as isize];
+                    let _: () = msg_send![cell, setContentView: subview];
+                    let _: () = msg_send![cell, setXPlacement: placement(cell_placement.horizontal_alignment)];
+                    let _: () = msg_send![cell, setYPlacement: placement(cell_placement.vertical_alignment)];
+                }
+            });
+        });
+
+        self.children.push(subview);
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
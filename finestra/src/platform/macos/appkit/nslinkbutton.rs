@@ -0,0 +1,164 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, NSString, NO};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+use super::foundation::NSRect;
+
+type Action = Box<dyn Fn()>;
+
+/// The button of a [`Link`](crate::Link): a borderless button with the title
+/// in the link color, which shows the pointing hand cursor on hover.
+pub struct NSLinkButton {
+    pub objc: ObjcProperty,
+    target: id,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSLinkButton {
+    pub fn new() -> Self {
+        unsafe {
+            let view: id = msg_send![button_class(), new];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setBordered: NO];
+
+            let color: id = msg_send![class!(NSColor), linkColor];
+            let _: () = msg_send![view, setContentTintColor: color];
+
+            // The target is released with the button, which is retained by
+            // this wrapper.
+            let target: id = msg_send![target_class(), new];
+            let _: () = msg_send![view, setTarget: target];
+            let _: () = msg_send![view, setAction: sel!(click:)];
+            associate(view, Association::Target, target);
+
+            Self {
+                objc: ObjcProperty::retain(view),
+                target,
+
+                center_x: LayoutAnchorX::Center(ShareId::from_ptr(msg_send![view, centerXAnchor])),
+                center_y: LayoutAnchorY::Center(ShareId::from_ptr(msg_send![view, centerYAnchor])),
+                top: LayoutAnchorY::Top(ShareId::from_ptr(msg_send![view, topAnchor])),
+                bottom: LayoutAnchorY::Bottom(ShareId::from_ptr(msg_send![view, bottomAnchor])),
+                left: LayoutAnchorX::Left(ShareId::from_ptr(msg_send![view, leftAnchor])),
+                right: LayoutAnchorX::Right(ShareId::from_ptr(msg_send![view, rightAnchor])),
+            }
+        }
+    }
+
+    /// The title is underlined and drawn in the link color.
+    pub fn set_title(objc: &ObjcProperty, title: &str) {
+        let title = NSString::new(title);
+        objc.with_mut(|view| unsafe {
+            let color: id = msg_send![class!(NSColor), linkColor];
+            let keys: [id; 2] = [NSForegroundColorAttributeName, NSUnderlineStyleAttributeName];
+            let underline: id = msg_send![class!(NSNumber), numberWithInteger: 1_isize];
+            let values: [id; 2] = [color, underline];
+            let attributes: id = msg_send![class!(NSDictionary), dictionaryWithObjects: values.as_ptr() forKeys: keys.as_ptr() count: 2_usize];
+
+            let string: id = msg_send![class!(NSAttributedString), alloc];
+            let string: id = msg_send![string, initWithString: &*title attributes: attributes];
+            let _: () = msg_send![view, setAttributedTitle: string];
+        });
+    }
+
+    /// Invokes the `action` when the user clicks the link.
+    pub fn set_action(&self, action: impl Fn() + 'static) {
+        let action: Action = Box::new(action);
+        unsafe {
+            replace_boxed_ivar(&mut *self.target, ACTION_IVAR, action);
+        }
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    static NSForegroundColorAttributeName: id;
+    static NSUnderlineStyleAttributeName: id;
+}
+
+/// An `NSButton` that shows the pointing hand cursor.
+fn button_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraLinkButton", class!(NSButton)).unwrap();
+
+        extern "C" fn reset_cursor_rects(this: &Object, _: Sel) {
+            unsafe {
+                let bounds: NSRect = msg_send![this, bounds];
+                let cursor: id = msg_send![class!(NSCursor), pointingHandCursor];
+                let _: () = msg_send![this, addCursorRect: bounds cursor: cursor];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(resetCursorRects), reset_cursor_rects as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraLinkButton)
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraLinkButtonTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn click(this: &Object, _: Sel, _sender: id) {
+            unsafe {
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const Action;
+                if !action.is_null() {
+                    (*action)();
+                }
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<Action>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(click:), click as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraLinkButtonTarget)
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Functions for the `NSPopUpButton` of a [`cacao::select::Select`].
+
+use std::sync::Once;
+
+use cacao::foundation::{id, NSString};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type PopUpAction = Box<dyn Fn(Option<usize>)>;
+
+/// Replaces the items of the pop-up button.
+pub(crate) fn set_popup_items(popup: &ObjcProperty, items: &[String]) {
+    popup.with_mut(|popup| unsafe {
+        let _: () = msg_send![popup, removeAllItems];
+        for item in items {
+            let item = NSString::new(item);
+            let _: () = msg_send![popup, addItemWithTitle:&*item];
+        }
+    });
+}
+
+/// Selects the item at `index`, or no item at all, which doesn't invoke the
+/// action.
+pub(crate) fn select_popup_item(popup: &ObjcProperty, index: Option<usize>) {
+    let index = index.map_or(-1, |index| index as isize);
+    popup.with_mut(|popup| unsafe {
+        let _: () = msg_send![popup, selectItemAtIndex:index];
+    });
+}
+
+/// Invokes the `action` with the index of the item the user selected.
+pub(crate) fn set_popup_action(popup: &ObjcProperty, action: impl Fn(Option<usize>) + 'static) {
+    let action: PopUpAction = Box::new(action);
+
+    popup.with_mut(|popup| unsafe {
+        let target: id = msg_send![target_class(), new];
+        replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+
+        let _: () = msg_send![popup, setTarget:target];
+        let _: () = msg_send![popup, setAction:sel!(selectItem:)];
+        associate(popup, Association::Target, target);
+    });
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraPopUpButtonTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn select_item(this: &Object, _: Sel, popup: id) {
+            unsafe {
+                let index: isize = msg_send![popup, indexOfSelectedItem];
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const PopUpAction;
+                (*action)(usize::try_from(index).ok());
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<PopUpAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(selectItem:), select_item as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraPopUpButtonTarget)
+}
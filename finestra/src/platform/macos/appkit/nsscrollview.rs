@@ -0,0 +1,133 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+
+use block::ConcreteBlock;
+use cacao::foundation::{id, nil, NSString, BOOL, NO, YES};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::scrollview::ScrollView;
+use cacao::utils::properties::ObjcProperty;
+
+use crate::platform::macos::DynamicViewWrapper;
+
+use super::{NSPoint, NSRect};
+
+thread_local! {
+    /// Scrolling programmatically posts the bounds notification as well, which
+    /// would make the listeners of a [`State`](crate::State) notify themselves.
+    static IS_SETTING_OFFSET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Places the `document` in the `NSScrollView`. Along the axes that don't
+/// scroll, the document is as large as the scroll view.
+pub(crate) fn set_document_view(scroll_view: &ScrollView, document: &DynamicViewWrapper, horizontal: bool, vertical: bool) {
+    scroll_view.objc.with_mut(|scroll_view| {
+        document.objc().with_mut(|document| unsafe {
+            let _: () = msg_send![scroll_view, setHasHorizontalScroller:if horizontal { YES } else { NO }];
+            let _: () = msg_send![scroll_view, setHasVerticalScroller:if vertical { YES } else { NO }];
+            let _: () = msg_send![scroll_view, setDocumentView:&*document];
+
+            let clip_view: id = msg_send![scroll_view, contentView];
+            let _: () = msg_send![clip_view, setPostsBoundsChangedNotifications:YES];
+
+            pin(document, clip_view, sel!(leadingAnchor), false);
+            pin(document, clip_view, sel!(topAnchor), false);
+            pin(document, clip_view, sel!(widthAnchor), horizontal);
+            pin(document, clip_view, sel!(heightAnchor), vertical);
+        });
+    });
+}
+
+/// Constrains the anchor of the document to the same anchor of the clip view.
+/// When `at_least` is set, the document can be larger than the clip view.
+unsafe fn pin(document: id, clip_view: id, anchor: cacao::objc::runtime::Sel, at_least: bool) {
+    let document_anchor: id = msg_send![document, performSelector:anchor];
+    let clip_anchor: id = msg_send![clip_view, performSelector:anchor];
+
+    let constraint: id = if at_least {
+        msg_send![document_anchor, constraintGreaterThanOrEqualToAnchor:clip_anchor]
+    } else {
+        msg_send![document_anchor, constraintEqualToAnchor:clip_anchor]
+    };
+
+    let _: () = msg_send![constraint, setActive:YES];
+}
+
+/// Get the scroll offset from the top left corner of the document.
+pub(crate) fn scroll_offset(scroll_view: &ObjcProperty) -> NSPoint {
+    scroll_view.get(|scroll_view| unsafe {
+        let document: id = msg_send![scroll_view, documentView];
+        if document == nil {
+            return NSPoint::default();
+        }
+
+        let visible: NSRect = msg_send![scroll_view, documentVisibleRect];
+        let frame: NSRect = msg_send![document, frame];
+        let flipped: BOOL = msg_send![document, isFlipped];
+
+        // Unflipped views have their origin in the bottom left corner.
+        let y = if flipped == YES {
+            visible.origin.y
+        } else {
+            frame.size.height - visible.origin.y - visible.size.height
+        };
+
+        NSPoint { x: visible.origin.x, y }
+    })
+}
+
+/// Scroll the document, such that `offset` is the top left corner of the
+/// visible area.
+pub(crate) fn set_scroll_offset(scroll_view: &ObjcProperty, offset: NSPoint) {
+    scroll_view.with_mut(|scroll_view| unsafe {
+        let document: id = msg_send![scroll_view, documentView];
+        if document == nil {
+            return;
+        }
+
+        let clip_view: id = msg_send![scroll_view, contentView];
+        let visible: NSRect = msg_send![scroll_view, documentVisibleRect];
+        let frame: NSRect = msg_send![document, frame];
+        let flipped: BOOL = msg_send![document, isFlipped];
+
+        let y = if flipped == YES {
+            offset.y
+        } else {
+            frame.size.height - offset.y - visible.size.height
+        };
+
+        let point = NSPoint { x: offset.x, y };
+        IS_SETTING_OFFSET.set(true);
+        let _: () = msg_send![clip_view, scrollToPoint:point];
+        let _: () = msg_send![scroll_view, reflectScrolledClipView:clip_view];
+        IS_SETTING_OFFSET.set(false);
+    });
+}
+
+/// Invokes `action` with the scroll offset, every time the user scrolls.
+///
+/// <https://developer.apple.com/documentation/appkit/nsview/1483585-boundsdidchangenotification>
+pub(crate) fn observe_scroll_offset(scroll_view: &ObjcProperty, action: impl Fn(NSPoint) + 'static) {
+    let objc = scroll_view.clone();
+    let block = ConcreteBlock::new(move |_: id| {
+        if !IS_SETTING_OFFSET.get() {
+            action(scroll_offset(&objc));
+        }
+    });
+    let block = block.copy();
+
+    scroll_view.get(|scroll_view| unsafe {
+        let clip_view: id = msg_send![scroll_view, contentView];
+        let name = NSString::new("NSViewBoundsDidChangeNotification");
+
+        let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+        let _: id = msg_send![
+            center,
+            addObserverForName:&*name
+            object:clip_view
+            queue:nil
+            usingBlock:&*block
+        ];
+    });
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, nil, NSInteger, NSString, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type TextAction = Box<dyn Fn(String)>;
+
+/// The tags of the items of the search menu template, which AppKit replaces
+/// by the recent searches.
+///
+/// <https://developer.apple.com/documentation/appkit/nssearchfield/recents_menu_tags>
+const RECENTS_MENU_ITEM_TAG: NSInteger = 1000;
+const NO_RECENTS_MENU_ITEM_TAG: NSInteger = 1003;
+
+/// The field of a [`SearchField`](crate::SearchField). The action of the field
+/// is only sent when the return key is pressed or the field is cleared, as it
+/// sends the whole search string.
+pub struct NSSearchField {
+    pub objc: ObjcProperty,
+    target: id,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSSearchField {
+    pub fn new(recents: bool) -> Self {
+        unsafe {
+            let view: id = msg_send![class!(NSSearchField), new];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setSendsWholeSearchString: YES];
+
+            let width: id = msg_send![view, widthAnchor];
+            let constraint: id = msg_send![width, constraintGreaterThanOrEqualToConstant: 200.0_f64];
+            let _: () = msg_send![constraint, setActive: YES];
+
+            if recents {
+                let _: () = msg_send![view, setSearchMenuTemplate: recents_menu_template()];
+            }
+
+            // The target is released with the field, which is retained by
+            // this wrapper.
+            let target: id = msg_send![target_class(), new];
+            let _: () = msg_send![view, setTarget: target];
+            let _: () = msg_send![view, setAction: sel!(search:)];
+            let _: () = msg_send![view, setDelegate: target];
+            associate(view, Association::Target, target);
+
+            Self {
+                objc: ObjcProperty::retain(view),
+                target,
+
+                center_x: LayoutAnchorX::Center(ShareId::from_ptr(msg_send![view, centerXAnchor])),
+                center_y: LayoutAnchorY::Center(ShareId::from_ptr(msg_send![view, centerYAnchor])),
+                top: LayoutAnchorY::Top(ShareId::from_ptr(msg_send![view, topAnchor])),
+                bottom: LayoutAnchorY::Bottom(ShareId::from_ptr(msg_send![view, bottomAnchor])),
+                left: LayoutAnchorX::Left(ShareId::from_ptr(msg_send![view, leftAnchor])),
+                right: LayoutAnchorX::Right(ShareId::from_ptr(msg_send![view, rightAnchor])),
+            }
+        }
+    }
+
+    pub fn set_placeholder(objc: &ObjcProperty, placeholder: &str) {
+        let placeholder = NSString::new(placeholder);
+        objc.with_mut(|view| unsafe {
+            let _: () = msg_send![view, setPlaceholderString: &*placeholder];
+        });
+    }
+
+    /// The recent searches are shown in the menu of the field, with the most
+    /// recent search first.
+    pub fn set_recent_searches(objc: &ObjcProperty, recent: &[String]) {
+        objc.with_mut(|view| unsafe {
+            let searches: id = msg_send![class!(NSMutableArray), new];
+            for search in recent {
+                let search = NSString::new(search);
+                let _: () = msg_send![searches, addObject: &*search];
+            }
+
+            let _: () = msg_send![view, setRecentSearches: searches];
+        });
+    }
+
+    /// Invokes the `action` with the text, every time the user changes it.
+    pub fn set_text_did_change(&self, action: impl Fn(String) + 'static) {
+        self.set_action(TEXT_ACTION_IVAR, action);
+    }
+
+    /// Invokes the `action` with the text, when the user presses the return
+    /// key or clears the field.
+    pub fn set_search(&self, action: impl Fn(String) + 'static) {
+        self.set_action(SEARCH_ACTION_IVAR, action);
+    }
+
+    fn set_action(&self, ivar: &str, action: impl Fn(String) + 'static) {
+        let action: TextAction = Box::new(action);
+        unsafe {
+            replace_boxed_ivar(&mut *self.target, ivar, action);
+        }
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+/// A menu that only contains the recent searches, or a disabled item if there
+/// are none.
+fn recents_menu_template() -> id {
+    unsafe {
+        let menu: id = msg_send![class!(NSMenu), new];
+
+        for (title, tag) in [("No Recent Searches", NO_RECENTS_MENU_ITEM_TAG), ("", RECENTS_MENU_ITEM_TAG)] {
+            let title = NSString::new(title);
+            let key = NSString::new("");
+            let item: id = msg_send![class!(NSMenuItem), alloc];
+            let item: id = msg_send![item, initWithTitle: &*title action: nil keyEquivalent: &*key];
+            let _: () = msg_send![item, setTag: tag];
+            let _: () = msg_send![menu, addItem: item];
+        }
+
+        menu
+    }
+}
+
+fn string_value_of(control: id) -> String {
+    unsafe {
+        let text: id = msg_send![control, stringValue];
+        NSString::retain(text).to_string()
+    }
+}
+
+const TEXT_ACTION_IVAR: &str = "finestraTextAction";
+const SEARCH_ACTION_IVAR: &str = "finestraSearchAction";
+
+/// The target and the `NSSearchFieldDelegate` of the search fields.
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraSearchFieldTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(TEXT_ACTION_IVAR);
+        decl.add_ivar::<usize>(SEARCH_ACTION_IVAR);
+
+        extern "C" fn search(this: &Object, _: Sel, sender: id) {
+            unsafe {
+                let action = *this.get_ivar::<usize>(SEARCH_ACTION_IVAR) as *const TextAction;
+                if !action.is_null() {
+                    (*action)(string_value_of(sender));
+                }
+            }
+        }
+
+        extern "C" fn text_did_change(this: &Object, _: Sel, notification: id) {
+            unsafe {
+                let action = *this.get_ivar::<usize>(TEXT_ACTION_IVAR) as *const TextAction;
+                if !action.is_null() {
+                    let field: id = msg_send![notification, object];
+                    (*action)(string_value_of(field));
+                }
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<TextAction>(this, TEXT_ACTION_IVAR);
+                drop_boxed_ivar::<TextAction>(this, SEARCH_ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(search:), search as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(controlTextDidChange:), text_did_change as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraSearchFieldTarget)
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use crate::Orientation;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type SliderAction = Box<dyn Fn(f64)>;
+
+pub struct NSSlider {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSSlider {
+    pub fn new(min: f64, max: f64, orientation: Orientation) -> Self {
+        let view: id = unsafe { msg_send![class!(NSSlider), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setMinValue:min];
+            let _: () = msg_send![view, setMaxValue:max];
+            let _: () = msg_send![view, setVertical:if orientation == Orientation::Vertical { YES } else { NO }];
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    /// Displays `count` tick marks, of which the values are the only values
+    /// the user can pick when `snap` is `true`.
+    pub fn set_tick_marks(&self, count: usize, snap: bool) {
+        self.objc.with_mut(|view| unsafe {
+            let _: () = msg_send![view, setNumberOfTickMarks:count as isize];
+            let _: () = msg_send![view, setAllowsTickMarkValuesOnly:if snap { YES } else { NO }];
+        });
+    }
+
+    /// Moves the knob, without invoking the action.
+    pub fn set_value(objc: &ObjcProperty, value: f64) {
+        objc.with_mut(|view| unsafe {
+            let _: () = msg_send![view, setDoubleValue:value];
+        });
+    }
+
+    /// Invokes the `action` with the value while the user drags the knob.
+    pub fn set_action(&self, action: impl Fn(f64) + 'static) {
+        let action: SliderAction = Box::new(action);
+
+        self.objc.with_mut(|view| unsafe {
+            let target: id = msg_send![target_class(), new];
+            replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+
+            let _: () = msg_send![view, setTarget:target];
+            let _: () = msg_send![view, setAction:sel!(changeValue:)];
+            let _: () = msg_send![view, setContinuous:YES];
+            associate(view, Association::Target, target);
+        });
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraSliderTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn change_value(this: &Object, _: Sel, slider: id) {
+            unsafe {
+                let value: f64 = msg_send![slider, doubleValue];
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const SliderAction;
+                (*action)(value);
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<SliderAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(changeValue:), change_value as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraSliderTarget)
+}
@@ -5,23 +5,48 @@ use cacao::foundation::{NSArray, NO, YES};
 use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
 use cacao::utils::properties::ObjcProperty;
 use objc_id::ShareId;
-use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::objc::{class, msg_send, sel, sel_impl, Encode, Encoding};
 
-use crate::StackDirection;
+use crate::{StackAlignment, StackDirection, StackDistribution, StackLayout};
 
 use crate::platform::macos::DynamicViewWrapper;
 
+/// <https://developer.apple.com/documentation/foundation/nsedgeinsets>
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct NSEdgeInsets {
+    top: f64,
+    left: f64,
+    bottom: f64,
+    right: f64,
+}
+
+unsafe impl Encode for NSEdgeInsets {
+    fn encode() -> Encoding {
+        unsafe { Encoding::from_str("{NSEdgeInsets=dddd}") }
+    }
+}
+
 pub struct NSStackView {
     pub objc: ObjcProperty,
 
+    /// The [`NSStackViewGravity`](https://developer.apple.com/documentation/appkit/nsstackview/gravity)
+    /// the views are added to, or [`None`] if the distribution doesn't use
+    /// gravity areas.
+    gravity: Option<isize>,
+
     pub center_x: LayoutAnchorX,
     pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
 
     pub children: Vec<DynamicViewWrapper>,
 }
 
 impl NSStackView {
-    pub fn new(direction: StackDirection) -> Self {
+    pub fn new(layout: &StackLayout) -> Self {
         let views = NSArray::new(&[]);
 
         let view: cacao::foundation::id = unsafe {
@@ -30,34 +55,72 @@ impl NSStackView {
             ]
         };
 
-        let orientation = direction as i32;
+        let orientation = layout.direction as i32;
+
+        // <https://developer.apple.com/documentation/appkit/nslayoutconstraint/attribute>
+        let alignment: isize = match (layout.direction, layout.alignment) {
+            (StackDirection::Horizontal, StackAlignment::Start) => 3, // .top
+            (StackDirection::Horizontal, StackAlignment::Center) => 10, // .centerY
+            (StackDirection::Horizontal, StackAlignment::End) => 4, // .bottom
+            (StackDirection::Horizontal, StackAlignment::Fill) => 8, // .height
+            (StackDirection::Vertical, StackAlignment::Start) => 5, // .leading
+            (StackDirection::Vertical, StackAlignment::Center) => 9, // .centerX
+            (StackDirection::Vertical, StackAlignment::End) => 6, // .trailing
+            (StackDirection::Vertical, StackAlignment::Fill) => 7, // .width
+        };
+
+        // <https://developer.apple.com/documentation/appkit/nsstackview/distribution>
+        let (distribution, gravity): (isize, _) = match layout.distribution {
+            StackDistribution::Start => (-1, Some(1)), // .gravityAreas, .leading
+            StackDistribution::Center => (-1, Some(2)), // .gravityAreas, .center
+            StackDistribution::End => (-1, Some(3)), // .gravityAreas, .trailing
+            StackDistribution::SpaceBetween => (3, None), // .equalSpacing
+            StackDistribution::FillEqually => (1, None), // .fillEqually
+        };
+
+        let insets = NSEdgeInsets {
+            top: layout.padding.top.into(),
+            left: layout.padding.left.into(),
+            bottom: layout.padding.bottom.into(),
+            right: layout.padding.right.into(),
+        };
 
         unsafe {
             let _: () = msg_send![view, setWantsLayer: YES];
             let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
             let _: () = msg_send![view, setOrientation: orientation]; // NSUserInterfaceLayoutOrientationVertical
-            // let _: () = msg_send![view, setAlignment: 7]; // .width
-            // let _: () = msg_send![view, setDistribution: 1]; // .fillEqually
+            let _: () = msg_send![view, setSpacing: f64::from(layout.spacing)];
+            let _: () = msg_send![view, setEdgeInsets: insets];
+            let _: () = msg_send![view, setAlignment: alignment];
+            let _: () = msg_send![view, setDistribution: distribution];
         }
 
         Self {
             objc: ObjcProperty::retain(view),
+            gravity,
 
             center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
             center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
 
             children: Vec::new(),
         }
     }
 
     pub fn add_view(&mut self, subview: DynamicViewWrapper) {
+        let gravity = self.gravity;
 
         self.objc.with_mut(|view| {
             subview.objc().with_mut(|subview| {
                 unsafe {
-                    // let _: () = msg_send![view, addView:subview inGravity:gravity];
-                    let _: () = msg_send![view, addArrangedSubview:subview];
-
+                    if let Some(gravity) = gravity {
+                        let _: () = msg_send![view, addView:subview inGravity:gravity];
+                    } else {
+                        let _: () = msg_send![view, addArrangedSubview:subview];
+                    }
                 }
             });
         });
@@ -0,0 +1,118 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::sync::Once;
+
+use cacao::foundation::{id, NO};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type StepperAction = Box<dyn Fn(bool)>;
+
+/// The increment and decrement buttons of a [`Stepper`](crate::Stepper). The
+/// value of the stepper isn't used, it stays at `0` so the action can tell
+/// which button was clicked.
+pub struct NSStepper {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSStepper {
+    pub fn new() -> Self {
+        let view: id = unsafe { msg_send![class!(NSStepper), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![view, setMinValue:-1.0_f64];
+            let _: () = msg_send![view, setMaxValue:1.0_f64];
+            let _: () = msg_send![view, setIncrement:1.0_f64];
+            let _: () = msg_send![view, setValueWraps:NO];
+            let _: () = msg_send![view, setDoubleValue:0.0_f64];
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    /// Invokes the `action` with `true` when the increment button is clicked,
+    /// and with `false` when the decrement button is clicked.
+    pub fn set_action(&self, action: impl Fn(bool) + 'static) {
+        let action: StepperAction = Box::new(action);
+
+        self.objc.with_mut(|view| unsafe {
+            let target: id = msg_send![target_class(), new];
+            replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+
+            let _: () = msg_send![view, setTarget:target];
+            let _: () = msg_send![view, setAction:sel!(step:)];
+            associate(view, Association::Target, target);
+        });
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraStepperTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn step(this: &Object, _: Sel, stepper: id) {
+            unsafe {
+                let value: f64 = msg_send![stepper, doubleValue];
+                let _: () = msg_send![stepper, setDoubleValue:0.0_f64];
+
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const StepperAction;
+                (*action)(value > 0.0);
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<StepperAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(step:), step as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraStepperTarget)
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::sync::Once;
+
+use block::ConcreteBlock;
+use cacao::foundation::{id, nil, NO, YES};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+
+use crate::{Key, RowChange, SelectionMode};
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+use super::NSRange;
+
+/// <https://developer.apple.com/documentation/foundation/nsnotfound>
+const NS_NOT_FOUND: usize = isize::MAX as usize;
+
+/// <https://developer.apple.com/documentation/appkit/nstableview/animationoptions>
+const NS_TABLE_VIEW_ANIMATION_EFFECT_FADE: usize = 0x1;
+
+/// <https://developer.apple.com/documentation/appkit/nsevent/eventtypemask/keydown>
+const NS_EVENT_MASK_KEY_DOWN: u64 = 1 << 10;
+
+/// The virtual key codes of `kVK_LeftArrow` and `kVK_RightArrow`.
+const KEY_CODE_LEFT_ARROW: u16 = 123;
+const KEY_CODE_RIGHT_ARROW: u16 = 124;
+
+type RowAction = Box<dyn Fn(usize)>;
+
+thread_local! {
+    /// Changing the selection or the rows programmatically notifies the
+    /// delegate as well, which would make the listeners of a
+    /// [`State`](crate::State) notify themselves.
+    static IS_CHANGING_ROWS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns `true` when the selection is changed by [`select_rows()`] or
+/// [`reload_rows()`], instead of by the user.
+pub(crate) fn is_changing_rows() -> bool {
+    IS_CHANGING_ROWS.get()
+}
+
+unsafe fn index_set(rows: &[usize]) -> id {
+    let set: id = msg_send![class!(NSMutableIndexSet), indexSet];
+    for row in rows {
+        let _: () = msg_send![set, addIndex:*row];
+    }
+    set
+}
+
+unsafe fn index_set_with_range(location: usize, length: usize) -> id {
+    msg_send![class!(NSIndexSet), indexSetWithIndexesInRange:NSRange { location, length }]
+}
+
+/// Configures how many rows the user can select.
+pub(crate) fn set_selection_mode(table_view: &ObjcProperty, mode: SelectionMode) {
+    table_view.with_mut(|table_view| unsafe {
+        let _: () = msg_send![table_view, setUsesAutomaticRowHeights:YES];
+        let _: () = msg_send![table_view, setAllowsEmptySelection:YES];
+        let _: () = msg_send![table_view, setAllowsMultipleSelection:if mode == SelectionMode::Multiple { YES } else { NO }];
+
+        if mode == SelectionMode::None {
+            // NSTableViewSelectionHighlightStyleNone
+            let _: () = msg_send![table_view, setSelectionHighlightStyle:-1isize];
+        }
+    });
+}
+
+/// Get the selected rows, in ascending order.
+pub(crate) fn selected_rows(table_view: &ObjcProperty) -> Vec<usize> {
+    table_view.get(|table_view| unsafe {
+        let set: id = msg_send![table_view, selectedRowIndexes];
+
+        let mut rows = Vec::new();
+        let mut row: usize = msg_send![set, firstIndex];
+        while row != NS_NOT_FOUND {
+            rows.push(row);
+            row = msg_send![set, indexGreaterThanIndex:row];
+        }
+        rows
+    })
+}
+
+/// Selects the given rows, and deselects the other rows.
+pub(crate) fn select_rows(table_view: &ObjcProperty, rows: &[usize]) {
+    table_view.with_mut(|table_view| unsafe {
+        IS_CHANGING_ROWS.set(true);
+        let _: () = msg_send![table_view, selectRowIndexes:index_set(rows) byExtendingSelection:NO];
+        IS_CHANGING_ROWS.set(false);
+    });
+}
+
+/// Replaces the rows that changed, keeping the other rows.
+pub(crate) fn reload_rows(table_view: &ObjcProperty, change: RowChange) {
+    table_view.with_mut(|table_view| unsafe {
+        IS_CHANGING_ROWS.set(true);
+        let _: () = msg_send![table_view, beginUpdates];
+
+        if change.removed != 0 {
+            let rows = index_set_with_range(change.start, change.removed);
+            let _: () = msg_send![table_view, removeRowsAtIndexes:rows withAnimation:NS_TABLE_VIEW_ANIMATION_EFFECT_FADE];
+        }
+
+        if change.inserted != 0 {
+            let rows = index_set_with_range(change.start, change.inserted);
+            let _: () = msg_send![table_view, insertRowsAtIndexes:rows withAnimation:NS_TABLE_VIEW_ANIMATION_EFFECT_FADE];
+        }
+
+        let _: () = msg_send![table_view, endUpdates];
+        IS_CHANGING_ROWS.set(false);
+    });
+}
+
+/// Invokes the `action` with the row that the user double clicked.
+///
+/// <https://developer.apple.com/documentation/appkit/nstableview/1535268-doubleaction>
+pub(crate) fn set_double_action(table_view: &ObjcProperty, action: impl Fn(usize) + 'static) {
+    let action: RowAction = Box::new(action);
+
+    table_view.with_mut(|table_view| unsafe {
+        let target: id = msg_send![target_class(), new];
+        replace_boxed_ivar(&mut *target, ACTION_IVAR, action);
+
+        let _: () = msg_send![table_view, setTarget:target];
+        let _: () = msg_send![table_view, setDoubleAction:sel!(activateRow:)];
+        associate(table_view, Association::Target, target);
+    });
+}
+
+/// Invokes `action` with the left and right arrow keys that are pressed while
+/// the table view has the keyboard focus, before the table view handles them.
+/// The table view doesn't handle the key when the `action` returns `true`.
+///
+/// <https://developer.apple.com/documentation/appkit/nsevent/1534971-addlocalmonitorforevents>
+pub(crate) fn set_key_action(table_view: &ObjcProperty, action: impl Fn(Key) -> bool + 'static) {
+    // The monitor is removed when the table view is deallocated, so it
+    // doesn't have to retain the table view.
+    let table_view_ptr = table_view.get(|table_view| table_view as *const Object as usize);
+    let block = ConcreteBlock::new(move |event: id| -> id {
+        unsafe {
+            let key_code: u16 = msg_send![event, keyCode];
+            let key = match key_code {
+                KEY_CODE_LEFT_ARROW => Key::Left,
+                KEY_CODE_RIGHT_ARROW => Key::Right,
+                _ => return event,
+            };
+
+            let window: id = msg_send![event, window];
+            if window == nil {
+                return event;
+            }
+
+            let first_responder: id = msg_send![window, firstResponder];
+            let is_focused = first_responder as usize == table_view_ptr;
+            if is_focused && action(key) {
+                nil
+            } else {
+                event
+            }
+        }
+    });
+    let block = block.copy();
+
+    table_view.with_mut(|table_view| unsafe {
+        let monitor: id = msg_send![
+            class!(NSEvent),
+            addLocalMonitorForEventsMatchingMask:NS_EVENT_MASK_KEY_DOWN
+            handler:&*block
+        ];
+        let _: id = msg_send![monitor, retain];
+
+        let owner: id = msg_send![key_monitor_class(), new];
+        (*owner).set_ivar::<usize>(MONITOR_IVAR, monitor as usize);
+        associate(table_view, Association::KeyMonitor, owner);
+    });
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraTableViewTarget", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn activate_row(this: &Object, _: Sel, table_view: id) {
+            unsafe {
+                let row: isize = msg_send![table_view, clickedRow];
+                if row < 0 {
+                    return;
+                }
+
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const RowAction;
+                (*action)(row as usize);
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<RowAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(activateRow:), activate_row as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraTableViewTarget)
+}
+
+const MONITOR_IVAR: &str = "finestraMonitor";
+
+/// Removes the event monitor of [`set_key_action()`] when it's deallocated,
+/// together with the table view it is associated with.
+fn key_monitor_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraTableViewKeyMonitor", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(MONITOR_IVAR);
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                let monitor = *this.get_ivar::<usize>(MONITOR_IVAR) as id;
+                if monitor != nil {
+                    let _: () = msg_send![class!(NSEvent), removeMonitor:monitor];
+                    let _: () = msg_send![monitor, release];
+                }
+
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraTableViewKeyMonitor)
+}
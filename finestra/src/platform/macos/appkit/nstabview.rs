@@ -0,0 +1,167 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::sync::Once;
+
+use cacao::foundation::{id, NSString, NO};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use crate::platform::macos::DynamicViewWrapper;
+use crate::{Image, ImageKind};
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+
+type TabAction = Box<dyn Fn(usize)>;
+
+thread_local! {
+    /// Selecting a tab programmatically notifies the delegate as well, which
+    /// would make the listeners of a [`State`](crate::State) notify
+    /// themselves.
+    static IS_SELECTING_TAB: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns `true` when the tab is selected by [`NSTabView::select_tab()`],
+/// instead of by the user.
+pub(crate) fn is_selecting_tab() -> bool {
+    IS_SELECTING_TAB.get()
+}
+
+/// Displays the `view` when the tab at `index` is selected.
+pub(crate) fn set_tab_content(tab_view: &ObjcProperty, index: usize, view: &DynamicViewWrapper) {
+    tab_view.with_mut(|tab_view| {
+        view.objc().with_mut(|view| unsafe {
+            let item: id = msg_send![tab_view, tabViewItemAtIndex:index];
+            let _: () = msg_send![item, setView:view];
+        });
+    });
+}
+
+pub struct NSTabView {
+    pub objc: ObjcProperty,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSTabView {
+    pub fn new() -> Self {
+        let view: id = unsafe { msg_send![class!(NSTabView), new] };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+        }
+
+        Self {
+            objc: ObjcProperty::retain(view),
+
+            center_x: LayoutAnchorX::Center(unsafe { ShareId::from_ptr(msg_send![view, centerXAnchor]) }),
+            center_y: LayoutAnchorY::Center(unsafe { ShareId::from_ptr(msg_send![view, centerYAnchor]) }),
+            top: LayoutAnchorY::Top(unsafe { ShareId::from_ptr(msg_send![view, topAnchor]) }),
+            bottom: LayoutAnchorY::Bottom(unsafe { ShareId::from_ptr(msg_send![view, bottomAnchor]) }),
+            left: LayoutAnchorX::Left(unsafe { ShareId::from_ptr(msg_send![view, leftAnchor]) }),
+            right: LayoutAnchorX::Right(unsafe { ShareId::from_ptr(msg_send![view, rightAnchor]) }),
+        }
+    }
+
+    /// Adds a tab without content, which is set using [`set_tab_content()`].
+    pub fn add_tab(&mut self, title: &str, icon: Option<&Image>) {
+        let title = NSString::new(title);
+
+        self.objc.with_mut(|view| unsafe {
+            let item: id = msg_send![class!(NSTabViewItem), new];
+            let _: () = msg_send![item, setLabel:&*title];
+
+            if let Some(ImageKind::File(path)) = icon.map(Image::kind) {
+                let path = NSString::new(&path.display().to_string());
+                let image: id = msg_send![class!(NSImage), alloc];
+                let image: id = msg_send![image, initWithContentsOfFile:&*path];
+                let _: () = msg_send![item, setImage:image];
+            }
+
+            let _: () = msg_send![view, addTabViewItem:item];
+        });
+    }
+
+    /// Selects the tab at `index`, which doesn't change the state.
+    pub fn select_tab(objc: &ObjcProperty, index: usize) {
+        objc.with_mut(|view| unsafe {
+            IS_SELECTING_TAB.set(true);
+            let _: () = msg_send![view, selectTabViewItemAtIndex:index as isize];
+            IS_SELECTING_TAB.set(false);
+        });
+    }
+
+    /// Invokes the `action` with the index of the tab that was selected.
+    ///
+    /// <https://developer.apple.com/documentation/appkit/nstabviewdelegate/1529011-tabview>
+    pub fn set_did_select(&self, action: impl Fn(usize) + 'static) {
+        let action: TabAction = Box::new(action);
+
+        self.objc.with_mut(|view| unsafe {
+            let delegate: id = msg_send![delegate_class(), new];
+            replace_boxed_ivar(&mut *delegate, ACTION_IVAR, action);
+
+            let _: () = msg_send![view, setDelegate:delegate];
+            associate(view, Association::Delegate, delegate);
+        });
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+const ACTION_IVAR: &str = "finestraAction";
+
+fn delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraTabViewDelegate", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(ACTION_IVAR);
+
+        extern "C" fn did_select(this: &Object, _: Sel, tab_view: id, item: id) {
+            unsafe {
+                let index: isize = msg_send![tab_view, indexOfTabViewItem:item];
+                if index < 0 {
+                    return;
+                }
+
+                let action = *this.get_ivar::<usize>(ACTION_IVAR) as *const TabAction;
+                (*action)(index as usize);
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<TabAction>(this, ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(tabView:didSelectTabViewItem:), did_select as extern "C" fn(&Object, Sel, id, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraTabViewDelegate)
+}
@@ -0,0 +1,237 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::sync::Once;
+
+use cacao::foundation::{id, NSString, NO, YES};
+use cacao::layout::{Layout, LayoutAnchorX, LayoutAnchorY};
+use cacao::objc::declare::ClassDecl;
+use cacao::objc::runtime::{Class, Object, Sel};
+use cacao::objc::{class, msg_send, sel, sel_impl};
+use cacao::utils::properties::ObjcProperty;
+use objc_id::ShareId;
+
+use crate::TextSelection;
+
+use super::associated::{associate, drop_boxed_ivar, replace_boxed_ivar, Association};
+use super::foundation::NSSize;
+use super::NSRange;
+
+type TextAction = Box<dyn Fn(String)>;
+type SelectionAction = Box<dyn Fn(TextSelection)>;
+
+thread_local! {
+    /// Changing the text or the selection programmatically invokes the
+    /// delegate as well, which would make the listeners of a
+    /// [`State`](crate::State) notify themselves.
+    static IS_CHANGING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The scrollable text view of a [`TextEditor`](crate::TextEditor).
+pub struct NSTextView {
+    /// The scroll view, which is added to the parent.
+    pub objc: ObjcProperty,
+    /// The text view inside the scroll view.
+    pub text_view: ObjcProperty,
+    delegate: id,
+
+    pub center_x: LayoutAnchorX,
+    pub center_y: LayoutAnchorY,
+    pub top: LayoutAnchorY,
+    pub bottom: LayoutAnchorY,
+    pub left: LayoutAnchorX,
+    pub right: LayoutAnchorX,
+}
+
+impl NSTextView {
+    pub fn new(word_wrap: bool) -> Self {
+        unsafe {
+            let scroll_view: id = msg_send![class!(NSTextView), scrollableTextView];
+            let text_view: id = msg_send![scroll_view, documentView];
+
+            let _: () = msg_send![scroll_view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+            let _: () = msg_send![text_view, setRichText: NO];
+            let _: () = msg_send![text_view, setAllowsUndo: YES];
+
+            // https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/TextStorageLayer/Tasks/TrackingSize.html
+            if !word_wrap {
+                let unlimited = NSSize { width: f64::from(f32::MAX), height: f64::from(f32::MAX) };
+                let container: id = msg_send![text_view, textContainer];
+                let _: () = msg_send![container, setWidthTracksTextView: NO];
+                let _: () = msg_send![container, setContainerSize: unlimited];
+                let _: () = msg_send![text_view, setHorizontallyResizable: YES];
+                let _: () = msg_send![text_view, setMaxSize: unlimited];
+                let _: () = msg_send![scroll_view, setHasHorizontalScroller: YES];
+            }
+
+            for (anchor, constant) in [(sel!(widthAnchor), 200.0_f64), (sel!(heightAnchor), 100.0_f64)] {
+                let anchor: id = msg_send![scroll_view, performSelector: anchor];
+                let constraint: id = msg_send![anchor, constraintGreaterThanOrEqualToConstant: constant];
+                let _: () = msg_send![constraint, setActive: YES];
+            }
+
+            // The delegate is released with the text view, which is retained
+            // by this wrapper.
+            let delegate: id = msg_send![delegate_class(), new];
+            let _: () = msg_send![text_view, setDelegate: delegate];
+            associate(text_view, Association::Delegate, delegate);
+
+            Self {
+                objc: ObjcProperty::retain(scroll_view),
+                text_view: ObjcProperty::retain(text_view),
+                delegate,
+
+                center_x: LayoutAnchorX::Center(ShareId::from_ptr(msg_send![scroll_view, centerXAnchor])),
+                center_y: LayoutAnchorY::Center(ShareId::from_ptr(msg_send![scroll_view, centerYAnchor])),
+                top: LayoutAnchorY::Top(ShareId::from_ptr(msg_send![scroll_view, topAnchor])),
+                bottom: LayoutAnchorY::Bottom(ShareId::from_ptr(msg_send![scroll_view, bottomAnchor])),
+                left: LayoutAnchorX::Left(ShareId::from_ptr(msg_send![scroll_view, leftAnchor])),
+                right: LayoutAnchorX::Right(ShareId::from_ptr(msg_send![scroll_view, rightAnchor])),
+            }
+        }
+    }
+
+    pub fn set_text(text_view: &ObjcProperty, text: &str) {
+        let text = NSString::new(text);
+        text_view.with_mut(|view| unsafe {
+            IS_CHANGING.set(true);
+            let _: () = msg_send![view, setString: &*text];
+            IS_CHANGING.set(false);
+        });
+    }
+
+    /// A read-only text view can still be selected, so the text can be copied.
+    pub fn set_editable(text_view: &ObjcProperty, editable: bool) {
+        text_view.with_mut(|view| unsafe {
+            let _: () = msg_send![view, setEditable: if editable { YES } else { NO }];
+        });
+    }
+
+    /// AppKit doesn't store which end of the selection is the anchor, so the
+    /// caret is always placed at the end of the range.
+    pub fn select(text_view: &ObjcProperty, selection: TextSelection) {
+        text_view.with_mut(|view| unsafe {
+            let text = text_of(view);
+            let range = selection.clamped(text.chars().count()).range();
+            let location = utf16_offset(&text, range.start);
+            let range = NSRange { location, length: utf16_offset(&text, range.end) - location };
+
+            IS_CHANGING.set(true);
+            let _: () = msg_send![view, setSelectedRange: range];
+            IS_CHANGING.set(false);
+        });
+    }
+
+    /// Invokes the `action` with the text, every time the user changes it.
+    pub fn set_text_did_change(&self, action: impl Fn(String) + 'static) {
+        let action: TextAction = Box::new(action);
+        unsafe {
+            replace_boxed_ivar(&mut *self.delegate, TEXT_ACTION_IVAR, action);
+        }
+    }
+
+    /// Invokes the `action` with the selection, every time the user selects
+    /// text or moves the caret.
+    pub fn set_selection_did_change(&self, action: impl Fn(TextSelection) + 'static) {
+        let action: SelectionAction = Box::new(action);
+        unsafe {
+            replace_boxed_ivar(&mut *self.delegate, SELECTION_ACTION_IVAR, action);
+        }
+    }
+
+    pub(crate) fn add_as_subview<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|backing_node| {
+            self.objc.with_mut(|subview| {
+                unsafe {
+                    let _: () = msg_send![backing_node, addSubview: subview];
+                }
+            })
+        });
+    }
+}
+
+fn text_of(text_view: &Object) -> String {
+    unsafe {
+        let text: id = msg_send![text_view, string];
+        NSString::retain(text).to_string()
+    }
+}
+
+/// Converts an offset in characters to an offset in UTF-16 code units, which
+/// are used by `NSString`.
+fn utf16_offset(text: &str, offset: usize) -> usize {
+    text.chars().take(offset).map(char::len_utf16).sum()
+}
+
+/// Converts an offset in UTF-16 code units to an offset in characters.
+fn char_offset(text: &str, utf16_offset: usize) -> usize {
+    let mut units = 0;
+    text.chars()
+        .take_while(|c| {
+            units += c.len_utf16();
+            units <= utf16_offset
+        })
+        .count()
+}
+
+const TEXT_ACTION_IVAR: &str = "finestraTextAction";
+const SELECTION_ACTION_IVAR: &str = "finestraSelectionAction";
+
+/// The `NSTextViewDelegate` that forwards the changes made by the user.
+fn delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("FinestraTextViewDelegate", class!(NSObject)).unwrap();
+        decl.add_ivar::<usize>(TEXT_ACTION_IVAR);
+        decl.add_ivar::<usize>(SELECTION_ACTION_IVAR);
+
+        extern "C" fn text_did_change(this: &Object, _: Sel, notification: id) {
+            unsafe {
+                let action = *this.get_ivar::<usize>(TEXT_ACTION_IVAR) as *const TextAction;
+                if action.is_null() || IS_CHANGING.get() {
+                    return;
+                }
+
+                let text_view: id = msg_send![notification, object];
+                (*action)(text_of(&*text_view));
+            }
+        }
+
+        extern "C" fn selection_did_change(this: &Object, _: Sel, notification: id) {
+            unsafe {
+                let action = *this.get_ivar::<usize>(SELECTION_ACTION_IVAR) as *const SelectionAction;
+                if action.is_null() || IS_CHANGING.get() {
+                    return;
+                }
+
+                let text_view: id = msg_send![notification, object];
+                let text = text_of(&*text_view);
+                let range: NSRange = msg_send![text_view, selectedRange];
+
+                let start = char_offset(&text, range.location);
+                let end = char_offset(&text, range.location + range.length);
+                (*action)(TextSelection::new(start, end));
+            }
+        }
+
+        extern "C" fn dealloc(this: &Object, _: Sel) {
+            unsafe {
+                drop_boxed_ivar::<TextAction>(this, TEXT_ACTION_IVAR);
+                drop_boxed_ivar::<SelectionAction>(this, SELECTION_ACTION_IVAR);
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(textDidChange:), text_did_change as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(textViewDidChangeSelection:), selection_did_change as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+        }
+
+        decl.register();
+    });
+
+    class!(FinestraTextViewDelegate)
+}
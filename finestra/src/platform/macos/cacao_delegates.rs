@@ -1,28 +1,150 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
 use cacao::input::TextFieldDelegate;
+use cacao::layout::LayoutConstraint;
+use cacao::listview::{ListView, ListViewDelegate, ListViewRow};
+use cacao::utils::properties::ObjcProperty;
 
-use crate::{EventHandlerMapRegistry, StateChangeOrigin, TextValue, ViewId, Window};
+use crate::event::{ViewScope, ViewTree};
+use crate::{AppDelegate, ChangedText, EventHandlerMapRegistry, ListSelection, RowBuilder, Rows, StateChangeOrigin, TextValue, Validation, ViewId, Window};
 
 use super::state::Event;
+use super::{is_changing_rows, selected_rows, set_selection_mode, DynamicViewWrapper, LayoutExt};
 
 pub(crate) struct MacOSTextFieldDelegate {
-    pub(crate) view_id: ViewId,
-    pub(crate) dispatcher: Box<dyn EventDispatcher>,
-    pub(crate) text: Option<TextValue>,
+    text_did_change: Box<dyn Fn(&str)>,
+}
+
+impl MacOSTextFieldDelegate {
+    /// Dispatches the changes of the text, validates them, and stores them in
+    /// the `text`.
+    pub(crate) fn new(view_id: ViewId, dispatcher: Box<dyn EventDispatcher>, text: Option<TextValue>, secure: bool, validation: Option<Validation>) -> Self {
+        Self::with_action(move |value| {
+            dispatcher.dispatch_event(Event::TextFieldChanged(view_id, ChangedText::new(value.to_string(), secure)));
+
+            if let Some(validation) = &validation {
+                validation.validate(value);
+            }
+
+            if let Some(text) = &text {
+                text.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            }
+        })
+    }
+
+    /// Invokes the `action` when the user changed the text, e.g. for text
+    /// fields that are part of another view.
+    pub(crate) fn with_action(action: impl Fn(&str) + 'static) -> Self {
+        Self {
+            text_did_change: Box::new(action),
+        }
+    }
 }
 
 impl TextFieldDelegate for MacOSTextFieldDelegate {
     const NAME: &'static str = "finestra.textfield";
 
     fn text_did_change(&self, value: &str) {
-        self.dispatcher.dispatch_event(Event::TextFieldChanged(self.view_id, value.to_string()));
+        (self.text_did_change)(value);
+    }
+}
+
+/// Provides the rows of a [`List`](crate::List). The rows are only built when
+/// the `NSTableView` displays them.
+pub(crate) struct MacOSListViewDelegate {
+    view_id: ViewId,
+    count: Box<dyn Fn() -> usize>,
+    build_row: Box<dyn Fn(usize) -> Option<(DynamicViewWrapper, ViewScope)>>,
+    remove_row: Box<dyn Fn(&ViewScope)>,
+    selection: ListSelection,
+    table_view: Option<ObjcProperty>,
+
+    /// The content of the rows is retained by their `ListViewRow`, but
+    /// dropping the wrapper would remove the content from its row. The event
+    /// handlers of the content are removed when the row is built again.
+    contents: RefCell<HashMap<usize, (DynamicViewWrapper, ViewScope)>>,
+}
+
+impl MacOSListViewDelegate {
+    pub(crate) fn new<T, Delegate, State>(
+        view_id: ViewId,
+        rows: Rows<T>,
+        row: RowBuilder<T, Delegate, State>,
+        tree: ViewTree<State>,
+        selection: ListSelection,
+    ) -> Self
+            where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+        let count = {
+            let rows = rows.clone();
+            Box::new(move || rows.len())
+        };
+
+        let tree = Rc::new(RefCell::new(tree));
+        let remove_row = {
+            let tree = Rc::clone(&tree);
+            Box::new(move |scope: &ViewScope| tree.borrow().remove_scope(scope))
+        };
+        let build_row = Box::new(move |index: usize| {
+            let mut view = rows.with(|items| items.get(index).map(|item| row(item)))?;
+            Some(tree.borrow_mut().scope(|tree| view.build_native(tree)))
+        });
+
+        Self {
+            view_id,
+            count,
+            build_row,
+            remove_row,
+            selection,
+            table_view: None,
+            contents: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl ListViewDelegate for MacOSListViewDelegate {
+    const NAME: &'static str = "finestra.listview";
+
+    fn did_load(&mut self, view: ListView) {
+        set_selection_mode(&view.objc, self.selection.mode());
+        self.table_view = Some(view.objc.clone());
+    }
+
+    fn number_of_items(&self) -> usize {
+        (self.count)()
+    }
+
+    fn item_for(&self, row: usize) -> ListViewRow {
+        let list_row = ListViewRow::new();
+
+        if let Some((content, scope)) = (self.build_row)(row) {
+            content.add_to_view(&list_row);
+            LayoutConstraint::activate(&[
+                content.layout_constraint_center_x().constraint_equal_to(&list_row.center_x),
+                content.layout_constraint_center_y().constraint_equal_to(&list_row.center_y),
+            ]);
+
+            let replaced = self.contents.borrow_mut().insert(row, (content, scope));
+            if let Some((_, scope)) = replaced {
+                (self.remove_row)(&scope);
+            }
+        }
+
+        list_row
+    }
 
-        if let Some(text) = &self.text {
-            text.set_with_origin(value, StateChangeOrigin::Owner(self.view_id));
+    fn item_selected(&self, _: usize) {
+        if is_changing_rows() {
+            return;
+        }
+
+        if let Some(table_view) = &self.table_view {
+            self.selection.set_rows(selected_rows(table_view), StateChangeOrigin::Owner(self.view_id));
         }
     }
 }
@@ -53,43 +175,63 @@ impl<State> EventDispatcher for StatefulEventDispatcher<State> {
 
         match event {
             Event::ButtonClicked(view_id) => {
-                let Some(handler) = self.event_registry.map.get(&view_id) else {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.click.clone()) else {
                     return;
                 };
 
-                let Some(handler) = &handler.click else {
+                (handler)(&mut state, self.window.clone());
+            }
+
+            Event::CheckboxChanged(view_id, is_checked) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.checked.clone()) else {
                     return;
                 };
 
-                (handler)(&mut state, self.window.clone());
+                (handler)(&mut state, is_checked, self.window.clone());
             }
 
-            Event::CheckboxChanged(view_id, is_checked) => {
-                let Some(handler) = self.event_registry.map.get(&view_id) else {
+            Event::ListRowActivated(view_id, row) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.activate.clone()) else {
                     return;
                 };
 
-                let Some(handler) = &handler.checked else {
+                (handler)(&mut state, row, self.window.clone());
+            }
+
+            Event::SelectionChanged(view_id, index) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.selection_changed.clone()) else {
                     return;
                 };
 
-                (handler)(&mut state, is_checked, self.window.clone());
+                (handler)(&mut state, index, self.window.clone());
             }
 
-            Event::MenuAction { .. } => {
-                panic!("Menu actions aren't supposed to be dispatched to the StatefulEventDispatcher")
+            Event::ValueChanged(view_id, value) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.value_changed.clone()) else {
+                    return;
+                };
+
+                (handler)(&mut state, value, self.window.clone());
             }
 
-            Event::TextFieldChanged(view_id, text) => {
-                let Some(handler) = self.event_registry.map.get(&view_id) else {
+            Event::ColorChanged(view_id, color) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.color_changed.clone()) else {
                     return;
                 };
 
-                let Some(handler) = &handler.text_changed else {
+                (handler)(&mut state, color, self.window.clone());
+            }
+
+            Event::MenuAction { .. } => {
+                panic!("Menu actions aren't supposed to be dispatched to the StatefulEventDispatcher")
+            }
+
+            Event::TextFieldChanged(view_id, text) => {
+                let Some(handler) = self.event_registry.map.get(&view_id).and_then(|map| map.text_changed.clone()) else {
                     return;
                 };
 
-                (handler)(&mut state, text, self.window.clone());
+                (handler)(&mut state, text.text, self.window.clone());
             }
         }
     }
@@ -7,7 +7,9 @@ use cacao::{
 
 use crate::{Constraint, ConstraintAlignment, ViewId};
 
-use super::{cacao_delegates::MacOSTextFieldDelegate, NSStackView};
+#[cfg(feature = "chrono")]
+use super::NSDatePicker;
+use super::{cacao_delegates::{MacOSListViewDelegate, MacOSTextFieldDelegate}, NSCanvasView, NSColorWell, NSComboBox, NSContainerView, NSGridView, NSLinkButton, NSSearchField, NSSlider, NSStackView, NSStepper, NSTabView, NSTextView};
 
 /// This internal type, represents [`Layout`](cacao::layout::Layout) in an
 /// object-safe manner.
@@ -64,17 +66,31 @@ impl DynamicViewWrapper {
 
 pub enum DynamicViewWrapperKind {
     Button(Button),
+    ComboBox(NSComboBox),
+    #[cfg(feature = "chrono")]
+    DatePicker(NSDatePicker),
+    GridView(NSGridView),
     ImageView(ImageView),
     Label(Label),
-    ListView(ListView),
+    #[allow(private_interfaces)]
+    ListView(ListView<MacOSListViewDelegate>),
     ListViewRow(ListViewRow),
     ProgressIndicator(ProgressIndicator),
     ScrollView(ScrollView),
+    Link(NSLinkButton),
+    SearchField(NSSearchField),
     Select(Select),
+    Slider(NSSlider),
     StackView(NSStackView),
+    Stepper(NSStepper),
+    ColorWell(NSColorWell),
+    Container(NSContainerView),
+    Canvas(NSCanvasView),
     Switch(Switch),
+    TabView(NSTabView),
     #[allow(private_interfaces)]
     TextField(TextField<MacOSTextFieldDelegate>),
+    TextView(NSTextView),
     View(View),
 }
 
@@ -83,16 +99,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn add_to_view<V: Layout>(&self, view: &V) {
         match self {
             Self::Button(subview) => view.add_subview(subview),
+            Self::ComboBox(subview) => subview.add_as_subview(view),
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => subview.add_as_subview(view),
+            Self::GridView(subview) => subview.add_as_subview(view),
             Self::ImageView(subview) => view.add_subview(subview),
             Self::Label(subview) => view.add_subview(subview),
             Self::ListView(subview) => view.add_subview(subview),
             Self::ListViewRow(subview) => view.add_subview(subview),
             Self::ProgressIndicator(subview) => view.add_subview(subview),
             Self::ScrollView(subview) => view.add_subview(subview),
+            Self::Link(subview) => subview.add_as_subview(view),
+            Self::SearchField(subview) => subview.add_as_subview(view),
             Self::Select(subview) => view.add_subview(subview),
+            Self::Slider(subview) => subview.add_as_subview(view),
             Self::StackView(subview) => subview.add_as_subview(view),
+            Self::Stepper(subview) => subview.add_as_subview(view),
+            Self::ColorWell(subview) => subview.add_as_subview(view),
+            Self::Container(subview) => subview.add_as_subview(view),
+            Self::Canvas(subview) => subview.add_as_subview(view),
             Self::Switch(subview) => view.add_subview(subview),
+            Self::TabView(subview) => subview.add_as_subview(view),
             Self::TextField(subview) => view.add_subview(subview),
+            Self::TextView(subview) => subview.add_as_subview(view),
             Self::View(subview) => view.add_subview(subview),
         }
     }
@@ -100,16 +129,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn layout_constraint_center_x(&self) -> &LayoutAnchorX {
         match self {
             Self::Button(subview) => &subview.center_x,
+            Self::ComboBox(subview) => &subview.center_x,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.center_x,
+            Self::GridView(subview) => &subview.center_x,
             Self::ImageView(subview) => &subview.center_x,
             Self::Label(subview) => &subview.center_x,
             Self::ListView(subview) => &subview.center_x,
             Self::ListViewRow(subview) => &subview.center_x,
             Self::ProgressIndicator(subview) => &subview.center_x,
             Self::ScrollView(subview) => &subview.center_x,
+            Self::Link(subview) => &subview.center_x,
+            Self::SearchField(subview) => &subview.center_x,
             Self::Select(subview) => &subview.center_x,
+            Self::Slider(subview) => &subview.center_x,
             Self::StackView(subview) => &subview.center_x,
+            Self::Stepper(subview) => &subview.center_x,
+            Self::ColorWell(subview) => &subview.center_x,
+            Self::Container(subview) => &subview.center_x,
+            Self::Canvas(subview) => &subview.center_x,
             Self::Switch(subview) => &subview.center_x,
+            Self::TabView(subview) => &subview.center_x,
             Self::TextField(subview) => &subview.center_x,
+            Self::TextView(subview) => &subview.center_x,
             Self::View(subview) => &subview.center_x,
         }
     }
@@ -117,16 +159,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn layout_constraint_center_y(&self) -> &LayoutAnchorY {
         match self {
             Self::Button(subview) => &subview.center_y,
+            Self::ComboBox(subview) => &subview.center_y,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.center_y,
+            Self::GridView(subview) => &subview.center_y,
             Self::ImageView(subview) => &subview.center_y,
             Self::Label(subview) => &subview.center_y,
             Self::ListView(subview) => &subview.center_y,
             Self::ListViewRow(subview) => &subview.center_y,
             Self::ProgressIndicator(subview) => &subview.center_y,
             Self::ScrollView(subview) => &subview.center_y,
+            Self::Link(subview) => &subview.center_y,
+            Self::SearchField(subview) => &subview.center_y,
             Self::Select(subview) => &subview.center_y,
+            Self::Slider(subview) => &subview.center_y,
             Self::StackView(subview) => &subview.center_y,
+            Self::Stepper(subview) => &subview.center_y,
+            Self::ColorWell(subview) => &subview.center_y,
+            Self::Container(subview) => &subview.center_y,
+            Self::Canvas(subview) => &subview.center_y,
             Self::Switch(subview) => &subview.center_y,
+            Self::TabView(subview) => &subview.center_y,
             Self::TextField(subview) => &subview.center_y,
+            Self::TextView(subview) => &subview.center_y,
             Self::View(subview) => &subview.center_y,
         }
     }
@@ -134,16 +189,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn objc(&self) -> &ObjcProperty {
         match self {
             Self::Button(subview) => &subview.objc,
+            Self::ComboBox(subview) => &subview.objc,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.objc,
+            Self::GridView(subview) => &subview.objc,
             Self::ImageView(subview) => &subview.objc,
             Self::Label(subview) => &subview.objc,
             Self::ListView(subview) => &subview.objc,
             Self::ListViewRow(subview) => &subview.objc,
             Self::ProgressIndicator(subview) => &subview.objc,
             Self::ScrollView(subview) => &subview.objc,
+            Self::Link(subview) => &subview.objc,
+            Self::SearchField(subview) => &subview.objc,
             Self::Select(subview) => &subview.objc,
+            Self::Slider(subview) => &subview.objc,
             Self::StackView(subview) => &subview.objc,
+            Self::Stepper(subview) => &subview.objc,
+            Self::ColorWell(subview) => &subview.objc,
+            Self::Container(subview) => &subview.objc,
+            Self::Canvas(subview) => &subview.objc,
             Self::Switch(subview) => &subview.objc,
+            Self::TabView(subview) => &subview.objc,
             Self::TextField(subview) => &subview.objc,
+            Self::TextView(subview) => &subview.objc,
             Self::View(subview) => &subview.objc,
         }
     }
@@ -151,16 +219,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn layout_constraint_top(&self) -> &LayoutAnchorY {
         match self {
             Self::Button(subview) => &subview.top,
+            Self::ComboBox(subview) => &subview.top,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.top,
+            Self::GridView(subview) => &subview.top,
             Self::ImageView(subview) => &subview.top,
             Self::Label(subview) => &subview.top,
             Self::ListView(subview) => &subview.top,
             Self::ListViewRow(subview) => &subview.top,
             Self::ProgressIndicator(subview) => &subview.top,
             Self::ScrollView(subview) => &subview.top,
+            Self::Link(subview) => &subview.top,
+            Self::SearchField(subview) => &subview.top,
             Self::Select(subview) => &subview.top,
-            Self::StackView(..) => todo!(),
+            Self::Slider(subview) => &subview.top,
+            Self::StackView(subview) => &subview.top,
+            Self::Stepper(subview) => &subview.top,
+            Self::ColorWell(subview) => &subview.top,
+            Self::Container(subview) => &subview.top,
+            Self::Canvas(subview) => &subview.top,
             Self::Switch(subview) => &subview.top,
+            Self::TabView(subview) => &subview.top,
             Self::TextField(subview) => &subview.top,
+            Self::TextView(subview) => &subview.top,
             Self::View(subview) => &subview.top,
         }
     }
@@ -168,16 +249,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn layout_constraint_bottom(&self) -> &LayoutAnchorY {
         match self {
             Self::Button(subview) => &subview.bottom,
+            Self::ComboBox(subview) => &subview.bottom,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.bottom,
+            Self::GridView(subview) => &subview.bottom,
             Self::ImageView(subview) => &subview.bottom,
             Self::Label(subview) => &subview.bottom,
             Self::ListView(subview) => &subview.bottom,
             Self::ListViewRow(subview) => &subview.bottom,
             Self::ProgressIndicator(subview) => &subview.bottom,
             Self::ScrollView(subview) => &subview.bottom,
+            Self::Link(subview) => &subview.bottom,
+            Self::SearchField(subview) => &subview.bottom,
             Self::Select(subview) => &subview.bottom,
-            Self::StackView(..) => todo!(),
+            Self::Slider(subview) => &subview.bottom,
+            Self::StackView(subview) => &subview.bottom,
+            Self::Stepper(subview) => &subview.bottom,
+            Self::ColorWell(subview) => &subview.bottom,
+            Self::Container(subview) => &subview.bottom,
+            Self::Canvas(subview) => &subview.bottom,
             Self::Switch(subview) => &subview.bottom,
+            Self::TabView(subview) => &subview.bottom,
             Self::TextField(subview) => &subview.bottom,
+            Self::TextView(subview) => &subview.bottom,
             Self::View(subview) => &subview.bottom,
         }
     }
@@ -185,16 +279,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn layout_constraint_left(&self) -> &LayoutAnchorX {
         match self {
             Self::Button(subview) => &subview.left,
+            Self::ComboBox(subview) => &subview.left,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.left,
+            Self::GridView(subview) => &subview.left,
             Self::ImageView(subview) => &subview.left,
             Self::Label(subview) => &subview.left,
             Self::ListView(subview) => &subview.left,
             Self::ListViewRow(subview) => &subview.left,
             Self::ProgressIndicator(subview) => &subview.left,
             Self::ScrollView(subview) => &subview.left,
+            Self::Link(subview) => &subview.left,
+            Self::SearchField(subview) => &subview.left,
             Self::Select(subview) => &subview.left,
-            Self::StackView(..) => todo!(),
+            Self::Slider(subview) => &subview.left,
+            Self::StackView(subview) => &subview.left,
+            Self::Stepper(subview) => &subview.left,
+            Self::ColorWell(subview) => &subview.left,
+            Self::Container(subview) => &subview.left,
+            Self::Canvas(subview) => &subview.left,
             Self::Switch(subview) => &subview.left,
+            Self::TabView(subview) => &subview.left,
             Self::TextField(subview) => &subview.left,
+            Self::TextView(subview) => &subview.left,
             Self::View(subview) => &subview.left,
         }
     }
@@ -202,16 +309,29 @@ impl DynamicViewWrapperKind {
     pub(crate) fn layout_constraint_right(&self) -> &LayoutAnchorX {
         match self {
             Self::Button(subview) => &subview.right,
+            Self::ComboBox(subview) => &subview.right,
+            #[cfg(feature = "chrono")]
+            Self::DatePicker(subview) => &subview.right,
+            Self::GridView(subview) => &subview.right,
             Self::ImageView(subview) => &subview.right,
             Self::Label(subview) => &subview.right,
             Self::ListView(subview) => &subview.right,
             Self::ListViewRow(subview) => &subview.right,
             Self::ProgressIndicator(subview) => &subview.right,
             Self::ScrollView(subview) => &subview.right,
+            Self::Link(subview) => &subview.right,
+            Self::SearchField(subview) => &subview.right,
             Self::Select(subview) => &subview.right,
-            Self::StackView(..) => todo!(),
+            Self::Slider(subview) => &subview.right,
+            Self::StackView(subview) => &subview.right,
+            Self::Stepper(subview) => &subview.right,
+            Self::ColorWell(subview) => &subview.right,
+            Self::Container(subview) => &subview.right,
+            Self::Canvas(subview) => &subview.right,
             Self::Switch(subview) => &subview.right,
+            Self::TabView(subview) => &subview.right,
             Self::TextField(subview) => &subview.right,
+            Self::TextView(subview) => &subview.right,
             Self::View(subview) => &subview.right,
         }
     }
@@ -223,6 +343,25 @@ impl From<Button> for DynamicViewWrapperKind {
     }
 }
 
+impl From<NSComboBox> for DynamicViewWrapperKind {
+    fn from(value: NSComboBox) -> Self {
+        Self::ComboBox(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NSDatePicker> for DynamicViewWrapperKind {
+    fn from(value: NSDatePicker) -> Self {
+        Self::DatePicker(value)
+    }
+}
+
+impl From<NSGridView> for DynamicViewWrapperKind {
+    fn from(value: NSGridView) -> Self {
+        Self::GridView(value)
+    }
+}
+
 impl From<ImageView> for DynamicViewWrapperKind {
     fn from(value: ImageView) -> Self {
         Self::ImageView(value)
@@ -235,8 +374,8 @@ impl From<Label> for DynamicViewWrapperKind {
     }
 }
 
-impl From<ListView> for DynamicViewWrapperKind {
-    fn from(value: ListView) -> Self {
+impl From<ListView<MacOSListViewDelegate>> for DynamicViewWrapperKind {
+    fn from(value: ListView<MacOSListViewDelegate>) -> Self {
         Self::ListView(value)
     }
 }
@@ -247,12 +386,60 @@ impl From<ListViewRow> for DynamicViewWrapperKind {
     }
 }
 
+impl From<NSSlider> for DynamicViewWrapperKind {
+    fn from(value: NSSlider) -> Self {
+        Self::Slider(value)
+    }
+}
+
+impl From<NSContainerView> for DynamicViewWrapperKind {
+    fn from(value: NSContainerView) -> Self {
+        Self::Container(value)
+    }
+}
+
 impl From<NSStackView> for DynamicViewWrapperKind {
     fn from(value: NSStackView) -> Self {
         Self::StackView(value)
     }
 }
 
+impl From<NSStepper> for DynamicViewWrapperKind {
+    fn from(value: NSStepper) -> Self {
+        Self::Stepper(value)
+    }
+}
+
+impl From<NSColorWell> for DynamicViewWrapperKind {
+    fn from(value: NSColorWell) -> Self {
+        Self::ColorWell(value)
+    }
+}
+
+impl From<NSLinkButton> for DynamicViewWrapperKind {
+    fn from(value: NSLinkButton) -> Self {
+        Self::Link(value)
+    }
+}
+
+impl From<NSSearchField> for DynamicViewWrapperKind {
+    fn from(value: NSSearchField) -> Self {
+        Self::SearchField(value)
+    }
+}
+
+impl From<NSTextView> for DynamicViewWrapperKind {
+    fn from(value: NSTextView) -> Self {
+        Self::TextView(value)
+    }
+}
+
+impl From<NSCanvasView> for DynamicViewWrapperKind {
+    fn from(value: NSCanvasView) -> Self {
+        Self::Canvas(value)
+    }
+}
+
 impl From<ProgressIndicator> for DynamicViewWrapperKind {
     fn from(value: ProgressIndicator) -> Self {
         Self::ProgressIndicator(value)
@@ -277,6 +464,12 @@ impl From<Switch> for DynamicViewWrapperKind {
     }
 }
 
+impl From<NSTabView> for DynamicViewWrapperKind {
+    fn from(value: NSTabView) -> Self {
+        Self::TabView(value)
+    }
+}
+
 impl From<TextField<MacOSTextFieldDelegate>> for DynamicViewWrapperKind {
     fn from(value: TextField<MacOSTextFieldDelegate>) -> Self {
         Self::TextField(value)
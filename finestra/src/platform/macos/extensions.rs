@@ -109,4 +109,5 @@ impl ButtonExtensions for cacao::button::Button {}
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum NSButtonType {
     Switch = 3,
+    Radio = 4,
 }
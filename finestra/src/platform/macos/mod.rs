@@ -8,6 +8,7 @@ mod dynamic_wrapper;
 mod extensions;
 mod resources;
 pub(crate) mod state;
+mod view_handle;
 mod window;
 
 pub(crate) use self::app::MacOSDelegate;
@@ -1,17 +1,32 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use cacao::foundation::{id, NSInteger, NSString};
-use cacao::objc::{msg_send, sel, sel_impl};
+use std::rc::Rc;
+
+use cacao::foundation::{id, NSInteger, NSString, NO, YES};
+use cacao::objc::{class, msg_send, sel, sel_impl};
 use cacao::{button::Button as CacaoButton, utils::properties::ObjcProperty};
 use cacao::input::TextField as CacaoTextField;
 use cacao::text::Label as CacaoLabel;
 use cacao::image::ImageView as CacaoImageView;
+use cacao::listview::ListView as CacaoListView;
+use cacao::progress::ProgressIndicator as CacaoProgressIndicator;
+use cacao::scrollview::ScrollView as CacaoScrollView;
+use cacao::select::Select as CacaoSelect;
+use cacao::switch::Switch as CacaoSwitch;
+
+use crate::{BaseView, Canvas, Checkbox, Color, ColorWell, ImageView, Link, List, ListSelection, MenuItem, ProgressBar, ProgressStyle, RadioGroup, ScrollView, SearchField, Select, Slider, State, StateChangeOrigin, StateOrRaw, Stepper, StepperValue, Switch, TabView, TextAlignment, TextBlock, TextEditor, TextField};
+use crate::{event::{ChangedText, ViewId}, Button, Label};
 
-use crate::{BaseView, Checkbox, Color, ImageView, MenuItem, StateChangeOrigin, StateOrRaw, TextAlignment, TextBlock, TextField};
-use crate::{event::ViewId, Button, Label};
+#[cfg(feature = "chrono")]
+use crate::{views::to_timestamp, Calendar, DatePicker, DateValue};
+#[cfg(feature = "chrono")]
+use super::{set_date_value, NSDatePicker};
 
 use super::resources::ToCacao;
+use super::cacao_delegates::MacOSListViewDelegate;
+use super::{scroll_offset, select_popup_item, select_rows, set_scroll_offset, NSCanvasView, NSColorWell, NSComboBox, NSLinkButton, NSPoint, NSSearchField, NSSlider, NSStackView, NSTabView, NSTextView};
+use super::view_handle::MacOSViewHandle;
 
 #[derive(Debug)]
 pub(crate) enum Event {
@@ -19,11 +34,16 @@ pub(crate) enum Event {
     MenuAction {
         item: MenuItem,
     },
-    TextFieldChanged(ViewId, String),
+    TextFieldChanged(ViewId, ChangedText),
     CheckboxChanged(ViewId, bool),
+    ListRowActivated(ViewId, usize),
+    SelectionChanged(ViewId, Option<usize>),
+    ValueChanged(ViewId, f64),
+    ColorChanged(ViewId, Color),
 }
 
 pub fn attach_base_state(finestra: &dyn BaseView, objc: &ObjcProperty) {
+    finestra.base().register_handle(|| MacOSViewHandle::new(objc));
     hook_tooltip_state(objc, &finestra.base().tooltip);
 }
 
@@ -34,6 +54,12 @@ pub fn attach_button_state<S>(view_id: ViewId, finestra: &Button<S>, cacao: &Cac
     hook_title_state(view_id, &cacao.objc, &finestra.text);
 }
 
+#[cfg(feature = "chrono")]
+pub fn attach_calendar_state<S>(view_id: ViewId, finestra: &Calendar<S>, cacao: &NSDatePicker) {
+    attach_base_state(finestra, &cacao.objc);
+    hook_date_state(view_id, &cacao.objc, &finestra.value);
+}
+
 pub fn attach_checkbox_state<S>(view_id: ViewId, finestra: &Checkbox<S>, cacao: &CacaoButton) {
     attach_base_state(finestra, &cacao.objc);
     hook_background_color_state(&cacao.objc, &finestra.background_color);
@@ -41,6 +67,12 @@ pub fn attach_checkbox_state<S>(view_id: ViewId, finestra: &Checkbox<S>, cacao:
     hook_title_state(view_id, &cacao.objc, &finestra.text);
 }
 
+#[cfg(feature = "chrono")]
+pub fn attach_date_picker_state<T: DateValue, S>(view_id: ViewId, finestra: &DatePicker<T, S>, cacao: &NSDatePicker) {
+    attach_base_state(finestra, &cacao.objc);
+    hook_date_state(view_id, &cacao.objc, &finestra.value);
+}
+
 pub fn attach_image_view_state<S>(view_id: ViewId, finestra: &ImageView<S>, cacao: &CacaoImageView) {
     attach_base_state(finestra, &cacao.objc);
 
@@ -54,6 +86,180 @@ pub fn attach_label_state<S>(view_id: ViewId, finestra: &Label<S>, cacao: &Cacao
     hook_string_value_state(view_id, &cacao.objc, &finestra.text);
 }
 
+pub fn attach_link_state<S>(finestra: &Link<S>, cacao: &NSLinkButton) {
+    attach_base_state(finestra, &cacao.objc);
+
+    finestra.text.with(|text| NSLinkButton::set_title(&cacao.objc, text));
+    if let StateOrRaw::State(text_state) = &finestra.text {
+        let objc = cacao.objc.clone();
+        text_state.add_listener(move |val| NSLinkButton::set_title(&objc, val));
+    }
+}
+
+pub fn attach_list_state<T, S, D>(view_id: ViewId, finestra: &List<T, S, D>, cacao: &CacaoListView<MacOSListViewDelegate>) {
+    attach_base_state(finestra, &cacao.objc);
+    hook_selection_state(view_id, &cacao.objc, &finestra.selection);
+}
+
+/// The text of the combo box is bound to the state of the editable select.
+pub fn attach_combo_box_state<T, S>(view_id: ViewId, finestra: &Select<T, S>, cacao: &NSComboBox) {
+    attach_base_state(finestra, &cacao.objc);
+
+    let text = StateOrRaw::State(finestra.text.clone().unwrap_or_default());
+    text.with(|text| {
+        let text = NSString::new(text);
+        cacao.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setStringValue:&*text];
+        });
+    });
+    hook_string_value_state(view_id, &cacao.objc, &text);
+}
+
+pub fn attach_select_state<T, S>(view_id: ViewId, finestra: &Select<T, S>, cacao: &CacaoSelect) {
+    attach_base_state(finestra, &cacao.objc);
+
+    select_popup_item(&cacao.objc, finestra.selection.clone_inner());
+
+    let objc = cacao.objc.clone();
+    finestra.selection.add_listener_with_origin(move |index| {
+        select_popup_item(&objc, *index);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_progress_bar_state<S>(finestra: &ProgressBar<S>, cacao: &CacaoProgressIndicator) {
+    attach_base_state(finestra, &cacao.objc);
+
+    cacao.objc.with_mut(|obj| unsafe {
+        // NSProgressIndicatorStyleBar and NSProgressIndicatorStyleSpinning
+        let style: NSInteger = if finestra.style == ProgressStyle::Circular { 1 } else { 0 };
+        let _: () = msg_send![obj, setStyle:style];
+        let _: () = msg_send![obj, setMinValue:0.0_f64];
+        let _: () = msg_send![obj, setMaxValue:1.0_f64];
+    });
+
+    let objc = cacao.objc.clone();
+    let show = move |value: Option<f64>| {
+        objc.with_mut(|obj| unsafe {
+            let nil: id = std::ptr::null_mut();
+            let _: () = msg_send![obj, setIndeterminate:if value.is_none() { YES } else { NO }];
+            if let Some(value) = value {
+                let _: () = msg_send![obj, stopAnimation:nil];
+                let _: () = msg_send![obj, setDoubleValue:value.clamp(0.0, 1.0)];
+            } else {
+                let _: () = msg_send![obj, startAnimation:nil];
+            }
+        });
+    };
+
+    show(finestra.value.clone_inner());
+
+    let StateOrRaw::State(value_state) = &finestra.value else {
+        return;
+    };
+
+    value_state.add_listener(move |val| show(*val));
+}
+
+pub fn attach_scroll_view_state<S, D>(view_id: ViewId, finestra: &ScrollView<S, D>, cacao: &CacaoScrollView) {
+    attach_base_state(finestra, &cacao.objc);
+    hook_scroll_offset_state(view_id, &cacao.objc, &finestra.horizontal_offset, |offset, val| offset.x = val);
+    hook_scroll_offset_state(view_id, &cacao.objc, &finestra.vertical_offset, |offset, val| offset.y = val);
+}
+
+/// The `buttons` are the radio buttons of the options, in the same order.
+pub fn attach_radio_group_state<T, S>(view_id: ViewId, finestra: &RadioGroup<T, S>, cacao: &NSStackView, buttons: &[CacaoButton])
+        where T: PartialEq + Clone + 'static {
+    attach_base_state(finestra, &cacao.objc);
+
+    for (button, option) in buttons.iter().zip(&finestra.options) {
+        hook_enabled_state(&button.objc, &option.enabled);
+    }
+
+    let buttons: Vec<_> = buttons.iter().map(|button| button.objc.clone()).collect();
+    let values: Vec<_> = finestra.options.iter().map(|option| option.value.clone()).collect();
+    let show = move |selected: &T| {
+        for (objc, value) in buttons.iter().zip(&values) {
+            // NSControlStateValueOn and NSControlStateValueOff
+            let state: NSInteger = if value == selected { 1 } else { 0 };
+            objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, setState:state];
+            });
+        }
+    };
+
+    finestra.selection.with(&show);
+    finestra.selection.add_listener_with_origin(show, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_canvas_state<S>(finestra: &Canvas<S>, cacao: &NSCanvasView) {
+    attach_base_state(finestra, &cacao.objc);
+}
+
+pub fn attach_color_well_state<S>(view_id: ViewId, finestra: &ColorWell<S>, cacao: &NSColorWell) {
+    attach_base_state(finestra, &cacao.objc);
+
+    NSColorWell::set_color(&cacao.objc, &finestra.value.clone_inner());
+
+    let objc = cacao.objc.clone();
+    finestra.value.add_listener_with_origin(move |color| {
+        NSColorWell::set_color(&objc, color);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_slider_state<S>(view_id: ViewId, finestra: &Slider<S>, cacao: &NSSlider) {
+    attach_base_state(finestra, &cacao.objc);
+
+    NSSlider::set_value(&cacao.objc, finestra.value.clone_inner());
+
+    let objc = cacao.objc.clone();
+    finestra.value.add_listener_with_origin(move |val| {
+        NSSlider::set_value(&objc, *val);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+/// The value is displayed in the `text_field` next to the stepper buttons.
+pub fn attach_stepper_state<T: StepperValue, S, D>(view_id: ViewId, finestra: &Stepper<T, S>, cacao: &NSStackView, text_field: &CacaoTextField<D>) {
+    attach_base_state(finestra, &cacao.objc);
+
+    let format = Rc::clone(&finestra.format);
+    let objc = text_field.objc.clone();
+    let show = move |value: &T| set_string_value(&objc, &format(*value));
+
+    finestra.value.with(&show);
+    finestra.value.add_listener_with_origin(show, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_switch_state<S>(view_id: ViewId, finestra: &Switch<S>, cacao: &CacaoSwitch) {
+    attach_base_state(finestra, &cacao.objc);
+    hook_title_state(view_id, &cacao.objc, &finestra.text);
+
+    let set_checked = |objc: &ObjcProperty, is_checked: bool| {
+        // NSControlStateValueOn and NSControlStateValueOff
+        let state: NSInteger = if is_checked { 1 } else { 0 };
+        objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setState:state];
+        });
+    };
+
+    set_checked(&cacao.objc, finestra.checked.clone_inner());
+
+    let objc = cacao.objc.clone();
+    finestra.checked.add_listener_with_origin(move |val| {
+        set_checked(&objc, *val);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+pub fn attach_tab_view_state<S, D>(view_id: ViewId, finestra: &TabView<S, D>, cacao: &NSTabView) {
+    attach_base_state(finestra, &cacao.objc);
+
+    NSTabView::select_tab(&cacao.objc, finestra.selection.clone_inner());
+
+    let objc = cacao.objc.clone();
+    finestra.selection.add_listener_with_origin(move |index| {
+        NSTabView::select_tab(&objc, *index);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
 pub fn attach_text_block_state<S>(view_id: ViewId, finestra: &TextBlock<S>, cacao: &CacaoLabel) {
     attach_base_state(finestra, &cacao.objc);
     hook_background_color_state(&cacao.objc, &finestra.background_color);
@@ -62,9 +268,73 @@ pub fn attach_text_block_state<S>(view_id: ViewId, finestra: &TextBlock<S>, caca
     hook_string_value_state(view_id, &cacao.objc, &finestra.text);
 }
 
+/// The text is set first, so that the selection is clamped to it.
+pub fn attach_search_field_state<S>(view_id: ViewId, finestra: &SearchField<S>, cacao: &NSSearchField) {
+    attach_base_state(finestra, &cacao.objc);
+
+    finestra.text.with(|text| set_string_value(&cacao.objc, text));
+    hook_string_value_state(view_id, &cacao.objc, &finestra.text);
+
+    finestra.placeholder_text.with(|placeholder| NSSearchField::set_placeholder(&cacao.objc, placeholder));
+    if let StateOrRaw::State(placeholder_state) = &finestra.placeholder_text {
+        let objc = cacao.objc.clone();
+        placeholder_state.add_listener(move |val| NSSearchField::set_placeholder(&objc, val));
+    }
+
+    if let Some(history) = &finestra.history {
+        history.with(|recent| NSSearchField::set_recent_searches(&cacao.objc, recent));
+
+        let objc = cacao.objc.clone();
+        history.add_listener(move |recent| NSSearchField::set_recent_searches(&objc, recent));
+    }
+}
+
+pub fn attach_text_editor_state<S>(view_id: ViewId, finestra: &TextEditor<S>, cacao: &NSTextView) {
+    attach_base_state(finestra, &cacao.text_view);
+
+    finestra.text.with(|text| NSTextView::set_text(&cacao.text_view, text));
+    if let StateOrRaw::State(text_state) = &finestra.text {
+        let text_view = cacao.text_view.clone();
+        text_state.add_listener_with_origin(move |val| {
+            NSTextView::set_text(&text_view, val);
+        }, StateChangeOrigin::Owner(view_id));
+    }
+
+    NSTextView::set_editable(&cacao.text_view, finestra.editable.clone_inner());
+    if let StateOrRaw::State(editable_state) = &finestra.editable {
+        let text_view = cacao.text_view.clone();
+        editable_state.add_listener(move |val| NSTextView::set_editable(&text_view, *val));
+    }
+
+    if let Some(selection) = &finestra.selection {
+        NSTextView::select(&cacao.text_view, selection.clone_inner());
+
+        let text_view = cacao.text_view.clone();
+        selection.add_listener_with_origin(move |val| {
+            NSTextView::select(&text_view, *val);
+        }, StateChangeOrigin::Owner(view_id));
+    }
+}
+
 pub fn attach_text_field_state<S, T>(view_id: ViewId, finestra: &TextField<S>, cacao: &CacaoTextField<T>) {
     attach_base_state(finestra, &cacao.objc);
     hook_string_value_state(view_id, &cacao.objc, &finestra.text);
+
+    if finestra.secure {
+        hook_reveal_state(&cacao.objc, &finestra.reveal);
+    }
+
+    if let Some(validation) = &finestra.validation {
+        hook_validation_error_state(&cacao.objc, &validation.error, &finestra.base().tooltip);
+    }
+}
+
+pub(crate) fn set_string_value(objc: &ObjcProperty, text: &str) {
+    let text = NSString::new(text);
+
+    objc.with_mut(|obj| unsafe {
+        let _: () = msg_send![obj, setStringValue:&*text];
+    });
 }
 
 fn hook_background_color_state(objc: &ObjcProperty, color: &StateOrRaw<Color>) {
@@ -84,6 +354,94 @@ fn hook_background_color_state(objc: &ObjcProperty, color: &StateOrRaw<Color>) {
     });
 }
 
+#[cfg(feature = "chrono")]
+fn hook_date_state<T: DateValue>(view_id: ViewId, objc: &ObjcProperty, value: &State<T>) {
+    set_date_value(objc, to_timestamp(value.clone_inner()));
+
+    let objc = objc.clone();
+    value.add_listener_with_origin(move |value| {
+        set_date_value(&objc, to_timestamp(*value));
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_enabled_state(objc: &ObjcProperty, enabled: &StateOrRaw<bool>) {
+    let set_enabled = |objc: &ObjcProperty, enabled: bool| {
+        objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setEnabled:if enabled { YES } else { NO }];
+        });
+    };
+
+    set_enabled(objc, enabled.clone_inner());
+
+    let StateOrRaw::State(enabled_state) = &enabled else {
+        return;
+    };
+
+    let objc = objc.clone();
+    enabled_state.add_listener(move |val| set_enabled(&objc, *val));
+}
+
+fn hook_scroll_offset_state(view_id: ViewId, objc: &ObjcProperty, offset: &StateOrRaw<f32>, set: fn(&mut NSPoint, f64)) {
+    let mut value = scroll_offset(objc);
+    set(&mut value, f64::from(offset.clone_inner()));
+    set_scroll_offset(objc, value);
+
+    let StateOrRaw::State(offset_state) = &offset else {
+        return;
+    };
+
+    let objc = objc.clone();
+    offset_state.add_listener_with_origin(move |val| {
+        let mut value = scroll_offset(&objc);
+        set(&mut value, f64::from(*val));
+        set_scroll_offset(&objc, value);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+fn hook_selection_state(view_id: ViewId, objc: &ObjcProperty, selection: &ListSelection) {
+    select_rows(objc, &selection.rows());
+
+    let objc = objc.clone();
+    selection.add_listener(move |rows| {
+        select_rows(&objc, &rows);
+    }, StateChangeOrigin::Owner(view_id));
+}
+
+/// A secure text field is an `NSTextField` with an `NSSecureTextFieldCell`,
+/// which masks the text, and doesn't allow copying or autocompleting it. The
+/// text is revealed by swapping the cell for a regular one.
+fn hook_reveal_state(objc: &ObjcProperty, reveal: &StateOrRaw<bool>) {
+    let set_masked = |objc: &ObjcProperty, masked: bool| {
+        objc.with_mut(|obj| unsafe {
+            let text: id = msg_send![obj, stringValue];
+            let cell: id = if masked {
+                msg_send![class!(NSSecureTextFieldCell), alloc]
+            } else {
+                msg_send![class!(NSTextFieldCell), alloc]
+            };
+            let cell: id = msg_send![cell, initTextCell:text];
+
+            let _: () = msg_send![cell, setEditable:YES];
+            let _: () = msg_send![cell, setBezeled:YES];
+            let _: () = msg_send![cell, setScrollable:YES];
+            let _: () = msg_send![cell, setUsesSingleLineMode:YES];
+            let _: () = msg_send![obj, setCell:cell];
+            let _: () = msg_send![cell, release];
+        });
+    };
+
+    set_masked(objc, !reveal.clone_inner());
+
+    let StateOrRaw::State(reveal_state) = &reveal else {
+        return;
+    };
+
+    let objc = objc.clone();
+    reveal_state.add_listener(move |val| {
+        set_masked(&objc, !*val);
+    });
+}
+
 fn hook_string_value_state(view_id: ViewId, objc: &ObjcProperty, text: &StateOrRaw<String>) {
     let StateOrRaw::State(text_state) = &text else {
         return;
@@ -144,6 +502,27 @@ fn hook_title_state(view_id: ViewId, objc: &ObjcProperty, text: &StateOrRaw<Stri
     }, StateChangeOrigin::Owner(view_id));
 }
 
+/// Invalid text is shown in red, and the message replaces the tooltip until
+/// the text is valid again.
+fn hook_validation_error_state(objc: &ObjcProperty, error: &State<Option<String>>, tooltip: &StateOrRaw<String>) {
+    let objc = objc.clone();
+    let tooltip = tooltip.clone();
+    error.add_listener(move |error| {
+        let message = NSString::new(&error.clone().unwrap_or_else(|| tooltip.clone_inner()));
+
+        objc.with_mut(|obj| unsafe {
+            let color: id = if error.is_some() {
+                msg_send![class!(NSColor), systemRedColor]
+            } else {
+                msg_send![class!(NSColor), controlTextColor]
+            };
+
+            let _: () = msg_send![obj, setTextColor:color];
+            let _: () = msg_send![obj, setToolTip:&*message];
+        });
+    });
+}
+
 fn hook_tooltip_state(objc: &ObjcProperty, text: &StateOrRaw<String>) {
     let StateOrRaw::State(text_state) = &text else {
         return;
@@ -0,0 +1,111 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use cacao::foundation::{id, NSString, BOOL, NO, YES};
+use cacao::objc::{msg_send, sel, sel_impl};
+use cacao::objc::runtime::Object;
+use cacao::utils::properties::ObjcProperty;
+
+use crate::ViewHandleApi;
+
+use super::NSRect;
+
+/// A [`ViewHandleApi`] for any `NSControl`.
+pub(crate) struct MacOSViewHandle {
+    objc: ObjcProperty,
+}
+
+impl MacOSViewHandle {
+    pub(crate) fn new(objc: &ObjcProperty) -> Self {
+        Self {
+            objc: objc.clone(),
+        }
+    }
+}
+
+/// The text of an `NSButton` is stored in the `title` property, where the text
+/// of other controls is stored in the `stringValue`.
+fn has_title(obj: &Object) -> bool {
+    let has_title: BOOL = unsafe {
+        msg_send![obj, respondsToSelector:sel!(setTitle:)]
+    };
+
+    has_title == YES
+}
+
+/// The text of an `NSTextView` is stored in the `string` property.
+fn has_string(obj: &Object) -> bool {
+    let has_string: BOOL = unsafe {
+        msg_send![obj, respondsToSelector:sel!(setString:)]
+    };
+
+    has_string == YES
+}
+
+impl ViewHandleApi for MacOSViewHandle {
+    fn text(&self) -> String {
+        self.objc.get(|obj| unsafe {
+            let text: id = if has_title(obj) {
+                msg_send![obj, title]
+            } else if has_string(obj) {
+                msg_send![obj, string]
+            } else {
+                msg_send![obj, stringValue]
+            };
+
+            NSString::retain(text).to_string()
+        })
+    }
+
+    fn set_text(&self, text: &str) {
+        let text = NSString::new(text);
+
+        self.objc.with_mut(|obj| unsafe {
+            if has_title(obj) {
+                let _: () = msg_send![obj, setTitle:&*text];
+            } else if has_string(obj) {
+                let _: () = msg_send![obj, setString:&*text];
+            } else {
+                let _: () = msg_send![obj, setStringValue:&*text];
+            }
+        });
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.objc.get(|obj| unsafe {
+            let enabled: BOOL = msg_send![obj, isEnabled];
+            enabled == YES
+        })
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setEnabled:if enabled { YES } else { NO }];
+        });
+    }
+
+    // https://developer.apple.com/documentation/appkit/nswindow/1419366-makefirstresponder
+    fn focus(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let window: id = msg_send![obj, window];
+            if !window.is_null() {
+                let _: BOOL = msg_send![window, makeFirstResponder:&*obj];
+            }
+        });
+    }
+
+    // https://developer.apple.com/documentation/appkit/nsview/1483811-scrollrecttovisible
+    fn scroll_into_view(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let bounds: NSRect = msg_send![obj, bounds];
+            let _: BOOL = msg_send![obj, scrollRectToVisible:bounds];
+        });
+    }
+
+    // https://developer.apple.com/documentation/appkit/nsview/1483360-needsdisplay
+    fn invalidate(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setNeedsDisplay:YES];
+        });
+    }
+}
@@ -11,11 +11,11 @@ use cacao::layout::LayoutConstraint;
 
 use crate::event::{EventHandlerMapRegistry, ViewTree};
 use super::cacao_delegates::StatefulEventDispatcher;
-use crate::{AppDelegate, DialogBuilder, View, Window, WindowDelegator};
+use crate::{AppDelegate, ColorDialogBuilder, DialogBuilder, View, Window, WindowDelegator};
 use super::dynamic_wrapper::DynamicViewWrapper;
 use super::dynamic_wrapper::LayoutExt;
 use super::extensions::WindowExtensions;
-use super::appkit::NSAlert;
+use super::appkit::{NSAlert, NSColorPanel};
 
 pub(super) struct MacOSWindowDelegate<Delegate, State>
         where State: 'static {
@@ -105,4 +105,12 @@ impl<CacaoDelegate> WindowDelegator for MacOSWindowDelegator<CacaoDelegate> {
     fn create_dialog(&self, text: Cow<'static, str>) -> crate::DialogBuilder {
         DialogBuilder::new(Box::new(NSAlert::with(text, self.window.get_title())))
     }
+
+    fn pick_color(&self) -> ColorDialogBuilder {
+        ColorDialogBuilder::new(Box::new(NSColorPanel::new(self.window.get_title())))
+    }
+
+    fn close(&self) {
+        self.window.close();
+    }
 }
@@ -1,6 +1,11 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+pub(crate) mod headless;
+
+#[cfg(all(target_os = "linux", feature = "gtk"))]
+pub(crate) mod gtk;
+
 #[cfg(target_os = "macos")]
 pub(crate) mod macos;
 
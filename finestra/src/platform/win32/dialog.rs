@@ -5,6 +5,14 @@ use std::cell::Cell;
 
 use windows::{
     core::PCSTR,
+    Win32::Foundation::{COLORREF, HWND},
+    Win32::UI::Controls::Dialogs::{
+        ChooseColorA,
+        CC_ANYCOLOR,
+        CC_FULLOPEN,
+        CC_RGBINIT,
+        CHOOSECOLORA,
+    },
     Win32::UI::WindowsAndMessaging::{
         MessageBoxA,
         MB_ICONERROR,
@@ -14,7 +22,7 @@ use windows::{
     }
 };
 
-use crate::{DialogApi, DialogKind};
+use crate::{Color, ColorDialogApi, ColorKind, DialogApi, DialogKind};
 
 pub(crate) struct Win32Dialog {
     text: Cell<String>,
@@ -75,3 +83,55 @@ impl DialogApi for Win32Dialog {
         }
     }
 }
+
+/// The common color dialog, which can't pick the opacity of a color and
+/// doesn't have a title.
+pub(crate) struct Win32ColorDialog {
+    owner: HWND,
+    color: Cell<Color>,
+}
+
+impl Win32ColorDialog {
+    pub fn new(owner: HWND) -> Self {
+        Self {
+            owner,
+            color: Cell::new(Color::default()),
+        }
+    }
+}
+
+impl ColorDialogApi for Win32ColorDialog {
+    fn set_alpha(&self, _alpha: bool) {}
+
+    fn set_color(&self, color: Color) {
+        self.color.set(color);
+    }
+
+    fn set_title(&self, _title: std::borrow::Cow<'static, str>) {}
+
+    fn show(&self, on_pick: Box<dyn Fn(Color)>) {
+        // Colors of the theme can't be chosen, so these start at black.
+        let initial = match *self.color.take().kind() {
+            ColorKind::Rgb { red, green, blue } => COLORREF(u32::from_le_bytes([red, green, blue, 0])),
+            ColorKind::Rgba { red, green, blue, .. } => COLORREF(u32::from_le_bytes([red, green, blue, 0])),
+            _ => COLORREF(0),
+        };
+
+        // The dialog lets the user store 16 colors of their own.
+        let mut custom_colors = [COLORREF(0x00FFFFFF); 16];
+
+        let mut options = CHOOSECOLORA {
+            lStructSize: std::mem::size_of::<CHOOSECOLORA>() as u32,
+            hwndOwner: self.owner,
+            rgbResult: initial,
+            lpCustColors: custom_colors.as_mut_ptr(),
+            Flags: CC_RGBINIT | CC_FULLOPEN | CC_ANYCOLOR,
+            ..Default::default()
+        };
+
+        if unsafe { ChooseColorA(&mut options) }.as_bool() {
+            let [red, green, blue, _] = options.rgbResult.0.to_le_bytes();
+            on_pick(Color::rgb(red, green, blue));
+        }
+    }
+}
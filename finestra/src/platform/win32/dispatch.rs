@@ -1,10 +1,22 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use windows::Win32::UI::WindowsAndMessaging::WM_QUIT;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use windows::Win32::Foundation::{GetLastError, HWND};
+use windows::Win32::UI::WindowsAndMessaging::{KillTimer, SetTimer, WM_QUIT};
+
+use crate::Timer;
 
 use super::wrapper::{get_next_message, MsgExtensions};
 
+thread_local! {
+    /// The actions of the timers scheduled by [`schedule_local_timer()`], by
+    /// the identifier of their Win32 timer.
+    static LOCAL_TIMERS: RefCell<HashMap<usize, Box<dyn FnOnce()>>> = RefCell::default();
+}
+
 pub fn run_message_pump() -> ! {
     loop {
         let message = get_next_message();
@@ -17,3 +29,34 @@ pub fn run_message_pump() -> ! {
         message.dispatch();
     }
 }
+
+/// Schedules the timer on the message pump of this thread, so the action
+/// doesn't have to be `Send`. The timer doesn't belong to a window, so its
+/// `WM_TIMER` message is dispatched to [`fire_local_timer()`].
+pub(crate) fn schedule_local_timer<F: FnOnce() + 'static>(timer: Timer<F>) {
+    let elapse = timer.delay.as_millis().try_into().unwrap_or(u32::MAX);
+    let id = unsafe { SetTimer(HWND::default(), 0, elapse, Some(fire_local_timer)) };
+    debug_assert!(id != 0, "{:#?}", unsafe { GetLastError() });
+
+    if id == 0 {
+        // No WM_TIMER message will be sent without a timer, so the action
+        // would never be invoked. Drop it instead of storing it forever.
+        drop(timer.action);
+        return;
+    }
+
+    LOCAL_TIMERS.with(|timers| {
+        timers.borrow_mut().insert(id, Box::new(move || (timer.action)()));
+    });
+}
+
+unsafe extern "system" fn fire_local_timer(_: HWND, _: u32, id: usize, _: u32) {
+    // Win32 timers repeat until they are killed.
+    _ = KillTimer(HWND::default(), id);
+
+    // Don't hold the borrow, as the action can schedule another timer.
+    let action = LOCAL_TIMERS.with(|timers| timers.borrow_mut().remove(&id));
+    if let Some(action) = action {
+        action();
+    }
+}
@@ -3,8 +3,9 @@
 
 use windows::{core::PCSTR, Win32::{Foundation::{GetLastError, HWND}, System::SystemServices::SS_CENTER, UI::WindowsAndMessaging::{CreateWindowExA, ShowWindow, SW_SHOWDEFAULT, WINDOW_STYLE, WS_CHILD, WS_TABSTOP, WS_VISIBLE}}};
 use windows::Win32::System::SystemServices::SS_SIMPLE;
+use windows::Win32::UI::WindowsAndMessaging::{SetWindowPos, BS_AUTOCHECKBOX, BS_DEFPUSHBUTTON, SWP_NOACTIVATE, SWP_NOZORDER};
 use crate::event::ViewId;
-use windows::Win32::UI::WindowsAndMessaging::BS_DEFPUSHBUTTON;
+use crate::{ContainerLayout, LayoutFrame, LayoutNode, LayoutSize};
 
 use super::{window::WindowData, wrapper::Hwnd};
 
@@ -24,10 +25,56 @@ impl WinView {
 
     pub(crate) fn install<Delegate, State>(&self, window: &WindowData<Delegate, State>)
             where Delegate: crate::AppDelegate<State> {
-        _ = window;
         if let Some(hwnd) = self.kind.hwnd() {
             hwnd.show(SW_SHOWDEFAULT);
         }
+
+        if let WinViewKind::Container(container) = &self.kind {
+            for child in &container.children {
+                child.install(window);
+            }
+        }
+    }
+
+    /// Creates the input of the layout pass, by measuring the controls.
+    pub(crate) fn layout_node(&self) -> LayoutNode {
+        match &self.kind {
+            WinViewKind::Empty => LayoutNode::Leaf(LayoutSize::zero()),
+            // Leave room for the border of the button.
+            WinViewKind::Button(button) => LayoutNode::Leaf(button.hwnd.measure_text() + LayoutSize::new(24.0, 10.0)),
+            WinViewKind::Label(label) => LayoutNode::Leaf(label.hwnd.measure_text()),
+            WinViewKind::Container(container) => container.layout.node(
+                container.children.iter().map(Self::layout_node).collect(),
+            ),
+        }
+    }
+
+    /// Moves the controls to the frames computed by the layout pass.
+    pub(crate) fn apply_layout(&self, frame: &LayoutFrame) {
+        if let WinViewKind::Container(container) = &self.kind {
+            for (child, frame) in container.children.iter().zip(&frame.children) {
+                child.apply_layout(frame);
+            }
+            return;
+        }
+
+        let Some(hwnd) = self.kind.hwnd() else {
+            return;
+        };
+
+        // <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowpos>
+        let rect = frame.rect.round();
+        _ = unsafe {
+            SetWindowPos(
+                *hwnd,
+                HWND::default(),
+                rect.origin.x as i32,
+                rect.origin.y as i32,
+                rect.size.width as i32,
+                rect.size.height as i32,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            )
+        };
     }
 }
 
@@ -36,6 +83,7 @@ pub enum WinViewKind {
     Empty,
     Button(WinButton),
     Label(WinLabel),
+    Container(WinContainer),
 }
 
 impl WinViewKind {
@@ -44,6 +92,7 @@ impl WinViewKind {
             Self::Empty => None,
             Self::Button(button) => Some(button.hwnd),
             Self::Label(label) => Some(label.hwnd),
+            Self::Container(..) => None,
         }
     }
 }
@@ -55,6 +104,16 @@ pub struct WinButton {
 
 impl WinButton {
     pub fn new(parent: HWND, text: &str) -> Self {
+        Self::with_style(parent, text, BS_DEFPUSHBUTTON)
+    }
+
+    /// Creates a checkbox, which is checked and unchecked by Win32 itself
+    /// when it is clicked.
+    pub fn new_checkbox(parent: HWND, text: &str) -> Self {
+        Self::with_style(parent, text, BS_AUTOCHECKBOX)
+    }
+
+    fn with_style(parent: HWND, text: &str, style: i32) -> Self {
         let class_name = PCSTR::from_raw("BUTTON\0".as_ptr());
         let text = PCSTR::from_raw(format!("{text}\0").as_ptr());
 
@@ -63,9 +122,9 @@ impl WinButton {
                 Default::default(),
                 class_name,
                 text,
-                WS_CHILD | WS_VISIBLE | WS_TABSTOP | WINDOW_STYLE(BS_DEFPUSHBUTTON as _),
-                10, 10,
-                100, 100,
+                WS_CHILD | WS_VISIBLE | WS_TABSTOP | WINDOW_STYLE(style as _),
+                0, 0,
+                0, 0,
                 parent,
                 None,
                 None,
@@ -103,8 +162,8 @@ impl WinLabel {
                 class_name,
                 text,
                 WS_CHILD | WS_VISIBLE | WS_TABSTOP | WINDOW_STYLE(SS_SIMPLE.0),
-                10, 10,
-                100, 100,
+                0, 0,
+                0, 0,
                 parent,
                 None,
                 None,
@@ -125,3 +184,12 @@ impl AsRef<Hwnd> for WinLabel {
         &self.hwnd
     }
 }
+
+/// Win32 doesn't have container controls, such as stacks and grids, so the
+/// children are placed directly in the window, at the frames computed by the
+/// layout pass.
+#[derive(Debug)]
+pub struct WinContainer {
+    pub(crate) layout: ContainerLayout,
+    pub(crate) children: Vec<WinView>,
+}
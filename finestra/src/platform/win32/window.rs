@@ -8,7 +8,7 @@ use std::sync::Arc;
 use std::{mem::size_of, sync::Once};
 
 use windows::core::PCSTR;
-use windows::Win32::Foundation::{GetLastError, BOOL};
+use windows::Win32::Foundation::{GetLastError, BOOL, RECT};
 use windows::Win32::Graphics::Dwm::{DwmSetWindowAttribute, DWMWA_USE_IMMERSIVE_DARK_MODE};
 use windows::Win32::Graphics::Gdi::{GetStockObject, UpdateWindow, BLACK_BRUSH, HDC, HOLLOW_BRUSH};
 use windows::Win32::UI::WindowsAndMessaging::*;
@@ -33,9 +33,9 @@ use windows::Win32::{
 };
 
 use crate::event::{EventHandlerMapRegistry, ViewTree};
-use crate::{AppDelegate, DialogBuilder, View, ViewId, WindowConfiguration};
+use crate::{AppDelegate, DialogBuilder, LayoutRect, LayoutSize, View, ViewId, WindowConfiguration};
 
-use super::dialog::Win32Dialog;
+use super::dialog::{Win32ColorDialog, Win32Dialog};
 use super::view::WinView;
 use super::wrapper::{ControlId, Hwnd};
 
@@ -138,6 +138,24 @@ impl<Delegate, State: 'static> WindowData<Delegate, State>
 
         view.install(&self);
         self.view = Some(view);
+        self.layout();
+    }
+
+    /// Places the views at the frames computed by the layout pass, using the
+    /// size of the client area.
+    fn layout(&self) {
+        let Some(view) = &self.view else {
+            return;
+        };
+
+        let mut client = RECT::default();
+        if unsafe { GetClientRect(self.hwnd, &mut client) }.is_err() {
+            return;
+        }
+
+        let size = LayoutSize::new((client.right - client.left) as f32, (client.bottom - client.top) as f32);
+        let node = view.layout_node();
+        view.apply_layout(&node.arrange(LayoutRect::from_size(size)));
     }
 
     fn handle_control_notification(&mut self, notification: u16, control_id: ControlId, hwnd: HWND) {
@@ -147,7 +165,12 @@ impl<Delegate, State: 'static> WindowData<Delegate, State>
         };
 
         match notification as u32 {
-            BN_CLICKED => if let Some(callback) = &control.click {
+            BN_CLICKED => if let Some(callback) = control.checked.clone() {
+                drop(control);
+                let is_checked = Hwnd::from(hwnd).is_checked();
+                callback(&mut self.state, is_checked, self.delegator.clone());
+            } else if let Some(callback) = control.click.clone() {
+                drop(control);
                 callback(&mut self.state, self.delegator.clone());
             }
 
@@ -160,6 +183,15 @@ impl<Delegate, State> crate::WindowDelegator for Window<Delegate, State> {
     fn create_dialog(&self, text: std::borrow::Cow<'static, str>) -> crate::DialogBuilder {
         DialogBuilder::new(Box::new(Win32Dialog::new(text.into_owned(), Hwnd::from(self.hwnd).get_text())))
     }
+
+    fn pick_color(&self) -> crate::ColorDialogBuilder {
+        crate::ColorDialogBuilder::new(Box::new(Win32ColorDialog::new(self.hwnd)))
+    }
+
+    fn close(&self) {
+        // WM_CLOSE will post the WM_QUIT message, which stops the message pump.
+        _ = unsafe { PostMessageA(self.hwnd, WM_CLOSE, WPARAM(0), LPARAM(0)) };
+    }
 }
 
 fn register_class<Delegate, State: 'static>()
@@ -310,6 +342,9 @@ fn handle_procedure<Delegate, State: 'static>(window: Window<Delegate, State>, m
             data.make_content_view();
         },
 
+        // <https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-size>
+        WM_SIZE => data.layout(),
+
         _ => ()
     }
 
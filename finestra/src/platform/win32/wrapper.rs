@@ -3,10 +3,13 @@
 
 use std::ops::Deref;
 
-use windows::{core::PCSTR, Win32::{Foundation::{HWND, LPARAM, WPARAM}, Graphics::Gdi::{GetStockObject, DEFAULT_GUI_FONT, HFONT}, UI::WindowsAndMessaging::{DispatchMessageA, GetDlgCtrlID, GetMessageA, GetWindowTextA, GetWindowTextLengthA, SendMessageA, SetWindowTextA, ShowWindow, TranslateMessage, MSG, WM_SETFONT}}};
+use windows::{core::PCSTR, Win32::{Foundation::{BOOL, HWND, LPARAM, SIZE, WPARAM}, Graphics::Gdi::{GetDC, InvalidateRect, GetStockObject, GetTextExtentPoint32A, ReleaseDC, SelectObject, DEFAULT_GUI_FONT, HFONT, HGDIOBJ}, UI::WindowsAndMessaging::{DispatchMessageA, GetDlgCtrlID, GetMessageA, GetWindowTextA, GetWindowTextLengthA, SendMessageA, SetWindowTextA, ShowWindow, TranslateMessage, MSG, WM_GETFONT, WM_SETFONT}}};
 use windows::Win32::UI::WindowsAndMessaging::SHOW_WINDOW_CMD;
+use windows::Win32::UI::Controls::{BST_CHECKED, BST_UNCHECKED};
+use windows::Win32::UI::Input::KeyboardAndMouse::{EnableWindow, IsWindowEnabled, SetFocus};
+use windows::Win32::UI::WindowsAndMessaging::{BM_GETCHECK, BM_SETCHECK};
 
-use crate::{State, StateChangeOrigin};
+use crate::{LayoutSize, State, StateChangeOrigin};
 
 pub fn get_next_message() -> MSG {
     let mut msg = MSG::default();
@@ -71,9 +74,10 @@ impl Hwnd {
         let mut chars = Vec::new();
         chars.resize((length + 1) as usize, 0u8);
 
-        unsafe {
-            GetWindowTextA(self.inner, chars.as_mut_slice());
-        }
+        // The buffer includes the NUL terminator, which isn't part of the
+        // text, so only the copied characters are kept.
+        let copied = unsafe { GetWindowTextA(self.inner, chars.as_mut_slice()) };
+        chars.truncate(copied.max(0) as usize);
 
         match String::from_utf8(chars) {
             Ok(str) => str,
@@ -81,6 +85,24 @@ impl Hwnd {
         }
     }
 
+    /// Measures the text of the window, using its font.
+    /// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gettextextentpoint32a>
+    pub fn measure_text(&self) -> LayoutSize {
+        let text = self.get_text();
+        let mut size = SIZE::default();
+
+        unsafe {
+            let font = SendMessageA(self.inner, WM_GETFONT, WPARAM(0), LPARAM(0));
+            let dc = GetDC(self.inner);
+            let previous_font = SelectObject(dc, HGDIOBJ(font.0));
+            _ = GetTextExtentPoint32A(dc, text.as_bytes(), &mut size);
+            SelectObject(dc, previous_font);
+            ReleaseDC(self.inner, dc);
+        }
+
+        LayoutSize::new(size.cx as f32, size.cy as f32)
+    }
+
     pub fn show(&self, command: SHOW_WINDOW_CMD) {
         unsafe { ShowWindow(self.inner, command) };
     }
@@ -106,6 +128,30 @@ impl Hwnd {
         }, StateChangeOrigin::System);
     }
 
+    /// Whether the checkbox is checked.
+    pub fn is_checked(&self) -> bool {
+        let state = unsafe { SendMessageA(self.inner, BM_GETCHECK, WPARAM(0), LPARAM(0)) };
+        state.0 == BST_CHECKED.0 as isize
+    }
+
+    pub fn set_checked(&self, is_checked: bool) {
+        let state = if is_checked { BST_CHECKED } else { BST_UNCHECKED };
+        unsafe {
+            SendMessageA(self.inner, BM_SETCHECK, WPARAM(state.0 as _), LPARAM(0));
+        }
+    }
+
+    /// Checks the checkbox when the `state` is changed, unless it was changed
+    /// by the checkbox itself.
+    pub fn subscribe_checked_update(&self, state: &State<bool>, id: crate::event::ViewId) {
+        self.set_checked(state.clone_inner());
+
+        let obj = self.clone();
+        state.add_listener_with_origin(move |is_checked| {
+            obj.set_checked(*is_checked);
+        }, StateChangeOrigin::Owner(id));
+    }
+
     pub fn use_default_font(&self) {
         let font = HFONT(unsafe { GetStockObject(DEFAULT_GUI_FONT) }.0);
         unsafe {
@@ -114,6 +160,40 @@ impl Hwnd {
     }
 }
 
+impl crate::ViewHandleApi for Hwnd {
+    fn text(&self) -> String {
+        self.get_text()
+    }
+
+    fn set_text(&self, text: &str) {
+        Hwnd::set_text(self, text);
+    }
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-iswindowenabled
+    fn is_enabled(&self) -> bool {
+        unsafe { IsWindowEnabled(self.inner) }.as_bool()
+    }
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enablewindow
+    fn set_enabled(&self, enabled: bool) {
+        _ = unsafe { EnableWindow(self.inner, BOOL::from(enabled)) };
+    }
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setfocus
+    fn focus(&self) {
+        _ = unsafe { SetFocus(self.inner) };
+    }
+
+    // The ScrollView doesn't scroll on Win32 yet, so the view is already
+    // visible.
+    fn scroll_into_view(&self) {}
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-invalidaterect
+    fn invalidate(&self) {
+        _ = unsafe { InvalidateRect(self.inner, None, BOOL::from(true)) };
+    }
+}
+
 impl From<HWND> for Hwnd {
     fn from(value: HWND) -> Self {
         Self::new(value)
@@ -0,0 +1,20 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// Specifies how a view is positioned within its cell of a
+/// [`Grid`](crate::Grid), when the cell is larger than the view.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CellAlignment {
+    /// Align the view to the left or top edge of the cell.
+    Start,
+
+    /// Center the view within the cell.
+    Center,
+
+    /// Align the view to the right or bottom edge of the cell.
+    End,
+
+    /// Stretch the view to the size of the cell.
+    #[default]
+    Fill,
+}
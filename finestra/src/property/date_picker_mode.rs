@@ -0,0 +1,16 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// The parts of a moment that a [`DatePicker`](crate::DatePicker) lets the
+/// user pick, which follows from the type of the value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DatePickerMode {
+    /// Only the day, month and year.
+    Date,
+
+    /// Only the hours and minutes.
+    Time,
+
+    /// Both the date and the time.
+    DateTime,
+}
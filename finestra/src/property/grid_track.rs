@@ -0,0 +1,21 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::Number;
+
+/// The size of a row or a column of a [`Grid`](crate::Grid).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum GridTrack {
+    /// The track is as large as its largest item.
+    #[default]
+    Auto,
+
+    /// The track has a fixed size, regardless of its items.
+    Fixed(Number),
+
+    /// The track gets a share of the space that remains after the
+    /// [fixed](GridTrack::Fixed) and [auto](GridTrack::Auto) tracks are sized.
+    /// The space is shared in proportion to the fractions, e.g. a track of
+    /// `Fraction(2.0)` gets twice the space of a track of `Fraction(1.0)`.
+    Fraction(Number),
+}
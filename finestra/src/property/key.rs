@@ -0,0 +1,24 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// A key on the keyboard that is used to navigate through a view, such as the
+/// arrow keys that move through the rows of a [`Tree`](crate::Tree), or to
+/// confirm the text of a field, such as a [`SearchField`](crate::SearchField).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Key {
+    /// The up arrow key.
+    Up,
+
+    /// The down arrow key.
+    Down,
+
+    /// The left arrow key.
+    Left,
+
+    /// The right arrow key.
+    Right,
+
+    /// The return key, which is also known as the enter key.
+    Return,
+}
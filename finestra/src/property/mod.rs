@@ -1,8 +1,35 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+mod cell_alignment;
+#[cfg(feature = "chrono")]
+mod date_picker_mode;
+mod grid_track;
+mod key;
+mod orientation;
+mod padding;
+mod progress_style;
+mod stack_alignment;
+mod stack_distribution;
+mod table_sort;
 mod text_alignment;
+mod text_selection;
 mod theme;
+mod validator;
 
+pub use self::cell_alignment::CellAlignment;
+#[cfg(feature = "chrono")]
+pub use self::date_picker_mode::DatePickerMode;
+pub use self::grid_track::GridTrack;
+pub use self::key::Key;
+pub use self::orientation::Orientation;
+pub use self::padding::Padding;
+pub use self::progress_style::ProgressStyle;
+pub use self::stack_alignment::StackAlignment;
+pub use self::stack_distribution::StackDistribution;
+pub use self::table_sort::{SortOrder, TableSort};
 pub use self::text_alignment::TextAlignment;
+pub use self::text_selection::TextSelection;
 pub use self::theme::Theme;
+pub use self::validator::Validator;
+pub(crate) use self::validator::Validation;
@@ -0,0 +1,14 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// The direction a control such as a [`Slider`](crate::Slider) is laid out
+/// in.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// The control goes from left to right.
+    #[default]
+    Horizontal,
+
+    /// The control goes from bottom to top.
+    Vertical,
+}
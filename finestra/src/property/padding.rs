@@ -0,0 +1,68 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::Number;
+
+/// The space between the edges of a container, such as a
+/// [`Stack`](crate::Stack), and its contents.
+///
+/// ```
+/// # use finestra::Padding;
+/// let all_sides = Padding::uniform(10.0);
+/// let sides = Padding::symmetric(20.0, 10.0);
+/// let custom = Padding::new(1.0, 2.0, 3.0, 4.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Padding {
+    /// The space above the contents.
+    pub top: Number,
+
+    /// The space to the right of the contents.
+    pub right: Number,
+
+    /// The space below the contents.
+    pub bottom: Number,
+
+    /// The space to the left of the contents.
+    pub left: Number,
+}
+
+impl Padding {
+    /// Create a padding with the given space per side, in the same order as
+    /// CSS: top, right, bottom, left.
+    #[must_use]
+    pub const fn new(top: Number, right: Number, bottom: Number, left: Number) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    /// Create a padding with the same space on all sides.
+    #[must_use]
+    pub const fn uniform(value: Number) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Create a padding with `horizontal` space on the left and right sides,
+    /// and `vertical` space on the top and bottom sides.
+    #[must_use]
+    pub const fn symmetric(horizontal: Number, vertical: Number) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// The sum of the left and right padding.
+    #[must_use]
+    pub fn horizontal(&self) -> Number {
+        self.left + self.right
+    }
+
+    /// The sum of the top and bottom padding.
+    #[must_use]
+    pub fn vertical(&self) -> Number {
+        self.top + self.bottom
+    }
+}
+
+impl From<Number> for Padding {
+    fn from(value: Number) -> Self {
+        Self::uniform(value)
+    }
+}
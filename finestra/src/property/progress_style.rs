@@ -0,0 +1,13 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// The appearance of a [`ProgressBar`](crate::ProgressBar).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProgressStyle {
+    /// A horizontal bar that fills up from left to right.
+    #[default]
+    Bar,
+
+    /// A small circle, which is a spinner while the progress is unknown.
+    Circular,
+}
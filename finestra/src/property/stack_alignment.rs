@@ -0,0 +1,21 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// Specifies how the items of a [`Stack`](crate::Stack) are positioned on the
+/// cross axis, i.e. horizontally for a vertical stack, and vertically for a
+/// horizontal stack.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StackAlignment {
+    /// Align the items to the left or top edge of the stack.
+    Start,
+
+    /// Center the items within the stack.
+    #[default]
+    Center,
+
+    /// Align the items to the right or bottom edge of the stack.
+    End,
+
+    /// Stretch the items to the size of the stack.
+    Fill,
+}
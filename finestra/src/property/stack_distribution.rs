@@ -0,0 +1,26 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// Specifies how the items of a [`Stack`](crate::Stack) are positioned on the
+/// main axis, i.e. vertically for a vertical stack, and horizontally for a
+/// horizontal stack, when the stack is larger than its contents.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StackDistribution {
+    /// Place the items at the start of the stack, i.e. the top or the left.
+    #[default]
+    Start,
+
+    /// Place the items in the center of the stack.
+    Center,
+
+    /// Place the items at the end of the stack, i.e. the bottom or the right.
+    End,
+
+    /// Place the first item at the start, the last item at the end, and
+    /// distribute the remaining space evenly between the items.
+    SpaceBetween,
+
+    /// Resize the items, such that each item is of equal size, and the items
+    /// fill the stack.
+    FillEqually,
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+/// The column by which the rows of a [`Table`](crate::Table) are sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    /// The index of the column.
+    pub column: usize,
+
+    /// Whether the rows are sorted from small to large, or the other way around.
+    pub order: SortOrder,
+}
+
+impl TableSort {
+    /// Sort by the given column, from the smallest to the largest value.
+    #[must_use]
+    pub const fn ascending(column: usize) -> Self {
+        Self { column, order: SortOrder::Ascending }
+    }
+
+    /// Sort by the given column, from the largest to the smallest value.
+    #[must_use]
+    pub const fn descending(column: usize) -> Self {
+        Self { column, order: SortOrder::Descending }
+    }
+
+    /// The sort after the user clicked the header of the `column`: clicking
+    /// the same column again reverses the order.
+    #[must_use]
+    pub(crate) fn after_click(current: Option<Self>, column: usize) -> Self {
+        match current {
+            Some(current) if current.column == column && current.order == SortOrder::Ascending => {
+                Self::descending(column)
+            }
+            _ => Self::ascending(column),
+        }
+    }
+}
+
+/// The order in which the rows of a [`Table`](crate::Table) are sorted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// From the smallest to the largest value.
+    #[default]
+    Ascending,
+
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicking_a_column_toggles_the_order() {
+        let sort = TableSort::after_click(None, 1);
+        assert_eq!(sort, TableSort::ascending(1));
+
+        let sort = TableSort::after_click(Some(sort), 1);
+        assert_eq!(sort, TableSort::descending(1));
+
+        let sort = TableSort::after_click(Some(sort), 1);
+        assert_eq!(sort, TableSort::ascending(1));
+
+        assert_eq!(TableSort::after_click(Some(TableSort::descending(0)), 1), TableSort::ascending(1));
+    }
+}
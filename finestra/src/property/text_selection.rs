@@ -0,0 +1,78 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::ops::Range;
+
+/// The selected text of a [`TextEditor`](crate::TextEditor), or the position
+/// of the caret when no text is selected. The offsets count characters
+/// (`char`s), not bytes.
+///
+/// ```
+/// # use finestra::TextSelection;
+/// let caret = TextSelection::caret_at(5);
+/// assert!(caret.is_empty());
+///
+/// // Selected from right to left, e.g. using Shift + Left.
+/// let selection = TextSelection::new(8, 3);
+/// assert_eq!(selection.range(), 3..8);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextSelection {
+    /// The offset where the selection started, which stays put while the
+    /// user extends the selection.
+    pub anchor: usize,
+
+    /// The offset of the caret, which is the end of the selection the user
+    /// moves.
+    pub caret: usize,
+}
+
+impl TextSelection {
+    /// Select the text between the `anchor` and the `caret`.
+    #[must_use]
+    pub const fn new(anchor: usize, caret: usize) -> Self {
+        Self { anchor, caret }
+    }
+
+    /// Place the caret at the `offset`, without selecting text.
+    #[must_use]
+    pub const fn caret_at(offset: usize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    /// Returns `true` if no text is selected.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.anchor == self.caret
+    }
+
+    /// Get the offsets of the selected characters, from left to right.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.caret)..self.anchor.max(self.caret)
+    }
+
+    /// Limits the offsets to the `length` of the text, in characters.
+    #[must_use]
+    pub(crate) fn clamped(self, length: usize) -> Self {
+        Self::new(self.anchor.min(length), self.caret.min(length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_is_ordered() {
+        assert_eq!(TextSelection::new(2, 6).range(), 2..6);
+        assert_eq!(TextSelection::new(6, 2).range(), 2..6);
+        assert_eq!(TextSelection::caret_at(4).range(), 4..4);
+    }
+
+    #[test]
+    fn clamped_to_text() {
+        assert_eq!(TextSelection::new(2, 10).clamped(5), TextSelection::new(2, 5));
+        assert_eq!(TextSelection::new(10, 12).clamped(5), TextSelection::caret_at(5));
+    }
+}
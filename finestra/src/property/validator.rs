@@ -0,0 +1,207 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::State;
+
+type Parse<T> = dyn Fn(&str) -> Result<T, String>;
+
+/// Checks the text the user entered into a [`TextField`](crate::TextField),
+/// and converts it into a value. When the text isn't valid, the validator
+/// returns the message that is shown to the user.
+///
+/// ```
+/// # use finestra::Validator;
+/// let even = Validator::new(|text| match text.parse::<u32>() {
+///     Ok(number) if number % 2 == 0 => Ok(number),
+///     _ => Err("Enter an even number".to_owned()),
+/// });
+///
+/// assert_eq!(even.validate("42"), Ok(42));
+/// assert_eq!(even.validate("7"), Err("Enter an even number".to_owned()));
+/// ```
+pub struct Validator<T> {
+    parse: Rc<Parse<T>>,
+}
+
+impl<T> Validator<T> {
+    /// Create a validator that converts the text using `parse`, which returns
+    /// the message for the user when the text isn't valid.
+    #[must_use]
+    pub fn new(parse: impl Fn(&str) -> Result<T, String> + 'static) -> Self {
+        Self {
+            parse: Rc::new(parse),
+        }
+    }
+
+    /// Check the `text`, and convert it into a value.
+    ///
+    /// ## Errors
+    /// Returns the message for the user when the text isn't valid.
+    pub fn validate(&self, text: &str) -> Result<T, String> {
+        (self.parse)(text)
+    }
+}
+
+impl Validator<i64> {
+    /// Accepts whole numbers, such as `-12` and `42`.
+    #[must_use]
+    pub fn integer() -> Self {
+        Self::new(|text| text.trim().parse().map_err(|_| "Enter a whole number".to_owned()))
+    }
+}
+
+impl Validator<f64> {
+    /// Accepts finite numbers, such as `3`, `-0.5` and `1e3`.
+    #[must_use]
+    pub fn float() -> Self {
+        Self::new(|text| {
+            text.trim()
+                .parse()
+                .ok()
+                .filter(|number: &f64| number.is_finite())
+                .ok_or_else(|| "Enter a number".to_owned())
+        })
+    }
+}
+
+impl Validator<String> {
+    /// Accepts email addresses, such as `tristan@thewoosh.org`. Only the shape
+    /// of the address is checked: a name, an `@` and a domain with a dot.
+    #[must_use]
+    pub fn email() -> Self {
+        Self::new(|text| {
+            let address = text.trim();
+            is_email_address(address)
+                .then(|| address.to_owned())
+                .ok_or_else(|| "Enter an email address".to_owned())
+        })
+    }
+
+    /// Accepts the text when it matches the `regex`, which should be anchored
+    /// using `^` and `$` to match the whole text. Otherwise, the `message` is
+    /// shown to the user.
+    ///
+    /// ```
+    /// # use finestra::{regex::Regex, Validator};
+    /// let postal_code = Validator::regex(Regex::new("^[0-9]{4} ?[A-Z]{2}$").unwrap(), "Enter a postal code, like 1234 AB");
+    /// assert!(postal_code.validate("1234 AB").is_ok());
+    /// assert!(postal_code.validate("1234 AB 5").is_err());
+    /// ```
+    #[cfg(feature = "regex")]
+    #[must_use]
+    pub fn regex(regex: regex::Regex, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(move |text| {
+            regex.is_match(text)
+                .then(|| text.to_owned())
+                .ok_or_else(|| message.clone())
+        })
+    }
+}
+
+impl<T> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            parse: Rc::clone(&self.parse),
+        }
+    }
+}
+
+fn is_email_address(address: &str) -> bool {
+    let Some((name, domain)) = address.split_once('@') else {
+        return false;
+    };
+
+    !name.is_empty()
+        && !address.contains(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+/// The [`Validator`] of a [`TextField`](crate::TextField), which stores the
+/// valid values in the bound state, and the message of invalid text in the
+/// `error`.
+#[derive(Clone)]
+pub(crate) struct Validation {
+    check: Rc<Parse<()>>,
+    pub(crate) error: State<Option<String>>,
+}
+
+impl Validation {
+    pub(crate) fn new<T: 'static>(validator: Validator<T>, value: &State<T>) -> Self {
+        let value = value.clone();
+        Self {
+            check: Rc::new(move |text| validator.validate(text).map(|valid| value.set(valid))),
+            error: State::new(None),
+        }
+    }
+
+    /// Validates the text the user entered.
+    pub(crate) fn validate(&self, text: &str) {
+        let error = (self.check)(text).err();
+        if self.error.with(|current| *current != error) {
+            self.error.set(error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer() {
+        let validator = Validator::integer();
+        assert_eq!(validator.validate("42"), Ok(42));
+        assert_eq!(validator.validate(" -12 "), Ok(-12));
+        assert!(validator.validate("").is_err());
+        assert!(validator.validate("4.2").is_err());
+        assert!(validator.validate("42a").is_err());
+    }
+
+    #[test]
+    fn float() {
+        let validator = Validator::float();
+        assert_eq!(validator.validate("-0.5"), Ok(-0.5));
+        assert_eq!(validator.validate("1e3"), Ok(1000.0));
+        assert!(validator.validate("inf").is_err());
+        assert!(validator.validate("NaN").is_err());
+        assert!(validator.validate("one").is_err());
+    }
+
+    #[test]
+    fn email() {
+        let validator = Validator::email();
+        assert_eq!(validator.validate(" tristan@thewoosh.org "), Ok("tristan@thewoosh.org".to_owned()));
+        assert!(validator.validate("first.last@mail.example.com").is_ok());
+
+        for invalid in ["", "tristan", "@thewoosh.org", "tristan@", "tristan@localhost", "tristan@thewoosh.", "a b@thewoosh.org", "a@b@thewoosh.org"] {
+            assert_eq!(validator.validate(invalid), Err("Enter an email address".to_owned()), "{invalid}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex() {
+        let validator = Validator::regex(regex::Regex::new("^[a-z]+$").unwrap(), "Enter lowercase letters");
+        assert!(validator.validate("finestra").is_ok());
+        assert_eq!(validator.validate("Finestra"), Err("Enter lowercase letters".to_owned()));
+    }
+
+    #[test]
+    fn validation_only_stores_valid_values() {
+        let value = State::new(0);
+        let validation = Validation::new(Validator::integer(), &value);
+
+        validation.validate("12");
+        assert_eq!(value.clone_inner(), 12);
+        assert_eq!(validation.error.clone_inner(), None);
+
+        validation.validate("12x");
+        assert_eq!(value.clone_inner(), 12);
+        assert_eq!(validation.error.clone_inner(), Some("Enter a whole number".to_owned()));
+    }
+}
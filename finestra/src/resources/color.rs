@@ -33,8 +33,9 @@
 /// let blue = Color::rgb(0x00, 0x00, 0xFF);
 /// let translucent_red = Color::rgba(255, 0, 0, 127);
 /// ```
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Color {
+    #[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
     kind: ColorKind,
 }
 
@@ -72,8 +73,30 @@ impl Color {
         }
     }
 
+    /// Create a color from the components in the range `0.0..=1.0`, as used by
+    /// the platforms. Opaque colors are RGB colors.
+    #[must_use]
+    #[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
+    pub(crate) fn from_components(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        let component = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        match component(alpha) {
+            255 => Self::rgb(component(red), component(green), component(blue)),
+            alpha => Self::rgba(component(red), component(green), component(blue), alpha),
+        }
+    }
+
+    /// Returns the color without its opacity.
+    #[must_use]
+    pub(crate) fn without_alpha(&self) -> Self {
+        match self.kind {
+            ColorKind::Rgba { red, green, blue, .. } => Self::rgb(red, green, blue),
+            _ => self.clone(),
+        }
+    }
+
     #[inline]
     #[must_use]
+    #[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
     /// Returns the associated [`ColorKind`].
     pub(crate) const fn kind(&self) -> &ColorKind {
         &self.kind
@@ -141,7 +164,8 @@ pub enum SystemColor {
     Gray,
 }
 
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
 pub(crate) enum ColorKind {
     /// A context-dependent default color.
     #[default]
@@ -164,6 +188,26 @@ pub(crate) enum ColorKind {
     },
 }
 
+impl SystemColor {
+    /// The named colors, which color pickers offer as swatches.
+    #[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
+    pub(crate) const SWATCHES: [SystemColor; 13] = [
+        SystemColor::Black,
+        SystemColor::Gray,
+        SystemColor::White,
+        SystemColor::Brown,
+        SystemColor::Red,
+        SystemColor::Orange,
+        SystemColor::Yellow,
+        SystemColor::Green,
+        SystemColor::Teal,
+        SystemColor::Blue,
+        SystemColor::Indigo,
+        SystemColor::Purple,
+        SystemColor::Pink,
+    ];
+}
+
 impl From<ColorKind> for Color {
     fn from(value: ColorKind) -> Self {
         Color {
@@ -183,3 +227,21 @@ impl From<SystemColor> for Color {
         Color::system(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_components() {
+        assert_eq!(Color::from_components(1.0, 0.5, 0.0, 1.0), Color::rgb(255, 128, 0));
+        assert_eq!(Color::from_components(0.0, 0.0, 1.0, 0.5), Color::rgba(0, 0, 255, 128));
+        assert_eq!(Color::from_components(1.2, -0.1, 0.0, 1.0), Color::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn without_alpha() {
+        assert_eq!(Color::rgba(1, 2, 3, 4).without_alpha(), Color::rgb(1, 2, 3));
+        assert_eq!(Color::system(SystemColor::Red).without_alpha(), Color::system(SystemColor::Red));
+    }
+}
@@ -3,7 +3,8 @@
 
 use std::time::Duration;
 
-use crate::Timer;
+use crate::platform::headless::HeadlessContext;
+use crate::{AppContext, Timer, UIBackend};
 
 /// The cursor is the pointer that the user can move around with the mouse.
 ///
@@ -47,28 +48,75 @@ impl Cursor {
             cursor.pop_internal();
         }).schedule_once();
     }
+
+    fn push_internal(&self) {
+        if AppContext::backend() == UIBackend::Headless {
+            HeadlessContext::push_cursor(self.clone());
+        } else {
+            self.push_native();
+        }
+    }
+
+    fn pop_internal(&self) {
+        if AppContext::backend() == UIBackend::Headless {
+            HeadlessContext::pop_cursor();
+        } else {
+            self.pop_native();
+        }
+    }
 }
 
 #[cfg(target_os = "macos")]
 impl Cursor {
-    fn push_internal(&self) {
+    fn push_native(&self) {
         use cacao::appkit::Cursor as CacaoCursor;
         CacaoCursor::push(self.clone().into());
     }
 
-    fn pop_internal(&self) {
+    fn pop_native(&self) {
         use cacao::appkit::Cursor as CacaoCursor;
         CacaoCursor::pop();
     }
 }
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(all(target_os = "linux", feature = "gtk"))]
 impl Cursor {
-    fn push_internal(&self) {
+    thread_local! {
+        static GTK_STACK: std::cell::RefCell<Vec<Cursor>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    fn push_native(&self) {
+        Self::GTK_STACK.with_borrow_mut(|stack| stack.push(self.clone()));
+        Self::apply_gtk(self);
+    }
+
+    fn pop_native(&self) {
+        let top = Self::GTK_STACK.with_borrow_mut(|stack| {
+            stack.pop();
+            stack.last().cloned().unwrap_or_default()
+        });
+        Self::apply_gtk(&top);
+    }
+
+    /// GTK sets cursors per widget, so the cursor is applied to every window.
+    fn apply_gtk(cursor: &Cursor) {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::ToGtk;
+
+        let name: &str = cursor.to_gtk();
+        for window in gtk4::Window::list_toplevels() {
+            window.set_cursor_from_name(Some(name));
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))))]
+impl Cursor {
+    fn push_native(&self) {
         todo!();
     }
 
-    fn pop_internal(&self) {
+    fn pop_native(&self) {
         todo!();
     }
 }
@@ -125,8 +173,8 @@ pub enum SystemCursor {
     /// * Windows: [IDC_IBEAM](https://learn.microsoft.com/en-us/windows/win32/menurc/about-cursors)
     IBeam,
 
-    /// The (open) hand cursor, often used for clickable components, like links
-    /// and buttons.
+    /// The pointing hand cursor, often used for clickable components, like
+    /// links.
     ///
     /// * macOS: [pointingHandCursor](https://developer.apple.com/documentation/appkit/nscursor/1531896-pointinghandcursor)
     /// * Windows: [IDC_HAND](https://learn.microsoft.com/en-us/windows/win32/menurc/about-cursors)
@@ -205,7 +253,7 @@ impl From<SystemCursor> for cacao::appkit::CursorType {
 
             SystemCursor::Arrow => Self::Arrow,
             SystemCursor::CrossHair => Self::Crosshair,
-            SystemCursor::Hand => Self::OpenHand,
+            SystemCursor::Hand => Self::PointingHand,
             SystemCursor::IBeam => Self::IBeam,
             SystemCursor::NotAllowed => Self::OperationNotAllowed,
             SystemCursor::ResizeDown => Self::ResizeDown,
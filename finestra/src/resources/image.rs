@@ -4,7 +4,7 @@
 use std::path::PathBuf;
 
 /// An image that can be used in an [ImageView](crate::ImageView).
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Image {
     kind: ImageKind,
 }
@@ -26,7 +26,7 @@ impl Image {
     }
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) enum ImageKind {
     #[default]
     None,
@@ -59,16 +59,12 @@ impl MenuBar {
         }
     }
 
+    #[cfg_attr(not(target_os = "macos"), allow(unused))]
     pub(crate) fn menu_opt(&mut self, name: &str) -> Option<&mut Menu> {
-        for menu in &mut self.menus {
-            if menu.name == name {
-                return Some(menu);
-            }
-        }
-
-        None
+        self.menus.iter_mut().find(|menu| menu.name == name)
     }
 
+    #[cfg_attr(not(target_os = "macos"), allow(unused))]
     pub(crate) fn ensure_menu_at(&mut self, name: impl Into<String>, pos: usize) -> &mut Menu {
         let name = name.into();
 
@@ -38,6 +38,27 @@ impl<F> Timer<F>
         match AppContext::backend() {
             UIBackend::AppKit => schedule_app_kit(self),
             UIBackend::Win32 => schedule_win32(self),
+            UIBackend::Gtk => schedule_gtk(self),
+            UIBackend::Headless => crate::platform::headless::schedule_timer(self),
+        }
+    }
+
+    /// Schedule the timer on the thread of the event loop, which allows the
+    /// action to use the views and the [`State`](crate::State), which aren't
+    /// `Send`.
+    pub(crate) fn schedule_local(self) {
+        match AppContext::backend() {
+            #[cfg(target_os = "macos")]
+            UIBackend::AppKit => crate::platform::macos::NSTimer::from(self).run(),
+            #[cfg(target_os = "windows")]
+            UIBackend::Win32 => crate::platform::win32::dispatch::schedule_local_timer(self),
+            #[cfg(all(target_os = "linux", feature = "gtk"))]
+            UIBackend::Gtk => crate::platform::gtk::schedule_local_timer(self),
+            UIBackend::Headless => crate::platform::headless::schedule_timer(self),
+
+            // The apps of the other backends can't be launched on this
+            // platform, so there is no event loop to schedule the timer on.
+            backend => unreachable!("{backend:?} isn't available on this platform"),
         }
     }
 }
@@ -65,6 +86,16 @@ fn schedule_win32<F: FnOnce() + Send + 'static>(timer: Timer<F>) {
     default_schedule_timer(timer);
 }
 
+#[cfg(all(target_os = "linux", feature = "gtk"))]
+fn schedule_gtk<F: FnOnce() + Send + 'static>(timer: Timer<F>) {
+    crate::platform::gtk::schedule_timer(timer);
+}
+
+#[cfg(not(all(target_os = "linux", feature = "gtk")))]
+fn schedule_gtk<F: FnOnce() + Send + 'static>(timer: Timer<F>) {
+    default_schedule_timer(timer);
+}
+
 fn default_schedule_timer<F: FnOnce() + Send + 'static>(timer: Timer<F>) {
     std::thread::Builder::new()
         .name("Finestra Timer Thread".into())
@@ -144,7 +144,7 @@ unsafe impl<T> Sync for State<T> {}
 /// A convenient wrapper for [`State`] or the "raw" value. A bunch of APIs let
 /// you call them with either of them, and this wrapper provides easy [`Into`]
 /// implementations.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum StateOrRaw<T> {
     /// A raw value is a value that is applied once, but can't be updated using
     /// the [State] pattern.
@@ -0,0 +1,1079 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! UI automation for testing Finestra apps without a screen.
+//!
+//! The [`TestApp`] launches an [`App`] using the [`UIBackend::Headless`], and
+//! lets you find views and interact with them like a user would. The
+//! interactions are routed through the same event handlers as on the native
+//! backends, so [`Button::with_on_click()`](crate::Button::with_on_click) and
+//! friends are invoked, and the [`State`](crate::State) objects are updated.
+//!
+//! ```
+//! # use finestra::*;
+//! # use finestra::testing::TestApp;
+//! struct CounterApp;
+//!
+//! #[derive(Default)]
+//! struct CounterState {
+//!     count: usize,
+//!     label: TextValue,
+//! }
+//!
+//! impl AppDelegate<CounterState> for CounterApp {
+//!     fn make_content_view(&mut self, state: &mut CounterState, _: Window) -> impl View<Self, CounterState> {
+//!         state.label.set("Count: 0");
+//!
+//!         Stack::vertical()
+//!             .with(Label::new(&state.label)
+//!                 .with_id("counter"))
+//!             .with(Button::new("Increment")
+//!                 .with_on_click(|state: &mut CounterState, _| {
+//!                     state.count += 1;
+//!                     state.label.set(format!("Count: {}", state.count));
+//!                 }))
+//!     }
+//! }
+//!
+//! let app = TestApp::launch(App::with_state(CounterApp, CounterState::default()));
+//!
+//! app.find_by_text("Increment").unwrap().click();
+//!
+//! assert_eq!(app.with_state(|state| state.count), 1);
+//! assert_eq!(app.find_by_id("counter").unwrap().text(), "Count: 1");
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Range;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::graphics::raster::Pixmap;
+use crate::platform::headless::{Event, HeadlessApp, HeadlessContext, HeadlessView, HeadlessViewKind};
+use crate::{App, AppDelegate, Color, Cursor, DrawCommand, Key, MenuItemKind, TextSelection, UIBackend, View, Window};
+
+type ProcessEvents = dyn Fn();
+
+/// An [`App`] that is launched for testing. See the
+/// [module-level documentation](self) for an example.
+pub struct TestApp<Delegate, State: 'static> {
+    app: Rc<RefCell<HeadlessApp<Delegate, State>>>,
+}
+
+impl<Delegate, State: 'static> TestApp<Delegate, State>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Launch the app using the [`UIBackend::Headless`], regardless of the
+    /// backend the [`App`] was configured with. The [`AppDelegate`] is invoked
+    /// up until (and including) [`AppDelegate::will_show_window()`].
+    #[must_use]
+    pub fn launch(app: App<Delegate, State>) -> Self {
+        let app = HeadlessApp::launch(app.with_backend(UIBackend::Headless));
+
+        Self {
+            app: Rc::new(RefCell::new(app)),
+        }
+    }
+
+    /// The number of views that have event handlers registered, which
+    /// shouldn't grow when views are replaced.
+    #[cfg(test)]
+    pub(crate) fn event_handler_count(&self) -> usize {
+        self.app.borrow().event_handler_count()
+    }
+
+    /// Inspect the state of the app.
+    pub fn with_state<F: FnOnce(&State) -> R, R>(&self, f: F) -> R {
+        f(self.app.borrow().state())
+    }
+
+    /// Inspect the delegate of the app.
+    pub fn with_delegate<F: FnOnce(&Delegate) -> R, R>(&self, f: F) -> R {
+        f(self.app.borrow().delegate())
+    }
+
+    /// Find the first view that displays the given `text`, such as a
+    /// [`Label`](crate::Label) or a [`Button`](crate::Button).
+    #[must_use]
+    pub fn find_by_text(&self, text: &str) -> Option<TestView> {
+        self.find(|view| view.with(|data| data.text == text))
+    }
+
+    /// Find the first view with the given tooltip.
+    #[must_use]
+    pub fn find_by_tooltip(&self, tooltip: &str) -> Option<TestView> {
+        self.find(|view| view.with(|data| data.tooltip == tooltip))
+    }
+
+    /// Find the view that was given this identifier using
+    /// [`BaseViewExt::with_id()`](crate::BaseViewExt::with_id).
+    #[must_use]
+    pub fn find_by_id(&self, id: &str) -> Option<TestView> {
+        self.find(|view| view.with(|data| data.user_id.as_deref() == Some(id)))
+    }
+
+    /// Get the root view of the window, i.e. the view returned by
+    /// [`AppDelegate::make_content_view()`].
+    #[must_use]
+    pub fn root(&self) -> TestView {
+        TestView {
+            view: self.app.borrow().root().clone(),
+            process_events: self.process_events(),
+        }
+    }
+
+    /// Invokes the item with the given `title` in the menu named `menu`, as if
+    /// the user selected it in the menu bar.
+    ///
+    /// ## Panics
+    /// When the menu bar of the window doesn't contain this item.
+    #[track_caller]
+    pub fn invoke_menu_item(&self, menu: &str, title: &str) {
+        let item = self.app.borrow().window().with(|data| {
+            data.menubar.menus.iter()
+                .filter(|x| x.name == menu)
+                .flat_map(|x| x.items.iter())
+                .find(|item| matches!(&item.kind, MenuItemKind::Titled(x) if x == title))
+                .cloned()
+        });
+
+        let Some(item) = item else {
+            panic!("the menu bar doesn't contain the item \"{title}\" in the menu \"{menu}\"");
+        };
+
+        HeadlessContext::dispatch(Event::MenuAction { item });
+        self.app.borrow_mut().process_events();
+    }
+
+    /// Get the URLs that were opened, e.g. by clicking a [`Link`](crate::Link),
+    /// in order. The headless backend doesn't open them in a browser, unless
+    /// the app was given an opener using
+    /// [`App::with_url_opener()`](crate::App::with_url_opener), which
+    /// receives them instead.
+    #[must_use]
+    pub fn opened_urls(&self) -> Vec<String> {
+        HeadlessContext::opened_urls()
+    }
+
+    /// Get the color that is initially selected in the color dialog that the
+    /// app opened using [`Window::pick_color()`](crate::Window::pick_color),
+    /// or [`None`] if no color dialog is open.
+    #[must_use]
+    pub fn color_dialog(&self) -> Option<Color> {
+        self.app.borrow().window().with(|data| {
+            data.color_dialog.as_ref().map(|dialog| dialog.color.clone())
+        })
+    }
+
+    /// Close the open color dialog, as if the user picked the `color`, or
+    /// cancelled the dialog when it is [`None`], and handle the resulting
+    /// events.
+    ///
+    /// ## Panics
+    /// When no color dialog is open.
+    #[track_caller]
+    pub fn answer_color_dialog(&self, color: Option<Color>) {
+        let dialog = self.app.borrow().window().with_mut(|data| data.color_dialog.take());
+        let Some(dialog) = dialog else {
+            panic!("there is no color dialog to answer");
+        };
+
+        if let Some(color) = color {
+            // Like a user, the test can't pick the opacity when it's disabled.
+            (dialog.on_pick)(if dialog.alpha { color } else { color.without_alpha() });
+        }
+
+        self.app.borrow_mut().process_events();
+    }
+
+    /// Advance the virtual clock of the app by `duration`, and invoke the
+    /// [`Timer`](crate::Timer)s that are due by then, in order. The events of
+    /// every timer are handled before the next one is invoked.
+    ///
+    /// Timers aren't invoked otherwise, so the test decides how much time
+    /// passes between the interactions.
+    pub fn advance_time(&self, duration: Duration) {
+        let deadline = HeadlessContext::now() + duration;
+        while HeadlessContext::fire_next_timer_due_by(deadline) {
+            self.app.borrow_mut().process_events();
+        }
+
+        HeadlessContext::advance_clock_to(deadline);
+    }
+
+    /// Resize the window, as if the user dragged its edges, and lay out the
+    /// views again.
+    pub fn resize(&self, width: f32, height: f32) {
+        let app = self.app.borrow();
+        app.window().with_mut(|data| {
+            data.width = width;
+            data.height = height;
+        });
+        app.layout();
+    }
+
+    fn find(&self, predicate: impl Fn(&HeadlessView) -> bool) -> Option<TestView> {
+        let view = self.app.borrow().root().find(&predicate)?;
+        Some(TestView {
+            view,
+            process_events: self.process_events(),
+        })
+    }
+
+    fn process_events(&self) -> Rc<ProcessEvents> {
+        let app = Rc::clone(&self.app);
+        Rc::new(move || {
+            app.borrow_mut().process_events();
+        })
+    }
+}
+
+type MakeView<Event> = dyn FnMut() -> Box<dyn View<ViewApp<Event>, Vec<Event>>>;
+
+/// A [`TestApp`] launched using [`TestApp::launch_view()`].
+pub type ViewTestApp<Event> = TestApp<ViewApp<Event>, Vec<Event>>;
+
+/// The [`AppDelegate`] of the apps launched using [`TestApp::launch_view()`].
+/// Its state is the list of events the event handlers of the view pushed.
+pub struct ViewApp<Event: 'static> {
+    make_view: Box<MakeView<Event>>,
+}
+
+impl<Event: 'static> AppDelegate<Vec<Event>> for ViewApp<Event> {
+    fn make_content_view(&mut self, _: &mut Vec<Event>, _: Window) -> impl View<Self, Vec<Event>> {
+        (self.make_view)()
+    }
+}
+
+impl<Event: 'static> TestApp<ViewApp<Event>, Vec<Event>> {
+    /// Launch an app that displays the view made by `make_view`, without
+    /// writing an [`AppDelegate`] for it. The state of the app is a [`Vec`],
+    /// to which the event handlers of the view can push their events.
+    ///
+    /// ```
+    /// # use finestra::*;
+    /// # use finestra::testing::TestApp;
+    /// let app = TestApp::launch_view(|| {
+    ///     Button::new("Save")
+    ///         .with_on_click(|events: &mut Vec<&str>, _| events.push("saved"))
+    /// });
+    ///
+    /// app.find_by_text("Save").unwrap().click();
+    /// assert_eq!(app.events(), ["saved"]);
+    /// ```
+    #[must_use]
+    pub fn launch_view<V>(mut make_view: impl FnMut() -> V + 'static) -> Self
+            where V: Into<Box<dyn View<ViewApp<Event>, Vec<Event>>>> {
+        let delegate = ViewApp {
+            make_view: Box::new(move || make_view().into()),
+        };
+
+        Self::launch(App::with_state(delegate, Vec::new()))
+    }
+
+    /// Get the events the event handlers pushed, in the order they were
+    /// pushed.
+    #[must_use]
+    pub fn events(&self) -> Vec<Event>
+            where Event: Clone {
+        self.with_state(Clone::clone)
+    }
+}
+
+/// A handle to a view in a [`TestApp`], which can be used to inspect the view,
+/// and to interact with it.
+#[derive(Clone)]
+pub struct TestView {
+    view: HeadlessView,
+    process_events: Rc<ProcessEvents>,
+}
+
+impl TestView {
+    /// Get the text of the view, e.g. the title of a [`Button`](crate::Button)
+    /// or the contents of a [`TextField`](crate::TextField).
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.view.with(|data| data.text.clone())
+    }
+
+    /// Get the tooltip of the view.
+    #[must_use]
+    pub fn tooltip(&self) -> String {
+        self.view.with(|data| data.tooltip.clone())
+    }
+
+    /// Get the placeholder of the view, e.g. of a [`TextField`](crate::TextField).
+    #[must_use]
+    pub fn placeholder(&self) -> String {
+        self.view.with(|data| data.placeholder.clone())
+    }
+
+    /// Get the value of a [`Slider`](crate::Slider).
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.view.with(|data| data.value)
+    }
+
+    /// Get the progress of a [`ProgressBar`](crate::ProgressBar), which is
+    /// [`None`] while the progress is unknown.
+    #[must_use]
+    pub fn progress(&self) -> Option<f64> {
+        self.view.with(|data| data.progress)
+    }
+
+    /// Get the color of the text of the view.
+    #[must_use]
+    pub fn text_color(&self) -> Color {
+        self.view.with(|data| data.text_color.clone())
+    }
+
+    /// Get the cursor that is shown when the mouse hovers over the view, or
+    /// [`None`] if the view doesn't change the cursor.
+    #[must_use]
+    pub fn cursor(&self) -> Option<Cursor> {
+        self.view.with(|data| data.cursor.clone())
+    }
+
+    /// Get the color of a [`ColorWell`](crate::ColorWell).
+    #[must_use]
+    pub fn color(&self) -> Color {
+        self.view.with(|data| data.background_color.clone())
+    }
+
+    /// Get the identifier given using
+    /// [`BaseViewExt::with_id()`](crate::BaseViewExt::with_id).
+    #[must_use]
+    pub fn id(&self) -> Option<String> {
+        self.view.with(|data| data.user_id.clone())
+    }
+
+    /// Returns `true` if the view is a checked [`Checkbox`](crate::Checkbox),
+    /// a [`Switch`](crate::Switch) that is on, or the chosen option of a
+    /// [`RadioGroup`](crate::RadioGroup).
+    #[must_use]
+    pub fn is_checked(&self) -> bool {
+        self.view.with(|data| data.checked)
+    }
+
+    /// Returns `true` if the user can interact with the view. A disabled view
+    /// ignores [`click()`](Self::click) and [`toggle()`](Self::toggle).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.view.with(|data| data.enabled)
+    }
+
+    /// Returns `true` if the view is a [secure](crate::TextField::with_secure)
+    /// text field of which the text is masked, i.e. not revealed. The
+    /// [`text()`](Self::text) is the clear text regardless.
+    #[must_use]
+    pub fn is_masked(&self) -> bool {
+        self.view.with(|data| data.secure && !data.revealed)
+    }
+
+    /// Get the message of the [`Validator`](crate::Validator) of a
+    /// [`TextField`](crate::TextField), when the text isn't valid.
+    #[must_use]
+    pub fn validation_error(&self) -> Option<String> {
+        self.view.with(|data| data.validation_error.clone())
+    }
+
+    /// Returns `true` if the view has the keyboard focus.
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        HeadlessContext::focused() == Some(self.view.id())
+    }
+
+    /// Get the frame of the view in window coordinates, as computed by the
+    /// layout pass. The headless backend measures text using fixed metrics:
+    /// every character is 8 points wide, and every line is 20 points high.
+    #[must_use]
+    pub fn frame(&self) -> euclid::default::Rect<f32> {
+        self.view.with(|data| data.frame)
+    }
+
+    /// Get the distance the content of a [`ScrollView`](crate::ScrollView) is
+    /// scrolled from the top left corner.
+    #[must_use]
+    pub fn scroll_offset(&self) -> euclid::default::Vector2D<f32> {
+        self.view.scroll_offset()
+    }
+
+    /// Get the indices of the selected rows of a [`List`](crate::List) or a
+    /// [`Table`](crate::Table), in ascending order.
+    #[must_use]
+    pub fn selected_rows(&self) -> Vec<usize> {
+        self.view.with(|data| data.selection.clone())
+    }
+
+    /// Get the selected text, or the position of the caret, of a
+    /// [`TextEditor`](crate::TextEditor).
+    #[must_use]
+    pub fn text_selection(&self) -> TextSelection {
+        self.view.with(|data| data.text_selection)
+    }
+
+    /// Get the titles of the options of a [`Select`](crate::Select), or the
+    /// recent searches of a [`SearchField`](crate::SearchField).
+    #[must_use]
+    pub fn options(&self) -> Vec<String> {
+        self.view.with(|data| data.options.clone())
+    }
+
+    /// Get the index of the selected option of a [`Select`](crate::Select).
+    #[must_use]
+    pub fn selected_option(&self) -> Option<usize> {
+        self.view.with(|data| data.selection.first().copied())
+    }
+
+    /// Get the titles of the columns of a [`Table`](crate::Table).
+    #[must_use]
+    pub fn column_titles(&self) -> Vec<String> {
+        self.view.with(|data| data.columns.iter().map(|column| column.title.clone()).collect())
+    }
+
+    /// Get the titles of the tabs of a [`TabView`](crate::TabView).
+    #[must_use]
+    pub fn tab_titles(&self) -> Vec<String> {
+        self.view.with(|data| data.tabs.iter().map(|tab| tab.title.clone()).collect())
+    }
+
+    /// Get the index of the selected tab of a [`TabView`](crate::TabView).
+    #[must_use]
+    pub fn selected_tab(&self) -> usize {
+        self.view.with(|data| data.selected_tab)
+    }
+
+    /// Get the child views of a container view, such as a
+    /// [`Stack`](crate::Stack).
+    #[must_use]
+    pub fn children(&self) -> Vec<TestView> {
+        self.view.children()
+            .into_iter()
+            .map(|view| TestView {
+                view,
+                process_events: Rc::clone(&self.process_events),
+            })
+            .collect()
+    }
+
+    /// Render the [`Canvas`](crate::Canvas) at the size of its frame, using
+    /// the software renderer of the headless backend. Like a native view, the
+    /// canvas is only painted again when its size changed, or when it was
+    /// [invalidated](crate::ViewHandle::invalidate).
+    ///
+    /// ## Panics
+    /// When this view isn't a canvas.
+    #[must_use]
+    #[track_caller]
+    pub fn snapshot(&self) -> Snapshot {
+        self.expect_kind(HeadlessViewKind::Canvas, "take a snapshot of");
+        let size = self.view.with(|data| data.frame.size);
+        Snapshot {
+            pixmap: Pixmap::render(size, &self.view.drawing()),
+        }
+    }
+
+    /// Get the text drawn on the [`Canvas`](crate::Canvas) using
+    /// [`DrawingContext::draw_text()`](crate::DrawingContext::draw_text),
+    /// in order. The software renderer doesn't paint text, so this can be
+    /// used to test the text instead.
+    ///
+    /// ## Panics
+    /// When this view isn't a canvas.
+    #[must_use]
+    #[track_caller]
+    pub fn drawn_text(&self) -> Vec<String> {
+        self.expect_kind(HeadlessViewKind::Canvas, "get the text of");
+        self.view.drawing()
+            .into_iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Click the [`Button`](crate::Button) or the [`Link`](crate::Link), and
+    /// handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a button or a link.
+    #[track_caller]
+    pub fn click(&self) {
+        let kind = self.view.kind();
+        assert!(matches!(kind, HeadlessViewKind::Button | HeadlessViewKind::Link), "can't click this view: {:?}", self.view);
+        self.view.perform_action();
+        (self.process_events)();
+    }
+
+    /// Toggle the [`Checkbox`](crate::Checkbox) or the
+    /// [`Switch`](crate::Switch), and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a checkbox or a switch.
+    #[track_caller]
+    pub fn toggle(&self) {
+        let kind = self.view.kind();
+        assert!(matches!(kind, HeadlessViewKind::Checkbox | HeadlessViewKind::Switch), "can't toggle this view: {:?}", self.view);
+        self.view.perform_action();
+        (self.process_events)();
+    }
+
+    /// Choose this option of a [`RadioGroup`](crate::RadioGroup), which are
+    /// the [`children()`](Self::children) of the group, and handle the
+    /// resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't an option of a radio group.
+    #[track_caller]
+    pub fn choose(&self) {
+        self.expect_kind(HeadlessViewKind::RadioButton, "choose");
+        self.view.perform_action();
+        (self.process_events)();
+    }
+
+    /// Type the `text` at the end of the [`TextField`](crate::TextField), of
+    /// the [`SearchField`](crate::SearchField), of an editable [`Select`](crate::Select), or of a
+    /// [`Stepper`](crate::Stepper). In a [`TextEditor`](crate::TextEditor),
+    /// the text replaces the selection instead. Like a user typing, the text
+    /// is entered character by character, so the change handler is invoked
+    /// for every character.
+    ///
+    /// ## Panics
+    /// When this view isn't a text field, an editable select, a stepper or an
+    /// editable text editor.
+    #[track_caller]
+    pub fn type_text(&self, text: &str) {
+        self.expect_editable("type text into");
+
+        if self.view.kind() == HeadlessViewKind::TextEditor {
+            let mut buffer = [0; 4];
+            for c in text.chars() {
+                self.view.insert_text(c.encode_utf8(&mut buffer));
+                (self.process_events)();
+            }
+            return;
+        }
+
+        let mut current = self.text();
+        for c in text.chars() {
+            current.push(c);
+            self.view.change_text(&current);
+            (self.process_events)();
+        }
+    }
+
+    /// Remove all the text of the view, as if the user selected it and
+    /// pressed backspace, and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When the text of this view can't be edited, see
+    /// [`type_text()`](Self::type_text).
+    #[track_caller]
+    pub fn clear_text(&self) {
+        self.expect_editable("clear the text of");
+        self.view.change_text("");
+        self.view.clamp_text_selection();
+        (self.process_events)();
+    }
+
+    /// Select the characters in `range` of the [`TextEditor`](crate::TextEditor),
+    /// or place the caret when the range is empty, and handle the resulting
+    /// events. The range is clamped to the text.
+    ///
+    /// ## Panics
+    /// When this view isn't a text editor, or is disabled.
+    #[track_caller]
+    pub fn select_text(&self, range: Range<usize>) {
+        self.expect_kind(HeadlessViewKind::TextEditor, "select text of");
+        assert!(self.view.select_text(TextSelection::new(range.start, range.end)), "can't select text of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Click the increment button of the [`Stepper`](crate::Stepper), and
+    /// handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a stepper.
+    #[track_caller]
+    pub fn increment(&self) {
+        self.expect_kind(HeadlessViewKind::Stepper, "increment");
+        self.view.step(true);
+        (self.process_events)();
+    }
+
+    /// Click the decrement button of the [`Stepper`](crate::Stepper), and
+    /// handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a stepper.
+    #[track_caller]
+    pub fn decrement(&self) {
+        self.expect_kind(HeadlessViewKind::Stepper, "decrement");
+        self.view.step(false);
+        (self.process_events)();
+    }
+
+    /// Scroll the [`ScrollView`](crate::ScrollView) to the given offset from
+    /// the top left corner, and handle the resulting events. The offset is
+    /// clamped to the size of the content, like a user can't scroll past it.
+    ///
+    /// ## Panics
+    /// When this view isn't a scroll view.
+    #[track_caller]
+    pub fn scroll_to(&self, x: f32, y: f32) {
+        self.expect_kind(HeadlessViewKind::ScrollView, "scroll");
+        self.view.scroll_to(euclid::default::Vector2D::new(x, y));
+        (self.process_events)();
+    }
+
+    /// Drag the knob of the [`Slider`](crate::Slider) to the given value, and
+    /// handle the resulting events. Like a user can't drag the knob past the
+    /// ends, the value is clamped to the range, and rounded to the step.
+    ///
+    /// ## Panics
+    /// When this view isn't a slider.
+    #[track_caller]
+    pub fn slide_to(&self, value: f64) {
+        assert!(matches!(self.view.kind(), HeadlessViewKind::Slider(..)), "can't slide this view: {:?}", self.view);
+        self.view.change_value(value);
+        (self.process_events)();
+    }
+
+    /// Pick the moment in the [`DatePicker`](crate::DatePicker), or the day
+    /// in the [`Calendar`](crate::Calendar), and handle the resulting events.
+    /// Like a user can't pick a moment outside the bounds, the moment is
+    /// clamped to them.
+    ///
+    /// ## Panics
+    /// When this view isn't a date picker of the same mode, or a calendar
+    /// when `value` is a [`NaiveDate`](chrono::NaiveDate).
+    #[cfg(feature = "chrono")]
+    #[track_caller]
+    pub fn pick_date<T: crate::DateValue>(&self, value: T) {
+        let kind = self.view.kind();
+        let can_pick = kind == HeadlessViewKind::DatePicker(T::MODE)
+            || (kind == HeadlessViewKind::Calendar && T::MODE == crate::DatePickerMode::Date);
+        assert!(can_pick, "can't pick a {:?} in this view: {:?}", T::MODE, self.view);
+
+        self.view.change_value(crate::views::to_timestamp(value));
+        (self.process_events)();
+    }
+
+    /// Pick the color in the [`ColorWell`](crate::ColorWell), and handle the
+    /// resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a color well.
+    #[track_caller]
+    pub fn pick_color(&self, color: impl Into<Color>) {
+        self.expect_kind(HeadlessViewKind::ColorWell, "pick a color in");
+        self.view.change_color(color.into());
+        (self.process_events)();
+    }
+
+    /// Select a single row of the [`List`](crate::List) or the
+    /// [`Table`](crate::Table), as if the user clicked it, and handle the
+    /// resulting events.
+    ///
+    /// ## Panics
+    /// When this view doesn't have rows, or when the row can't be selected.
+    #[track_caller]
+    pub fn select_row(&self, row: usize) {
+        self.select_rows(&[row]);
+    }
+
+    /// Select the given rows of the [`List`](crate::List) or the
+    /// [`Table`](crate::Table), and handle the resulting events. An empty
+    /// slice clears the selection.
+    ///
+    /// ## Panics
+    /// When this view doesn't have rows, or when the rows can't be selected,
+    /// e.g. when the list doesn't allow multiple selection.
+    #[track_caller]
+    pub fn select_rows(&self, rows: &[usize]) {
+        self.expect_rows("select rows of");
+        assert!(self.view.select_rows(rows.to_vec()), "can't select the rows {rows:?} of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Activate a row of the [`List`](crate::List) or the
+    /// [`Table`](crate::Table), as if the user double clicked it, and handle
+    /// the resulting events.
+    ///
+    /// ## Panics
+    /// When this view doesn't have rows, or when the row doesn't exist.
+    #[track_caller]
+    pub fn activate_row(&self, row: usize) {
+        self.expect_rows("activate a row of");
+        assert!(self.view.activate_row(row), "can't activate row {row} of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Click the header of a column of the [`Table`](crate::Table), which
+    /// sorts the rows by that column, and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a table, or when the column can't be sorted.
+    #[track_caller]
+    pub fn click_column(&self, column: usize) {
+        self.expect_kind(HeadlessViewKind::Table, "click a column of");
+        assert!(self.view.click_column(column), "can't sort by column {column} of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Replace the text of a cell of the [`Table`](crate::Table), as if the
+    /// user edited it and pressed the return key, and handle the resulting
+    /// events.
+    ///
+    /// ## Panics
+    /// When this view isn't a table, or when the cell can't be edited.
+    #[track_caller]
+    pub fn edit_cell(&self, row: usize, column: usize, text: &str) {
+        self.expect_kind(HeadlessViewKind::Table, "edit a cell of");
+        assert!(self.view.edit_cell(row, column, text), "can't edit the cell at row {row}, column {column} of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Select an option of the [`Select`](crate::Select), as if the user
+    /// picked it from the menu, and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a select, or when the option doesn't exist.
+    #[track_caller]
+    pub fn select_option(&self, index: usize) {
+        self.expect_kind(HeadlessViewKind::Select, "select an option of");
+        assert!(self.view.select_option(index), "can't select option {index} of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Select a tab of the [`TabView`](crate::TabView), which displays the
+    /// content of the tab, and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a tab view, or when the tab doesn't exist.
+    #[track_caller]
+    pub fn select_tab(&self, index: usize) {
+        self.expect_kind(HeadlessViewKind::TabView, "select a tab of");
+        assert!(self.view.select_tab(index), "can't select tab {index} of this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Click the clear button of the [`SearchField`](crate::SearchField),
+    /// and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When this view isn't a search field, or is disabled.
+    #[track_caller]
+    pub fn click_clear_button(&self) {
+        self.expect_kind(HeadlessViewKind::SearchField, "clear");
+        assert!(self.view.perform_action(), "can't clear this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    /// Press a key while this view has the focus, e.g. to expand a row of a
+    /// [`Tree`](crate::Tree), and handle the resulting events.
+    ///
+    /// ## Panics
+    /// When the key doesn't do anything in this view.
+    #[track_caller]
+    pub fn press_key(&self, key: Key) {
+        assert!(self.view.press_key(key), "can't press {key:?} in this view: {:?}", self.view);
+        (self.process_events)();
+    }
+
+    #[track_caller]
+    fn expect_kind(&self, kind: HeadlessViewKind, action: &str) {
+        let actual = self.view.kind();
+        assert_eq!(actual, kind, "can't {action} this view: {:?}", self.view);
+    }
+
+    #[track_caller]
+    fn expect_editable(&self, action: &str) {
+        let editable = self.view.with(|data| match data.kind {
+            HeadlessViewKind::SearchField | HeadlessViewKind::Stepper | HeadlessViewKind::TextField => true,
+            HeadlessViewKind::Select | HeadlessViewKind::TextEditor => data.editable,
+            _ => false,
+        });
+        assert!(editable, "can't {action} this view: {:?}", self.view);
+    }
+
+    #[track_caller]
+    fn expect_rows(&self, action: &str) {
+        let kind = self.view.kind();
+        assert!(matches!(kind, HeadlessViewKind::List | HeadlessViewKind::Table), "can't {action} this view: {:?}", self.view);
+    }
+}
+
+impl Debug for TestView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.view.fmt(f)
+    }
+}
+
+/// The pixels of a [`Canvas`](crate::Canvas), rendered using
+/// [`TestView::snapshot()`]. Pixels that weren't drawn on are transparent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    pixmap: Pixmap,
+}
+
+impl Snapshot {
+    /// Get the width in pixels, which is the width of the canvas rounded up.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.pixmap.width()
+    }
+
+    /// Get the height in pixels, which is the height of the canvas rounded
+    /// up.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.pixmap.height()
+    }
+
+    /// Get the color of the pixel at the given coordinates, or [`None`] if the
+    /// coordinates are outside the snapshot. Opaque pixels are returned as a
+    /// [`Color::rgb()`], and other pixels as a [`Color::rgba()`].
+    #[must_use]
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Color> {
+        self.pixmap.pixel(x, y)
+    }
+
+    /// Get the pixels row by row, as red, green, blue and alpha bytes. These
+    /// can be written to an image file, to compare them with a reference
+    /// image.
+    #[must_use]
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.pixmap.to_rgba8()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use super::*;
+
+    struct FormApp;
+
+    #[derive(Default)]
+    struct FormState {
+        clicks: usize,
+        accepted: Option<bool>,
+        changes: Vec<String>,
+        name: TextValue,
+        menu_items: Vec<MenuItem>,
+    }
+
+    impl AppDelegate<FormState> for FormApp {
+        fn configure_main_window(&mut self, _: &mut FormState) -> WindowConfiguration {
+            WindowConfiguration::new()
+                .with_menu(Menu::new("File")
+                    .with_item("Save")
+                    .with_item(MenuItem::separator())
+                    .with_item("Quit"))
+        }
+
+        fn make_content_view(&mut self, state: &mut FormState, _: Window) -> impl View<Self, FormState> {
+            Stack::vertical()
+                .with(TextField::new(&state.name)
+                    .with_placeholder("Name")
+                    .with_on_change(|state: &mut FormState, text, _| {
+                        state.changes.push(text);
+                    }))
+                .with(Checkbox::new("Accept")
+                    .with_id("accept")
+                    .with_on_checked(|state: &mut FormState, checked, _| {
+                        state.accepted = Some(checked);
+                    }))
+                .with(Button::new("Submit")
+                    .with_tooltip("Submit the form")
+                    .with_on_click(|state: &mut FormState, _| {
+                        state.clicks += 1;
+                    }))
+        }
+
+        fn did_invoke_menu_action(&mut self, item: MenuItem, state: &mut FormState, _: Window) {
+            state.menu_items.push(item);
+        }
+    }
+
+    fn launch() -> TestApp<FormApp, FormState> {
+        TestApp::launch(App::with_state(FormApp, FormState::default()))
+    }
+
+    #[test]
+    fn find_views() {
+        let app = launch();
+
+        assert_eq!(app.find_by_tooltip("Submit the form").unwrap().text(), "Submit");
+        assert_eq!(app.find_by_id("accept").unwrap().text(), "Accept");
+        assert!(app.find_by_text("Cancel").is_none());
+        assert_eq!(app.root().children().len(), 3);
+        assert_eq!(app.root().children()[0].placeholder(), "Name");
+    }
+
+    #[test]
+    fn click_button() {
+        let app = launch();
+
+        let button = app.find_by_text("Submit").unwrap();
+        button.click();
+        button.click();
+
+        assert_eq!(app.with_state(|state| state.clicks), 2);
+    }
+
+    #[test]
+    fn toggle_checkbox() {
+        let app = launch();
+
+        let checkbox = app.find_by_id("accept").unwrap();
+        checkbox.toggle();
+        assert!(checkbox.is_checked());
+        assert_eq!(app.with_state(|state| state.accepted), Some(true));
+
+        checkbox.toggle();
+        assert!(!checkbox.is_checked());
+        assert_eq!(app.with_state(|state| state.accepted), Some(false));
+    }
+
+    #[test]
+    fn type_text() {
+        let app = launch();
+
+        let text_field = app.root().children()[0].clone();
+        text_field.type_text("Hi");
+        text_field.type_text("!");
+
+        assert_eq!(text_field.text(), "Hi!");
+        assert_eq!(app.with_state(|state| state.name.clone_inner()), "Hi!");
+        assert_eq!(app.with_state(|state| state.changes.clone()), ["H", "Hi", "Hi!"]);
+    }
+
+    #[test]
+    fn invoke_menu_item() {
+        let app = launch();
+
+        app.invoke_menu_item("File", "Save");
+
+        assert_eq!(app.with_state(|state| state.menu_items.clone()), [MenuItem::titled("Save")]);
+    }
+
+    #[test]
+    fn layout_views() {
+        let app = launch();
+        let frames = || app.root().children().iter().map(TestView::frame).collect::<Vec<_>>();
+
+        // The window doesn't have a size, so the preferred size is used.
+        assert_eq!(app.root().frame(), euclid::rect(0.0, 0.0, 200.0, 88.0));
+        assert_eq!(frames(), [
+            euclid::rect(0.0, 0.0, 200.0, 24.0),
+            euclid::rect(64.0, 32.0, 72.0, 20.0),
+            euclid::rect(64.0, 60.0, 72.0, 28.0),
+        ]);
+
+        app.resize(400.0, 300.0);
+        assert_eq!(app.root().frame(), euclid::rect(0.0, 0.0, 400.0, 300.0));
+        assert_eq!(frames(), [
+            euclid::rect(100.0, 0.0, 200.0, 24.0),
+            euclid::rect(164.0, 32.0, 72.0, 20.0),
+            euclid::rect(164.0, 60.0, 72.0, 28.0),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't click this view")]
+    fn click_non_button() {
+        let app = launch();
+        app.find_by_text("Accept").unwrap().click();
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't contain the item")]
+    fn invoke_missing_menu_item() {
+        let app = launch();
+        app.invoke_menu_item("File", "Print");
+    }
+
+    #[test]
+    fn view_by_id_modifies_views() {
+        let app: ViewTestApp<()> = TestApp::launch_view(|| {
+            Stack::vertical()
+                .with(TextField::new("")
+                    .with_id("name"))
+                .with(Button::new("Lock")
+                    .with_id("lock")
+                    .with_on_click(|_, window| {
+                        let button = window.view_by_id("lock").unwrap();
+                        button.set_text("Locked");
+                        button.set_enabled(false);
+
+                        window.view_by_id("name").unwrap().focus();
+                    }))
+        });
+
+        let button = app.find_by_id("lock").unwrap();
+        let name = app.find_by_id("name").unwrap();
+        assert!(button.is_enabled());
+        assert!(!name.is_focused());
+
+        button.click();
+        assert_eq!(button.text(), "Locked");
+        assert!(!button.is_enabled());
+        assert!(name.is_focused());
+
+        // Disabled buttons can't be clicked.
+        button.click();
+        assert_eq!(button.text(), "Locked");
+    }
+
+    /// Launches an app with a button that picks the `background` color using
+    /// the color dialog.
+    fn launch_color_picker(background: &ColorValue) -> ViewTestApp<()> {
+        let background = background.clone();
+        TestApp::launch_view(move || {
+            let background = background.clone();
+            Button::new("Background")
+                .with_on_click(move |_, window| {
+                    let background = background.clone();
+                    window.pick_color()
+                        .color(background.clone_inner())
+                        .show(move |color| background.set(color));
+                })
+        })
+    }
+
+    #[test]
+    fn pick_color_dialog() {
+        let background = ColorValue::new(Color::rgb(255, 255, 255));
+        let app = launch_color_picker(&background);
+        assert_eq!(app.color_dialog(), None);
+
+        app.find_by_text("Background").unwrap().click();
+        assert_eq!(app.color_dialog(), Some(Color::rgb(255, 255, 255)));
+
+        app.answer_color_dialog(None);
+        assert_eq!(app.color_dialog(), None);
+        assert_eq!(background.clone_inner(), Color::rgb(255, 255, 255));
+
+        app.find_by_text("Background").unwrap().click();
+        app.answer_color_dialog(Some(Color::rgba(0, 0, 0, 127)));
+        assert_eq!(background.clone_inner(), Color::rgba(0, 0, 0, 127));
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no color dialog to answer")]
+    fn answer_missing_color_dialog() {
+        let app = launch_color_picker(&ColorValue::new(Color::rgb(0, 0, 0)));
+        app.answer_color_dialog(Some(Color::rgb(0, 0, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't take a snapshot of this view")]
+    fn snapshot_of_button() {
+        let app = launch();
+        _ = app.find_by_text("Submit").unwrap().snapshot();
+    }
+}
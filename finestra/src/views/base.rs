@@ -1,11 +1,27 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::StateOrRaw;
+use std::rc::Rc;
+
+use crate::{AppContext, StateOrRaw, ViewHandle, ViewHandleApi};
 
 #[derive(Debug, Default)]
 pub(crate) struct ViewBase {
     pub(crate) tooltip: StateOrRaw<String>,
+    pub(crate) id: Option<String>,
+}
+
+impl ViewBase {
+    /// Registers the native view, so it can be found using
+    /// [`Window::view_by_id()`](crate::Window::view_by_id). This is only done
+    /// when the view was given an identifier.
+    pub(crate) fn register_handle<H: ViewHandleApi + 'static>(&self, make_handle: impl FnOnce() -> H) {
+        let Some(id) = &self.id else {
+            return;
+        };
+
+        AppContext::register_view(ViewHandle::new(id.clone(), Rc::new(make_handle())));
+    }
 }
 
 pub(crate) trait BaseView {
@@ -26,6 +42,27 @@ pub trait BaseViewExt {
     /// provide the user with more context, or to let them know what the action
     /// will do.
     fn with_tooltip(self, tooltip: impl Into<StateOrRaw<String>>) -> Self;
+
+    /// Set the identifier of the view, which is a stable name that can be used
+    /// to find the view at runtime using
+    /// [`Window::view_by_id()`](crate::Window::view_by_id), and in UI tests
+    /// using [`TestApp::find_by_id()`](crate::testing::TestApp::find_by_id).
+    /// The identifier should be unique within the window.
+    fn set_id(&mut self, id: impl Into<String>);
+
+    /// Set the identifier of the view, which is a stable name that can be used
+    /// to find the view at runtime using
+    /// [`Window::view_by_id()`](crate::Window::view_by_id), and in UI tests
+    /// using [`TestApp::find_by_id()`](crate::testing::TestApp::find_by_id).
+    /// The identifier should be unique within the window.
+    ///
+    /// ```
+    /// # use finestra::*;
+    /// # type Button = finestra::Button<()>;
+    /// let button = Button::new("Save")
+    ///     .with_id("save-button");
+    /// ```
+    fn with_id(self, id: impl Into<String>) -> Self;
 }
 
 impl<T> BaseViewExt for T
@@ -38,4 +75,13 @@ impl<T> BaseViewExt for T
         self.set_tooltip(tooltip);
         self
     }
+
+    fn set_id(&mut self, id: impl Into<String>) {
+        self.base_mut().id = Some(id.into());
+    }
+
+    fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.set_id(id);
+        self
+    }
 }
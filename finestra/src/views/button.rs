@@ -1,6 +1,8 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use std::rc::Rc;
+
 use crate::{event::EventHandlerMap, AppDelegate, Color, StateOrRaw, View, ViewBase, Window};
 
 use super::base::BaseView;
@@ -36,12 +38,12 @@ impl<State> Button<State> {
 
     /// Get notified when the button is clicked.
     pub fn set_on_click(&mut self, action: impl Fn(&mut State, Window) + 'static) {
-        self.event_handler_map.click = Some(Box::new(action));
+        self.event_handler_map.click = Some(Rc::new(action));
     }
 
     /// Get notified when the button is clicked.
     pub fn with_on_click(mut self, action: impl Fn(&mut State, Window) + 'static) -> Self {
-        self.event_handler_map.click = Some(Box::new(action));
+        self.event_handler_map.click = Some(Rc::new(action));
         self
     }
 
@@ -132,6 +134,7 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Button<State
         });
 
         button.as_ref().subscribe_text_update(self.text.as_state());
+        self.base.register_handle(|| *button.as_ref());
 
         let id = ViewId(button.as_ref().get_control_id().0 as _);
 
@@ -140,6 +143,42 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Button<State
 
         WinView::new(id, WinViewKind::Button(button))
     }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::Event;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let button = self.text.with(|text| {
+            gtk4::Button::with_label(text)
+        });
+
+        crate::platform::gtk::state::attach_button_state(id, self, &button);
+
+        let dispatcher = tree.create_dispatcher();
+        button.connect_clicked(move |_| {
+            dispatcher.dispatch_event(Event::ButtonClicked(id));
+        });
+        button.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let button = HeadlessView::new(id, HeadlessViewKind::Button);
+        crate::platform::headless::state::attach_button_state(self, &button);
+
+        button.set_action(move || {
+            HeadlessContext::dispatch(Event::ButtonClicked(id));
+        });
+        button
+    }
 }
 
 impl<Delegate, State> From<Button<State>> for Box<dyn View<Delegate, State>>
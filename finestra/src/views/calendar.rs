@@ -0,0 +1,259 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+
+use crate::{event::EventHandlerMap, AppDelegate, StateOrRaw, View, ViewBase, ViewId, Window};
+
+use super::{base::BaseView, date_picker::{from_timestamp, to_timestamp, DateInput}};
+
+/// A calendar shows the days of a month in a grid, of which the user can
+/// select one. The month of the selected date is shown.
+///
+/// ```
+/// # use finestra::*;
+/// use chrono::NaiveDate;
+///
+/// let appointment = State::new(NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+///
+/// # let _: Calendar<()> =
+/// Calendar::new(&appointment)
+///     .with_max(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+///     .with_on_change(|_, date, _| println!("Appointment on {date}"));
+/// ```
+///
+/// This view is only available with the `chrono` feature.
+pub struct Calendar<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) value: crate::State<NaiveDate>,
+    pub(crate) min: Option<NaiveDate>,
+    pub(crate) max: Option<NaiveDate>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> Calendar<State> {
+    /// Creates a new [`Calendar`] without bounds. If the value is a
+    /// [`State`](crate::State), it is updated when the user selects a day.
+    #[must_use]
+    pub fn new(value: impl Into<StateOrRaw<NaiveDate>>) -> Self {
+        let value = match value.into() {
+            StateOrRaw::State(state) => state,
+            StateOrRaw::Raw(value) => crate::State::new(value),
+        };
+
+        Self {
+            base: ViewBase::default(),
+            value,
+            min: None,
+            max: None,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the user selected a day.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, NaiveDate, Window) + 'static) {
+        let value = self.value.clone();
+        self.event_handler_map.value_changed = Some(Rc::new(move |state, _, window| {
+            action(state, value.clone_inner(), window);
+        }));
+    }
+
+    /// Get notified when the user selected a day.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, NaiveDate, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+
+    /// Don't let the user select a day before `min`.
+    #[must_use]
+    pub fn with_min(mut self, min: NaiveDate) -> Self {
+        self.set_min(min);
+        self
+    }
+
+    /// Don't let the user select a day before `min`.
+    pub fn set_min(&mut self, min: NaiveDate) {
+        self.min = Some(min);
+    }
+
+    /// Don't let the user select a day after `max`.
+    #[must_use]
+    pub fn with_max(mut self, max: NaiveDate) -> Self {
+        self.set_max(max);
+        self
+    }
+
+    /// Don't let the user select a day after `max`.
+    pub fn set_max(&mut self, max: NaiveDate) {
+        self.max = Some(max);
+    }
+
+    fn input(&self, view_id: ViewId) -> DateInput<NaiveDate> {
+        DateInput {
+            view_id,
+            value: self.value.clone(),
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+impl<State> BaseView for Calendar<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Calendar<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::DatePickerMode;
+        use crate::platform::macos::{Event, NSDatePicker};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let calendar = NSDatePicker::new(DatePickerMode::Date, true);
+        calendar.set_bounds(self.min.map(to_timestamp), self.max.map(to_timestamp));
+        crate::platform::macos::state::attach_calendar_state(id, self, &calendar);
+
+        let input = self.input(id);
+        let dispatcher = tree.create_dispatcher();
+        calendar.set_action(move |timestamp| {
+            let Some(value) = from_timestamp(timestamp).and_then(|value| input.pick(value)) else {
+                return;
+            };
+
+            dispatcher.dispatch_event(Event::ValueChanged(id, to_timestamp(value)));
+        });
+
+        calendar.into()
+    }
+
+    /// Calendars aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use crate::platform::gtk::{date_from_gtk, Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let calendar = gtk4::Calendar::new();
+
+        let guard = SignalGuard::default();
+        let select = crate::platform::gtk::state::attach_calendar_state(id, self, &calendar, &guard);
+
+        let input = self.input(id);
+        let dispatcher = tree.create_dispatcher();
+        calendar.connect_day_selected(move |calendar| {
+            if guard.is_active() {
+                return;
+            }
+
+            if let Some(value) = date_from_gtk(&calendar.date()).and_then(|value| input.pick(value)) {
+                dispatcher.dispatch_event(Event::ValueChanged(id, to_timestamp(value)));
+            }
+
+            // GTK can't limit the days that can be selected, so the selection
+            // is moved back into the bounds.
+            input.value.with(&select);
+        });
+
+        calendar.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let calendar = HeadlessView::new(id, HeadlessViewKind::Calendar);
+        crate::platform::headless::state::attach_calendar_state(self, &calendar);
+
+        let input = self.input(id);
+        let view = calendar.clone();
+        calendar.set_value_did_change(move |timestamp| {
+            let picked = from_timestamp(timestamp).and_then(|value| input.pick(value));
+            crate::platform::headless::state::show_date(&view, input.value.clone_inner());
+
+            if let Some(value) = picked {
+                HeadlessContext::dispatch(Event::ValueChanged(id, to_timestamp(value)));
+            }
+        });
+        calendar
+    }
+}
+
+impl<Delegate, State> From<Calendar<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Calendar<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, DatePicker, Stack, State};
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn launch(departure: &State<NaiveDate>) -> ViewTestApp<NaiveDate> {
+        let departure = departure.clone();
+        TestApp::launch_view(move || {
+            Stack::vertical()
+                .with(DatePicker::<NaiveDate, _>::new(&departure)
+                    .with_id("departure"))
+                .with(Calendar::new(&departure)
+                    .with_id("calendar")
+                    .with_on_change(|departures: &mut Vec<NaiveDate>, date, _| departures.push(date)))
+        })
+    }
+
+    #[test]
+    fn date_binding() {
+        let departure = State::new(date(2024, 7, 1));
+        let app = launch(&departure);
+        let calendar = app.find_by_id("calendar").unwrap();
+
+        calendar.pick_date(date(2024, 12, 25));
+        assert_eq!(departure.clone_inner(), date(2024, 12, 25));
+        assert_eq!(app.find_by_id("departure").unwrap().text(), "2024-12-25");
+
+        // Picking the same day again isn't a change.
+        calendar.pick_date(date(2024, 12, 25));
+        assert_eq!(app.events(), [date(2024, 12, 25)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't pick a Time in this view")]
+    fn pick_time() {
+        let app = launch(&State::new(date(2024, 7, 1)));
+        app.find_by_id("calendar").unwrap().pick_date(chrono::NaiveTime::MIN);
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use euclid::default::Size2D;
+
+use crate::{event::EventHandlerMap, AppDelegate, DrawingContext, View, ViewBase};
+
+use super::base::BaseView;
+
+/// A view with custom 2D graphics, which are drawn by the painter using a
+/// [`DrawingContext`]. The painter is invoked whenever the canvas needs to be
+/// drawn, e.g. when it is shown or resized, so it shouldn't do much more than
+/// drawing.
+///
+/// To draw the canvas again after the data it shows changed, give it an
+/// identifier, and call [`ViewHandle::invalidate()`](crate::ViewHandle::invalidate).
+///
+/// ```
+/// # use finestra::*;
+/// use finestra::euclid::rect;
+///
+/// let values = State::new(vec![3.0_f32, 5.0, 2.0]);
+///
+/// # let _: Canvas<()> =
+/// Canvas::new(move |context| {
+///     let height = context.size().height;
+///     values.with(|values| {
+///         for (index, value) in values.iter().enumerate() {
+///             let bar = rect(index as f32 * 20.0, height - value * 10.0, 16.0, value * 10.0);
+///             context.fill_rect(bar, Color::system(SystemColor::Blue));
+///         }
+///     });
+/// })
+/// .with_size(60.0, 50.0)
+/// .with_id("chart");
+/// ```
+pub struct Canvas<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) painter: Rc<dyn Fn(&mut DrawingContext)>,
+    pub(crate) size: Size2D<f32>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> Canvas<State> {
+    /// Creates a new [`Canvas`], which is drawn by the `painter`. The
+    /// preferred size is 200 by 200 points.
+    #[must_use]
+    pub fn new(painter: impl Fn(&mut DrawingContext) + 'static) -> Self {
+        Self {
+            base: ViewBase::default(),
+            painter: Rc::new(painter),
+            size: Size2D::new(200.0, 200.0),
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Set the preferred size of the canvas. The layout can give the canvas
+    /// another size, which is given to the painter using
+    /// [`DrawingContext::size()`].
+    #[must_use]
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.set_size(width, height);
+        self
+    }
+
+    /// Set the preferred size of the canvas. The layout can give the canvas
+    /// another size, which is given to the painter using
+    /// [`DrawingContext::size()`].
+    pub fn set_size(&mut self, width: f32, height: f32) {
+        self.size = Size2D::new(width, height);
+    }
+}
+
+impl<State> BaseView for Canvas<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Canvas<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::NSCanvasView;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        _ = tree.exchange_events_for_id(map);
+
+        let canvas = NSCanvasView::new(Rc::clone(&self.painter), self.size);
+        crate::platform::macos::state::attach_canvas_state(self, &canvas);
+        canvas.into()
+    }
+
+    /// Canvases aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::draw_commands;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        _ = tree.exchange_events_for_id(map);
+
+        let canvas = gtk4::DrawingArea::new();
+        canvas.set_content_width(self.size.width.round() as i32);
+        canvas.set_content_height(self.size.height.round() as i32);
+
+        let painter = Rc::clone(&self.painter);
+        canvas.set_draw_func(move |_, cairo, width, height| {
+            let mut context = DrawingContext::new(Size2D::new(width as f32, height as f32));
+            painter(&mut context);
+            draw_commands(cairo, &context.into_commands());
+        });
+
+        crate::platform::gtk::state::attach_canvas_state(self, &canvas);
+        canvas.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let canvas = HeadlessView::new(id, HeadlessViewKind::Canvas);
+        canvas.set_painter(self.size, Rc::clone(&self.painter));
+        crate::platform::headless::state::attach_canvas_state(self, &canvas);
+        canvas
+    }
+}
+
+impl<Delegate, State> From<Canvas<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Canvas<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Button, Color, Stack, State, SystemColor};
+
+    use super::*;
+
+    /// A bar chart of the `sales`, with a button that adds a sale and
+    /// invalidates the chart.
+    fn launch(sales: &State<Vec<f32>>) -> ViewTestApp<()> {
+        let sales = sales.clone();
+        TestApp::launch_view(move || {
+            let drawn = sales.clone();
+            let added = sales.clone();
+            Stack::vertical()
+                .with(Canvas::new(move |context| {
+                    let size = context.size();
+                    context.fill_rect(euclid::rect(0.0, 0.0, size.width, size.height), SystemColor::White);
+                    drawn.with(|sales| {
+                        for (index, value) in sales.iter().enumerate() {
+                            let bar = euclid::rect(index as f32 * 10.0, size.height - value, 10.0, *value);
+                            context.fill_rect(bar, SystemColor::Blue);
+                        }
+                    });
+                    context.draw_text("Sales", euclid::point2(0.0, 12.0), SystemColor::Label);
+                })
+                    .with_size(40.0, 20.0)
+                    .with_id("chart"))
+                .with(Button::new("Add")
+                    .with_on_click(move |_, window| {
+                        added.with_mut(|sales| sales.push(20.0));
+                        window.view_by_id("chart").unwrap().invalidate();
+                    }))
+        })
+    }
+
+    #[test]
+    fn snapshot() {
+        let app = launch(&State::new(vec![5.0, 10.0]));
+        let chart = app.find_by_id("chart").unwrap();
+        assert_eq!(chart.frame().size, euclid::size2(40.0, 20.0));
+
+        let snapshot = chart.snapshot();
+        assert_eq!((snapshot.width(), snapshot.height()), (40, 20));
+        assert_eq!(snapshot.pixel(5, 17), Some(Color::rgb(0x35, 0x84, 0xE4)));
+        assert_eq!(snapshot.pixel(5, 12), Some(Color::rgb(255, 255, 255)));
+        assert_eq!(snapshot.pixel(15, 12), Some(Color::rgb(0x35, 0x84, 0xE4)));
+        assert_eq!(snapshot.pixel(25, 19), Some(Color::rgb(255, 255, 255)));
+        assert_eq!(snapshot.to_rgba8().len(), 40 * 20 * 4);
+        assert_eq!(chart.drawn_text(), ["Sales"]);
+    }
+
+    #[test]
+    fn paint_when_invalidated() {
+        let sales = State::new(vec![5.0, 10.0]);
+        let app = launch(&sales);
+        let chart = app.find_by_id("chart").unwrap();
+        let before = chart.snapshot();
+
+        // Changing the data doesn't paint the canvas again.
+        sales.set(vec![20.0, 20.0, 20.0]);
+        assert_eq!(chart.snapshot(), before);
+
+        app.find_by_text("Add").unwrap().click();
+        let after = chart.snapshot();
+        assert_eq!(after.pixel(25, 1), Some(Color::rgb(0x35, 0x84, 0xE4)));
+        assert_eq!(after.pixel(35, 1), Some(Color::rgb(0x35, 0x84, 0xE4)));
+    }
+}
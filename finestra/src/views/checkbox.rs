@@ -1,6 +1,8 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use std::rc::Rc;
+
 use crate::{event::EventHandlerMap, AppDelegate, Color, StateOrRaw, View, ViewBase, Window};
 
 use super::base::BaseView;
@@ -38,7 +40,7 @@ impl<State> Checkbox<State> {
 
     /// Get notified when the checkbox has been switched to on or off.
     pub fn set_on_checked(&mut self, action: impl Fn(&mut State, bool, Window) + 'static) {
-        self.event_handler_map.checked = Some(Box::new(action));
+        self.event_handler_map.checked = Some(Rc::new(action));
     }
 
     /// Get notified when the checkbox has been switched to on or off.
@@ -135,18 +137,84 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Checkbox<Sta
         use crate::{platform::win32::view::{WinButton, WinView, WinViewKind}, ViewId};
 
         let checkbox = self.text.with(|text| {
-            WinButton::new(parent, text)
+            WinButton::new_checkbox(parent, text)
         });
 
         checkbox.as_ref().subscribe_text_update(self.text.as_state());
+        self.base.register_handle(|| *checkbox.as_ref());
 
         let id = ViewId(checkbox.as_ref().get_control_id().0 as _);
 
-        let map = std::mem::take(&mut self.event_handler_map);
+        // The checkbox is toggled by Win32, so only the state is updated.
+        let mut map = std::mem::take(&mut self.event_handler_map);
+        if let Some(state) = self.checked.as_state() {
+            checkbox.as_ref().subscribe_checked_update(&state, id);
+
+            let on_checked = map.checked.take();
+            map.checked = Some(Rc::new(move |app_state, is_checked, window| {
+                state.set_with_origin(is_checked, crate::StateChangeOrigin::Owner(id));
+                if let Some(on_checked) = &on_checked {
+                    on_checked(app_state, is_checked, window);
+                }
+            }));
+        } else {
+            checkbox.as_ref().set_checked(self.checked.clone_inner());
+        }
         tree.put_event_handlers_with_id(id, map);
 
         WinView::new(id, WinViewKind::Button(checkbox))
     }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+        use crate::StateChangeOrigin;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let checkbox = self.text.with(|text| {
+            gtk4::CheckButton::with_label(text)
+        });
+        checkbox.set_active(self.checked.clone_inner());
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_checkbox_state(id, self, &checkbox, &guard);
+
+        let state = self.checked.as_state().unwrap().clone();
+        let dispatcher = tree.create_dispatcher();
+        checkbox.connect_toggled(move |checkbox| {
+            if guard.is_active() {
+                return;
+            }
+
+            let is_checked = checkbox.is_active();
+            state.set_with_origin(is_checked, StateChangeOrigin::Owner(id));
+
+            dispatcher.dispatch_event(Event::CheckboxChanged(id, is_checked));
+        });
+        checkbox.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let checkbox = HeadlessView::new(id, HeadlessViewKind::Checkbox);
+        crate::platform::headless::state::attach_checkbox_state(self, &checkbox);
+
+        let state = self.checked.as_state().unwrap().clone();
+        checkbox.set_action(move || {
+            let is_checked = !state.clone_inner();
+            state.set(is_checked);
+
+            HeadlessContext::dispatch(Event::CheckboxChanged(id, is_checked));
+        });
+        checkbox
+    }
 }
 
 impl<Delegate, State> From<Checkbox<State>> for Box<dyn View<Delegate, State>>
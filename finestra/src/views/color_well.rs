@@ -0,0 +1,224 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::{event::EventHandlerMap, AppDelegate, Color, StateChangeOrigin, StateOrRaw, View, ViewBase, ViewId, Window};
+
+use super::base::BaseView;
+
+/// A color well shows a color, and lets the user pick another one by clicking
+/// it. The named [`SystemColor`](crate::SystemColor)s are offered as swatches
+/// where the platform supports it.
+///
+/// ```
+/// # use finestra::*;
+/// let accent = ColorValue::new(Color::system(SystemColor::Teal));
+///
+/// # let _: ColorWell<()> =
+/// ColorWell::new(&accent)
+///     .with_alpha(false)
+///     .with_on_change(|_, color, _| println!("The accent color is now {color:?}"));
+/// ```
+pub struct ColorWell<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) value: crate::State<Color>,
+    pub(crate) alpha: bool,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> ColorWell<State> {
+    /// Creates a new [`ColorWell`], in which the user can pick the opacity as
+    /// well. If the value is a [`ColorValue`](crate::ColorValue), it is
+    /// updated when the user picks a color.
+    #[must_use]
+    pub fn new(value: impl Into<StateOrRaw<Color>>) -> Self {
+        let value = match value.into() {
+            StateOrRaw::State(state) => state,
+            StateOrRaw::Raw(value) => crate::State::new(value),
+        };
+
+        Self {
+            base: ViewBase::default(),
+            value,
+            alpha: true,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Set whether the user can pick the opacity of the color, in which case
+    /// the picked color can be a [`Color::rgba()`].
+    #[must_use]
+    pub fn with_alpha(mut self, alpha: bool) -> Self {
+        self.set_alpha(alpha);
+        self
+    }
+
+    /// Set whether the user can pick the opacity of the color, in which case
+    /// the picked color can be a [`Color::rgba()`].
+    pub fn set_alpha(&mut self, alpha: bool) {
+        self.alpha = alpha;
+    }
+
+    /// Get notified when the user picked a color.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, Color, Window) + 'static) {
+        self.event_handler_map.color_changed = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user picked a color.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, Color, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+}
+
+impl<State> BaseView for ColorWell<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+/// Stores the picked `color`, and returns whether it changed.
+fn pick(value: &crate::State<Color>, view_id: ViewId, color: Color) -> bool {
+    if value.with(|current| *current == color) {
+        return false;
+    }
+
+    value.set_with_origin(color, StateChangeOrigin::Owner(view_id));
+    true
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for ColorWell<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{Event, NSColorWell};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let color_well = NSColorWell::new(self.alpha);
+        crate::platform::macos::state::attach_color_well_state(id, self, &color_well);
+
+        let value = self.value.clone();
+        let dispatcher = tree.create_dispatcher();
+        color_well.set_action(move |color| {
+            if pick(&value, id, color.clone()) {
+                dispatcher.dispatch_event(Event::ColorChanged(id, color));
+            }
+        });
+
+        color_well.into()
+    }
+
+    /// Color wells aren't supported on Win32 yet, but colors can be picked
+    /// using [`Window::pick_color()`].
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{add_swatches, color_from_gtk, Event};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let button = gtk4::ColorButton::new();
+        button.set_use_alpha(self.alpha);
+        add_swatches(&button);
+        crate::platform::gtk::state::attach_color_well_state(id, self, &button);
+
+        // Unlike the other signals, this one isn't emitted when the color is
+        // set by the program.
+        let value = self.value.clone();
+        let dispatcher = tree.create_dispatcher();
+        button.connect_color_set(move |button| {
+            let color = color_from_gtk(&button.rgba());
+            if pick(&value, id, color.clone()) {
+                dispatcher.dispatch_event(Event::ColorChanged(id, color));
+            }
+        });
+
+        button.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let color_well = HeadlessView::new(id, HeadlessViewKind::ColorWell);
+        crate::platform::headless::state::attach_color_well_state(self, &color_well);
+
+        // Like a user, the test can't pick the opacity when it's disabled.
+        let alpha = self.alpha;
+        let value = self.value.clone();
+        let view = color_well.clone();
+        color_well.set_color_did_change(move |color| {
+            let color = if alpha { color } else { color.without_alpha() };
+            view.with_mut(|data| data.background_color = color.clone());
+            if pick(&value, id, color.clone()) {
+                HeadlessContext::dispatch(Event::ColorChanged(id, color));
+            }
+        });
+        color_well
+    }
+}
+
+impl<Delegate, State> From<ColorWell<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: ColorWell<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::TestApp;
+    use crate::{BaseViewExt, Color, ColorValue, SystemColor};
+
+    use super::*;
+
+    #[test]
+    fn color_binding() {
+        let accent = ColorValue::new(Color::system(SystemColor::Teal));
+        let app = TestApp::launch_view({
+            let accent = accent.clone();
+            move || {
+                ColorWell::new(&accent)
+                    .with_id("accent")
+                    .with_alpha(false)
+                    .with_on_change(|changes: &mut Vec<Color>, color, _| changes.push(color))
+            }
+        });
+
+        let well = app.find_by_id("accent").unwrap();
+        assert_eq!(well.color(), Color::system(SystemColor::Teal));
+
+        // The opacity can't be picked.
+        well.pick_color(Color::rgba(10, 20, 30, 40));
+        assert_eq!(accent.clone_inner(), Color::rgb(10, 20, 30));
+
+        well.pick_color(Color::rgb(10, 20, 30));
+        assert_eq!(app.events(), [Color::rgb(10, 20, 30)]);
+
+        accent.set(SystemColor::Red);
+        assert_eq!(well.color(), Color::system(SystemColor::Red));
+    }
+}
@@ -0,0 +1,477 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::{event::EventHandlerMap, AppDelegate, DatePickerMode, StateChangeOrigin, StateOrRaw, View, ViewBase, ViewId, Window};
+
+use super::base::BaseView;
+
+/// A moment that can be picked using a [`DatePicker`]. The type decides the
+/// [`DatePickerMode`]: a [`NaiveDate`] is only a date, a [`NaiveTime`] is only
+/// a time, and a [`NaiveDateTime`] is both.
+pub trait DateValue: Copy + PartialOrd + 'static {
+    /// The parts of the moment the user can pick.
+    const MODE: DatePickerMode;
+
+    #[doc(hidden)]
+    fn to_date_time(self) -> NaiveDateTime;
+
+    #[doc(hidden)]
+    fn from_date_time(value: NaiveDateTime) -> Self;
+}
+
+impl DateValue for NaiveDate {
+    const MODE: DatePickerMode = DatePickerMode::Date;
+
+    fn to_date_time(self) -> NaiveDateTime {
+        self.and_time(NaiveTime::MIN)
+    }
+
+    fn from_date_time(value: NaiveDateTime) -> Self {
+        value.date()
+    }
+}
+
+impl DateValue for NaiveTime {
+    const MODE: DatePickerMode = DatePickerMode::Time;
+
+    /// Times are on the first day of the Unix epoch, so the timestamps are
+    /// small.
+    fn to_date_time(self) -> NaiveDateTime {
+        DateTime::UNIX_EPOCH.date_naive().and_time(self)
+    }
+
+    fn from_date_time(value: NaiveDateTime) -> Self {
+        value.time()
+    }
+}
+
+impl DateValue for NaiveDateTime {
+    const MODE: DatePickerMode = DatePickerMode::DateTime;
+
+    fn to_date_time(self) -> NaiveDateTime {
+        self
+    }
+
+    fn from_date_time(value: NaiveDateTime) -> Self {
+        value
+    }
+}
+
+/// A date picker lets the user pick a date, a time, or both, depending on the
+/// type of the value.
+///
+/// ```
+/// # use finestra::*;
+/// use chrono::NaiveDate;
+///
+/// let departure = State::new(NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+///
+/// # let _: DatePicker<NaiveDate, ()> =
+/// DatePicker::new(&departure)
+///     .with_min(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())
+///     .with_on_change(|_, date, _| println!("Departing on {date}"));
+/// ```
+///
+/// This view is only available with the `chrono` feature.
+pub struct DatePicker<T, State> {
+    pub(crate) base: ViewBase,
+    pub(crate) value: crate::State<T>,
+    pub(crate) min: Option<T>,
+    pub(crate) max: Option<T>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<T: DateValue, State> DatePicker<T, State> {
+    /// Creates a new [`DatePicker`] without bounds. If the value is a
+    /// [`State`](crate::State), it is updated when the user picks a moment.
+    #[must_use]
+    pub fn new(value: impl Into<StateOrRaw<T>>) -> Self {
+        let value = match value.into() {
+            StateOrRaw::State(state) => state,
+            StateOrRaw::Raw(value) => crate::State::new(value),
+        };
+
+        Self {
+            base: ViewBase::default(),
+            value,
+            min: None,
+            max: None,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the user picked a moment.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, T, Window) + 'static) {
+        let value = self.value.clone();
+        self.event_handler_map.value_changed = Some(Rc::new(move |state, _, window| {
+            action(state, value.clone_inner(), window);
+        }));
+    }
+
+    /// Get notified when the user picked a moment.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, T, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+
+    /// Don't let the user pick a moment before `min`.
+    #[must_use]
+    pub fn with_min(mut self, min: T) -> Self {
+        self.set_min(min);
+        self
+    }
+
+    /// Don't let the user pick a moment before `min`.
+    pub fn set_min(&mut self, min: T) {
+        self.min = Some(min);
+    }
+
+    /// Don't let the user pick a moment after `max`.
+    #[must_use]
+    pub fn with_max(mut self, max: T) -> Self {
+        self.set_max(max);
+        self
+    }
+
+    /// Don't let the user pick a moment after `max`.
+    pub fn set_max(&mut self, max: T) {
+        self.max = Some(max);
+    }
+
+    /// Get the parts of the moment the user can pick.
+    #[must_use]
+    pub fn mode(&self) -> DatePickerMode {
+        T::MODE
+    }
+
+    fn input(&self, view_id: ViewId) -> DateInput<T> {
+        DateInput {
+            view_id,
+            value: self.value.clone(),
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+impl<T, State> BaseView for DatePicker<T, State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+/// Handles the moments the user picks, which is the same for every backend,
+/// and for the [`Calendar`](crate::Calendar).
+#[derive(Clone)]
+pub(crate) struct DateInput<T> {
+    pub(crate) view_id: ViewId,
+    pub(crate) value: crate::State<T>,
+    pub(crate) min: Option<T>,
+    pub(crate) max: Option<T>,
+}
+
+impl<T: DateValue> DateInput<T> {
+    /// Stores the picked `value`, moved into the bounds, and returns the new
+    /// value if it changed.
+    pub(crate) fn pick(&self, value: T) -> Option<T> {
+        let value = clamp_date(value, self.min, self.max);
+        if self.value.with(|current| *current == value) {
+            return None;
+        }
+
+        self.value.set_with_origin(value, StateChangeOrigin::Owner(self.view_id));
+        Some(value)
+    }
+
+    /// Parses the `text` typed by the user, which is in the format of
+    /// [`format_date()`].
+    #[cfg_attr(not(all(target_os = "linux", feature = "gtk")), allow(unused))]
+    #[must_use]
+    pub(crate) fn parse(&self, text: &str) -> Option<T> {
+        let text = text.trim();
+        let value = match T::MODE {
+            DatePickerMode::Date => NaiveDate::parse_from_str(text, pattern(T::MODE)).ok()?.to_date_time(),
+            DatePickerMode::Time => NaiveTime::parse_from_str(text, pattern(T::MODE)).ok()?.to_date_time(),
+            DatePickerMode::DateTime => NaiveDateTime::parse_from_str(text, pattern(T::MODE)).ok()?,
+        };
+        Some(T::from_date_time(value))
+    }
+}
+
+/// The format of the text a moment is displayed as, in the syntax of
+/// [`chrono::format::strftime`].
+const fn pattern(mode: DatePickerMode) -> &'static str {
+    match mode {
+        DatePickerMode::Date => "%Y-%m-%d",
+        DatePickerMode::Time => "%H:%M",
+        DatePickerMode::DateTime => "%Y-%m-%d %H:%M",
+    }
+}
+
+#[must_use]
+pub(crate) fn format_date<T: DateValue>(value: T) -> String {
+    value.to_date_time().format(pattern(T::MODE)).to_string()
+}
+
+/// Moves the `value` into the bounds.
+#[must_use]
+pub(crate) fn clamp_date<T: DateValue>(value: T, min: Option<T>, max: Option<T>) -> T {
+    match (min, max) {
+        (Some(min), _) if value < min => min,
+        (_, Some(max)) if value > max => max,
+        _ => value,
+    }
+}
+
+/// The number of seconds since the Unix epoch, as used by the platforms.
+#[must_use]
+pub(crate) fn to_timestamp<T: DateValue>(value: T) -> f64 {
+    value.to_date_time().and_utc().timestamp() as f64
+}
+
+#[must_use]
+pub(crate) fn from_timestamp<T: DateValue>(timestamp: f64) -> Option<T> {
+    let value = DateTime::from_timestamp(timestamp.floor() as i64, 0)?;
+    Some(T::from_date_time(value.naive_utc()))
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for DatePicker<T, State>
+        where T: DateValue, Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{Event, NSDatePicker};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let date_picker = NSDatePicker::new(T::MODE, false);
+        date_picker.set_bounds(self.min.map(to_timestamp), self.max.map(to_timestamp));
+        crate::platform::macos::state::attach_date_picker_state(id, self, &date_picker);
+
+        let input = self.input(id);
+        let dispatcher = tree.create_dispatcher();
+        date_picker.set_action(move |timestamp| {
+            let Some(value) = from_timestamp(timestamp).and_then(|value| input.pick(value)) else {
+                return;
+            };
+
+            dispatcher.dispatch_event(Event::ValueChanged(id, to_timestamp(value)));
+        });
+
+        date_picker.into()
+    }
+
+    /// Date pickers aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    /// GTK doesn't have a date picker, so the moment is typed in a
+    /// `GtkEntry`. When the moment has a date, a button next to the entry
+    /// opens a popover with a `GtkCalendar`.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{date_from_gtk, Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let container = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        container.add_css_class("linked");
+
+        let entry = gtk4::Entry::new();
+        entry.set_hexpand(true);
+        entry.set_placeholder_text(Some(&format_date(T::from_date_time(NaiveDateTime::default()))));
+        container.append(&entry);
+
+        let calendar = (T::MODE != DatePickerMode::Time).then(|| {
+            let calendar = gtk4::Calendar::new();
+            let popover = gtk4::Popover::new();
+            popover.set_child(Some(&calendar));
+
+            let button = gtk4::MenuButton::new();
+            button.set_icon_name("x-office-calendar-symbolic");
+            button.set_popover(Some(&popover));
+            container.append(&button);
+            calendar
+        });
+
+        let guard = SignalGuard::default();
+        let show = crate::platform::gtk::state::attach_date_picker_state(id, self, &container, &entry, calendar.as_ref(), &guard);
+
+        let input = self.input(id);
+        let dispatcher = tree.create_dispatcher();
+        let pick = Rc::new(move |value: Option<T>| {
+            if let Some(value) = value.and_then(|value| input.pick(value)) {
+                dispatcher.dispatch_event(Event::ValueChanged(id, to_timestamp(value)));
+            }
+
+            // Show the picked moment, or the previous one when the text was
+            // invalid.
+            input.value.with(&show);
+        });
+
+        let parse = self.input(id);
+        let entry_pick = Rc::clone(&pick);
+        entry.connect_activate(move |entry| entry_pick(parse.parse(&entry.text())));
+
+        if let Some(calendar) = calendar {
+            let value = self.value.clone();
+            calendar.connect_day_selected(move |calendar| {
+                if guard.is_active() {
+                    return;
+                }
+
+                // Keep the time of the moment, only the date is picked.
+                let time = value.clone_inner().to_date_time().time();
+                pick(date_from_gtk(&calendar.date()).map(|date| T::from_date_time(date.and_time(time))));
+            });
+        }
+
+        container.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let date_picker = HeadlessView::new(id, HeadlessViewKind::DatePicker(T::MODE));
+        crate::platform::headless::state::attach_date_picker_state(self, &date_picker);
+
+        let input = self.input(id);
+        let view = date_picker.clone();
+        date_picker.set_value_did_change(move |timestamp| {
+            let picked = from_timestamp(timestamp).and_then(|value| input.pick(value));
+            crate::platform::headless::state::show_date(&view, input.value.clone_inner());
+
+            if let Some(value) = picked {
+                HeadlessContext::dispatch(Event::ValueChanged(id, to_timestamp(value)));
+            }
+        });
+        date_picker
+    }
+}
+
+impl<T, Delegate, State> From<DatePicker<T, State>> for Box<dyn View<Delegate, State>>
+        where T: DateValue, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: DatePicker<T, State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Calendar, Stack, State};
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn clamp_to_bounds() {
+        let (min, max) = (Some(date(2024, 1, 1)), Some(date(2024, 12, 31)));
+        assert_eq!(clamp_date(date(2023, 6, 1), min, max), date(2024, 1, 1));
+        assert_eq!(clamp_date(date(2024, 6, 1), min, max), date(2024, 6, 1));
+        assert_eq!(clamp_date(date(2025, 6, 1), min, max), date(2024, 12, 31));
+        assert_eq!(clamp_date(date(2025, 6, 1), min, None), date(2025, 6, 1));
+    }
+
+    #[test]
+    fn timestamps_round_trip() {
+        let moment = date(2024, 2, 29).and_hms_opt(13, 37, 0).unwrap();
+        assert_eq!(from_timestamp(to_timestamp(moment)), Some(moment));
+        assert_eq!(from_timestamp(to_timestamp(moment)), Some(moment.date()));
+        assert_eq!(from_timestamp(to_timestamp(moment)), Some(moment.time()));
+        assert_eq!(to_timestamp(NaiveTime::from_hms_opt(1, 0, 0).unwrap()), 3600.0);
+    }
+
+    #[test]
+    fn format_and_parse() {
+        let input = DateInput {
+            view_id: ViewId(0),
+            value: crate::State::new(NaiveDateTime::default()),
+            min: None,
+            max: None,
+        };
+
+        let moment = date(2024, 7, 1).and_hms_opt(9, 30, 0).unwrap();
+        assert_eq!(format_date(moment), "2024-07-01 09:30");
+        assert_eq!(format_date(moment.time()), "09:30");
+        assert_eq!(input.parse(" 2024-07-01 09:30 "), Some(moment));
+        assert_eq!(input.parse("2024-07-01"), None);
+    }
+
+    fn launch() -> (ViewTestApp<NaiveDate>, State<NaiveDate>, State<NaiveTime>) {
+        let departure = State::new(date(2024, 7, 1));
+        let time = State::new(NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+        let app = TestApp::launch_view({
+            let (departure, time) = (departure.clone(), time.clone());
+            move || {
+                Stack::vertical()
+                    .with(DatePicker::new(&departure)
+                        .with_id("departure")
+                        .with_min(date(2024, 6, 1))
+                        .with_max(date(2024, 8, 31))
+                        .with_on_change(|departures: &mut Vec<NaiveDate>, date, _| departures.push(date)))
+                    .with(DatePicker::new(&time)
+                        .with_id("time")
+                        .with_max(NaiveTime::from_hms_opt(22, 0, 0).unwrap()))
+                    .with(Calendar::new(&departure)
+                        .with_id("calendar"))
+            }
+        });
+        (app, departure, time)
+    }
+
+    #[test]
+    fn date_binding() {
+        let (app, departure, time) = launch();
+        let picker = app.find_by_id("departure").unwrap();
+        assert_eq!(picker.text(), "2024-07-01");
+        assert_eq!(app.find_by_id("time").unwrap().text(), "09:30");
+
+        picker.pick_date(date(2024, 7, 14));
+        assert_eq!(departure.clone_inner(), date(2024, 7, 14));
+        assert_eq!(picker.text(), "2024-07-14");
+
+        // The date is moved into the bounds.
+        picker.pick_date(date(2025, 1, 1));
+        assert_eq!(picker.text(), "2024-08-31");
+        assert_eq!(app.events(), [date(2024, 7, 14), date(2024, 8, 31)]);
+
+        departure.set(date(2024, 6, 2));
+        assert_eq!(picker.text(), "2024-06-02");
+        assert_eq!(app.find_by_id("calendar").unwrap().text(), "2024-06-02");
+
+        let time_picker = app.find_by_id("time").unwrap();
+        time_picker.pick_date(NaiveTime::from_hms_opt(18, 5, 0).unwrap());
+        assert_eq!(time.clone_inner(), NaiveTime::from_hms_opt(18, 5, 0).unwrap());
+
+        time_picker.pick_date(NaiveTime::from_hms_opt(23, 15, 0).unwrap());
+        assert_eq!(time_picker.text(), "22:00");
+        assert_eq!(app.events().len(), 2);
+    }
+}
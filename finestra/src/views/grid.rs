@@ -0,0 +1,312 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::marker::PhantomData;
+
+use crate::{AppDelegate, CellAlignment, GridLayout, GridPlacement, GridTrack, Number, Padding, View, ViewBase};
+
+use super::base::BaseView;
+
+/// A grid places views in rows and columns, which makes the views line up,
+/// e.g. for forms with a column of labels and a column of fields.
+///
+/// ```
+/// # use finestra::*;
+/// # let _: Grid<(), ()> =
+/// Grid::new()
+///     .with_columns([GridTrack::Auto, GridTrack::Fraction(1.0)])
+///     .with(0, 0, Label::new("Name"))
+///     .with(0, 1, TextField::new(""))
+///     .with(1, 0, Label::new("Email"))
+///     .with(1, 1, TextField::new(""))
+///     .with_cell(GridCell::new(2, 0, Button::new("Submit"))
+///         .with_column_span(2)
+///         .with_horizontal_alignment(CellAlignment::End));
+/// ```
+pub struct Grid<State, Delegate> {
+    base: ViewBase,
+    _phantom: PhantomData<Delegate>,
+
+    pub(crate) layout: GridLayout,
+    pub(crate) children: Vec<Box<dyn View<Delegate, State>>>,
+}
+
+impl<State, Delegate> Grid<State, Delegate> {
+    /// Create an empty grid. Rows and columns that aren't defined using
+    /// [`Self::with_rows()`] and [`Self::with_columns()`] are
+    /// [`GridTrack::Auto`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            base: ViewBase::default(),
+            _phantom: PhantomData,
+
+            layout: GridLayout::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Define the sizes of the columns, from left to right.
+    #[must_use]
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = GridTrack>) -> Self {
+        self.set_columns(columns);
+        self
+    }
+
+    /// Define the sizes of the columns, from left to right.
+    pub fn set_columns(&mut self, columns: impl IntoIterator<Item = GridTrack>) {
+        self.layout.columns = columns.into_iter().collect();
+    }
+
+    /// Define the sizes of the rows, from top to bottom.
+    #[must_use]
+    pub fn with_rows(mut self, rows: impl IntoIterator<Item = GridTrack>) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    /// Define the sizes of the rows, from top to bottom.
+    pub fn set_rows(&mut self, rows: impl IntoIterator<Item = GridTrack>) {
+        self.layout.rows = rows.into_iter().collect();
+    }
+
+    /// Set the space between the rows and between the columns. The default is
+    /// `8.0`.
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: Number) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    /// Set the space between the rows and between the columns. The default is
+    /// `8.0`.
+    pub fn set_spacing(&mut self, spacing: Number) {
+        self.layout.row_spacing = spacing;
+        self.layout.column_spacing = spacing;
+    }
+
+    /// Set the space between the rows. The default is `8.0`.
+    #[must_use]
+    pub fn with_row_spacing(mut self, spacing: Number) -> Self {
+        self.set_row_spacing(spacing);
+        self
+    }
+
+    /// Set the space between the rows. The default is `8.0`.
+    pub fn set_row_spacing(&mut self, spacing: Number) {
+        self.layout.row_spacing = spacing;
+    }
+
+    /// Set the space between the columns. The default is `8.0`.
+    #[must_use]
+    pub fn with_column_spacing(mut self, spacing: Number) -> Self {
+        self.set_column_spacing(spacing);
+        self
+    }
+
+    /// Set the space between the columns. The default is `8.0`.
+    pub fn set_column_spacing(&mut self, spacing: Number) {
+        self.layout.column_spacing = spacing;
+    }
+
+    /// Set the space between the edges of the grid and its cells.
+    #[must_use]
+    pub fn with_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.set_padding(padding);
+        self
+    }
+
+    /// Set the space between the edges of the grid and its cells.
+    pub fn set_padding(&mut self, padding: impl Into<Padding>) {
+        self.layout.padding = padding.into();
+    }
+}
+
+impl<State: 'static, Delegate> Grid<State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Place a view in the cell at the given `row` and `column`, which start
+    /// at zero.
+    pub fn with(self, row: usize, column: usize, view: impl Into<Box<dyn View<Delegate, State>>>) -> Self {
+        self.with_cell(GridCell::new(row, column, view))
+    }
+
+    /// Place a view in a cell, where the cell can span multiple rows or
+    /// columns, and can specify the alignment of the view.
+    pub fn with_cell(mut self, cell: GridCell<State, Delegate>) -> Self {
+        self.layout.cells.push(cell.placement);
+        self.children.push(cell.view);
+        self
+    }
+}
+
+impl<State, Delegate> Default for Grid<State, Delegate> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State, Delegate> BaseView for Grid<State, Delegate> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+/// A view placed in a [`Grid`], see [`Grid::with_cell()`].
+pub struct GridCell<State, Delegate> {
+    placement: GridPlacement,
+    view: Box<dyn View<Delegate, State>>,
+}
+
+impl<State: 'static, Delegate> GridCell<State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Place the view in the cell at the given `row` and `column`, which
+    /// start at zero.
+    #[must_use]
+    pub fn new(row: usize, column: usize, view: impl Into<Box<dyn View<Delegate, State>>>) -> Self {
+        Self {
+            placement: GridPlacement::new(row, column),
+            view: view.into(),
+        }
+    }
+
+    /// Let the cell span multiple rows, downwards. The default is `1`.
+    #[must_use]
+    pub fn with_row_span(mut self, span: usize) -> Self {
+        self.placement.row_span = span.max(1);
+        self
+    }
+
+    /// Let the cell span multiple columns, to the right. The default is `1`.
+    #[must_use]
+    pub fn with_column_span(mut self, span: usize) -> Self {
+        self.placement.column_span = span.max(1);
+        self
+    }
+
+    /// Set how the view is positioned horizontally within the cell. The
+    /// default is [`CellAlignment::Fill`].
+    #[must_use]
+    pub fn with_horizontal_alignment(mut self, alignment: CellAlignment) -> Self {
+        self.placement.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Set how the view is positioned vertically within the cell. The default
+    /// is [`CellAlignment::Fill`].
+    #[must_use]
+    pub fn with_vertical_alignment(mut self, alignment: CellAlignment) -> Self {
+        self.placement.vertical_alignment = alignment;
+        self
+    }
+}
+
+impl<Delegate, State> From<Grid<State, Delegate>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Grid<State, Delegate>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Grid<State, Delegate>
+        where Delegate: 'static, State: 'static {
+    /// `NSGridView` lays out the views itself. It doesn't support fractional
+    /// tracks, which are sized to fit their views instead.
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::NSGridView;
+
+        let mut view = NSGridView::new(&self.layout);
+        for (child, cell) in self.children.iter_mut().zip(&self.layout.cells) {
+            let comp = child.build_native(tree);
+            view.add_view(cell, comp);
+        }
+
+        view.into()
+    }
+
+    /// Internal API: creates a native view (for Win32).
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinContainer, WinView, WinViewKind};
+        use crate::ContainerLayout;
+
+        let children = self.children.iter_mut()
+            .map(|child| child.build_native(tree, parent))
+            .collect();
+
+        let container = WinContainer {
+            layout: ContainerLayout::Grid(self.layout.clone()),
+            children,
+        };
+
+        WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Container(container))
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::ContainerLayoutManager;
+        use crate::ContainerLayout;
+
+        let view = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        view.set_layout_manager(Some(ContainerLayoutManager::new(ContainerLayout::Grid(self.layout.clone()))));
+
+        for child in &mut self.children {
+            let comp = child.build_native(tree);
+            view.append(comp.widget());
+        }
+
+        crate::platform::gtk::state::attach_base_state(self, &view);
+        view.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+        use crate::ContainerLayout;
+
+        let id = tree.exchange_events_for_id(Default::default());
+
+        let view = HeadlessView::new(id, HeadlessViewKind::Grid);
+        view.with_mut(|data| data.container = Some(ContainerLayout::Grid(self.layout.clone())));
+        for child in &mut self.children {
+            let comp = child.build_headless(tree);
+            view.add_child(comp);
+        }
+
+        crate::platform::headless::state::attach_base_state(self, &view);
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, GridTrack, Label, TextField};
+
+    use super::*;
+
+    #[test]
+    fn align_fields_after_longest_label() {
+        let app: ViewTestApp<()> = TestApp::launch_view(|| {
+            Grid::new()
+                .with_columns([GridTrack::Auto, GridTrack::Fraction(1.0)])
+                .with(0, 0, Label::new("Name"))
+                .with(0, 1, TextField::new("").with_id("name"))
+                .with(1, 0, Label::new("Address"))
+                .with(1, 1, TextField::new("").with_id("address"))
+        });
+        app.resize(400.0, 100.0);
+
+        assert_eq!(app.find_by_id("name").unwrap().frame(), euclid::rect(64.0, 0.0, 336.0, 24.0));
+        assert_eq!(app.find_by_id("address").unwrap().frame(), euclid::rect(64.0, 32.0, 336.0, 24.0));
+        assert_eq!(app.find_by_text("Name").unwrap().frame(), euclid::rect(0.0, 0.0, 56.0, 24.0));
+    }
+}
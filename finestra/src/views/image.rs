@@ -135,6 +135,43 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for ImageView<St
         use crate::platform::win32::view::{WinView, WinViewKind};
         WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Empty)
     }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::ImageKind;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        _ = tree.exchange_events_for_id(map);
+
+        let image_view = match self.image.kind() {
+            ImageKind::File(file) => gtk4::Picture::for_filename(file),
+            ImageKind::None => gtk4::Picture::new(),
+        };
+        image_view.set_size_request(200, 200);
+
+        crate::platform::gtk::state::attach_image_view_state(self, &image_view);
+        image_view.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+        use crate::ImageKind;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let image_view = HeadlessView::new(id, HeadlessViewKind::ImageView);
+        image_view.with_mut(|data| {
+            data.image = match self.image.kind() {
+                ImageKind::File(file) => Some(file.clone()),
+                ImageKind::None => None,
+            };
+        });
+
+        crate::platform::headless::state::attach_image_view_state(self, &image_view);
+        image_view
+    }
 }
 
 impl<Delegate, State> From<ImageView<State>> for Box<dyn View<Delegate, State>>
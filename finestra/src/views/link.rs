@@ -0,0 +1,234 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::{event::EventHandlerMap, AppContext, AppDelegate, StateOrRaw, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// A [`View`] that displays text in the [link color](crate::SystemColor::Link)
+/// and opens a URL in the default browser when it is clicked. The hand
+/// cursor is shown when the mouse hovers over the link.
+///
+/// ```
+/// # // This is usually used in a context where the `State` generic parameter
+/// # // is inferred by the compiler.
+/// # type Link = finestra::Link<()>;
+/// let link = Link::new("Finestra on GitHub")
+///     .with_url("https://github.com/usadson/finestra");
+/// ```
+///
+/// Instead of opening a URL, the link can also perform an action:
+/// ```
+/// # type Link = finestra::Link<()>;
+/// let link = Link::new("Show more")
+///     .with_on_click(|_, _| println!("Showing more"));
+/// ```
+///
+/// The way URLs are opened can be changed using
+/// [`App::with_url_opener()`](crate::App::with_url_opener).
+pub struct Link<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) text: StateOrRaw<String>,
+    pub(crate) url: Option<StateOrRaw<String>>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> Link<State> {
+    /// Creates a new [`Link`] with the associated string.
+    #[must_use]
+    pub fn new(text: impl Into<StateOrRaw<String>>) -> Self {
+        Self {
+            base: ViewBase::default(),
+            text: text.into(),
+            url: None,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Set the URL that is opened when the link is clicked.
+    #[must_use]
+    pub fn with_url(mut self, url: impl Into<StateOrRaw<String>>) -> Self {
+        self.set_url(url);
+        self
+    }
+
+    /// Set the URL that is opened when the link is clicked.
+    pub fn set_url(&mut self, url: impl Into<StateOrRaw<String>>) {
+        self.url = Some(url.into());
+    }
+
+    /// Get notified when the link is clicked, after its URL is opened.
+    pub fn set_on_click(&mut self, action: impl Fn(&mut State, Window) + 'static) {
+        self.event_handler_map.click = Some(Rc::new(action));
+    }
+
+    /// Get notified when the link is clicked, after its URL is opened.
+    #[must_use]
+    pub fn with_on_click(mut self, action: impl Fn(&mut State, Window) + 'static) -> Self {
+        self.set_on_click(action);
+        self
+    }
+
+    /// Opens the URL of the link, if it has one.
+    fn open_action(&self) -> impl Fn() + 'static {
+        let url = self.url.clone();
+        move || {
+            if let Some(url) = &url {
+                url.with(|url| AppContext::open_url(url));
+            }
+        }
+    }
+}
+
+impl<State> BaseView for Link<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Link<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{Event, NSLinkButton};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let link = NSLinkButton::new();
+        crate::platform::macos::state::attach_link_state(self, &link);
+
+        let open = self.open_action();
+        let dispatcher = tree.create_dispatcher();
+        link.set_action(move || {
+            open();
+            dispatcher.dispatch_event(Event::ButtonClicked(id));
+        });
+        link.into()
+    }
+
+    /// Links aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, ToGtk};
+        use crate::{Cursor, SystemCursor};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let link = gtk4::LinkButton::new("");
+        link.set_cursor_from_name(Some(Cursor::system(SystemCursor::Hand).to_gtk()));
+        crate::platform::gtk::state::attach_link_state(id, self, &link);
+
+        // The URL is opened by the app instead of GTK, so it can be replaced.
+        let open = self.open_action();
+        let dispatcher = tree.create_dispatcher();
+        link.connect_activate_link(move |_| {
+            open();
+            dispatcher.dispatch_event(Event::ButtonClicked(id));
+            gtk4::glib::Propagation::Stop
+        });
+        link.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let link = HeadlessView::new(id, HeadlessViewKind::Link);
+        crate::platform::headless::state::attach_link_state(self, &link);
+
+        let open = self.open_action();
+        link.set_action(move || {
+            open();
+            HeadlessContext::dispatch(Event::ButtonClicked(id));
+        });
+        link
+    }
+}
+
+impl<Delegate, State> From<Link<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Link<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::testing::TestApp;
+    use crate::{App, Color, Cursor, Stack, SystemColor, SystemCursor};
+
+    use super::*;
+
+    #[test]
+    fn open_url() {
+        let app = TestApp::launch_view(|| {
+            Stack::vertical()
+                .with(Link::new("Documentation")
+                    .with_url("https://docs.rs/finestra")
+                    .with_on_click(|clicks: &mut Vec<&str>, _| clicks.push("Documentation")))
+                .with(Link::new("Show more")
+                    .with_on_click(|clicks: &mut Vec<&str>, _| clicks.push("Show more")))
+        });
+        let link = app.find_by_text("Documentation").unwrap();
+        assert_eq!(link.text_color(), Color::system(SystemColor::Link));
+        assert_eq!(link.cursor(), Some(Cursor::system(SystemCursor::Hand)));
+        assert!(app.opened_urls().is_empty());
+
+        link.click();
+        assert_eq!(app.opened_urls(), ["https://docs.rs/finestra"]);
+        assert_eq!(app.events(), ["Documentation"]);
+
+        // Links without a URL only invoke their handler.
+        app.find_by_text("Show more").unwrap().click();
+        assert_eq!(app.opened_urls(), ["https://docs.rs/finestra"]);
+        assert_eq!(app.events(), ["Documentation", "Show more"]);
+    }
+
+    #[test]
+    fn use_url_opener() {
+        struct LinkApp;
+
+        impl AppDelegate for LinkApp {
+            fn make_content_view(&mut self, _: &mut (), _: Window) -> impl View<Self> {
+                Link::new("Documentation")
+                    .with_url("https://docs.rs/finestra")
+            }
+        }
+
+        let opened = Rc::new(RefCell::new(Vec::new()));
+        let app = TestApp::launch(App::new(LinkApp)
+            .with_url_opener({
+                let opened = Rc::clone(&opened);
+                move |url| opened.borrow_mut().push(url.to_owned())
+            }));
+
+        app.find_by_text("Documentation").unwrap().click();
+        assert_eq!(*opened.borrow(), ["https://docs.rs/finestra"]);
+        assert!(app.opened_urls().is_empty());
+    }
+}
@@ -0,0 +1,422 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::{AppDelegate, EventHandlerMap, ListSelection, RowBuilder, Rows, StackAlignment, StackDirection, StackLayout, StateChangeOrigin, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// A list displays a row for every item of a [`State<Vec<T>>`](crate::State).
+/// When the items change, only the rows of the items that changed are built
+/// again.
+///
+/// ```
+/// # use finestra::*;
+/// let fruits = State::new(vec!["Apple".to_string(), "Banana".to_string()]);
+/// let selected = State::new(None);
+///
+/// # let _: List<String, (), ()> =
+/// List::new(&fruits, |fruit: &String| Label::new(fruit.clone()))
+///     .with_selection(&selected)
+///     .with_on_activate(|_, row, _| println!("Opened row {row}"));
+///
+/// // Adds a single row to the list.
+/// let mut items = fruits.clone_inner();
+/// items.push("Cherry".to_string());
+/// fruits.set(items);
+/// ```
+pub struct List<T, State, Delegate> {
+    base: ViewBase,
+
+    pub(crate) items: crate::State<Vec<T>>,
+    pub(crate) row: RowBuilder<T, Delegate, State>,
+    pub(crate) selection: ListSelection,
+    pub(crate) event_handler_map: EventHandlerMap<State>,
+}
+
+impl<T, State: 'static, Delegate> List<T, State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Create a list that displays the `items`, using `row` to create the view
+    /// of every item.
+    #[must_use]
+    pub fn new<V>(items: &crate::State<Vec<T>>, row: impl Fn(&T) -> V + 'static) -> Self
+            where V: Into<Box<dyn View<Delegate, State>>> {
+        Self::with_row_builder(items, Rc::new(move |item| row(item).into()))
+    }
+
+    #[must_use]
+    pub(crate) fn with_row_builder(items: &crate::State<Vec<T>>, row: RowBuilder<T, Delegate, State>) -> Self {
+        Self {
+            base: ViewBase::default(),
+
+            items: items.clone(),
+            row,
+            selection: ListSelection::None,
+            event_handler_map: EventHandlerMap::default(),
+        }
+    }
+}
+
+impl<T, State, Delegate> List<T, State, Delegate> {
+    /// Let the user select a single row. The state contains the index of the
+    /// selected row, and can be changed to select another row.
+    #[must_use]
+    pub fn with_selection(mut self, selection: &crate::State<Option<usize>>) -> Self {
+        self.set_selection(selection);
+        self
+    }
+
+    /// Let the user select a single row. The state contains the index of the
+    /// selected row, and can be changed to select another row.
+    pub fn set_selection(&mut self, selection: &crate::State<Option<usize>>) {
+        self.selection = ListSelection::Single(selection.clone());
+    }
+
+    /// Let the user select multiple rows. The state contains the indices of
+    /// the selected rows, and can be changed to select other rows.
+    #[must_use]
+    pub fn with_multiple_selection(mut self, selection: &crate::State<Vec<usize>>) -> Self {
+        self.set_multiple_selection(selection);
+        self
+    }
+
+    /// Let the user select multiple rows. The state contains the indices of
+    /// the selected rows, and can be changed to select other rows.
+    pub fn set_multiple_selection(&mut self, selection: &crate::State<Vec<usize>>) {
+        self.selection = ListSelection::Multiple(selection.clone());
+    }
+
+    /// Get notified when the user activates a row, e.g. by double clicking it,
+    /// or by pressing the return key.
+    pub fn set_on_activate(&mut self, action: impl Fn(&mut State, usize, Window) + 'static) {
+        self.event_handler_map.activate = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user activates a row, e.g. by double clicking it,
+    /// or by pressing the return key.
+    #[must_use]
+    pub fn with_on_activate(mut self, action: impl Fn(&mut State, usize, Window) + 'static) -> Self {
+        self.set_on_activate(action);
+        self
+    }
+}
+
+impl<T, State, Delegate> BaseView for List<T, State, Delegate> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<T, Delegate, State> From<List<T, State, Delegate>> for Box<dyn View<Delegate, State>>
+        where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: List<T, State, Delegate>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// The rows are stacked vertically, and are as wide as the list.
+pub(super) fn row_layout() -> StackLayout {
+    StackLayout {
+        spacing: 0.0,
+        alignment: StackAlignment::Fill,
+        ..StackLayout::new(StackDirection::Vertical)
+    }
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for List<T, State, Delegate>
+        where T: Clone + PartialEq + 'static, Delegate: 'static, State: 'static {
+    /// The rows are displayed by an `NSTableView`, which only creates the
+    /// rows that are visible.
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::cacao_delegates::MacOSListViewDelegate;
+        use crate::platform::macos::{reload_rows, set_double_action};
+        use crate::platform::macos::state::Event;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let rows = Rows::new(&self.items);
+        let delegate = MacOSListViewDelegate::new(view_id, rows.clone(), self.row.clone(), tree.fork(), self.selection.clone());
+        let list_view = cacao::listview::ListView::with(delegate);
+
+        crate::platform::macos::state::attach_list_state(view_id, self, &list_view);
+
+        let dispatcher = tree.create_dispatcher();
+        set_double_action(&list_view.objc, move |row| {
+            dispatcher.dispatch_event(Event::ListRowActivated(view_id, row));
+        });
+
+        let objc = list_view.objc.clone();
+        rows.observe(&self.items, &self.selection, move |change| {
+            reload_rows(&objc, change);
+        });
+
+        list_view.into()
+    }
+
+    /// The rows are built once, and can't be selected on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinContainer, WinView, WinViewKind};
+        use crate::ContainerLayout;
+
+        let row = Rc::clone(&self.row);
+        let children = self.items.with(|items| {
+            items.iter()
+                .map(|item| row(item).build_native(tree, parent))
+                .collect()
+        });
+
+        let container = WinContainer {
+            layout: ContainerLayout::Stack(row_layout()),
+            children,
+        };
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Container(container))
+    }
+
+    /// The rows are displayed by a `GtkListView`, which only creates the rows
+    /// that are visible.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use std::cell::RefCell;
+        use std::collections::HashMap;
+
+        use gtk4::prelude::*;
+        use crate::ViewScope;
+        use crate::platform::gtk::{create_row_model, Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let guard = SignalGuard::default();
+        let rows = Rows::new(&self.items);
+        let selection_model = create_row_model(view_id, &rows, &self.items, &self.selection, &guard);
+
+        // The event handlers of a row are removed when its `ListItem` is
+        // unbound or torn down, as the row is built again when it is bound.
+        let factory = gtk4::SignalListItemFactory::new();
+        let row_tree = Rc::new(RefCell::new(tree.fork()));
+        let scopes: Rc<RefCell<HashMap<gtk4::ListItem, ViewScope>>> = Rc::default();
+        let remove_row = {
+            let (row_tree, scopes) = (Rc::clone(&row_tree), Rc::clone(&scopes));
+            move |item: &gtk4::ListItem| {
+                item.set_child(None::<&gtk4::Widget>);
+                if let Some(scope) = scopes.borrow_mut().remove(item) {
+                    row_tree.borrow().remove_scope(&scope);
+                }
+            }
+        };
+
+        let row = Rc::clone(&self.row);
+        let unbind_row = remove_row.clone();
+        factory.connect_bind(move |_, item| {
+            let item = item.downcast_ref::<gtk4::ListItem>().unwrap();
+            unbind_row(item);
+
+            let view = rows.with(|items| items.get(item.position() as usize).map(|item| row(item)));
+            if let Some(mut view) = view {
+                let (widget, scope) = row_tree.borrow_mut().scope(|tree| view.build_native(tree));
+                item.set_child(Some(widget.widget()));
+                scopes.borrow_mut().insert(item.clone(), scope);
+            }
+        });
+
+        let unbind_row = remove_row.clone();
+        factory.connect_unbind(move |_, item| {
+            unbind_row(item.downcast_ref::<gtk4::ListItem>().unwrap());
+        });
+        factory.connect_teardown(move |_, item| {
+            remove_row(item.downcast_ref::<gtk4::ListItem>().unwrap());
+        });
+
+        let list_view = gtk4::ListView::new(Some(selection_model.clone()), Some(factory));
+
+        let dispatcher = tree.create_dispatcher();
+        list_view.connect_activate(move |_, position| {
+            dispatcher.dispatch_event(Event::ListRowActivated(view_id, position as usize));
+        });
+
+        crate::platform::gtk::state::attach_list_state(view_id, self, &list_view, &selection_model, &guard);
+
+        let scrolled_window = gtk4::ScrolledWindow::new();
+        scrolled_window.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled_window.set_vexpand(true);
+        scrolled_window.set_child(Some(&list_view));
+        scrolled_window.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+        use crate::ContainerLayout;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let view = HeadlessView::new(view_id, HeadlessViewKind::List);
+        view.with_mut(|data| data.container = Some(ContainerLayout::Stack(row_layout())));
+
+        build_headless_rows(&view, &self.items, &self.row, &self.selection, tree);
+        crate::platform::headless::state::attach_list_state(self, &view);
+        view
+    }
+}
+
+/// Adds a row for every item to the `view`, and updates the rows and the
+/// selection when the items change.
+pub(super) fn build_headless_rows<T, Delegate, State>(
+    view: &crate::platform::headless::HeadlessView,
+    items: &crate::State<Vec<T>>,
+    row: &RowBuilder<T, Delegate, State>,
+    selection: &ListSelection,
+    tree: &mut crate::event::ViewTree<State>,
+)
+        where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    use std::cell::RefCell;
+
+    use crate::platform::headless::{Event, HeadlessContext};
+
+    let view_id = view.id();
+
+    // The event handlers of the rows are removed when the rows are removed.
+    let rows = Rows::new(items);
+    let mut scopes = Vec::new();
+    rows.with(|items| {
+        for item in items {
+            let (child, scope) = tree.scope(|tree| row(item).build_headless(tree));
+            view.add_child(child);
+            scopes.push(scope);
+        }
+    });
+
+    let selection_state = selection.clone();
+    view.set_selection_did_change(move |rows| {
+        selection_state.set_rows(rows, StateChangeOrigin::Owner(view_id));
+    });
+
+    view.set_row_activated(move |row| {
+        HeadlessContext::dispatch(Event::ListRowActivated(view_id, row));
+    });
+
+    let list = view.clone();
+    let row = Rc::clone(row);
+    let tree = RefCell::new(tree.fork());
+    let snapshot = rows.clone();
+    let scopes = RefCell::new(scopes);
+    rows.observe(items, selection, move |change| {
+        let mut tree = tree.borrow_mut();
+        let (children, added_scopes): (Vec<_>, Vec<_>) = snapshot.with(|items| {
+            items[change.start..change.start + change.inserted].iter()
+                .map(|item| tree.scope(|tree| row(item).build_headless(tree)))
+                .unzip()
+        });
+
+        let removed = change.start..change.start + change.removed;
+        for scope in scopes.borrow_mut().splice(removed.clone(), added_scopes) {
+            tree.remove_scope(&scope);
+        }
+
+        list.splice_children(removed, children);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, TestView, ViewTestApp};
+    use crate::{BaseViewExt, Button, Label, Stack, State};
+
+    use super::*;
+
+    fn launch(items: &State<Vec<String>>, selection: &State<Option<usize>>) -> ViewTestApp<usize> {
+        items.set(vec!["Wash".to_string(), "Cook".to_string()]);
+
+        let (items, selection) = (items.clone(), selection.clone());
+        TestApp::launch_view(move || {
+            List::new(&items, |item: &String| Label::new(item.clone()))
+                .with_id("todo")
+                .with_selection(&selection)
+                .with_on_activate(|opened: &mut Vec<usize>, row, _| opened.push(row))
+        })
+    }
+
+    #[test]
+    fn selection_and_activation() {
+        let selection = State::new(None);
+        let app = launch(&State::default(), &selection);
+        let list = app.find_by_id("todo").unwrap();
+        let texts = || list.children().iter().map(TestView::text).collect::<Vec<_>>();
+        assert_eq!(texts(), ["Wash", "Cook"]);
+
+        list.select_row(1);
+        assert_eq!(selection.clone_inner(), Some(1));
+
+        selection.set(Some(0));
+        assert_eq!(list.selected_rows(), [0]);
+
+        list.activate_row(1);
+        assert_eq!(app.events(), [1]);
+    }
+
+    #[test]
+    fn update_rows_incrementally() {
+        let (items, selection) = (State::default(), State::new(None));
+        let app = launch(&items, &selection);
+        let list = app.find_by_id("todo").unwrap();
+        list.select_row(1);
+
+        let wash = list.children()[0].clone();
+        items.set(vec!["Shop".to_string(), "Wash".to_string(), "Cook".to_string()]);
+        items.set(vec!["Shop".to_string(), "Wash".to_string(), "Cook".to_string(), "Clean".to_string()]);
+
+        let texts = list.children().iter().map(TestView::text).collect::<Vec<_>>();
+        assert_eq!(texts, ["Shop", "Wash", "Cook", "Clean"]);
+
+        // The selection follows the item.
+        assert_eq!(selection.clone_inner(), Some(2));
+        assert_eq!(list.selected_rows(), [2]);
+
+        // The row of "Wash" was moved instead of built again.
+        app.resize(200.0, 200.0);
+        assert_eq!(wash.frame(), euclid::rect(0.0, 20.0, 200.0, 20.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't select the rows")]
+    fn single_selection() {
+        let app = launch(&State::default(), &State::new(None));
+        app.find_by_id("todo").unwrap().select_rows(&[0, 1]);
+    }
+
+    #[test]
+    fn remove_events_of_replaced_rows() {
+        let items = State::new(vec![0, 1, 2]);
+        let app: ViewTestApp<()> = TestApp::launch_view({
+            let items = items.clone();
+            move || {
+                List::new(&items, |item: &i32| Stack::horizontal()
+                    .with(Label::new(item.to_string()))
+                    .with(Button::new("Remove")))
+            }
+        });
+        let count = app.event_handler_count();
+
+        for round in 1..50 {
+            items.set(vec![round, round + 1, round + 2]);
+        }
+        assert_eq!(app.event_handler_count(), count);
+
+        items.set(vec![7]);
+        assert!(app.event_handler_count() < count);
+    }
+}
@@ -3,23 +3,69 @@
 
 mod base;
 mod button;
+#[cfg(feature = "chrono")]
+mod calendar;
+mod canvas;
 mod checkbox;
+mod color_well;
+#[cfg(feature = "chrono")]
+mod date_picker;
+mod grid;
 mod image;
 mod label;
+mod link;
+mod list;
+mod progress_bar;
+mod radio_group;
+mod rows;
+mod scroll_view;
+mod search_field;
+mod select;
+mod slider;
 mod stack;
+mod stepper;
+mod switch;
+mod tab_view;
+mod table;
 mod text_block;
+mod text_editor;
 mod text_field;
+mod tree;
 
 use crate::AppDelegate;
 
 pub use self::base::*;
 pub use self::button::Button;
+#[cfg(feature = "chrono")]
+pub use self::calendar::Calendar;
+pub use self::canvas::Canvas;
 pub use self::checkbox::Checkbox;
+pub use self::color_well::ColorWell;
+#[cfg(feature = "chrono")]
+pub use self::date_picker::{DatePicker, DateValue};
+#[cfg(feature = "chrono")]
+pub(crate) use self::date_picker::{format_date, to_timestamp};
+pub use self::grid::{Grid, GridCell};
 pub use self::image::*;
 pub use self::label::Label;
+pub use self::link::Link;
+pub use self::list::List;
+pub use self::progress_bar::ProgressBar;
+pub use self::radio_group::{RadioGroup, RadioOption};
+pub(crate) use self::rows::*;
+pub use self::scroll_view::ScrollView;
+pub use self::search_field::SearchField;
+pub use self::select::Select;
+pub use self::slider::Slider;
 pub use self::stack::{Stack, StackDirection};
+pub use self::stepper::{Stepper, StepperValue};
+pub use self::switch::Switch;
+pub use self::tab_view::{Tab, TabView};
+pub use self::table::{Table, TableColumn};
 pub use self::text_block::TextBlock;
+pub use self::text_editor::TextEditor;
 pub use self::text_field::TextField;
+pub use self::tree::Tree;
 
 /// A generic graphical component.
 ///
@@ -38,6 +84,13 @@ pub trait View<Delegate, State=()>
         tree: &mut crate::event::ViewTree<State>,
         parent: windows::Win32::Foundation::HWND,
     ) -> crate::platform::win32::view::WinView;
+
+    /// Internal API: creates a native view (for GTK).
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView;
+
+    /// Internal API: creates an in-memory view (for the headless backend).
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView;
 }
 
 impl<Delegate, State: 'static> View<Delegate, State> for ()
@@ -58,4 +111,41 @@ impl<Delegate, State: 'static> View<Delegate, State> for ()
 
         WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Empty)
     }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, _tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        gtk4::Label::new(None).into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+
+        HeadlessView::new(tree.exchange_events_for_id(Default::default()), HeadlessViewKind::Empty)
+    }
+}
+
+impl<Delegate, State: 'static> View<Delegate, State> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        (**self).build_native(tree)
+    }
+
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        (**self).build_native(tree, parent)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        (**self).build_native(tree)
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        (**self).build_headless(tree)
+    }
 }
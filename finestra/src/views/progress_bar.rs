@@ -0,0 +1,182 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{event::EventHandlerMap, AppDelegate, ProgressStyle, StateOrRaw, View, ViewBase};
+
+use super::base::BaseView;
+
+/// A progress bar shows how much of a long-running task is done. The progress
+/// goes from `0.0` to `1.0`, and [`None`] means that the progress is unknown,
+/// which is shown using an animation.
+///
+/// ```
+/// # use finestra::*;
+/// let progress = State::new(None);
+///
+/// # let _: ProgressBar<()> =
+/// ProgressBar::new(&progress);
+///
+/// // Half of the task is done.
+/// progress.set(Some(0.5));
+/// ```
+pub struct ProgressBar<State=()> {
+    pub(crate) base: ViewBase,
+    pub(crate) value: StateOrRaw<Option<f64>>,
+    pub(crate) style: ProgressStyle,
+
+    #[allow(unused)]
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> ProgressBar<State> {
+    /// Creates a new [`ProgressBar`] that displays the progress.
+    #[must_use]
+    pub fn new(value: impl Into<StateOrRaw<Option<f64>>>) -> Self {
+        Self {
+            base: ViewBase::default(),
+            value: value.into(),
+            style: ProgressStyle::default(),
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Set the appearance of the progress bar.
+    #[must_use]
+    pub fn with_style(mut self, style: ProgressStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    /// Set the appearance of the progress bar.
+    pub fn set_style(&mut self, style: ProgressStyle) {
+        self.style = style;
+    }
+}
+
+impl<State> BaseView for ProgressBar<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for ProgressBar<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        let map = std::mem::take(&mut self.event_handler_map);
+        _ = tree.exchange_events_for_id(map);
+
+        let progress_indicator = cacao::progress::ProgressIndicator::new();
+        crate::platform::macos::state::attach_progress_bar_state(self, &progress_indicator);
+        progress_indicator.into()
+    }
+
+    /// Progress bars aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    /// GTK doesn't have a circular progress indicator that shows the
+    /// progress, so the circular style uses a `GtkSpinner` while the progress
+    /// is unknown, and a `GtkProgressBar` otherwise.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        _ = tree.exchange_events_for_id(map);
+
+        let progress_bar = gtk4::ProgressBar::new();
+
+        // The bar only moves when it is pulsed, for as long as it exists.
+        let bar = progress_bar.downgrade();
+        let initial = self.value.clone_inner();
+        let state = self.value.as_state();
+        gtk4::glib::timeout_add_local(std::time::Duration::from_millis(100), move || {
+            let Some(bar) = bar.upgrade() else {
+                return gtk4::glib::ControlFlow::Break;
+            };
+
+            if state.as_ref().map_or(initial, crate::State::clone_inner).is_none() {
+                bar.pulse();
+            }
+            gtk4::glib::ControlFlow::Continue
+        });
+
+        if self.style == ProgressStyle::Bar {
+            crate::platform::gtk::state::attach_progress_bar_state(self, &progress_bar, None);
+            return progress_bar.into();
+        }
+
+        let spinner = gtk4::Spinner::new();
+        let container = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        container.append(&spinner);
+        container.append(&progress_bar);
+
+        crate::platform::gtk::state::attach_progress_bar_state(self, &progress_bar, Some(&spinner));
+        container.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let progress_bar = HeadlessView::new(id, HeadlessViewKind::ProgressBar(self.style));
+        crate::platform::headless::state::attach_progress_bar_state(self, &progress_bar);
+        progress_bar
+    }
+}
+
+impl<Delegate, State> From<ProgressBar<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: ProgressBar<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Stack, State};
+
+    use super::*;
+
+    #[test]
+    fn follow_state() {
+        let progress = State::new(None);
+        let app: ViewTestApp<()> = TestApp::launch_view({
+            let progress = progress.clone();
+            move || {
+                Stack::vertical()
+                    .with(ProgressBar::new(&progress).with_id("bar"))
+                    .with(ProgressBar::new(Some(1.0))
+                        .with_id("circle")
+                        .with_style(ProgressStyle::Circular))
+            }
+        });
+
+        let bar = app.find_by_id("bar").unwrap();
+        assert_eq!(bar.progress(), None);
+
+        progress.set(Some(0.25));
+        assert_eq!(bar.progress(), Some(0.25));
+
+        app.resize(0.0, 0.0);
+        assert_eq!(bar.frame().size, euclid::size2(160.0, 16.0));
+        assert_eq!(app.find_by_id("circle").unwrap().frame().size, euclid::size2(32.0, 32.0));
+    }
+}
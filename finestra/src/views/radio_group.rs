@@ -0,0 +1,361 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::{event::EventHandlerMap, AppDelegate, Orientation, StackDirection, StackLayout, StateChangeOrigin, StateOrRaw, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// An option of a [`RadioGroup`], which is shown as a radio button.
+pub struct RadioOption<T> {
+    pub(crate) title: String,
+    pub(crate) value: T,
+    pub(crate) enabled: StateOrRaw<bool>,
+}
+
+impl<T> RadioOption<T> {
+    /// Creates a new [`RadioOption`] with the given title, that selects the
+    /// `value` when it is chosen.
+    #[must_use]
+    pub fn new(title: impl Into<String>, value: T) -> Self {
+        Self {
+            title: title.into(),
+            value,
+            enabled: StateOrRaw::Raw(true),
+        }
+    }
+
+    /// Set whether the user can choose this option.
+    #[must_use]
+    pub fn with_enabled(mut self, enabled: impl Into<StateOrRaw<bool>>) -> Self {
+        self.set_enabled(enabled);
+        self
+    }
+
+    /// Set whether the user can choose this option.
+    pub fn set_enabled(&mut self, enabled: impl Into<StateOrRaw<bool>>) {
+        self.enabled = enabled.into();
+    }
+}
+
+/// A group of radio buttons, of which the user can choose exactly one. The
+/// value of the chosen option is stored in a [`State`](crate::State).
+///
+/// ```
+/// # use finestra::*;
+/// #[derive(Clone, PartialEq)]
+/// enum Theme {
+///     Light,
+///     Dark,
+///     System,
+/// }
+///
+/// let theme = State::new(Theme::System);
+///
+/// # let _: RadioGroup<Theme, ()> =
+/// RadioGroup::new(&theme)
+///     .with_option(RadioOption::new("Light", Theme::Light))
+///     .with_option(RadioOption::new("Dark", Theme::Dark))
+///     .with_option(RadioOption::new("Same as System", Theme::System))
+///     .with_orientation(Orientation::Horizontal);
+/// ```
+pub struct RadioGroup<T, State> {
+    pub(crate) base: ViewBase,
+    pub(crate) options: Vec<RadioOption<T>>,
+    pub(crate) selection: crate::State<T>,
+    pub(crate) orientation: Orientation,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<T, State> RadioGroup<T, State>
+        where T: Clone + 'static {
+    /// Creates a new vertical [`RadioGroup`] without options. The `selection`
+    /// is updated when the user chooses an option, and the option with the
+    /// same value is chosen when the `selection` is changed.
+    #[must_use]
+    pub fn new(selection: &crate::State<T>) -> Self {
+        Self {
+            base: ViewBase::default(),
+            options: Vec::new(),
+            selection: selection.clone(),
+            orientation: Orientation::Vertical,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Add an option after the existing options.
+    #[must_use]
+    pub fn with_option(mut self, option: RadioOption<T>) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Add an option after the existing options.
+    pub fn add_option(&mut self, option: RadioOption<T>) {
+        self.options.push(option);
+    }
+
+    /// Set the direction the radio buttons are placed in.
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.set_orientation(orientation);
+        self
+    }
+
+    /// Set the direction the radio buttons are placed in.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    /// Get notified when the user chose an option.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, T, Window) + 'static) {
+        let selection = self.selection.clone();
+        self.event_handler_map.checked = Some(Rc::new(move |state, _, window| {
+            action(state, selection.clone_inner(), window);
+        }));
+    }
+
+    /// Get notified when the user chose an option.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, T, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+
+    fn layout(&self) -> StackLayout {
+        StackLayout::new(match self.orientation {
+            Orientation::Horizontal => StackDirection::Horizontal,
+            Orientation::Vertical => StackDirection::Vertical,
+        })
+    }
+}
+
+impl<T, State> BaseView for RadioGroup<T, State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for RadioGroup<T, State>
+        where T: PartialEq + Clone + 'static, Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use cacao::appkit::App;
+        use crate::platform::macos::{ButtonExtensions, Event, MacOSDelegate, NSButtonType, NSStackView};
+
+        let mut map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(EventHandlerMap::default());
+
+        // The actions of the buttons have to be `Send`, so they only send the
+        // index of the option, and the value is looked up on the main thread.
+        let values: Rc<[T]> = self.options.iter().map(|option| option.value.clone()).collect();
+        let selection = self.selection.clone();
+        let checked = map.checked.take();
+        map.selection_changed = Some(Rc::new(move |state, index, window| {
+            let Some(value) = index.and_then(|index| values.get(index)) else {
+                return;
+            };
+
+            if selection.with(|selected| selected == value) {
+                return;
+            }
+
+            selection.set_with_origin(value.clone(), StateChangeOrigin::Owner(id));
+            if let Some(checked) = &checked {
+                checked(state, true, window);
+            }
+        }));
+        tree.put_event_handlers_with_id(id, map);
+
+        let mut group = NSStackView::new(&self.layout());
+        let mut buttons = Vec::with_capacity(self.options.len());
+        for (index, option) in self.options.iter().enumerate() {
+            let mut button = cacao::button::Button::new(&option.title);
+            button.set_button_type(NSButtonType::Radio);
+            button.set_action(move || {
+                App::<MacOSDelegate<Delegate, State>, Event>::dispatch_main(Event::SelectionChanged(id, Some(index)));
+            });
+
+            buttons.push(button);
+        }
+
+        crate::platform::macos::state::attach_radio_group_state(id, self, &group, &buttons);
+        for button in buttons {
+            group.add_view(button.into());
+        }
+
+        group.into()
+    }
+
+    /// Radio groups aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard, ToGtk};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let group = gtk4::Box::new(self.orientation.to_gtk(), StackLayout::DEFAULT_SPACING as i32);
+        let buttons: Vec<_> = self.options.iter()
+            .map(|option| gtk4::CheckButton::with_label(&option.title))
+            .collect();
+
+        for button in &buttons {
+            group.append(button);
+        }
+        for button in buttons.iter().skip(1) {
+            button.set_group(buttons.first());
+        }
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_radio_group_state(id, self, &group, &buttons, &guard);
+
+        for (button, option) in buttons.iter().zip(&self.options) {
+            let guard = guard.clone();
+            let selection = self.selection.clone();
+            let value = option.value.clone();
+            let dispatcher = tree.create_dispatcher();
+
+            // The button that was chosen before is toggled off as well.
+            button.connect_toggled(move |button| {
+                if guard.is_active() || !button.is_active() {
+                    return;
+                }
+
+                if selection.with(|selected| *selected == value) {
+                    return;
+                }
+
+                selection.set_with_origin(value.clone(), StateChangeOrigin::Owner(id));
+                dispatcher.dispatch_event(Event::CheckboxChanged(id, true));
+            });
+        }
+
+        group.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let layout = self.layout();
+        let group = HeadlessView::new(id, HeadlessViewKind::Stack(layout.direction));
+        group.with_mut(|data| data.container = Some(crate::ContainerLayout::Stack(layout)));
+
+        let buttons: Vec<_> = self.options.iter()
+            .map(|_| HeadlessView::new(tree.exchange_events_for_id(Default::default()), HeadlessViewKind::RadioButton))
+            .collect();
+
+        for button in &buttons {
+            group.add_child(button.clone());
+        }
+
+        crate::platform::headless::state::attach_radio_group_state(self, &group, &buttons);
+
+        for (index, (button, option)) in buttons.iter().zip(&self.options).enumerate() {
+            let selection = self.selection.clone();
+            let value = option.value.clone();
+            let group = buttons.clone();
+            button.set_action(move || {
+                for (other, button) in group.iter().enumerate() {
+                    button.with_mut(|data| data.checked = other == index);
+                }
+
+                if selection.with(|selected| *selected == value) {
+                    return;
+                }
+
+                selection.set_with_origin(value.clone(), StateChangeOrigin::Owner(id));
+                HeadlessContext::dispatch(Event::CheckboxChanged(id, true));
+            });
+        }
+
+        group
+    }
+}
+
+impl<T, Delegate, State> From<RadioGroup<T, State>> for Box<dyn View<Delegate, State>>
+        where T: PartialEq + Clone + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: RadioGroup<T, State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, TestView};
+    use crate::{BaseViewExt, State};
+
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Shipping {
+        Standard,
+        Express,
+        Pickup,
+    }
+
+    #[test]
+    fn selection_binding() {
+        let shipping = State::new(Shipping::Standard);
+        let pickup_available = State::new(false);
+        let app = TestApp::launch_view({
+            let (shipping, pickup_available) = (shipping.clone(), pickup_available.clone());
+            move || {
+                RadioGroup::new(&shipping)
+                    .with_id("shipping")
+                    .with_option(RadioOption::new("Standard", Shipping::Standard))
+                    .with_option(RadioOption::new("Express", Shipping::Express))
+                    .with_option(RadioOption::new("Pick up in store", Shipping::Pickup)
+                        .with_enabled(&pickup_available))
+                    .with_on_change(|changes: &mut Vec<Shipping>, shipping, _| changes.push(shipping))
+            }
+        });
+
+        let options = app.find_by_id("shipping").unwrap().children();
+        let checked = || options.iter().map(TestView::is_checked).collect::<Vec<_>>();
+        assert_eq!(options.iter().map(TestView::text).collect::<Vec<_>>(), ["Standard", "Express", "Pick up in store"]);
+        assert_eq!(checked(), [true, false, false]);
+
+        options[1].choose();
+        assert_eq!(checked(), [false, true, false]);
+        assert_eq!(shipping.clone_inner(), Shipping::Express);
+
+        // Disabled options can't be chosen.
+        assert!(!options[2].is_enabled());
+        options[2].choose();
+        assert_eq!(shipping.clone_inner(), Shipping::Express);
+
+        pickup_available.set(true);
+        options[2].choose();
+        assert_eq!(shipping.clone_inner(), Shipping::Pickup);
+
+        // Choosing the chosen option again doesn't change the selection.
+        options[2].choose();
+        assert_eq!(app.events(), [Shipping::Express, Shipping::Pickup]);
+
+        shipping.set(Shipping::Standard);
+        assert_eq!(checked(), [true, false, false]);
+        assert_eq!(app.events().len(), 2);
+    }
+}
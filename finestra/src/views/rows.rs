@@ -0,0 +1,360 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The row model that is shared by the views that display a row per item, such
+//! as the [`List`](crate::List): the items are observed, and only the rows of
+//! the items that changed are updated.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{State, StateChangeOrigin, View};
+
+/// Creates the view of a row from its item.
+pub(crate) type RowBuilder<T, Delegate, State> = Rc<dyn Fn(&T) -> Box<dyn View<Delegate, State>>>;
+
+/// The rows that changed between two versions of the items: the `removed` rows
+/// starting at `start` were replaced by `inserted` rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RowChange {
+    pub(crate) start: usize,
+    pub(crate) removed: usize,
+    pub(crate) inserted: usize,
+}
+
+impl RowChange {
+    /// Compares the items, skipping the items at the start and the end that are
+    /// the same. Returns [`None`] if the items are the same.
+    #[must_use]
+    pub(crate) fn between<T: PartialEq>(old: &[T], new: &[T]) -> Option<Self> {
+        let start = old.iter()
+            .zip(new)
+            .take_while(|(old, new)| old == new)
+            .count();
+
+        if start == old.len() && start == new.len() {
+            return None;
+        }
+
+        let end = old[start..].iter().rev()
+            .zip(new[start..].iter().rev())
+            .take_while(|(old, new)| old == new)
+            .count();
+
+        Some(Self {
+            start,
+            removed: old.len() - start - end,
+            inserted: new.len() - start - end,
+        })
+    }
+
+    /// Moves the selected rows along with their items. Rows of which the item
+    /// was replaced stay selected, e.g. after editing the item, but rows that
+    /// were removed are deselected.
+    #[must_use]
+    pub(crate) fn update_selection(&self, rows: &[usize]) -> Vec<usize> {
+        rows.iter()
+            .filter_map(|&row| {
+                if row < self.start + self.removed.min(self.inserted) {
+                    Some(row)
+                } else if row >= self.start + self.removed {
+                    Some(row - self.removed + self.inserted)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// A copy of the items, which is compared to the new items when the
+/// [`State`] changes. The rows are built from this copy, as the listeners of
+/// a state can't access the state itself.
+pub(crate) struct Rows<T> {
+    items: Rc<RefCell<Vec<T>>>,
+}
+
+impl<T> Clone for Rows<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: Rc::clone(&self.items),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Rows<T> {
+    #[must_use]
+    pub(crate) fn new(items: &State<Vec<T>>) -> Self {
+        Self {
+            items: Rc::new(RefCell::new(items.clone_inner())),
+        }
+    }
+
+    #[must_use]
+    #[cfg_attr(not(any(target_os = "macos", all(target_os = "linux", feature = "gtk"))), allow(unused))]
+    pub(crate) fn len(&self) -> usize {
+        self.items.borrow().len()
+    }
+
+    pub(crate) fn with<F: FnOnce(&[T]) -> R, R>(&self, f: F) -> R {
+        f(&self.items.borrow())
+    }
+
+    /// Invokes `on_change` when the items change, after which the selection
+    /// is updated to follow the items that moved.
+    pub(crate) fn observe(&self, items: &State<Vec<T>>, selection: &ListSelection, on_change: impl Fn(RowChange) + 'static) {
+        let rows = self.clone();
+        let selection = selection.clone();
+        items.add_listener(move |items| {
+            let change = {
+                let mut rows = rows.items.borrow_mut();
+                let change = RowChange::between(&rows, items);
+                items.clone_into(&mut rows);
+                change
+            };
+
+            let Some(change) = change else {
+                return;
+            };
+
+            on_change(change);
+
+            // The native views deselect the rows that were replaced, so the
+            // selection is set again, even when it stayed the same.
+            let selected = selection.rows();
+            let updated = change.update_selection(&selected);
+            if updated != selected || (change.removed != 0 && !updated.is_empty()) {
+                selection.set_rows(updated, StateChangeOrigin::System);
+            }
+        });
+    }
+}
+
+/// The order in which a view displays the items, when it sorts its rows
+/// without reordering the items themselves, such as the
+/// [`Table`](crate::Table). Maps the rows to the items and back.
+#[derive(Clone, Default)]
+pub(crate) struct RowOrder {
+    items: Rc<RefCell<Vec<usize>>>,
+}
+
+impl RowOrder {
+    /// Set the indices of the items, in the order of the rows.
+    pub(crate) fn set(&self, items: Vec<usize>) {
+        *self.items.borrow_mut() = items;
+    }
+
+    /// Get the items in the order of the rows.
+    #[must_use]
+    pub(crate) fn apply<T: Clone>(&self, items: &[T]) -> Vec<T> {
+        self.items.borrow().iter()
+            .filter_map(|item| items.get(*item).cloned())
+            .collect()
+    }
+
+    /// Get the index of the item that is displayed by the `row`.
+    #[must_use]
+    pub(crate) fn item(&self, row: usize) -> Option<usize> {
+        self.items.borrow().get(row).copied()
+    }
+
+    /// Get the indices of the items that are displayed by the `rows`.
+    #[must_use]
+    pub(crate) fn items_of(&self, rows: &[usize]) -> Vec<usize> {
+        rows.iter().filter_map(|row| self.item(*row)).collect()
+    }
+
+    /// Get the rows that display the `items`.
+    #[must_use]
+    pub(crate) fn rows_of(&self, items: &[usize]) -> Vec<usize> {
+        let order = self.items.borrow();
+        items.iter()
+            .filter_map(|item| order.iter().position(|row_item| row_item == item))
+            .collect()
+    }
+}
+
+/// How many rows the user can select.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SelectionMode {
+    None,
+    Single,
+    Multiple,
+}
+
+/// The selected rows of a view, which are bound to a [`State`].
+#[derive(Clone, Default)]
+pub(crate) enum ListSelection {
+    #[default]
+    None,
+    Single(State<Option<usize>>),
+    Multiple(State<Vec<usize>>),
+}
+
+impl ListSelection {
+    #[must_use]
+    pub(crate) fn mode(&self) -> SelectionMode {
+        match self {
+            Self::None => SelectionMode::None,
+            Self::Single(..) => SelectionMode::Single,
+            Self::Multiple(..) => SelectionMode::Multiple,
+        }
+    }
+
+    /// Creates a selection of the same mode without selected rows, which
+    /// isn't bound to the state of this selection.
+    #[must_use]
+    pub(crate) fn empty_like(&self) -> Self {
+        match self {
+            Self::None => Self::None,
+            Self::Single(..) => Self::Single(State::new(None)),
+            Self::Multiple(..) => Self::Multiple(State::new(Vec::new())),
+        }
+    }
+
+    /// Get the selected rows, in ascending order.
+    #[must_use]
+    pub(crate) fn rows(&self) -> Vec<usize> {
+        match self {
+            Self::None => Vec::new(),
+            Self::Single(state) => state.clone_inner().into_iter().collect(),
+            Self::Multiple(state) => {
+                let mut rows = state.clone_inner();
+                rows.sort_unstable();
+                rows
+            }
+        }
+    }
+
+    /// Select the given rows. When a single row can be selected, only the
+    /// first row is selected.
+    pub(crate) fn set_rows(&self, mut rows: Vec<usize>, origin: StateChangeOrigin) {
+        match self {
+            Self::None => (),
+            Self::Single(state) => state.set_with_origin(rows.first().copied(), origin),
+            Self::Multiple(state) => {
+                rows.sort_unstable();
+                rows.dedup();
+                state.set_with_origin(rows, origin);
+            }
+        }
+    }
+
+    /// Select the given rows, unless exactly these rows are selected already.
+    pub(crate) fn update_rows(&self, rows: Vec<usize>, origin: StateChangeOrigin) {
+        let changed = match self {
+            Self::None => false,
+            Self::Single(state) => state.clone_inner() != rows.first().copied(),
+            Self::Multiple(..) => {
+                let mut sorted = rows.clone();
+                sorted.sort_unstable();
+                sorted.dedup();
+                self.rows() != sorted
+            }
+        };
+
+        if changed {
+            self.set_rows(rows, origin);
+        }
+    }
+
+    /// Invokes `listener` with the selected rows when the selection changes,
+    /// unless the change was made by `owner`.
+    pub(crate) fn add_listener(&self, listener: impl Fn(Vec<usize>) + 'static, owner: StateChangeOrigin) {
+        match self {
+            Self::None => (),
+            Self::Single(state) => state.add_listener_with_origin(move |row| {
+                listener(row.iter().copied().collect());
+            }, owner),
+            Self::Multiple(state) => state.add_listener_with_origin(move |rows| {
+                let mut rows = rows.clone();
+                rows.sort_unstable();
+                listener(rows);
+            }, owner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(start: usize, removed: usize, inserted: usize) -> Option<RowChange> {
+        Some(RowChange { start, removed, inserted })
+    }
+
+    #[test]
+    fn unchanged_items() {
+        assert_eq!(RowChange::between(&[1, 2, 3], &[1, 2, 3]), None);
+        assert_eq!(RowChange::between::<i32>(&[], &[]), None);
+    }
+
+    #[test]
+    fn appended_and_removed_items() {
+        assert_eq!(RowChange::between(&[1, 2], &[1, 2, 3, 4]), change(2, 0, 2));
+        assert_eq!(RowChange::between(&[1, 2, 3, 4], &[1, 2]), change(2, 2, 0));
+        assert_eq!(RowChange::between(&[1, 2], &[0, 1, 2]), change(0, 0, 1));
+        assert_eq!(RowChange::between(&[1, 2, 3], &[1, 3]), change(1, 1, 0));
+    }
+
+    #[test]
+    fn replaced_items() {
+        assert_eq!(RowChange::between(&[1, 2, 3], &[1, 5, 3]), change(1, 1, 1));
+        assert_eq!(RowChange::between(&[1, 2, 3], &[4, 5]), change(0, 3, 2));
+    }
+
+    #[test]
+    fn repeated_items() {
+        // The common suffix can't overlap with the common prefix.
+        assert_eq!(RowChange::between(&[1, 1], &[1, 1, 1]), change(2, 0, 1));
+        assert_eq!(RowChange::between(&[1, 1, 1], &[1]), change(1, 2, 0));
+    }
+
+    #[test]
+    fn selection_follows_items() {
+        let inserted = RowChange { start: 1, removed: 0, inserted: 2 };
+        assert_eq!(inserted.update_selection(&[0, 1, 4]), [0, 3, 6]);
+
+        let removed = RowChange { start: 1, removed: 2, inserted: 0 };
+        assert_eq!(removed.update_selection(&[0, 1, 2, 3]), [0, 1]);
+
+        let replaced = RowChange { start: 1, removed: 2, inserted: 1 };
+        assert_eq!(replaced.update_selection(&[1, 2, 3]), [1, 2]);
+    }
+
+    #[test]
+    fn observe_items() {
+        let items = State::new(vec!["a", "b", "c"]);
+        let selection = ListSelection::Single(State::new(Some(2)));
+        let changes = Rc::new(RefCell::new(Vec::new()));
+
+        let rows = Rows::new(&items);
+        rows.observe(&items, &selection, {
+            let changes = Rc::clone(&changes);
+            move |change| changes.borrow_mut().push(change)
+        });
+
+        items.set(vec!["z", "a", "b", "c"]);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(selection.rows(), [3]);
+
+        items.set(vec!["z", "a", "b"]);
+        assert_eq!(selection.rows(), []);
+        assert_eq!(*changes.borrow(), [
+            RowChange { start: 0, removed: 0, inserted: 1 },
+            RowChange { start: 3, removed: 1, inserted: 0 },
+        ]);
+    }
+
+    #[test]
+    fn order_maps_rows_to_items() {
+        let order = RowOrder::default();
+        order.set(vec![2, 0, 1]);
+
+        assert_eq!(order.apply(&["a", "b", "c"]), ["c", "a", "b"]);
+        assert_eq!(order.item(0), Some(2));
+        assert_eq!(order.item(3), None);
+        assert_eq!(order.items_of(&[0, 2]), [2, 1]);
+        assert_eq!(order.rows_of(&[0, 2, 5]), [1, 0]);
+    }
+}
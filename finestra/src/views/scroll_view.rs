@@ -0,0 +1,309 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{AppDelegate, StateChangeOrigin, StateOrRaw, View, ViewBase};
+
+use super::base::BaseView;
+
+/// A scroll view displays a view that is larger than the space available, and
+/// lets the user scroll to see the rest of it. By default, only vertical
+/// scrolling is enabled.
+///
+/// ```
+/// # use finestra::*;
+/// # let _: ScrollView<(), ()> =
+/// ScrollView::new(
+///     Stack::vertical()
+///         .with(Label::new("Terms and conditions"))
+///         .with(TextBlock::new("...").with_id("terms"))
+/// );
+/// ```
+///
+/// Use [`Window::scroll_to_view()`](crate::Window::scroll_to_view) to scroll
+/// a view with an identifier into view.
+///
+/// ## Platform support
+/// The [`UIBackend::Win32`](crate::UIBackend::Win32) doesn't support scrolling
+/// yet: the content is laid out in place of the scroll view, and is clipped
+/// by the window when it doesn't fit. The offsets aren't updated, and
+/// [`Window::scroll_to_view()`](crate::Window::scroll_to_view) has no effect.
+pub struct ScrollView<State, Delegate> {
+    base: ViewBase,
+
+    pub(crate) content: Box<dyn View<Delegate, State>>,
+    pub(crate) horizontal: bool,
+    pub(crate) vertical: bool,
+    pub(crate) horizontal_offset: StateOrRaw<f32>,
+    pub(crate) vertical_offset: StateOrRaw<f32>,
+}
+
+impl<State: 'static, Delegate> ScrollView<State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Create a scroll view that displays the given `content`.
+    #[must_use]
+    pub fn new(content: impl Into<Box<dyn View<Delegate, State>>>) -> Self {
+        Self {
+            base: ViewBase::default(),
+
+            content: content.into(),
+            horizontal: false,
+            vertical: true,
+            horizontal_offset: StateOrRaw::default(),
+            vertical_offset: StateOrRaw::default(),
+        }
+    }
+}
+
+impl<State, Delegate> ScrollView<State, Delegate> {
+    /// Allow the user to scroll horizontally. When disabled, the content is
+    /// as wide as the scroll view. The default is `false`.
+    #[must_use]
+    pub fn with_horizontal_scrolling(mut self, enabled: bool) -> Self {
+        self.set_horizontal_scrolling(enabled);
+        self
+    }
+
+    /// Allow the user to scroll horizontally. When disabled, the content is
+    /// as wide as the scroll view. The default is `false`.
+    pub fn set_horizontal_scrolling(&mut self, enabled: bool) {
+        self.horizontal = enabled;
+    }
+
+    /// Allow the user to scroll vertically. When disabled, the content is as
+    /// high as the scroll view. The default is `true`.
+    #[must_use]
+    pub fn with_vertical_scrolling(mut self, enabled: bool) -> Self {
+        self.set_vertical_scrolling(enabled);
+        self
+    }
+
+    /// Allow the user to scroll vertically. When disabled, the content is as
+    /// high as the scroll view. The default is `true`.
+    pub fn set_vertical_scrolling(&mut self, enabled: bool) {
+        self.vertical = enabled;
+    }
+
+    /// Set the distance the content is scrolled from the left. When this is a
+    /// [`State`](crate::State), it is updated when the user scrolls.
+    #[must_use]
+    pub fn with_horizontal_offset(mut self, offset: impl Into<StateOrRaw<f32>>) -> Self {
+        self.set_horizontal_offset(offset);
+        self
+    }
+
+    /// Set the distance the content is scrolled from the left. When this is a
+    /// [`State`](crate::State), it is updated when the user scrolls.
+    pub fn set_horizontal_offset(&mut self, offset: impl Into<StateOrRaw<f32>>) {
+        self.horizontal_offset = offset.into();
+    }
+
+    /// Set the distance the content is scrolled from the top. When this is a
+    /// [`State`](crate::State), it is updated when the user scrolls.
+    ///
+    /// ```
+    /// # use finestra::*;
+    /// let offset = State::new(0.0);
+    ///
+    /// # let _: ScrollView<(), ()> =
+    /// ScrollView::new(Label::new("Content"))
+    ///     .with_vertical_offset(&offset);
+    ///
+    /// // Scroll back to the top.
+    /// offset.set(0.0);
+    /// ```
+    #[must_use]
+    pub fn with_vertical_offset(mut self, offset: impl Into<StateOrRaw<f32>>) -> Self {
+        self.set_vertical_offset(offset);
+        self
+    }
+
+    /// Set the distance the content is scrolled from the top. When this is a
+    /// [`State`](crate::State), it is updated when the user scrolls.
+    pub fn set_vertical_offset(&mut self, offset: impl Into<StateOrRaw<f32>>) {
+        self.vertical_offset = offset.into();
+    }
+}
+
+impl<State, Delegate> BaseView for ScrollView<State, Delegate> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate, State> From<ScrollView<State, Delegate>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: ScrollView<State, Delegate>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for ScrollView<State, Delegate>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{observe_scroll_offset, set_document_view};
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let scroll_view = cacao::scrollview::ScrollView::new();
+        let content = self.content.build_native(tree);
+        set_document_view(&scroll_view, &content, self.horizontal, self.vertical);
+
+        crate::platform::macos::state::attach_scroll_view_state(view_id, self, &scroll_view);
+
+        let horizontal = self.horizontal_offset.as_state();
+        let vertical = self.vertical_offset.as_state();
+        observe_scroll_offset(&scroll_view.objc, move |offset| {
+            if let Some(horizontal) = &horizontal {
+                horizontal.set_with_origin(offset.x as f32, StateChangeOrigin::Owner(view_id));
+            }
+
+            if let Some(vertical) = &vertical {
+                vertical.set_with_origin(offset.y as f32, StateChangeOrigin::Owner(view_id));
+            }
+        });
+
+        // Dropping the wrapper would remove the document view from the scroll
+        // view, which retains it from now on.
+        std::mem::forget(content);
+        scroll_view.into()
+    }
+
+    /// Scrolling isn't supported on Win32 yet, see the documentation of the
+    /// type.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        self.content.build_native(tree, parent)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use gtk4::PolicyType;
+        use crate::platform::gtk::SignalGuard;
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let policy = |enabled| if enabled { PolicyType::Automatic } else { PolicyType::Never };
+
+        let scrolled_window = gtk4::ScrolledWindow::new();
+        scrolled_window.set_policy(policy(self.horizontal), policy(self.vertical));
+        scrolled_window.set_hexpand(self.horizontal);
+        scrolled_window.set_vexpand(self.vertical);
+
+        let content = self.content.build_native(tree);
+        scrolled_window.set_child(Some(content.widget()));
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_scroll_view_state(view_id, self, &scrolled_window, &guard);
+
+        for (adjustment, offset) in [
+            (scrolled_window.hadjustment(), self.horizontal_offset.as_state()),
+            (scrolled_window.vadjustment(), self.vertical_offset.as_state()),
+        ] {
+            let Some(offset) = offset else {
+                continue;
+            };
+
+            let guard = guard.clone();
+            adjustment.connect_value_changed(move |adjustment| {
+                if !guard.is_active() {
+                    offset.set_with_origin(adjustment.value() as f32, StateChangeOrigin::Owner(view_id));
+                }
+            });
+        }
+
+        scrolled_window.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+        use crate::{ContainerLayout, ScrollLayout};
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let view = HeadlessView::new(view_id, HeadlessViewKind::ScrollView);
+        view.with_mut(|data| {
+            data.container = Some(ContainerLayout::Scroll(ScrollLayout {
+                horizontal: self.horizontal,
+                vertical: self.vertical,
+                ..Default::default()
+            }));
+        });
+        view.add_child(self.content.build_headless(tree));
+
+        crate::platform::headless::state::attach_scroll_view_state(self, &view);
+
+        let horizontal = self.horizontal_offset.as_state();
+        let vertical = self.vertical_offset.as_state();
+        view.set_scroll_did_change(move |offset| {
+            if let Some(horizontal) = &horizontal {
+                horizontal.set_with_origin(offset.x, StateChangeOrigin::Owner(view_id));
+            }
+
+            if let Some(vertical) = &vertical {
+                vertical.set_with_origin(offset.y, StateChangeOrigin::Owner(view_id));
+            }
+        });
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Button, Label, Stack, StackAlignment, State};
+
+    use super::*;
+
+    #[test]
+    fn scroll_offset_binding() {
+        let offset = State::new(0.0);
+        let app: ViewTestApp<()> = TestApp::launch_view({
+            let offset = offset.clone();
+            move || {
+                let mut stack = Stack::vertical()
+                    .with_spacing(0.0)
+                    .with_alignment(StackAlignment::Fill);
+                for i in 0..20 {
+                    stack = stack.with(Label::new(format!("Item {i}")).with_id(format!("item-{i}")));
+                }
+
+                ScrollView::new(stack.with(Button::new("Top").with_id("top").with_on_click(|_, window| {
+                    window.scroll_to_view("item-0");
+                })))
+                    .with_vertical_offset(&offset)
+            }
+        });
+        app.resize(100.0, 100.0);
+
+        // The items are 20 points high, so the content is 428 points high.
+        let scroll_view = app.root();
+        assert_eq!(scroll_view.children()[0].frame(), euclid::rect(0.0, 0.0, 100.0, 428.0));
+
+        scroll_view.scroll_to(0.0, 50.0);
+        assert_eq!(offset.clone_inner(), 50.0);
+        assert_eq!(app.find_by_id("item-3").unwrap().frame(), euclid::rect(0.0, 10.0, 100.0, 20.0));
+
+        // The user can't scroll past the content.
+        scroll_view.scroll_to(0.0, 1000.0);
+        assert_eq!(offset.clone_inner(), 328.0);
+
+        offset.set(100.0);
+        app.resize(100.0, 100.0);
+        assert_eq!(scroll_view.scroll_offset(), euclid::vec2(0.0, 100.0));
+        assert_eq!(app.find_by_id("item-5").unwrap().frame(), euclid::rect(0.0, 0.0, 100.0, 20.0));
+
+        app.find_by_id("top").unwrap().click();
+        assert_eq!(offset.clone_inner(), 0.0);
+        assert_eq!(app.find_by_id("item-0").unwrap().frame(), euclid::rect(0.0, 0.0, 100.0, 20.0));
+    }
+}
@@ -0,0 +1,473 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::{event::EventHandlerMap, AppDelegate, StateChangeOrigin, StateOrRaw, Timer, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// A search field is a [`TextField`](crate::TextField) for entering a search
+/// query. It has a button that clears the query, and it can remember the
+/// recent searches.
+///
+/// The search is started when the user presses the return key, or when the
+/// user stopped typing for the [debounce](Self::with_debounce) interval.
+///
+/// ```
+/// # use finestra::*;
+/// use std::time::Duration;
+///
+/// let query = TextValue::default();
+/// let recent = State::new(Vec::new());
+///
+/// # let _: SearchField<()> =
+/// SearchField::new(&query)
+///     .with_debounce(Duration::from_millis(300))
+///     .with_history(&recent)
+///     .with_on_search(|_, query, _| println!("Searching for {query}"));
+/// ```
+pub struct SearchField<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) text: StateOrRaw<String>,
+    pub(crate) placeholder_text: StateOrRaw<String>,
+    pub(crate) debounce: Option<Duration>,
+    pub(crate) history: Option<crate::State<Vec<String>>>,
+    pub(crate) history_limit: usize,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> SearchField<State> {
+    /// Creates a new [`SearchField`] with an associated string that contains
+    /// the initial query. If the value is a [`TextValue`](crate::TextValue),
+    /// this value will be updated if the query changes.
+    #[must_use]
+    pub fn new(text: impl Into<StateOrRaw<String>>) -> Self {
+        Self {
+            base: ViewBase::default(),
+            text: text.into(),
+            placeholder_text: StateOrRaw::Raw("Search".into()),
+            debounce: None,
+            history: None,
+            history_limit: 10,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the user searches, with the query. Clearing the
+    /// field searches for the empty query.
+    pub fn set_on_search(&mut self, action: impl Fn(&mut State, String, Window) + 'static) {
+        self.event_handler_map.text_changed = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user searches, with the query. Clearing the
+    /// field searches for the empty query.
+    #[must_use]
+    pub fn with_on_search(mut self, action: impl Fn(&mut State, String, Window) + 'static) -> Self {
+        self.set_on_search(action);
+        self
+    }
+
+    /// Set the placeholder of the field, which is displayed when the field is
+    /// empty. This is "Search" by default.
+    #[must_use]
+    pub fn with_placeholder(mut self, placeholder: impl Into<StateOrRaw<String>>) -> Self {
+        self.set_placeholder(placeholder);
+        self
+    }
+
+    /// Set the placeholder of the field, which is displayed when the field is
+    /// empty. This is "Search" by default.
+    pub fn set_placeholder(&mut self, placeholder: impl Into<StateOrRaw<String>>) {
+        self.placeholder_text = placeholder.into();
+    }
+
+    /// Search when the user stopped typing for the `interval`, in addition to
+    /// when the return key is pressed. By default, the field only searches
+    /// when the return key is pressed.
+    #[must_use]
+    pub fn with_debounce(mut self, interval: Duration) -> Self {
+        self.set_debounce(interval);
+        self
+    }
+
+    /// Search when the user stopped typing for the `interval`, in addition to
+    /// when the return key is pressed. By default, the field only searches
+    /// when the return key is pressed.
+    pub fn set_debounce(&mut self, interval: Duration) {
+        self.debounce = Some(interval);
+    }
+
+    /// Remember the recent searches in the `history`, with the most recent
+    /// search first. The field offers them as suggestions.
+    #[must_use]
+    pub fn with_history(mut self, history: &crate::State<Vec<String>>) -> Self {
+        self.set_history(history);
+        self
+    }
+
+    /// Remember the recent searches in the `history`, with the most recent
+    /// search first. The field offers them as suggestions.
+    pub fn set_history(&mut self, history: &crate::State<Vec<String>>) {
+        self.history = Some(history.clone());
+    }
+
+    /// Set the number of recent searches that are remembered in the
+    /// [history](Self::with_history), which is 10 by default.
+    #[must_use]
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.set_history_limit(limit);
+        self
+    }
+
+    /// Set the number of recent searches that are remembered in the
+    /// [history](Self::with_history), which is 10 by default.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+    }
+
+    /// Creates the function that searches for a query. It cancels the pending
+    /// debounced search, remembers the query and dispatches the event using
+    /// `dispatch`.
+    fn search_action(&self, debouncer: &Debouncer, dispatch: impl Fn(String) + 'static) -> Rc<dyn Fn(String)> {
+        let debouncer = debouncer.clone();
+        let history = self.history.clone();
+        let limit = self.history_limit;
+
+        Rc::new(move |query: String| {
+            debouncer.cancel();
+
+            if let Some(history) = &history {
+                if !query.is_empty() && history.with(|recent| recent.first() != Some(&query)) {
+                    let mut recent = history.clone_inner();
+                    remember(&mut recent, &query, limit);
+                    history.set(recent);
+                }
+            }
+
+            dispatch(query);
+        })
+    }
+}
+
+/// Moves the `query` to the front of the `recent` searches, and forgets the
+/// searches beyond the `limit`.
+fn remember(recent: &mut Vec<String>, query: &str, limit: usize) {
+    recent.retain(|search| search != query);
+    recent.insert(0, query.to_owned());
+    recent.truncate(limit);
+}
+
+/// Invokes an action after the text didn't change for the debounce interval.
+/// Every change cancels the previously scheduled action, as [`Timer`]s can't
+/// be cancelled themselves.
+#[derive(Clone)]
+struct Debouncer {
+    interval: Option<Duration>,
+    generation: Rc<Cell<usize>>,
+}
+
+impl Debouncer {
+    fn new(interval: Option<Duration>) -> Self {
+        Self {
+            interval,
+            generation: Rc::new(Cell::new(0)),
+        }
+    }
+
+    fn cancel(&self) {
+        self.generation.set(self.generation.get() + 1);
+    }
+
+    fn schedule(&self, action: impl FnOnce() + 'static) {
+        self.cancel();
+
+        let Some(interval) = self.interval else {
+            return;
+        };
+
+        let generation = Rc::clone(&self.generation);
+        let scheduled = generation.get();
+        Timer::delayed_action(interval, move || {
+            if generation.get() == scheduled {
+                action();
+            }
+        }).schedule_local();
+    }
+}
+
+impl<State> BaseView for SearchField<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for SearchField<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{Event, NSSearchField};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let search_field = NSSearchField::new(self.history.is_some());
+        crate::platform::macos::state::attach_search_field_state(view_id, self, &search_field);
+
+        let debouncer = Debouncer::new(self.debounce);
+        let dispatcher = tree.create_dispatcher();
+        let search = self.search_action(&debouncer, move |query| {
+            dispatcher.dispatch_event(Event::TextFieldChanged(view_id, query.into()));
+        });
+
+        let text = self.text.as_state();
+        let text_search = Rc::clone(&search);
+        search_field.set_text_did_change(move |value| {
+            if let Some(text) = &text {
+                text.set_with_origin(value.clone(), StateChangeOrigin::Owner(view_id));
+            }
+
+            let search = Rc::clone(&text_search);
+            debouncer.schedule(move || search(value));
+        });
+
+        // The action is sent when the return key is pressed, and when the
+        // field is cleared.
+        search_field.set_search(move |value| search(value));
+
+        search_field.into()
+    }
+
+    /// Search fields aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let entry = gtk4::Entry::new();
+        self.text.with(|text| entry.set_text(text));
+        entry.set_icon_from_icon_name(gtk4::EntryIconPosition::Primary, Some("system-search-symbolic"));
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_search_field_state(view_id, self, &entry, &guard);
+
+        let debouncer = Debouncer::new(self.debounce);
+        let dispatcher = tree.create_dispatcher();
+        let search = self.search_action(&debouncer, move |query| {
+            dispatcher.dispatch_event(Event::TextFieldChanged(view_id, query.into()));
+        });
+
+        let text = self.text.as_state();
+        let text_search = Rc::clone(&search);
+        entry.connect_changed(move |entry| {
+            if guard.is_active() {
+                return;
+            }
+
+            let value = entry.text().to_string();
+            if let Some(text) = &text {
+                text.set_with_origin(value.clone(), StateChangeOrigin::Owner(view_id));
+            }
+
+            let search = Rc::clone(&text_search);
+            debouncer.schedule(move || search(value));
+        });
+
+        let activate_search = Rc::clone(&search);
+        entry.connect_activate(move |entry| activate_search(entry.text().to_string()));
+
+        // Clearing the text invokes the change handler, which is cancelled by
+        // searching immediately.
+        entry.connect_icon_release(move |entry, position| {
+            if position == gtk4::EntryIconPosition::Secondary {
+                entry.set_text("");
+                search(String::new());
+            }
+        });
+
+        entry.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+        use crate::Key;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let search_field = HeadlessView::new(view_id, HeadlessViewKind::SearchField);
+        crate::platform::headless::state::attach_search_field_state(self, &search_field);
+
+        let debouncer = Debouncer::new(self.debounce);
+        let search = self.search_action(&debouncer, move |query| {
+            HeadlessContext::dispatch(Event::TextFieldChanged(view_id, query.into()));
+        });
+
+        let text = self.text.as_state();
+        let text_search = Rc::clone(&search);
+        let set_text = move |value: &str| {
+            if let Some(text) = &text {
+                text.set_with_origin(value.to_owned(), StateChangeOrigin::Owner(view_id));
+            }
+        };
+
+        let change_text = set_text.clone();
+        search_field.set_text_did_change(move |value| {
+            change_text(value);
+
+            let search = Rc::clone(&text_search);
+            let value = value.to_owned();
+            debouncer.schedule(move || search(value));
+        });
+
+        let view = search_field.clone();
+        let key_search = Rc::clone(&search);
+        search_field.set_key_pressed(move |key| {
+            if key != Key::Return {
+                return false;
+            }
+
+            key_search(view.with(|data| data.text.clone()));
+            true
+        });
+
+        // The action is the clear button.
+        let view = search_field.clone();
+        search_field.set_action(move || {
+            view.with_mut(|data| data.text.clear());
+            set_text("");
+            search(String::new());
+        });
+
+        search_field
+    }
+}
+
+impl<Delegate, State> From<SearchField<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: SearchField<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{Key, State, TextValue};
+
+    use super::*;
+
+    #[test]
+    fn remember_recent_searches() {
+        let mut recent = Vec::new();
+        remember(&mut recent, "rust", 3);
+        remember(&mut recent, "gtk", 3);
+        remember(&mut recent, "cocoa", 3);
+        assert_eq!(recent, ["cocoa", "gtk", "rust"]);
+
+        remember(&mut recent, "rust", 3);
+        assert_eq!(recent, ["rust", "cocoa", "gtk"]);
+
+        remember(&mut recent, "win32", 3);
+        assert_eq!(recent, ["win32", "rust", "cocoa"]);
+    }
+
+    fn launch() -> (ViewTestApp<String>, TextValue, State<Vec<String>>) {
+        let query = TextValue::default();
+        let recent = State::new(Vec::new());
+        let app = TestApp::launch_view({
+            let (query, recent) = (query.clone(), recent.clone());
+            move || {
+                SearchField::new(&query)
+                    .with_debounce(Duration::from_millis(300))
+                    .with_history(&recent)
+                    .with_history_limit(2)
+                    .with_on_search(|searches: &mut Vec<String>, query, _| searches.push(query))
+            }
+        });
+        (app, query, recent)
+    }
+
+    #[test]
+    fn debounce_searches() {
+        let (app, query, _) = launch();
+        let field = app.root();
+        assert_eq!(field.placeholder(), "Search");
+
+        field.type_text("ru");
+        app.advance_time(Duration::from_millis(200));
+        field.type_text("st");
+        app.advance_time(Duration::from_millis(299));
+        assert_eq!(query.clone_inner(), "rust");
+        assert!(app.events().is_empty());
+
+        app.advance_time(Duration::from_millis(1));
+        assert_eq!(app.events(), ["rust"]);
+
+        // The debounced search doesn't fire again.
+        app.advance_time(Duration::from_secs(1));
+        assert_eq!(app.events().len(), 1);
+    }
+
+    #[test]
+    fn search_on_return() {
+        let (app, _, recent) = launch();
+        let field = app.root();
+
+        field.type_text("gtk");
+        field.press_key(Key::Return);
+        assert_eq!(app.events(), ["gtk"]);
+
+        // Pressing return cancels the pending search.
+        app.advance_time(Duration::from_secs(1));
+        assert_eq!(app.events().len(), 1);
+
+        field.clear_text();
+        field.type_text("cocoa");
+        field.press_key(Key::Return);
+        field.clear_text();
+        field.type_text("win32");
+        field.press_key(Key::Return);
+        assert_eq!(recent.clone_inner(), ["win32", "cocoa"]);
+        assert_eq!(field.options(), ["win32", "cocoa"]);
+    }
+
+    #[test]
+    fn clear_button() {
+        let (app, query, recent) = launch();
+        let field = app.root();
+
+        field.type_text("rust");
+        field.click_clear_button();
+        assert_eq!(field.text(), "");
+        assert_eq!(query.clone_inner(), "");
+        assert_eq!(app.events(), [""]);
+
+        // The empty query isn't remembered, and the typed query was never
+        // searched for.
+        app.advance_time(Duration::from_secs(1));
+        assert_eq!(app.events().len(), 1);
+        assert!(recent.clone_inner().is_empty());
+    }
+}
@@ -0,0 +1,534 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::{AppDelegate, EventHandlerMap, ListSelection, Rows, StateChangeOrigin, TextValue, View, ViewBase, ViewId, Window};
+
+use super::base::BaseView;
+
+type FormatFn<T> = Rc<dyn Fn(&T) -> String>;
+
+/// A select lets the user pick one of the items of a
+/// [`State<Vec<T>>`](crate::State) from a drop-down menu, which displays the
+/// items using a formatter.
+///
+/// ```
+/// # use finestra::*;
+/// let fruits = State::new(vec!["Apple".to_string(), "Banana".to_string()]);
+/// let selected = State::new(None);
+///
+/// # let _: Select<String, ()> =
+/// Select::new(&fruits, |fruit: &String| fruit.clone())
+///     .with_selection(&selected)
+///     .with_on_change(|_, index, _| println!("Picked {index:?}"));
+/// ```
+///
+/// An editable select is a combo box: the user can enter any text, and the
+/// items are suggestions. An item is selected when the text is equal to its
+/// title.
+///
+/// ```
+/// # use finestra::*;
+/// let countries = State::new(vec!["Belgium", "The Netherlands"]);
+/// let country = TextValue::new(String::new());
+///
+/// # let _: Select<&str, ()> =
+/// Select::new(&countries, |country: &&str| country.to_string())
+///     .with_editable_text(&country);
+/// ```
+pub struct Select<T, State> {
+    base: ViewBase,
+
+    pub(crate) options: crate::State<Vec<T>>,
+    format: FormatFn<T>,
+    pub(crate) selection: crate::State<Option<usize>>,
+    pub(crate) text: Option<TextValue>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<T: 'static, State> Select<T, State> {
+    /// Create a select that displays the `options`, using `format` to create
+    /// the title of every option.
+    #[must_use]
+    pub fn new(options: &crate::State<Vec<T>>, format: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            base: ViewBase::default(),
+
+            options: options.clone(),
+            format: Rc::new(format),
+            selection: crate::State::new(None),
+            text: None,
+            event_handler_map: EventHandlerMap::default(),
+        }
+    }
+
+    /// The titles of the options, which are updated when the options change.
+    #[must_use]
+    fn titles(&self) -> crate::State<Vec<String>> {
+        let format = Rc::clone(&self.format);
+        let titles = crate::State::new(self.options.with(|options| {
+            options.iter().map(|option| format(option)).collect::<Vec<_>>()
+        }));
+
+        let state = titles.clone();
+        self.options.add_listener(move |options| {
+            state.set(options.iter().map(|option| format(option)).collect::<Vec<_>>());
+        });
+
+        titles
+    }
+}
+
+impl<T, State> Select<T, State> {
+    /// Bind the selected option to a state. The state contains the index of
+    /// the selected option, and can be changed to select another option.
+    #[must_use]
+    pub fn with_selection(mut self, selection: &crate::State<Option<usize>>) -> Self {
+        self.set_selection(selection);
+        self
+    }
+
+    /// Bind the selected option to a state. The state contains the index of
+    /// the selected option, and can be changed to select another option.
+    pub fn set_selection(&mut self, selection: &crate::State<Option<usize>>) {
+        self.selection = selection.clone();
+    }
+
+    /// Let the user enter any text, using the options as suggestions. The
+    /// state contains the text, and selecting an option replaces it with the
+    /// title of that option.
+    #[must_use]
+    pub fn with_editable_text(mut self, text: &TextValue) -> Self {
+        self.set_editable_text(text);
+        self
+    }
+
+    /// Let the user enter any text, using the options as suggestions. The
+    /// state contains the text, and selecting an option replaces it with the
+    /// title of that option.
+    pub fn set_editable_text(&mut self, text: &TextValue) {
+        self.text = Some(text.clone());
+    }
+
+    /// Get notified when the user selects another option, or enters a text
+    /// that doesn't match the selected option anymore.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, Option<usize>, Window) + 'static) {
+        self.event_handler_map.selection_changed = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user selects another option, or enters a text
+    /// that doesn't match the selected option anymore.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, Option<usize>, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+}
+
+impl<T, State> BaseView for Select<T, State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<T, Delegate, State> From<Select<T, State>> for Box<dyn View<Delegate, State>>
+        where T: 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Select<T, State>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// Applies the changes made by the user to the selection and the text, and
+/// keeps the text of an editable select equal to the title of the selected
+/// option.
+#[derive(Clone)]
+pub(crate) struct SelectController {
+    view_id: ViewId,
+    titles: Rows<String>,
+    selection: crate::State<Option<usize>>,
+    text: Option<TextValue>,
+
+    /// The selection and the text are updated by each other's listeners, and
+    /// a listener can't change the state that notified it.
+    is_syncing: Rc<Cell<bool>>,
+}
+
+impl SelectController {
+    #[must_use]
+    fn new<T, State>(view_id: ViewId, titles: &Rows<String>, select: &Select<T, State>) -> Self {
+        let controller = Self {
+            view_id,
+            titles: titles.clone(),
+            selection: select.selection.clone(),
+            text: select.text.clone(),
+            is_syncing: Rc::default(),
+        };
+
+        if let Some(text) = &controller.text {
+            let this = controller.clone();
+            controller.selection.add_listener_with_origin(move |index| {
+                this.sync(|this| this.show_title(*index));
+            }, StateChangeOrigin::Owner(view_id));
+
+            let this = controller.clone();
+            text.add_listener_with_origin(move |text| {
+                this.sync(|this| this.selection.set(this.position(text)));
+            }, StateChangeOrigin::Owner(view_id));
+        }
+
+        controller
+    }
+
+    #[must_use]
+    pub(crate) fn selected(&self) -> Option<usize> {
+        self.selection.clone_inner()
+    }
+
+    /// The user selected the option at `index`.
+    pub(crate) fn select(&self, index: Option<usize>) {
+        self.selection.set_with_origin(index, StateChangeOrigin::Owner(self.view_id));
+
+        if let (Some(text), Some(title)) = (&self.text, index.and_then(|index| self.title(index))) {
+            text.set_with_origin(title, StateChangeOrigin::Owner(self.view_id));
+        }
+    }
+
+    /// The user entered the `text` into an editable select. Returns `true` if
+    /// the selection changed.
+    pub(crate) fn type_text(&self, text: &str) -> bool {
+        if let Some(state) = &self.text {
+            state.set_with_origin(text.to_owned(), StateChangeOrigin::Owner(self.view_id));
+        }
+
+        let index = self.position(text);
+        if index == self.selected() {
+            return false;
+        }
+
+        self.selection.set_with_origin(index, StateChangeOrigin::Owner(self.view_id));
+        true
+    }
+
+    fn sync(&self, f: impl FnOnce(&Self)) {
+        if self.is_syncing.replace(true) {
+            return;
+        }
+
+        f(self);
+        self.is_syncing.set(false);
+    }
+
+    fn show_title(&self, index: Option<usize>) {
+        if let (Some(text), Some(title)) = (&self.text, index.and_then(|index| self.title(index))) {
+            text.set_with_origin(title, StateChangeOrigin::System);
+        }
+    }
+
+    #[must_use]
+    fn title(&self, index: usize) -> Option<String> {
+        self.titles.with(|titles| titles.get(index).cloned())
+    }
+
+    #[must_use]
+    fn position(&self, text: &str) -> Option<usize> {
+        self.titles.with(|titles| titles.iter().position(|title| title == text))
+    }
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for Select<T, State>
+        where T: 'static, Delegate: 'static, State: 'static {
+    /// The options are displayed by an `NSPopUpButton`, or by an `NSComboBox`
+    /// when the select is editable.
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{select_popup_item, set_popup_action, set_popup_items, NSComboBox};
+        use crate::platform::macos::state::Event;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let titles = self.titles();
+        let rows = Rows::new(&titles);
+        let controller = SelectController::new(view_id, &rows, self);
+        let dispatcher = tree.create_dispatcher();
+
+        if self.text.is_some() {
+            let combo_box = NSComboBox::new();
+            rows.with(|titles| NSComboBox::set_items(&combo_box.objc, titles));
+            crate::platform::macos::state::attach_combo_box_state(view_id, self, &combo_box);
+
+            let objc = combo_box.objc.clone();
+            let snapshot = rows.clone();
+            rows.observe(&titles, &ListSelection::Single(self.selection.clone()), move |_| {
+                snapshot.with(|titles| NSComboBox::set_items(&objc, titles));
+            });
+
+            combo_box.set_action(move |text, _| {
+                if controller.type_text(&text) {
+                    dispatcher.dispatch_event(Event::SelectionChanged(view_id, controller.selected()));
+                }
+            });
+
+            return combo_box.into();
+        }
+
+        let select = cacao::select::Select::new();
+        rows.with(|titles| set_popup_items(&select.objc, titles));
+        crate::platform::macos::state::attach_select_state(view_id, self, &select);
+
+        // The pop-up button selects the first item when the items are
+        // replaced, so the selection is shown again.
+        let objc = select.objc.clone();
+        let snapshot = rows.clone();
+        let selection = self.selection.clone();
+        rows.observe(&titles, &ListSelection::Single(self.selection.clone()), move |_| {
+            snapshot.with(|titles| set_popup_items(&objc, titles));
+            select_popup_item(&objc, selection.clone_inner());
+        });
+
+        set_popup_action(&select.objc, move |index| {
+            controller.select(index);
+            dispatcher.dispatch_event(Event::SelectionChanged(view_id, index));
+        });
+
+        select.into()
+    }
+
+    /// Selects aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    /// The options are displayed by a `GtkDropDown`, or by a `GtkComboBoxText`
+    /// with an entry when the select is editable.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let titles = self.titles();
+        let rows = Rows::new(&titles);
+        let controller = SelectController::new(view_id, &rows, self);
+        let dispatcher = tree.create_dispatcher();
+        let guard = SignalGuard::default();
+
+        if self.text.is_some() {
+            let combo_box = gtk4::ComboBoxText::with_entry();
+            rows.with(|titles| {
+                for title in titles {
+                    combo_box.append_text(title);
+                }
+            });
+
+            crate::platform::gtk::state::attach_combo_box_state(view_id, self, &combo_box, &guard);
+
+            let combo = combo_box.clone();
+            let snapshot = rows.clone();
+            let signal_guard = guard.clone();
+            rows.observe(&titles, &ListSelection::Single(self.selection.clone()), move |change| {
+                signal_guard.run(|| {
+                    for _ in 0..change.removed {
+                        combo.remove(change.start as i32);
+                    }
+
+                    snapshot.with(|titles| {
+                        for (index, title) in titles[change.start..change.start + change.inserted].iter().enumerate() {
+                            combo.insert_text((change.start + index) as i32, title);
+                        }
+                    });
+                });
+            });
+
+            combo_box.connect_changed(move |combo_box| {
+                if guard.is_active() {
+                    return;
+                }
+
+                let text = combo_box.active_text().map(String::from).unwrap_or_default();
+                if controller.type_text(&text) {
+                    dispatcher.dispatch_event(Event::SelectionChanged(view_id, controller.selected()));
+                }
+            });
+
+            return combo_box.into();
+        }
+
+        let model = rows.with(|titles| {
+            gtk4::StringList::new(&titles.iter().map(String::as_str).collect::<Vec<_>>())
+        });
+        let drop_down = gtk4::DropDown::new(Some(model.clone()), None::<gtk4::Expression>);
+        crate::platform::gtk::state::attach_select_state(view_id, self, &drop_down, &guard);
+
+        // The drop down selects the first item when the model is no longer
+        // empty, so the selection is shown again.
+        let select = drop_down.clone();
+        let snapshot = rows.clone();
+        let selection = self.selection.clone();
+        let signal_guard = guard.clone();
+        rows.observe(&titles, &ListSelection::Single(self.selection.clone()), move |change| {
+            signal_guard.run(|| {
+                snapshot.with(|titles| {
+                    let inserted: Vec<&str> = titles[change.start..change.start + change.inserted].iter()
+                        .map(String::as_str)
+                        .collect();
+                    model.splice(change.start as u32, change.removed as u32, &inserted);
+                });
+
+                select.set_selected(selection.clone_inner().map_or(gtk4::INVALID_LIST_POSITION, |index| index as u32));
+            });
+        });
+
+        drop_down.connect_selected_notify(move |drop_down| {
+            if guard.is_active() {
+                return;
+            }
+
+            let index = Some(drop_down.selected())
+                .filter(|index| *index != gtk4::INVALID_LIST_POSITION)
+                .map(|index| index as usize);
+            controller.select(index);
+            dispatcher.dispatch_event(Event::SelectionChanged(view_id, index));
+        });
+
+        drop_down.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let titles = self.titles();
+        let rows = Rows::new(&titles);
+        let controller = SelectController::new(view_id, &rows, self);
+
+        let view = HeadlessView::new(view_id, HeadlessViewKind::Select);
+        view.with_mut(|data| {
+            data.options = rows.with(<[String]>::to_vec);
+            data.editable = self.text.is_some();
+        });
+        crate::platform::headless::state::attach_select_state(self, &view);
+
+        let select = view.clone();
+        let snapshot = rows.clone();
+        rows.observe(&titles, &ListSelection::Single(self.selection.clone()), move |change| {
+            let inserted = snapshot.with(|titles| titles[change.start..change.start + change.inserted].to_vec());
+            select.with_mut(|data| {
+                data.options.splice(change.start..change.start + change.removed, inserted);
+            });
+        });
+
+        let select = controller.clone();
+        view.set_selection_did_change(move |rows| {
+            let index = rows.first().copied();
+            select.select(index);
+            HeadlessContext::dispatch(Event::SelectionChanged(view_id, index));
+        });
+
+        // Like a native combo box, the view selects the option itself.
+        let select = view.clone();
+        view.set_text_did_change(move |text| {
+            if controller.type_text(text) {
+                let index = controller.selected();
+                select.with_mut(|data| data.selection = index.into_iter().collect());
+                HeadlessContext::dispatch(Event::SelectionChanged(view_id, index));
+            }
+        });
+
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Stack, State, TextValue};
+
+    use super::*;
+
+    fn launch(countries: &State<Vec<&'static str>>, selection: &State<Option<usize>>, city: &TextValue) -> ViewTestApp<Option<usize>> {
+        let (countries, selection, city) = (countries.clone(), selection.clone(), city.clone());
+        TestApp::launch_view(move || {
+            let cities = State::new(vec!["Amsterdam", "Brussels"]);
+            Stack::vertical()
+                .with(Select::new(&countries, |country: &&str| country.to_uppercase())
+                    .with_id("country")
+                    .with_selection(&selection)
+                    .with_on_change(|changes: &mut Vec<Option<usize>>, index, _| changes.push(index)))
+                .with(Select::new(&cities, |city: &&str| city.to_string())
+                    .with_id("city")
+                    .with_editable_text(&city)
+                    .with_on_change(|changes: &mut Vec<Option<usize>>, index, _| changes.push(index)))
+        })
+    }
+
+    #[test]
+    fn options_and_selection() {
+        let (countries, selection) = (State::new(vec!["Belgium", "France"]), State::new(None));
+        let app = launch(&countries, &selection, &TextValue::default());
+
+        let country = app.find_by_id("country").unwrap();
+        assert_eq!(country.options(), ["BELGIUM", "FRANCE"]);
+        assert_eq!(country.selected_option(), None);
+        assert_eq!(country.text(), "");
+
+        country.select_option(1);
+        assert_eq!(selection.clone_inner(), Some(1));
+        assert_eq!(country.text(), "FRANCE");
+        assert_eq!(app.events(), [Some(1)]);
+
+        // The selection follows the option when options are inserted before it.
+        countries.set(vec!["Austria", "Belgium", "France"]);
+        assert_eq!(country.options(), ["AUSTRIA", "BELGIUM", "FRANCE"]);
+        assert_eq!(selection.clone_inner(), Some(2));
+        assert_eq!(country.text(), "FRANCE");
+
+        selection.set(Some(0));
+        assert_eq!(country.selected_option(), Some(0));
+        assert_eq!(country.text(), "AUSTRIA");
+
+        // Changes made by the program don't invoke the handler.
+        assert_eq!(app.events().len(), 1);
+    }
+
+    #[test]
+    fn editable_text_matches_options() {
+        let city = TextValue::default();
+        let app = launch(&State::new(vec!["Belgium"]), &State::new(None), &city);
+
+        let city_select = app.find_by_id("city").unwrap();
+        city_select.type_text("Brussel");
+        assert_eq!(city.clone_inner(), "Brussel");
+        assert_eq!(city_select.selected_option(), None);
+
+        city_select.type_text("s");
+        assert_eq!(city_select.selected_option(), Some(1));
+
+        city_select.select_option(0);
+        assert_eq!(city.clone_inner(), "Amsterdam");
+        assert_eq!(city_select.text(), "Amsterdam");
+        assert_eq!(app.events(), [Some(1), Some(0)]);
+
+        city.set("Rotterdam");
+        assert_eq!(city_select.text(), "Rotterdam");
+        assert_eq!(city_select.selected_option(), None);
+    }
+}
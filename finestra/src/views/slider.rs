@@ -0,0 +1,372 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use crate::{event::EventHandlerMap, AppDelegate, Orientation, StateChangeOrigin, StateOrRaw, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// A slider lets the user pick a number from a range by dragging a knob, e.g.
+/// to change the volume.
+///
+/// ```
+/// # use finestra::*;
+/// let volume = State::new(0.5);
+///
+/// # let _: Slider<()> =
+/// Slider::new(&volume)
+///     .with_range(0.0..=1.0)
+///     .with_step(0.1)
+///     .with_tick_marks(11)
+///     .with_on_change(|_, volume, _| println!("Volume: {volume}"));
+/// ```
+pub struct Slider<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) value: crate::State<f64>,
+    pub(crate) range: RangeInclusive<f64>,
+    pub(crate) step: Option<f64>,
+    pub(crate) orientation: Orientation,
+    pub(crate) tick_marks: usize,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> Slider<State> {
+    /// Creates a new horizontal [`Slider`] that goes from `0.0` to `1.0`. If
+    /// the value is a [`State`](crate::State), it is updated when the user
+    /// moves the knob.
+    #[must_use]
+    pub fn new(value: impl Into<StateOrRaw<f64>>) -> Self {
+        let value = match value.into() {
+            StateOrRaw::State(state) => state,
+            StateOrRaw::Raw(value) => crate::State::new(value),
+        };
+
+        Self {
+            base: ViewBase::default(),
+            value,
+            range: 0.0..=1.0,
+            step: None,
+            orientation: Orientation::default(),
+            tick_marks: 0,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the user moved the knob.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, f64, Window) + 'static) {
+        self.event_handler_map.value_changed = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user moved the knob.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, f64, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+
+    /// Set the minimum and the maximum value. A reversed range is flipped, so
+    /// `1.0..=0.0` is the same as `0.0..=1.0`.
+    ///
+    /// ## Panics
+    /// When the minimum or the maximum is NaN.
+    #[must_use]
+    #[track_caller]
+    pub fn with_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.set_range(range);
+        self
+    }
+
+    /// Set the minimum and the maximum value. A reversed range is flipped, so
+    /// `1.0..=0.0` is the same as `0.0..=1.0`.
+    ///
+    /// ## Panics
+    /// When the minimum or the maximum is NaN.
+    #[track_caller]
+    pub fn set_range(&mut self, range: RangeInclusive<f64>) {
+        let (start, end) = range.into_inner();
+        assert!(!start.is_nan() && !end.is_nan(), "the range of a slider can't be NaN: {start}..={end}");
+
+        self.range = start.min(end)..=start.max(end);
+    }
+
+    /// Only let the user pick values that are a multiple of `step` away from
+    /// the minimum value.
+    #[must_use]
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.set_step(step);
+        self
+    }
+
+    /// Only let the user pick values that are a multiple of `step` away from
+    /// the minimum value.
+    pub fn set_step(&mut self, step: f64) {
+        self.step = Some(step).filter(|step| *step > 0.0);
+    }
+
+    /// Set the direction the slider goes in.
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.set_orientation(orientation);
+        self
+    }
+
+    /// Set the direction the slider goes in.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    /// Display `count` tick marks, spread evenly from the minimum to the
+    /// maximum value.
+    #[must_use]
+    pub fn with_tick_marks(mut self, count: usize) -> Self {
+        self.set_tick_marks(count);
+        self
+    }
+
+    /// Display `count` tick marks, spread evenly from the minimum to the
+    /// maximum value.
+    pub fn set_tick_marks(&mut self, count: usize) {
+        self.tick_marks = count;
+    }
+}
+
+impl<State> BaseView for Slider<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+/// Clamps the `value` to the `range`, and rounds it to the nearest `step`.
+#[must_use]
+pub(crate) fn snap_value(value: f64, range: &RangeInclusive<f64>, step: Option<f64>) -> f64 {
+    let (min, max) = (*range.start(), *range.end());
+    let value = value.clamp(min, max);
+
+    let Some(step) = step else {
+        return value;
+    };
+
+    let snapped = min + ((value - min) / step).round() * step;
+    if max - value < (value - snapped).abs() {
+        max
+    } else {
+        snapped.min(max)
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Slider<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::NSSlider;
+        use crate::platform::macos::state::Event;
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let slider = NSSlider::new(*self.range.start(), *self.range.end(), self.orientation);
+        if self.tick_marks != 0 {
+            // The tick marks are the only values the user can pick when they
+            // are a step apart.
+            let tick_step = (self.range.end() - self.range.start()) / (self.tick_marks.max(2) - 1) as f64;
+            slider.set_tick_marks(self.tick_marks, self.step == Some(tick_step));
+        }
+
+        crate::platform::macos::state::attach_slider_state(id, self, &slider);
+
+        let state = self.value.clone();
+        let (range, step) = (self.range.clone(), self.step);
+        let objc = slider.objc.clone();
+        let dispatcher = tree.create_dispatcher();
+        slider.set_action(move |value| {
+            let value = snap_value(value, &range, step);
+            NSSlider::set_value(&objc, value);
+
+            if value == state.clone_inner() {
+                return;
+            }
+
+            state.set_with_origin(value, StateChangeOrigin::Owner(id));
+            dispatcher.dispatch_event(Event::ValueChanged(id, value));
+        });
+
+        slider.into()
+    }
+
+    /// Sliders aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard, ToGtk};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let increment = self.step.unwrap_or((max - min) / 100.0);
+        let slider = gtk4::Scale::with_range(self.orientation.to_gtk(), min, max, increment);
+        slider.set_draw_value(false);
+        slider.set_inverted(self.orientation == Orientation::Vertical);
+
+        if self.tick_marks != 0 {
+            let position = match self.orientation {
+                Orientation::Horizontal => gtk4::PositionType::Bottom,
+                Orientation::Vertical => gtk4::PositionType::Right,
+            };
+
+            let tick_step = (max - min) / (self.tick_marks.max(2) - 1) as f64;
+            for index in 0..self.tick_marks {
+                slider.add_mark(min + index as f64 * tick_step, position, None);
+            }
+        }
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_slider_state(id, self, &slider, &guard);
+
+        let state = self.value.clone();
+        let (range, step) = (self.range.clone(), self.step);
+        let dispatcher = tree.create_dispatcher();
+        slider.connect_value_changed(move |slider| {
+            if guard.is_active() {
+                return;
+            }
+
+            let value = snap_value(slider.value(), &range, step);
+            if value != slider.value() {
+                guard.run(|| slider.set_value(value));
+            }
+
+            if value == state.clone_inner() {
+                return;
+            }
+
+            state.set_with_origin(value, StateChangeOrigin::Owner(id));
+            dispatcher.dispatch_event(Event::ValueChanged(id, value));
+        });
+        slider.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let slider = HeadlessView::new(id, HeadlessViewKind::Slider(self.orientation));
+        crate::platform::headless::state::attach_slider_state(self, &slider);
+
+        let state = self.value.clone();
+        let (range, step) = (self.range.clone(), self.step);
+        let view = slider.clone();
+        slider.set_value_did_change(move |value| {
+            let value = snap_value(value, &range, step);
+            view.with_mut(|data| data.value = value);
+
+            if value == state.clone_inner() {
+                return;
+            }
+
+            state.set_with_origin(value, StateChangeOrigin::Owner(id));
+            HeadlessContext::dispatch(Event::ValueChanged(id, value));
+        });
+        slider
+    }
+}
+
+impl<Delegate, State> From<Slider<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Slider<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::TestApp;
+    use crate::{BaseViewExt, State};
+
+    use super::*;
+
+    #[test]
+    fn snap_to_range() {
+        assert_eq!(snap_value(-1.0, &(0.0..=1.0), None), 0.0);
+        assert_eq!(snap_value(0.25, &(0.0..=1.0), None), 0.25);
+        assert_eq!(snap_value(3.0, &(0.0..=1.0), None), 1.0);
+    }
+
+    #[test]
+    fn snap_to_step() {
+        assert_eq!(snap_value(0.3, &(0.0..=1.0), Some(0.5)), 0.5);
+        assert_eq!(snap_value(0.2, &(0.0..=1.0), Some(0.5)), 0.0);
+        assert_eq!(snap_value(14.0, &(10.0..=20.0), Some(5.0)), 15.0);
+
+        // The maximum is reachable even when it isn't a multiple of the step.
+        assert_eq!(snap_value(9.9, &(0.0..=10.0), Some(4.0)), 10.0);
+        assert_eq!(snap_value(8.5, &(0.0..=10.0), Some(4.0)), 8.0);
+    }
+
+    #[test]
+    fn reversed_range() {
+        let slider: Slider<()> = Slider::new(5.0).with_range(10.0..=0.0);
+        assert_eq!(slider.range, 0.0..=10.0);
+        assert_eq!(snap_value(12.0, &slider.range, None), 10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be NaN")]
+    fn nan_range() {
+        let _: Slider<()> = Slider::new(0.0).with_range(0.0..=f64::NAN);
+    }
+
+    #[test]
+    fn value_binding() {
+        let volume = State::new(5.0);
+        let app = TestApp::launch_view({
+            let volume = volume.clone();
+            move || {
+                Slider::new(&volume)
+                    .with_id("volume")
+                    .with_range(0.0..=10.0)
+                    .with_step(0.5)
+                    .with_on_change(|changes: &mut Vec<f64>, volume, _| changes.push(volume))
+            }
+        });
+
+        let slider = app.find_by_id("volume").unwrap();
+        assert_eq!(slider.value(), 5.0);
+
+        // The value is rounded to the step, and clamped to the range.
+        slider.slide_to(7.3);
+        assert_eq!(slider.value(), 7.5);
+        assert_eq!(volume.clone_inner(), 7.5);
+
+        slider.slide_to(12.0);
+        assert_eq!(volume.clone_inner(), 10.0);
+
+        // Dragging within the same step doesn't change the value.
+        slider.slide_to(9.9);
+        assert_eq!(app.events(), [7.5, 10.0]);
+
+        volume.set(2.0);
+        assert_eq!(slider.value(), 2.0);
+        assert_eq!(app.events().len(), 2);
+    }
+}
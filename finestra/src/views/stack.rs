@@ -3,7 +3,7 @@
 
 use std::marker::PhantomData;
 
-use crate::{AppDelegate, View, ViewBase};
+use crate::{AppDelegate, Number, Padding, StackAlignment, StackDistribution, StackLayout, View, ViewBase};
 
 use super::base::BaseView;
 
@@ -25,7 +25,7 @@ pub struct Stack<State, Delegate> {
     base: ViewBase,
     _phantom: PhantomData<Delegate>,
 
-    pub(crate) direction: StackDirection,
+    pub(crate) layout: StackLayout,
     pub(crate) children: Vec<Box<dyn View<Delegate, State>>>,
 }
 
@@ -39,7 +39,7 @@ impl<State, Delegate> Stack<State, Delegate> {
             base: ViewBase::default(),
             _phantom: PhantomData,
 
-            direction,
+            layout: StackLayout::new(direction),
             children: Vec::new(),
         }
     }
@@ -67,6 +67,65 @@ impl<State, Delegate> Stack<State, Delegate> {
     pub fn vertical() -> Self {
         Self::new(StackDirection::Vertical)
     }
+
+    /// Set the space between the items of the stack. The default is `8.0`.
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: Number) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    /// Set the space between the items of the stack. The default is `8.0`.
+    pub fn set_spacing(&mut self, spacing: Number) {
+        self.layout.spacing = spacing;
+    }
+
+    /// Set the space between the edges of the stack and its items.
+    /// ```
+    /// # use finestra::{Padding, Stack};
+    /// # let _: Stack<(), ()> =
+    /// Stack::vertical()
+    ///     .with_padding(10.0)
+    ///     .with_padding(Padding::symmetric(20.0, 10.0));
+    /// ```
+    #[must_use]
+    pub fn with_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.set_padding(padding);
+        self
+    }
+
+    /// Set the space between the edges of the stack and its items.
+    pub fn set_padding(&mut self, padding: impl Into<Padding>) {
+        self.layout.padding = padding.into();
+    }
+
+    /// Set how the items are positioned on the cross axis, e.g. horizontally
+    /// for a [vertical](Stack::vertical) stack.
+    #[must_use]
+    pub fn with_alignment(mut self, alignment: StackAlignment) -> Self {
+        self.set_alignment(alignment);
+        self
+    }
+
+    /// Set how the items are positioned on the cross axis, e.g. horizontally
+    /// for a [vertical](Stack::vertical) stack.
+    pub fn set_alignment(&mut self, alignment: StackAlignment) {
+        self.layout.alignment = alignment;
+    }
+
+    /// Set how the items are positioned on the main axis, e.g. vertically for
+    /// a [vertical](Stack::vertical) stack.
+    #[must_use]
+    pub fn with_distribution(mut self, distribution: StackDistribution) -> Self {
+        self.set_distribution(distribution);
+        self
+    }
+
+    /// Set how the items are positioned on the main axis, e.g. vertically for
+    /// a [vertical](Stack::vertical) stack.
+    pub fn set_distribution(&mut self, distribution: StackDistribution) {
+        self.layout.distribution = distribution;
+    }
 }
 
 impl<State: 'static, Delegate> Stack<State, Delegate>
@@ -99,9 +158,10 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Stack<State,
         where Delegate: 'static, State: 'static {
     #[cfg(target_os = "macos")]
     fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
-        use crate::platform::macos::NSStackView;
+        use crate::platform::macos::NSContainerView;
+        use crate::ContainerLayout;
 
-        let mut view = NSStackView::new(self.direction);
+        let mut view = NSContainerView::new(ContainerLayout::Stack(self.layout));
         for child in &mut self.children {
             let comp = child.build_native(tree);
             view.add_view(comp);
@@ -117,6 +177,52 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Stack<State,
         tree: &mut crate::event::ViewTree<State>,
         parent: windows::Win32::Foundation::HWND,
     ) -> crate::platform::win32::view::WinView {
-        <() as super::View<Delegate, State>>::build_native(&mut (), tree, parent)
+        use crate::platform::win32::view::{WinContainer, WinView, WinViewKind};
+        use crate::ContainerLayout;
+
+        let children = self.children.iter_mut()
+            .map(|child| child.build_native(tree, parent))
+            .collect();
+
+        let container = WinContainer {
+            layout: ContainerLayout::Stack(self.layout),
+            children,
+        };
+
+        WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Container(container))
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::ContainerLayoutManager;
+        use crate::ContainerLayout;
+
+        let view = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        view.set_layout_manager(Some(ContainerLayoutManager::new(ContainerLayout::Stack(self.layout))));
+
+        for child in &mut self.children {
+            let comp = child.build_native(tree);
+            view.append(comp.widget());
+        }
+
+        crate::platform::gtk::state::attach_base_state(self, &view);
+        view.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+
+        let id = tree.exchange_events_for_id(Default::default());
+
+        let view = HeadlessView::new(id, HeadlessViewKind::Stack(self.layout.direction));
+        view.with_mut(|data| data.container = Some(crate::ContainerLayout::Stack(self.layout)));
+        for child in &mut self.children {
+            let comp = child.build_headless(tree);
+            view.add_child(comp);
+        }
+
+        crate::platform::headless::state::attach_base_state(self, &view);
+        view
     }
 }
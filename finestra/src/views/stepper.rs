@@ -0,0 +1,522 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::{event::EventHandlerMap, AppDelegate, StateChangeOrigin, StateOrRaw, View, ViewBase, ViewId, Window};
+
+use super::base::BaseView;
+
+type Format<T> = Rc<dyn Fn(T) -> String>;
+type Parse<T> = Rc<dyn Fn(&str) -> Option<T>>;
+
+/// A number that can be picked using a [`Stepper`]. An [`i64`] only allows
+/// whole numbers, while an [`f64`] allows decimal numbers as well.
+pub trait StepperValue: Copy + PartialOrd + Display + FromStr + 'static {
+    #[doc(hidden)]
+    const DECIMAL: bool;
+
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+
+    #[doc(hidden)]
+    fn from_f64(value: f64) -> Self;
+}
+
+impl StepperValue for i64 {
+    const DECIMAL: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i64
+    }
+}
+
+impl StepperValue for f64 {
+    const DECIMAL: bool = true;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// A stepper (also known as a spin box) lets the user enter a number in a text
+/// field, or change it using the increment and decrement buttons next to it.
+///
+/// ```
+/// # use finestra::*;
+/// let copies = State::new(1_i64);
+///
+/// # let _: Stepper<i64, ()> =
+/// Stepper::new(&copies)
+///     .with_range(1..=99)
+///     .with_format(|copies| format!("{copies}x"), |text| text.trim_end_matches('x').parse().ok())
+///     .with_on_invalid_input(|_, text, _| println!("Not a number of copies: {text}"));
+/// ```
+pub struct Stepper<T, State> {
+    pub(crate) base: ViewBase,
+    pub(crate) value: crate::State<T>,
+    pub(crate) range: RangeInclusive<T>,
+    pub(crate) step: T,
+    pub(crate) wraps: bool,
+    pub(crate) format: Format<T>,
+    pub(crate) parse: Parse<T>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<T: StepperValue, State> Stepper<T, State> {
+    /// Creates a new [`Stepper`] that goes from `0` to `100` in steps of `1`.
+    /// If the value is a [`State`](crate::State), it is updated when the user
+    /// changes the number.
+    #[must_use]
+    pub fn new(value: impl Into<StateOrRaw<T>>) -> Self {
+        let value = match value.into() {
+            StateOrRaw::State(state) => state,
+            StateOrRaw::Raw(value) => crate::State::new(value),
+        };
+
+        Self {
+            base: ViewBase::default(),
+            value,
+            range: T::from_f64(0.0)..=T::from_f64(100.0),
+            step: T::from_f64(1.0),
+            wraps: false,
+            format: Rc::new(|value: T| value.to_string()),
+            parse: Rc::new(|text: &str| text.trim().parse().ok()),
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the user changed the number.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, T, Window) + 'static) {
+        self.event_handler_map.value_changed = Some(Rc::new(move |state, value, window| {
+            action(state, T::from_f64(value), window);
+        }));
+    }
+
+    /// Get notified when the user changed the number.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, T, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+
+    /// Get notified when the user typed text that isn't a number in the
+    /// range. The value isn't changed in that case.
+    pub fn set_on_invalid_input(&mut self, action: impl Fn(&mut State, String, Window) + 'static) {
+        self.event_handler_map.text_changed = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user typed text that isn't a number in the
+    /// range. The value isn't changed in that case.
+    #[must_use]
+    pub fn with_on_invalid_input(mut self, action: impl Fn(&mut State, String, Window) + 'static) -> Self {
+        self.set_on_invalid_input(action);
+        self
+    }
+
+    /// Set the minimum and the maximum value.
+    #[must_use]
+    pub fn with_range(mut self, range: RangeInclusive<T>) -> Self {
+        self.set_range(range);
+        self
+    }
+
+    /// Set the minimum and the maximum value.
+    pub fn set_range(&mut self, range: RangeInclusive<T>) {
+        self.range = range;
+    }
+
+    /// Set the amount the buttons increment or decrement the value by.
+    #[must_use]
+    pub fn with_step(mut self, step: T) -> Self {
+        self.set_step(step);
+        self
+    }
+
+    /// Set the amount the buttons increment or decrement the value by.
+    pub fn set_step(&mut self, step: T) {
+        self.step = step;
+    }
+
+    /// Go to the minimum when incrementing past the maximum, and the other way
+    /// around, instead of stopping at the ends.
+    #[must_use]
+    pub fn with_wraps(mut self, wraps: bool) -> Self {
+        self.set_wraps(wraps);
+        self
+    }
+
+    /// Go to the minimum when incrementing past the maximum, and the other way
+    /// around, instead of stopping at the ends.
+    pub fn set_wraps(&mut self, wraps: bool) {
+        self.wraps = wraps;
+    }
+
+    /// Display the value using `format`, and convert typed text back to a
+    /// value using `parse`, which returns [`None`] when the text is invalid.
+    #[must_use]
+    pub fn with_format(
+        mut self,
+        format: impl Fn(T) -> String + 'static,
+        parse: impl Fn(&str) -> Option<T> + 'static,
+    ) -> Self {
+        self.set_format(format, parse);
+        self
+    }
+
+    /// Display the value using `format`, and convert typed text back to a
+    /// value using `parse`, which returns [`None`] when the text is invalid.
+    pub fn set_format(
+        &mut self,
+        format: impl Fn(T) -> String + 'static,
+        parse: impl Fn(&str) -> Option<T> + 'static,
+    ) {
+        self.format = Rc::new(format);
+        self.parse = Rc::new(parse);
+    }
+
+    fn input(&self, view_id: ViewId) -> StepperInput<T> {
+        StepperInput {
+            view_id,
+            value: self.value.clone(),
+            range: self.range.clone(),
+            step: self.step,
+            wraps: self.wraps,
+            format: Rc::clone(&self.format),
+            parse: Rc::clone(&self.parse),
+        }
+    }
+}
+
+impl<T, State> BaseView for Stepper<T, State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+/// Handles the input of the user, which is the same for every backend.
+#[derive(Clone)]
+pub(crate) struct StepperInput<T> {
+    view_id: ViewId,
+    value: crate::State<T>,
+    range: RangeInclusive<T>,
+    step: T,
+    wraps: bool,
+    format: Format<T>,
+    parse: Parse<T>,
+}
+
+impl<T: StepperValue> StepperInput<T> {
+    #[must_use]
+    pub(crate) fn format(&self, value: T) -> String {
+        (self.format)(value)
+    }
+
+    /// Returns the value of the `text`, or [`None`] if it isn't a number in
+    /// the range.
+    #[must_use]
+    pub(crate) fn parse(&self, text: &str) -> Option<T> {
+        (self.parse)(text).filter(|value| self.range.contains(value))
+    }
+
+    /// Increments (`up`) or decrements the value, and returns the new value if
+    /// it changed.
+    pub(crate) fn step(&self, up: bool) -> Option<T> {
+        let value = step_value(self.value.clone_inner(), up, &self.range, self.step, self.wraps);
+        self.commit(value).then_some(value)
+    }
+
+    /// Stores the `value` the user entered, and returns `true` if it changed.
+    pub(crate) fn commit(&self, value: T) -> bool {
+        if self.value.with(|current| *current == value) {
+            return false;
+        }
+
+        self.value.set_with_origin(value, StateChangeOrigin::Owner(self.view_id));
+        true
+    }
+}
+
+/// Increments (`up`) or decrements the `value` by the `step`. Past the ends of
+/// the `range`, the value stops at that end, or goes to the other end when it
+/// `wraps`.
+#[must_use]
+pub(crate) fn step_value<T: StepperValue>(value: T, up: bool, range: &RangeInclusive<T>, step: T, wraps: bool) -> T {
+    let (min, max) = (*range.start(), *range.end());
+    let step = if up { step.to_f64() } else { -step.to_f64() };
+    let value = T::from_f64(value.to_f64() + step);
+
+    if value > max {
+        if wraps { min } else { max }
+    } else if value < min {
+        if wraps { max } else { min }
+    } else {
+        value
+    }
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for Stepper<T, State>
+        where T: StepperValue, Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{cacao_delegates::MacOSTextFieldDelegate, Event, NSStackView, NSStepper};
+        use crate::{StackDirection, StackLayout};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let input = self.input(id);
+        let stepper = NSStepper::new();
+
+        let text_field = {
+            let input = input.clone();
+            let dispatcher = tree.create_dispatcher();
+            cacao::input::TextField::with(MacOSTextFieldDelegate::with_action(move |text| {
+                let Some(value) = input.parse(text) else {
+                    dispatcher.dispatch_event(Event::TextFieldChanged(id, text.to_string().into()));
+                    return;
+                };
+
+                if input.commit(value) {
+                    dispatcher.dispatch_event(Event::ValueChanged(id, value.to_f64()));
+                }
+            }))
+        };
+        text_field.set_uses_single_line(true);
+        text_field.width.constraint_equal_to_constant(80.).set_active(true);
+
+        let objc = text_field.objc.clone();
+        let dispatcher = tree.create_dispatcher();
+        stepper.set_action(move |up| {
+            let value = input.step(up);
+            crate::platform::macos::state::set_string_value(&objc, &input.format(input.value.clone_inner()));
+
+            if let Some(value) = value {
+                dispatcher.dispatch_event(Event::ValueChanged(id, value.to_f64()));
+            }
+        });
+
+        let mut view = NSStackView::new(&StackLayout::new(StackDirection::Horizontal));
+        crate::platform::macos::state::attach_stepper_state(id, self, &view, &text_field);
+
+        view.add_view(text_field.into());
+        view.add_view(stepper.into());
+        view.into()
+    }
+
+    /// Steppers aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    /// A `GtkSpinButton` is a text field with increment and decrement buttons,
+    /// which parses and formats the text using its `input` and `output`
+    /// signals.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let (min, max) = (self.range.start().to_f64(), self.range.end().to_f64());
+        let step = self.step.to_f64();
+        let adjustment = gtk4::Adjustment::new(self.value.clone_inner().to_f64(), min, max, step, step * 10.0, 0.0);
+
+        let spin_button = gtk4::SpinButton::new(Some(&adjustment), 1.0, if T::DECIMAL { 2 } else { 0 });
+        spin_button.set_numeric(false);
+        spin_button.set_wrap(self.wraps);
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_stepper_state(id, self, &spin_button, &guard);
+
+        let input = self.input(id);
+
+        let format = input.clone();
+        spin_button.connect_output(move |spin_button| {
+            spin_button.set_text(&format.format(T::from_f64(spin_button.value())));
+            gtk4::glib::Propagation::Stop
+        });
+
+        let parse = input.clone();
+        let dispatcher = tree.create_dispatcher();
+        spin_button.connect_input(move |spin_button| {
+            let text = spin_button.text();
+            match parse.parse(&text) {
+                Some(value) => Some(Ok(value.to_f64())),
+                None => {
+                    dispatcher.dispatch_event(Event::TextFieldChanged(id, text.to_string().into()));
+                    Some(Err(()))
+                }
+            }
+        });
+
+        let dispatcher = tree.create_dispatcher();
+        spin_button.connect_value_changed(move |spin_button| {
+            if guard.is_active() {
+                return;
+            }
+
+            let value = T::from_f64(spin_button.value());
+            if input.commit(value) {
+                dispatcher.dispatch_event(Event::ValueChanged(id, value.to_f64()));
+            }
+        });
+        spin_button.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let stepper = HeadlessView::new(id, HeadlessViewKind::Stepper);
+        crate::platform::headless::state::attach_stepper_state(self, &stepper);
+
+        let input = self.input(id);
+        let view = stepper.clone();
+        stepper.set_stepped(move |up| {
+            let value = input.step(up);
+
+            let current = input.value.clone_inner();
+            view.with_mut(|data| {
+                data.value = current.to_f64();
+                data.text = input.format(current);
+            });
+
+            if let Some(value) = value {
+                HeadlessContext::dispatch(Event::ValueChanged(id, value.to_f64()));
+            }
+        });
+
+        let input = self.input(id);
+        let view = stepper.clone();
+        stepper.set_text_did_change(move |text| {
+            let Some(value) = input.parse(text) else {
+                HeadlessContext::dispatch(Event::TextFieldChanged(id, text.to_string().into()));
+                return;
+            };
+
+            view.with_mut(|data| data.value = value.to_f64());
+            if input.commit(value) {
+                HeadlessContext::dispatch(Event::ValueChanged(id, value.to_f64()));
+            }
+        });
+        stepper
+    }
+}
+
+impl<T, Delegate, State> From<Stepper<T, State>> for Box<dyn View<Delegate, State>>
+        where T: StepperValue, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Stepper<T, State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::TestApp;
+    use crate::{BaseViewExt, State};
+
+    use super::*;
+
+    #[test]
+    fn step_within_range() {
+        assert_eq!(step_value(5, true, &(0..=10), 2, false), 7);
+        assert_eq!(step_value(5, false, &(0..=10), 2, false), 3);
+        assert_eq!(step_value(0.5, true, &(0.0..=1.0), 0.25, false), 0.75);
+    }
+
+    #[test]
+    fn step_past_the_ends() {
+        assert_eq!(step_value(9, true, &(0..=10), 2, false), 10);
+        assert_eq!(step_value(1, false, &(0..=10), 2, false), 0);
+
+        assert_eq!(step_value(10, true, &(0..=10), 2, true), 0);
+        assert_eq!(step_value(0, false, &(0..=10), 2, true), 10);
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Input {
+        Changed(i64),
+        Invalid(String),
+    }
+
+    #[test]
+    fn value_binding() {
+        let copies = State::new(1);
+        let app = TestApp::launch_view({
+            let copies = copies.clone();
+            move || {
+                Stepper::new(&copies)
+                    .with_id("copies")
+                    .with_range(1..=5)
+                    .with_step(2)
+                    .with_format(|copies| format!("{copies}x"), |text| text.strip_suffix('x')?.parse().ok())
+                    .with_on_change(|inputs: &mut Vec<Input>, copies, _| inputs.push(Input::Changed(copies)))
+                    .with_on_invalid_input(|inputs: &mut Vec<Input>, text, _| inputs.push(Input::Invalid(text)))
+            }
+        });
+        let changes = || app.events().into_iter()
+            .filter_map(|input| match input { Input::Changed(copies) => Some(copies), _ => None })
+            .collect::<Vec<_>>();
+        let invalid_input = || app.events().into_iter()
+            .filter_map(|input| match input { Input::Invalid(text) => Some(text), _ => None })
+            .collect::<Vec<_>>();
+
+        let stepper = app.find_by_id("copies").unwrap();
+        assert_eq!(stepper.text(), "1x");
+
+        stepper.increment();
+        stepper.increment();
+        assert_eq!(stepper.text(), "5x");
+
+        // The value stops at the maximum.
+        stepper.increment();
+        assert_eq!(copies.clone_inner(), 5);
+
+        stepper.decrement();
+        assert_eq!(stepper.text(), "3x");
+        assert_eq!(changes(), [3, 5, 3]);
+
+        // Invalid input doesn't change the value.
+        stepper.clear_text();
+        stepper.type_text("9x");
+        assert_eq!(copies.clone_inner(), 3);
+        assert_eq!(invalid_input(), ["", "9", "9x"]);
+
+        stepper.clear_text();
+        stepper.type_text("2x");
+        assert_eq!(copies.clone_inner(), 2);
+        assert_eq!(stepper.value(), 2.0);
+
+        copies.set(4);
+        assert_eq!(stepper.text(), "4x");
+        assert_eq!(changes(), [3, 5, 3, 2]);
+    }
+}
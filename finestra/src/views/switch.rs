@@ -0,0 +1,230 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::{event::EventHandlerMap, AppDelegate, StateChangeOrigin, StateOrRaw, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// A labelled on/off switch, which is commonly used for settings. When the
+/// platform doesn't have a switch control, a checkbox is shown instead.
+///
+/// ```
+/// # use finestra::*;
+/// let notifications = State::new(true);
+///
+/// # let _: Switch<()> =
+/// Switch::new("Show notifications", &notifications)
+///     .with_on_checked(|_, is_on, _| println!("Notifications are on: {is_on}"));
+/// ```
+pub struct Switch<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) text: StateOrRaw<String>,
+    pub(crate) checked: crate::State<bool>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> Switch<State> {
+    /// Creates a new [`Switch`] with the associated label. If `checked` is a
+    /// [`State`](crate::State), it is updated when the user flips the switch.
+    #[must_use]
+    pub fn new(text: impl Into<StateOrRaw<String>>, checked: impl Into<StateOrRaw<bool>>) -> Self {
+        let checked = match checked.into() {
+            StateOrRaw::State(state) => state,
+            StateOrRaw::Raw(value) => crate::State::new(value),
+        };
+
+        Self {
+            base: ViewBase::default(),
+            text: text.into(),
+            checked,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the switch has been turned on or off.
+    pub fn set_on_checked(&mut self, action: impl Fn(&mut State, bool, Window) + 'static) {
+        self.event_handler_map.checked = Some(Rc::new(action));
+    }
+
+    /// Get notified when the switch has been turned on or off.
+    #[must_use]
+    pub fn with_on_checked(mut self, action: impl Fn(&mut State, bool, Window) + 'static) -> Self {
+        self.set_on_checked(action);
+        self
+    }
+}
+
+impl<State> BaseView for Switch<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for Switch<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use cacao::appkit::App;
+        use crate::platform::macos::{Event, MacOSDelegate};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let mut switch = self.text.with(|text| {
+            cacao::switch::Switch::new(text)
+        });
+
+        crate::platform::macos::state::attach_switch_state(id, self, &switch);
+
+        let state = self.checked.clone();
+        switch.set_action(move || {
+            let is_checked = !state.clone_inner();
+            state.set_with_origin(is_checked, StateChangeOrigin::Owner(id));
+
+            App::<MacOSDelegate<Delegate, State>, Event>::dispatch_main(Event::CheckboxChanged(id, is_checked));
+        });
+        switch.into()
+    }
+
+    /// Win32 doesn't have a switch control, so a checkbox is used instead.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::{platform::win32::view::{WinButton, WinView, WinViewKind}, ViewId};
+
+        let switch = self.text.with(|text| {
+            WinButton::new_checkbox(parent, text)
+        });
+
+        switch.as_ref().subscribe_text_update(self.text.as_state());
+        self.base.register_handle(|| *switch.as_ref());
+
+        let id = ViewId(switch.as_ref().get_control_id().0 as _);
+        switch.as_ref().subscribe_checked_update(&self.checked, id);
+
+        // The checkbox is toggled by Win32, so only the state is updated.
+        let mut map = std::mem::take(&mut self.event_handler_map);
+        let on_checked = map.checked.take();
+        let state = self.checked.clone();
+        map.checked = Some(Rc::new(move |app_state, is_checked, window| {
+            state.set_with_origin(is_checked, StateChangeOrigin::Owner(id));
+            if let Some(on_checked) = &on_checked {
+                on_checked(app_state, is_checked, window);
+            }
+        }));
+        tree.put_event_handlers_with_id(id, map);
+
+        WinView::new(id, WinViewKind::Button(switch))
+    }
+
+    /// A `GtkSwitch` doesn't have a label, so it is placed in a box after a
+    /// `GtkLabel`.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let label = self.text.with(|text| {
+            gtk4::Label::new(Some(text))
+        });
+        label.set_hexpand(true);
+        label.set_xalign(0.0);
+
+        let switch = gtk4::Switch::new();
+        switch.set_valign(gtk4::Align::Center);
+
+        let container = gtk4::Box::new(gtk4::Orientation::Horizontal, crate::StackLayout::DEFAULT_SPACING as i32);
+        container.append(&label);
+        container.append(&switch);
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_switch_state(id, self, &container, &label, &switch, &guard);
+
+        let state = self.checked.clone();
+        let dispatcher = tree.create_dispatcher();
+        switch.connect_active_notify(move |switch| {
+            if guard.is_active() {
+                return;
+            }
+
+            let is_checked = switch.is_active();
+            state.set_with_origin(is_checked, StateChangeOrigin::Owner(id));
+
+            dispatcher.dispatch_event(Event::CheckboxChanged(id, is_checked));
+        });
+        container.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let id = tree.exchange_events_for_id(map);
+
+        let switch = HeadlessView::new(id, HeadlessViewKind::Switch);
+        crate::platform::headless::state::attach_switch_state(self, &switch);
+
+        let state = self.checked.clone();
+        let view = switch.clone();
+        switch.set_action(move || {
+            let is_checked = !state.clone_inner();
+            view.with_mut(|data| data.checked = is_checked);
+            state.set_with_origin(is_checked, StateChangeOrigin::Owner(id));
+
+            HeadlessContext::dispatch(Event::CheckboxChanged(id, is_checked));
+        });
+        switch
+    }
+}
+
+impl<Delegate, State> From<Switch<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Switch<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::TestApp;
+    use crate::{BaseViewExt, State};
+
+    use super::*;
+
+    #[test]
+    fn checked_binding() {
+        let notifications = State::new(true);
+        let app = TestApp::launch_view({
+            let notifications = notifications.clone();
+            move || {
+                Switch::new("Notifications", &notifications)
+                    .with_id("notifications")
+                    .with_on_checked(|changes: &mut Vec<bool>, checked, _| changes.push(checked))
+            }
+        });
+
+        let switch = app.find_by_id("notifications").unwrap();
+        assert_eq!(switch.text(), "Notifications");
+        assert!(switch.is_checked());
+
+        switch.toggle();
+        assert!(!switch.is_checked());
+        assert!(!notifications.clone_inner());
+
+        notifications.set(true);
+        assert!(switch.is_checked());
+        assert_eq!(app.events(), [false]);
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{AppDelegate, Image, StateChangeOrigin, View, ViewBase};
+
+use super::base::BaseView;
+
+type TabContent<Delegate, State> = Box<dyn FnOnce() -> Box<dyn View<Delegate, State>>>;
+
+/// A tab of a [`TabView`], with a title, an optional icon and the view that is
+/// displayed when the tab is selected.
+///
+/// ```
+/// # use finestra::*;
+/// # let _: Tab<(), ()> =
+/// Tab::new("General", Label::new("Launch at login"))
+///     .with_icon(Image::with_contents_of_file("general.png"));
+/// ```
+pub struct Tab<State, Delegate> {
+    pub(crate) title: String,
+    pub(crate) icon: Option<Image>,
+    /// The content is taken when the tab view is built.
+    content: Option<TabContent<Delegate, State>>,
+}
+
+impl<State: 'static, Delegate> Tab<State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Create a tab that displays the `content`. The native view of the
+    /// content is built when the tab is selected for the first time.
+    #[must_use]
+    pub fn new(title: impl Into<String>, content: impl Into<Box<dyn View<Delegate, State>>>) -> Self {
+        let content = content.into();
+        Self::lazy(title, move || content)
+    }
+
+    /// Create a tab of which the content is created when the tab is selected
+    /// for the first time, e.g. when the content is expensive to create.
+    ///
+    /// ```
+    /// # use finestra::*;
+    /// # let _: Tab<(), ()> =
+    /// Tab::lazy("Statistics", || {
+    ///     let summary = std::fs::read_to_string("statistics.txt").unwrap_or_default();
+    ///     TextBlock::new(summary)
+    /// });
+    /// ```
+    #[must_use]
+    pub fn lazy<V>(title: impl Into<String>, content: impl FnOnce() -> V + 'static) -> Self
+            where V: Into<Box<dyn View<Delegate, State>>> {
+        Self {
+            title: title.into(),
+            icon: None,
+            content: Some(Box::new(move || content().into())),
+        }
+    }
+}
+
+impl<State, Delegate> Tab<State, Delegate> {
+    /// Set the icon that is displayed in front of the title.
+    #[must_use]
+    pub fn with_icon(mut self, icon: Image) -> Self {
+        self.set_icon(icon);
+        self
+    }
+
+    /// Set the icon that is displayed in front of the title.
+    pub fn set_icon(&mut self, icon: Image) {
+        self.icon = Some(icon);
+    }
+}
+
+/// A tab view displays one of its [`Tab`]s at a time, and lets the user
+/// switch between them.
+///
+/// ```
+/// # use finestra::*;
+/// let selected_tab = State::new(0);
+///
+/// # let _: TabView<(), ()> =
+/// TabView::new()
+///     .with_tab(Tab::new("General", Checkbox::new("Launch at login")))
+///     .with_tab(Tab::lazy("Advanced", || Label::new("Expensive settings")))
+///     .with_selection(&selected_tab);
+///
+/// // Switches to the "Advanced" tab, which is built at this point.
+/// selected_tab.set(1_usize);
+/// ```
+pub struct TabView<State, Delegate> {
+    base: ViewBase,
+
+    pub(crate) tabs: Vec<Tab<State, Delegate>>,
+    pub(crate) selection: crate::State<usize>,
+}
+
+impl<State, Delegate> Default for TabView<State, Delegate> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State, Delegate> TabView<State, Delegate> {
+    /// Create a tab view without any tabs.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            base: ViewBase::default(),
+
+            tabs: Vec::new(),
+            selection: crate::State::new(0),
+        }
+    }
+
+    /// Add a tab after the existing tabs.
+    #[must_use]
+    pub fn with_tab(mut self, tab: Tab<State, Delegate>) -> Self {
+        self.add_tab(tab);
+        self
+    }
+
+    /// Add a tab after the existing tabs.
+    pub fn add_tab(&mut self, tab: Tab<State, Delegate>) {
+        self.tabs.push(tab);
+    }
+
+    /// Bind the index of the selected tab to a state, which is updated when
+    /// the user selects another tab, and can be changed to select another tab.
+    /// The first tab is selected by default.
+    #[must_use]
+    pub fn with_selection(mut self, selection: &crate::State<usize>) -> Self {
+        self.set_selection(selection);
+        self
+    }
+
+    /// Bind the index of the selected tab to a state, which is updated when
+    /// the user selects another tab, and can be changed to select another tab.
+    /// The first tab is selected by default.
+    pub fn set_selection(&mut self, selection: &crate::State<usize>) {
+        self.selection = selection.clone();
+    }
+
+    /// Takes the contents of the tabs, which are built when needed.
+    #[cfg_attr(target_os = "windows", allow(unused))]
+    fn take_pages<P>(&mut self) -> Rc<RefCell<TabPages<Delegate, State, P>>> {
+        Rc::new(RefCell::new(TabPages {
+            contents: self.tabs.iter_mut().map(|tab| tab.content.take()).collect(),
+            pages: std::iter::repeat_with(|| None).take(self.tabs.len()).collect(),
+        }))
+    }
+}
+
+impl<State, Delegate> BaseView for TabView<State, Delegate> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate, State> From<TabView<State, Delegate>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: TabView<State, Delegate>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// The contents of the tabs, of which the pages `P` are built by the backend
+/// when a tab is selected for the first time.
+struct TabPages<Delegate, State, P> {
+    contents: Vec<Option<TabContent<Delegate, State>>>,
+    pages: Vec<Option<P>>,
+}
+
+impl<Delegate, State, P: Clone> TabPages<Delegate, State, P> {
+    /// Get the page of the tab at `index`, building it when it's selected for
+    /// the first time. Returns [`None`] if the tab doesn't exist.
+    fn page(&mut self, index: usize, build: impl FnOnce(Box<dyn View<Delegate, State>>) -> P) -> Option<P> {
+        let page = self.pages.get_mut(index)?;
+        if page.is_none() {
+            let content = self.contents[index].take()?;
+            *page = Some(build(content()));
+        }
+
+        page.clone()
+    }
+}
+
+/// The height of the tab bar of the headless backend.
+const TAB_BAR_HEIGHT: f32 = 28.0;
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for TabView<State, Delegate>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{is_selecting_tab, set_tab_content, NSTabView};
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let mut tab_view = NSTabView::new();
+        for tab in &self.tabs {
+            tab_view.add_tab(&tab.title, tab.icon.as_ref());
+        }
+
+        let pages = self.take_pages();
+        let tree = RefCell::new(tree.fork());
+        let objc = tab_view.objc.clone();
+        let show = move |index: usize| {
+            pages.borrow_mut().page(index, |mut content| {
+                let view = content.build_native(&mut tree.borrow_mut());
+                set_tab_content(&objc, index, &view);
+
+                // The tab view item retains the view from now on.
+                std::mem::forget(view);
+            });
+        };
+
+        show(self.selection.clone_inner());
+        crate::platform::macos::state::attach_tab_view_state(view_id, self, &tab_view);
+
+        // The tab is also selected by the state, in which case the state
+        // isn't changed.
+        let selection = self.selection.clone();
+        tab_view.set_did_select(move |index| {
+            show(index);
+            if !is_selecting_tab() {
+                selection.set_with_origin(index, StateChangeOrigin::Owner(view_id));
+            }
+        });
+
+        tab_view.into()
+    }
+
+    /// Switching tabs isn't supported on Win32 yet, so only the selected tab
+    /// is displayed.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let content = self.tabs.get_mut(self.selection.clone_inner()).and_then(|tab| tab.content.take());
+        match content {
+            Some(content) => content().build_native(tree, parent),
+            None => WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Empty),
+        }
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::SignalGuard;
+        use crate::ImageKind;
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let notebook = gtk4::Notebook::new();
+        notebook.set_hexpand(true);
+        notebook.set_vexpand(true);
+
+        for tab in &self.tabs {
+            let label = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
+            if let Some(ImageKind::File(path)) = tab.icon.as_ref().map(Image::kind) {
+                label.append(&gtk4::Image::from_file(path));
+            }
+            label.append(&gtk4::Label::new(Some(&tab.title)));
+
+            // The content is added to the page when the tab is selected for
+            // the first time.
+            let page = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+            notebook.append_page(&page, Some(&label));
+        }
+
+        let pages = self.take_pages();
+        let tree = RefCell::new(tree.fork());
+        let page_boxes = notebook.clone();
+        let show = move |index: usize| {
+            pages.borrow_mut().page(index, |mut content| {
+                let view = content.build_native(&mut tree.borrow_mut());
+                if let Some(page) = page_boxes.nth_page(Some(index as u32)).and_downcast::<gtk4::Box>() {
+                    page.append(view.widget());
+                }
+            });
+        };
+
+        let guard = SignalGuard::default();
+        show(self.selection.clone_inner());
+        crate::platform::gtk::state::attach_tab_view_state(view_id, self, &notebook, &guard);
+
+        let selection = self.selection.clone();
+        notebook.connect_switch_page(move |_, _, index| {
+            show(index as usize);
+            if !guard.is_active() {
+                selection.set_with_origin(index as usize, StateChangeOrigin::Owner(view_id));
+            }
+        });
+
+        notebook.into()
+    }
+
+    /// The content of the selected tab is the only child of the view, and is
+    /// placed below the tab bar.
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessTab, HeadlessView, HeadlessViewKind};
+        use crate::{ContainerLayout, GridLayout, GridPlacement, GridTrack, ImageKind};
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let view = HeadlessView::new(view_id, HeadlessViewKind::TabView);
+        view.with_mut(|data| {
+            data.container = Some(ContainerLayout::Grid(GridLayout {
+                columns: vec![GridTrack::Fraction(1.0)],
+                rows: vec![GridTrack::Fixed(TAB_BAR_HEIGHT), GridTrack::Fraction(1.0)],
+                row_spacing: 0.0,
+                cells: vec![GridPlacement::new(1, 0)],
+                ..Default::default()
+            }));
+
+            data.tabs = self.tabs.iter()
+                .map(|tab| HeadlessTab {
+                    title: tab.title.clone(),
+                    icon: match tab.icon.as_ref().map(Image::kind) {
+                        Some(ImageKind::File(path)) => Some(path.clone()),
+                        _ => None,
+                    },
+                })
+                .collect();
+        });
+
+        let pages = self.take_pages();
+        let tree = RefCell::new(tree.fork());
+        let tab_view = view.clone();
+        let show = move |index: usize| {
+            let page = pages.borrow_mut().page(index, |mut content| content.build_headless(&mut tree.borrow_mut()));
+            let Some(page) = page else {
+                return;
+            };
+
+            let count = tab_view.with_mut(|data| {
+                data.selected_tab = index;
+                data.children.len()
+            });
+            tab_view.splice_children(0..count, vec![page]);
+        };
+
+        let show = Rc::new(show);
+        show(self.selection.clone_inner());
+        crate::platform::headless::state::attach_tab_view_state(self, &view, {
+            let show = Rc::clone(&show);
+            move |index| show(index)
+        });
+
+        let selection = self.selection.clone();
+        view.set_tab_selected(move |index| {
+            show(index);
+            selection.set_with_origin(index, StateChangeOrigin::Owner(view_id));
+        });
+
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Checkbox, Label, State};
+
+    use super::*;
+
+    #[test]
+    fn build_tabs_lazily() {
+        let (selected_tab, builds) = (State::new(0), Rc::new(RefCell::new(0)));
+        let app: ViewTestApp<()> = TestApp::launch_view({
+            let (selected_tab, builds) = (selected_tab.clone(), Rc::clone(&builds));
+            move || {
+                let builds = Rc::clone(&builds);
+                TabView::new()
+                    .with_id("tabs")
+                    .with_tab(Tab::new("General", Checkbox::new("Launch at login").with_id("general")))
+                    .with_tab(Tab::lazy("Advanced", move || {
+                        *builds.borrow_mut() += 1;
+                        Label::new("Expensive settings").with_id("advanced")
+                    }))
+                    .with_selection(&selected_tab)
+            }
+        });
+
+        let tabs = app.find_by_id("tabs").unwrap();
+        assert_eq!(tabs.tab_titles(), ["General", "Advanced"]);
+        assert!(app.find_by_id("general").is_some());
+        assert!(app.find_by_id("advanced").is_none());
+        assert_eq!(*builds.borrow(), 0);
+
+        tabs.select_tab(1);
+        assert_eq!(selected_tab.clone_inner(), 1);
+        assert!(app.find_by_id("general").is_none());
+        assert_eq!(*builds.borrow(), 1);
+
+        // The content is placed below the tab bar.
+        app.resize(300.0, 200.0);
+        assert_eq!(app.find_by_id("advanced").unwrap().frame(), euclid::rect(0.0, 28.0, 300.0, 172.0));
+
+        selected_tab.set(0_usize);
+        assert_eq!(tabs.selected_tab(), 0);
+        assert!(app.find_by_id("general").is_some());
+
+        selected_tab.set(1_usize);
+        assert!(app.find_by_id("advanced").is_some());
+        assert_eq!(*builds.borrow(), 1);
+    }
+}
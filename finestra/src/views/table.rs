@@ -0,0 +1,886 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::{AppDelegate, CellAlignment, EventHandlerMap, GridTrack, ListSelection, RowBuilder, RowOrder, Rows, SortOrder, StateChangeOrigin, TableSort, TextAlignment, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+type ValueFn<T> = Rc<dyn Fn(&T) -> String>;
+type CompareFn<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+type EditFn<T> = Rc<dyn Fn(&mut T, String)>;
+
+/// The space between the columns of a [`Table`].
+const COLUMN_SPACING: f32 = 8.0;
+
+/// A column of a [`Table`], which displays a value of every item.
+///
+/// ```
+/// # use finestra::*;
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// # let _: TableColumn<Person> =
+/// TableColumn::new("Age", |person: &Person| person.age)
+///     .with_width(60.0)
+///     .with_alignment(TextAlignment::Right)
+///     .with_sort_by(|a, b| a.age.cmp(&b.age));
+/// ```
+pub struct TableColumn<T> {
+    pub(crate) title: String,
+    pub(crate) width: Option<f32>,
+    pub(crate) alignment: TextAlignment,
+    pub(crate) value: ValueFn<T>,
+    pub(crate) compare: Option<CompareFn<T>>,
+    pub(crate) edit: Option<EditFn<T>>,
+}
+
+impl<T: 'static> TableColumn<T> {
+    /// Create a column with the given title, which displays the `value` of
+    /// every item. By default, the rows can be sorted by the displayed text.
+    #[must_use]
+    pub fn new<V: ToString>(title: impl Into<String>, value: impl Fn(&T) -> V + 'static) -> Self {
+        let value: ValueFn<T> = Rc::new(move |item| value(item).to_string());
+
+        let compare: CompareFn<T> = {
+            let value = Rc::clone(&value);
+            Rc::new(move |a, b| value(a).cmp(&value(b)))
+        };
+
+        Self {
+            title: title.into(),
+            width: None,
+            alignment: TextAlignment::Default,
+            value,
+            compare: Some(compare),
+            edit: None,
+        }
+    }
+
+    /// Compare the items using `compare` when the rows are sorted by this
+    /// column, e.g. to sort numbers by their value instead of their text.
+    #[must_use]
+    pub fn with_sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.set_sort_by(compare);
+        self
+    }
+
+    /// Compare the items using `compare` when the rows are sorted by this
+    /// column, e.g. to sort numbers by their value instead of their text.
+    pub fn set_sort_by(&mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) {
+        self.compare = Some(Rc::new(compare));
+    }
+
+    /// Let the user edit the cells of this column. When the user finished
+    /// editing a cell, `edit` is invoked with the item and the new text, after
+    /// which the items of the table are set. Cells can't be edited on macOS
+    /// and Win32 yet, where the column is shown read-only.
+    #[must_use]
+    pub fn with_on_edit(mut self, edit: impl Fn(&mut T, String) + 'static) -> Self {
+        self.set_on_edit(edit);
+        self
+    }
+
+    /// Let the user edit the cells of this column. When the user finished
+    /// editing a cell, `edit` is invoked with the item and the new text, after
+    /// which the items of the table are set. Cells can't be edited on macOS
+    /// and Win32 yet, where the column is shown read-only.
+    pub fn set_on_edit(&mut self, edit: impl Fn(&mut T, String) + 'static) {
+        self.edit = Some(Rc::new(edit));
+    }
+
+    /// Allow the user to sort the rows by this column, by clicking its
+    /// header. The default is `true`.
+    #[must_use]
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.set_sortable(sortable);
+        self
+    }
+
+    /// Allow the user to sort the rows by this column, by clicking its
+    /// header. The default is `true`.
+    pub fn set_sortable(&mut self, sortable: bool) {
+        if !sortable {
+            self.compare = None;
+        } else if self.compare.is_none() {
+            let value = Rc::clone(&self.value);
+            self.compare = Some(Rc::new(move |a, b| value(a).cmp(&value(b))));
+        }
+    }
+}
+
+impl<T> TableColumn<T> {
+    /// Set the width of the column. By default, the columns without a width
+    /// share the remaining space.
+    #[must_use]
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.set_width(width);
+        self
+    }
+
+    /// Set the width of the column. By default, the columns without a width
+    /// share the remaining space.
+    pub fn set_width(&mut self, width: f32) {
+        self.width = Some(width);
+    }
+
+    /// Set the alignment of the text in the cells of the column.
+    #[must_use]
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.set_alignment(alignment);
+        self
+    }
+
+    /// Set the alignment of the text in the cells of the column.
+    pub fn set_alignment(&mut self, alignment: TextAlignment) {
+        self.alignment = alignment;
+    }
+
+    #[must_use]
+    pub(crate) fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    #[must_use]
+    pub(crate) fn is_editable(&self) -> bool {
+        self.edit.is_some()
+    }
+
+    #[must_use]
+    pub(crate) fn track(&self) -> GridTrack {
+        match self.width {
+            Some(width) => GridTrack::Fixed(width),
+            None => GridTrack::Fraction(1.0),
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn cell_alignment(&self) -> CellAlignment {
+        match self.alignment {
+            TextAlignment::Default | TextAlignment::Left => CellAlignment::Start,
+            TextAlignment::Center => CellAlignment::Center,
+            TextAlignment::Right => CellAlignment::End,
+        }
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            title: self.title.clone(),
+            width: self.width,
+            alignment: self.alignment,
+            value: Rc::clone(&self.value),
+            compare: self.compare.clone(),
+            edit: self.edit.clone(),
+        }
+    }
+}
+
+/// A table displays a row for every item of a [`State<Vec<T>>`](crate::State),
+/// and a column for every [`TableColumn`]. The user can sort the rows by
+/// clicking the header of a column. Only the rows are sorted: the items of the
+/// state keep their order, and the selected and activated rows are the
+/// indices of their items in the state.
+///
+/// Like a [`List`](crate::List), only the rows of the items that changed are
+/// built again, and the selected rows are bound to a state.
+///
+/// ```
+/// # use finestra::*;
+/// #[derive(Clone, PartialEq)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let people = State::new(vec![
+///     Person { name: "Alice".into(), age: 34 },
+///     Person { name: "Bob".into(), age: 27 },
+/// ]);
+///
+/// # let _: Table<Person, (), ()> =
+/// Table::new(&people)
+///     .with_column(TableColumn::new("Name", |person: &Person| person.name.clone())
+///         .with_on_edit(|person, name| person.name = name))
+///     .with_column(TableColumn::new("Age", |person: &Person| person.age)
+///         .with_sort_by(|a, b| a.age.cmp(&b.age)))
+///     .with_sort(&State::new(Some(TableSort::ascending(1))));
+/// ```
+pub struct Table<T, State, Delegate> {
+    base: ViewBase,
+    _phantom: PhantomData<Delegate>,
+
+    pub(crate) items: crate::State<Vec<T>>,
+    pub(crate) columns: Vec<TableColumn<T>>,
+    pub(crate) selection: ListSelection,
+    pub(crate) sort: crate::State<Option<TableSort>>,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<T, State: 'static, Delegate> Table<T, State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Create a table that displays the `items`. Use [`Self::with_column()`]
+    /// to add the columns.
+    #[must_use]
+    pub fn new(items: &crate::State<Vec<T>>) -> Self {
+        Self {
+            base: ViewBase::default(),
+            _phantom: PhantomData,
+
+            items: items.clone(),
+            columns: Vec::new(),
+            selection: ListSelection::None,
+            sort: crate::State::new(None),
+            event_handler_map: EventHandlerMap::default(),
+        }
+    }
+}
+
+impl<T, State, Delegate> Table<T, State, Delegate> {
+    /// Add a column after the existing columns.
+    #[must_use]
+    pub fn with_column(mut self, column: TableColumn<T>) -> Self {
+        self.add_column(column);
+        self
+    }
+
+    /// Add a column after the existing columns.
+    pub fn add_column(&mut self, column: TableColumn<T>) {
+        self.columns.push(column);
+    }
+
+    /// Let the user select a single row. The state contains the index of the
+    /// selected row, and can be changed to select another row.
+    #[must_use]
+    pub fn with_selection(mut self, selection: &crate::State<Option<usize>>) -> Self {
+        self.set_selection(selection);
+        self
+    }
+
+    /// Let the user select a single row. The state contains the index of the
+    /// selected row, and can be changed to select another row.
+    pub fn set_selection(&mut self, selection: &crate::State<Option<usize>>) {
+        self.selection = ListSelection::Single(selection.clone());
+    }
+
+    /// Let the user select multiple rows. The state contains the indices of
+    /// the selected rows, and can be changed to select other rows.
+    #[must_use]
+    pub fn with_multiple_selection(mut self, selection: &crate::State<Vec<usize>>) -> Self {
+        self.set_multiple_selection(selection);
+        self
+    }
+
+    /// Let the user select multiple rows. The state contains the indices of
+    /// the selected rows, and can be changed to select other rows.
+    pub fn set_multiple_selection(&mut self, selection: &crate::State<Vec<usize>>) {
+        self.selection = ListSelection::Multiple(selection.clone());
+    }
+
+    /// Bind the column by which the rows are sorted to a state, which is
+    /// updated when the user clicks the header of a column. Changing the state
+    /// sorts the rows as well.
+    #[must_use]
+    pub fn with_sort(mut self, sort: &crate::State<Option<TableSort>>) -> Self {
+        self.set_sort(sort);
+        self
+    }
+
+    /// Bind the column by which the rows are sorted to a state, which is
+    /// updated when the user clicks the header of a column. Changing the state
+    /// sorts the rows as well.
+    pub fn set_sort(&mut self, sort: &crate::State<Option<TableSort>>) {
+        self.sort = sort.clone();
+    }
+
+    /// Get notified when the user activates a row, e.g. by double clicking it,
+    /// or by pressing the return key.
+    pub fn set_on_activate(&mut self, action: impl Fn(&mut State, usize, Window) + 'static) {
+        self.event_handler_map.activate = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user activates a row, e.g. by double clicking it,
+    /// or by pressing the return key.
+    #[must_use]
+    pub fn with_on_activate(mut self, action: impl Fn(&mut State, usize, Window) + 'static) -> Self {
+        self.set_on_activate(action);
+        self
+    }
+}
+
+impl<T, State, Delegate> Table<T, State, Delegate>
+        where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    /// Takes the event handlers, of which the activated row is mapped to the
+    /// index of its item.
+    fn take_event_handler_map(&mut self, order: &RowOrder) -> EventHandlerMap<State> {
+        let mut map = std::mem::take(&mut self.event_handler_map);
+        if let Some(activate) = map.activate.take() {
+            let order = order.clone();
+            map.activate = Some(Rc::new(move |state, row, window| {
+                if let Some(item) = order.item(row) {
+                    activate(state, item, window);
+                }
+            }));
+        }
+        map
+    }
+
+    /// Creates the view of a row: a single row of a [`Grid`](crate::Grid),
+    /// with a label for every column.
+    fn row_builder(columns: &Rc<[TableColumn<T>]>) -> RowBuilder<T, Delegate, State> {
+        use crate::{Grid, GridCell, Label};
+
+        let columns = Rc::clone(columns);
+        Rc::new(move |item| {
+            let mut grid = Grid::new()
+                .with_columns(columns.iter().map(TableColumn::track))
+                .with_column_spacing(COLUMN_SPACING);
+
+            for (index, column) in columns.iter().enumerate() {
+                let label = Label::new((column.value)(item));
+                grid = grid.with_cell(GridCell::new(0, index, label)
+                    .with_horizontal_alignment(column.cell_alignment()));
+            }
+
+            grid.into()
+        })
+    }
+
+    /// Composes the table of a header with a button for every column, and a
+    /// [`List`](crate::List) of the rows, for the platforms that don't use a
+    /// native table view yet. The cells are labels, so editable columns are
+    /// shown read-only.
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    fn compose(&mut self) -> crate::Stack<State, Delegate> {
+        use crate::{Button, Grid, GridCell, Label, List, Stack};
+
+        let columns: Rc<[TableColumn<T>]> = self.columns.clone().into();
+        let sorted = SortedRows::new(&self.items, &columns, &self.sort, &self.selection);
+
+        let mut header = Grid::new()
+            .with_columns(columns.iter().map(TableColumn::track))
+            .with_column_spacing(COLUMN_SPACING);
+
+        for (index, column) in columns.iter().enumerate() {
+            let cell: Box<dyn View<Delegate, State>> = if column.is_sortable() {
+                let sort = self.sort.clone();
+                Button::new(column.title.clone())
+                    .with_on_click(move |_, _| {
+                        sort.set(Some(TableSort::after_click(sort.clone_inner(), index)));
+                    })
+                    .into()
+            } else {
+                Label::new(column.title.clone()).into()
+            };
+
+            header = header.with_cell(GridCell::new(0, index, cell)
+                .with_horizontal_alignment(column.cell_alignment()));
+        }
+
+        let mut list = List::with_row_builder(&sorted.rows, Self::row_builder(&columns));
+        list.selection = sorted.selection.clone();
+        list.event_handler_map = self.take_event_handler_map(&sorted.order);
+
+        let mut stack = Stack::vertical()
+            .with(header)
+            .with(list);
+        *stack.base_mut() = std::mem::take(&mut self.base);
+        stack
+    }
+}
+
+/// Get the indices of the items in the order of the rows, when the rows are
+/// sorted by the given column. The items are in their own order when they
+/// aren't sorted.
+fn sort_order<T>(items: &[T], columns: &[TableColumn<T>], sort: Option<TableSort>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+
+    let Some(sort) = sort else {
+        return order;
+    };
+
+    let Some(compare) = columns.get(sort.column).and_then(|column| column.compare.clone()) else {
+        return order;
+    };
+
+    order.sort_by(|a, b| {
+        let ordering = compare(&items[*a], &items[*b]);
+        match sort.order {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    });
+    order
+}
+
+/// The rows of a table: the items in the order of the sort, from which the
+/// views of the rows are built. The items themselves aren't reordered, as the
+/// [`RowOrder`] maps the rows to the items. The selected rows are kept in sync
+/// with the selected items.
+struct SortedRows<T> {
+    rows: crate::State<Vec<T>>,
+    selection: ListSelection,
+    order: RowOrder,
+}
+
+impl<T: Clone + PartialEq + 'static> SortedRows<T> {
+    fn new(
+        items: &crate::State<Vec<T>>,
+        columns: &Rc<[TableColumn<T>]>,
+        sort: &crate::State<Option<TableSort>>,
+        selection: &ListSelection,
+    ) -> Self {
+        let sorted = Self {
+            rows: crate::State::new(Vec::new()),
+            selection: selection.empty_like(),
+            order: RowOrder::default(),
+        };
+
+        // Set while one selection is updated from the other, so the update
+        // isn't sent back.
+        let syncing = Rc::new(Cell::new(false));
+
+        let update = {
+            let (rows, rows_selection, order) = (sorted.rows.clone(), sorted.selection.clone(), sorted.order.clone());
+            let (columns, selection, syncing) = (Rc::clone(columns), selection.clone(), Rc::clone(&syncing));
+            Rc::new(move |items: &[T], sort: Option<TableSort>| {
+                syncing.set(true);
+                order.set(sort_order(items, &columns, sort));
+                rows.set(order.apply(items));
+                rows_selection.update_rows(order.rows_of(&selection.rows()), StateChangeOrigin::System);
+                syncing.set(false);
+            })
+        };
+
+        items.with(|items| update(items, sort.clone_inner()));
+
+        // The selected items follow the items that moved, after which the
+        // rows are sorted again.
+        let snapshot = Rows::new(items);
+        {
+            let (update, sort, snapshot_items) = (Rc::clone(&update), sort.clone(), snapshot.clone());
+            snapshot.observe(items, selection, move |_| {
+                snapshot_items.with(|items| update(items, sort.clone_inner()));
+            });
+        }
+
+        sort.add_listener(move |sort| {
+            snapshot.with(|items| update(items, *sort));
+        });
+
+        {
+            let (rows_selection, order, syncing) = (sorted.selection.clone(), sorted.order.clone(), Rc::clone(&syncing));
+            selection.add_listener(move |items| {
+                if !syncing.get() {
+                    syncing.set(true);
+                    rows_selection.update_rows(order.rows_of(&items), StateChangeOrigin::System);
+                    syncing.set(false);
+                }
+            }, StateChangeOrigin::User);
+        }
+
+        {
+            let (selection, order) = (selection.clone(), sorted.order.clone());
+            sorted.selection.add_listener(move |rows| {
+                if !syncing.get() {
+                    syncing.set(true);
+                    selection.update_rows(order.items_of(&rows), StateChangeOrigin::System);
+                    syncing.set(false);
+                }
+            }, StateChangeOrigin::User);
+        }
+
+        sorted
+    }
+}
+
+/// Invoked when the user finished editing a cell, after which the items are set
+/// to notify the listeners.
+fn edit_cell<T: Clone>(items: &crate::State<Vec<T>>, columns: &[TableColumn<T>], row: usize, column: usize, text: String) {
+    let Some(edit) = columns.get(column).and_then(|column| column.edit.clone()) else {
+        return;
+    };
+
+    let mut current = items.clone_inner();
+    let Some(item) = current.get_mut(row) else {
+        return;
+    };
+
+    edit(item, text);
+    items.set(current);
+}
+
+impl<T, State, Delegate> BaseView for Table<T, State, Delegate> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<T, Delegate, State> From<Table<T, State, Delegate>> for Box<dyn View<Delegate, State>>
+        where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Table<T, State, Delegate>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for Table<T, State, Delegate>
+        where T: Clone + PartialEq + 'static, Delegate: 'static, State: 'static {
+    /// The header is a row of buttons above a [`List`](crate::List), until
+    /// the table is backed by an `NSTableView` with multiple columns.
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        self.compose().build_native(tree)
+    }
+
+    /// The header is a row of buttons above a [`List`](crate::List), which
+    /// builds all rows at once on Win32.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        self.compose().build_native(tree, parent)
+    }
+
+    /// The rows are displayed by a `GtkColumnView`, which only creates the
+    /// rows that are visible.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{create_row_model, Event, SignalGuard};
+
+        let columns: Rc<[TableColumn<T>]> = self.columns.clone().into();
+        let sorted = SortedRows::new(&self.items, &columns, &self.sort, &self.selection);
+
+        let map = self.take_event_handler_map(&sorted.order);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let guard = SignalGuard::default();
+        let rows = Rows::new(&sorted.rows);
+        let selection_model = create_row_model(view_id, &rows, &sorted.rows, &sorted.selection, &guard);
+
+        let column_view = gtk4::ColumnView::new(Some(selection_model.clone()));
+        column_view.set_reorderable(false);
+
+        // The sorter of the column view compares by the primary column, and
+        // inverts the result when it is sorted in descending order. The
+        // sorters of the columns record which column was compared, so the sort
+        // can be read from the column view, without sorting the model itself.
+        let compared_column = Rc::new(Cell::new(None));
+
+        let mut gtk_columns = Vec::with_capacity(columns.len());
+        for (index, column) in columns.iter().enumerate() {
+            let factory = gtk4::SignalListItemFactory::new();
+            let xalign = match column.alignment {
+                TextAlignment::Default | TextAlignment::Left => 0.0,
+                TextAlignment::Center => 0.5,
+                TextAlignment::Right => 1.0,
+            };
+
+            if column.is_editable() {
+                let items = self.items.clone();
+                let order = sorted.order.clone();
+                let edit_columns = Rc::clone(&columns);
+                factory.connect_setup(move |_, item| {
+                    let item = item.downcast_ref::<gtk4::ListItem>().unwrap();
+                    let label = gtk4::EditableLabel::new("");
+                    label.set_alignment(xalign);
+
+                    let items = items.clone();
+                    let order = order.clone();
+                    let columns = Rc::clone(&edit_columns);
+                    let list_item = item.downgrade();
+                    label.connect_editing_notify(move |label| {
+                        if label.is_editing() {
+                            return;
+                        }
+
+                        let row = list_item.upgrade().and_then(|list_item| order.item(list_item.position() as usize));
+                        if let Some(row) = row {
+                            edit_cell(&items, &columns, row, index, label.text().into());
+                        }
+                    });
+
+                    item.set_child(Some(&label));
+                });
+            } else {
+                factory.connect_setup(move |_, item| {
+                    let item = item.downcast_ref::<gtk4::ListItem>().unwrap();
+                    let label = gtk4::Label::new(None);
+                    label.set_xalign(xalign);
+                    item.set_child(Some(&label));
+                });
+            }
+
+            let value = Rc::clone(&column.value);
+            let snapshot = rows.clone();
+            factory.connect_bind(move |_, item| {
+                let item = item.downcast_ref::<gtk4::ListItem>().unwrap();
+                let text = snapshot.with(|items| items.get(item.position() as usize).map(|item| value(item)));
+                let text = text.unwrap_or_default();
+
+                if let Some(label) = item.child().and_downcast::<gtk4::Label>() {
+                    label.set_text(&text);
+                } else if let Some(label) = item.child().and_downcast::<gtk4::EditableLabel>() {
+                    label.set_text(&text);
+                }
+            });
+
+            let gtk_column = gtk4::ColumnViewColumn::new(Some(&column.title), Some(factory));
+            match column.width {
+                Some(width) => gtk_column.set_fixed_width(width as i32),
+                None => gtk_column.set_expand(true),
+            }
+
+            if column.is_sortable() {
+                let compared_column = Rc::clone(&compared_column);
+                gtk_column.set_sorter(Some(&gtk4::CustomSorter::new(move |_, _| {
+                    compared_column.set(Some(index));
+                    gtk4::Ordering::Smaller
+                })));
+            }
+
+            column_view.append_column(&gtk_column);
+            gtk_columns.push(gtk_column);
+        }
+
+        if let Some(sorter) = column_view.sorter() {
+            let sort = self.sort.clone();
+            let sort_guard = guard.clone();
+            let placeholder = gtk4::StringObject::new("");
+            sorter.connect_changed(move |sorter, _| {
+                if sort_guard.is_active() {
+                    return;
+                }
+
+                compared_column.set(None);
+                let ordering = sorter.compare(&placeholder, &placeholder);
+                let value = compared_column.get().map(|column| TableSort {
+                    column,
+                    order: if ordering == gtk4::Ordering::Larger { SortOrder::Descending } else { SortOrder::Ascending },
+                });
+
+                sort.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            });
+        }
+
+        let dispatcher = tree.create_dispatcher();
+        column_view.connect_activate(move |_, position| {
+            dispatcher.dispatch_event(Event::ListRowActivated(view_id, position as usize));
+        });
+
+        crate::platform::gtk::state::attach_table_state(view_id, self, &sorted.selection, &column_view, &gtk_columns, &selection_model, &guard);
+
+        let scrolled_window = gtk4::ScrolledWindow::new();
+        scrolled_window.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled_window.set_vexpand(true);
+        scrolled_window.set_child(Some(&column_view));
+        scrolled_window.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessColumn, HeadlessView, HeadlessViewKind};
+        use crate::ContainerLayout;
+
+        let columns: Rc<[TableColumn<T>]> = self.columns.clone().into();
+        let sorted = SortedRows::new(&self.items, &columns, &self.sort, &self.selection);
+
+        let map = self.take_event_handler_map(&sorted.order);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let view = HeadlessView::new(view_id, HeadlessViewKind::Table);
+        view.with_mut(|data| {
+            data.container = Some(ContainerLayout::Stack(super::list::row_layout()));
+            data.columns = columns.iter()
+                .map(|column| HeadlessColumn {
+                    title: column.title.clone(),
+                    sortable: column.is_sortable(),
+                    editable: column.is_editable(),
+                })
+                .collect();
+        });
+
+        super::list::build_headless_rows(&view, &sorted.rows, &Self::row_builder(&columns), &sorted.selection, tree);
+        crate::platform::headless::state::attach_table_state(self, &sorted.selection, &view);
+
+        let sort = self.sort.clone();
+        view.set_column_clicked(move |column| {
+            sort.set(Some(TableSort::after_click(sort.clone_inner(), column)));
+        });
+
+        let items = self.items.clone();
+        view.set_cell_edited(move |row, column, text| {
+            if let Some(row) = sorted.order.item(row) {
+                edit_cell(&items, &columns, row, column, text.to_owned());
+            }
+        });
+
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, TestView, ViewTestApp};
+    use crate::{BaseViewExt, State, TextAlignment};
+
+    use super::*;
+
+    fn columns() -> Vec<TableColumn<(&'static str, u32)>> {
+        vec![
+            TableColumn::new("Name", |item: &(&str, u32)| item.0),
+            TableColumn::new("Count", |item: &(&str, u32)| item.1)
+                .with_sort_by(|a, b| a.1.cmp(&b.1))
+                .with_on_edit(|item, text| item.1 = text.parse().unwrap_or_default()),
+        ]
+    }
+
+    #[test]
+    fn sort_by_text_and_by_value() {
+        let items = [("b", 10), ("c", 9), ("a", 100)];
+
+        assert_eq!(sort_order(&items, &columns(), Some(TableSort::ascending(0))), [2, 0, 1]);
+        assert_eq!(sort_order(&items, &columns(), Some(TableSort::descending(1))), [2, 0, 1]);
+        assert_eq!(sort_order(&items, &columns(), Some(TableSort::ascending(1))), [1, 0, 2]);
+        assert_eq!(sort_order(&items, &columns(), None), [0, 1, 2]);
+    }
+
+    #[test]
+    fn unsortable_column() {
+        let items = [("b", 1), ("a", 2)];
+        let columns = [TableColumn::new("Name", |item: &(&str, u32)| item.0).with_sortable(false)];
+
+        assert_eq!(sort_order(&items, &columns, Some(TableSort::ascending(0))), [0, 1]);
+    }
+
+    #[test]
+    fn sorted_rows_follow_items_and_selection() {
+        let items = State::new(vec![("b", 10), ("c", 9), ("a", 100)]);
+        let sort = State::new(Some(TableSort::ascending(0)));
+        let selection = ListSelection::Single(State::new(Some(0)));
+        let sorted = SortedRows::new(&items, &columns().into(), &sort, &selection);
+        assert_eq!(sorted.rows.clone_inner(), [("a", 100), ("b", 10), ("c", 9)]);
+        assert_eq!(sorted.selection.rows(), [1]);
+
+        // The items keep their order, and the selection follows the item.
+        sort.set(Some(TableSort::ascending(1)));
+        assert_eq!(items.clone_inner(), [("b", 10), ("c", 9), ("a", 100)]);
+        assert_eq!(sorted.rows.clone_inner(), [("c", 9), ("b", 10), ("a", 100)]);
+        assert_eq!(sorted.selection.rows(), [1]);
+        assert_eq!(selection.rows(), [0]);
+
+        items.set(vec![("d", 1), ("b", 10), ("c", 9), ("a", 100)]);
+        assert_eq!(sorted.rows.clone_inner(), [("d", 1), ("c", 9), ("b", 10), ("a", 100)]);
+        assert_eq!(selection.rows(), [1]);
+        assert_eq!(sorted.selection.rows(), [2]);
+
+        sorted.selection.set_rows(vec![3], StateChangeOrigin::System);
+        assert_eq!(selection.rows(), [3]);
+
+        selection.set_rows(vec![0], StateChangeOrigin::User);
+        assert_eq!(sorted.selection.rows(), [0]);
+    }
+
+    #[test]
+    fn edit_writes_back_to_the_items() {
+        let items = State::new(vec![("a", 1), ("b", 2)]);
+
+        edit_cell(&items, &columns(), 1, 1, "42".into());
+        assert_eq!(items.clone_inner(), [("a", 1), ("b", 42)]);
+
+        // The name column isn't editable.
+        edit_cell(&items, &columns(), 0, 0, "z".into());
+        assert_eq!(items.clone_inner(), [("a", 1), ("b", 42)]);
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Book {
+        title: String,
+        pages: u32,
+    }
+
+    fn book(title: &str, pages: u32) -> Book {
+        Book { title: title.to_string(), pages }
+    }
+
+    fn launch(books: &State<Vec<Book>>, selection: &State<Option<usize>>, sort: &State<Option<TableSort>>) -> ViewTestApp<usize> {
+        let (books, selection, sort) = (books.clone(), selection.clone(), sort.clone());
+        TestApp::launch_view(move || {
+            Table::new(&books)
+                .with_id("books")
+                .with_column(TableColumn::new("Title", |book: &Book| book.title.clone())
+                    .with_on_edit(|book, title| book.title = title))
+                .with_column(TableColumn::new("Pages", |book: &Book| book.pages)
+                    .with_width(60.0)
+                    .with_alignment(TextAlignment::Right)
+                    .with_sort_by(|a, b| a.pages.cmp(&b.pages)))
+                .with_selection(&selection)
+                .with_sort(&sort)
+                .with_on_activate(|opened: &mut Vec<usize>, row, _| opened.push(row))
+        })
+    }
+
+    #[test]
+    fn sorting_and_editing() {
+        let books = State::new(vec![book("Emma", 474), book("Ulysses", 730), book("Candide", 94)]);
+        let (selection, sort) = (State::new(Some(1)), State::new(None));
+        let app = launch(&books, &selection, &sort);
+
+        let table = app.find_by_id("books").unwrap();
+        let cells = || table.children().iter()
+            .map(|row| row.children().iter().map(TestView::text).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>();
+        assert_eq!(table.column_titles(), ["Title", "Pages"]);
+        assert_eq!(cells(), ["Emma 474", "Ulysses 730", "Candide 94"]);
+
+        // The pages are sorted by their value, and the selection follows.
+        table.click_column(1);
+        assert_eq!(sort.clone_inner(), Some(TableSort::ascending(1)));
+        assert_eq!(cells(), ["Candide 94", "Emma 474", "Ulysses 730"]);
+        assert_eq!(table.selected_rows(), [2]);
+
+        // Only the rows are sorted, so the selected item stays the same.
+        table.click_column(1);
+        assert_eq!(cells(), ["Ulysses 730", "Emma 474", "Candide 94"]);
+        assert_eq!(table.selected_rows(), [0]);
+        assert_eq!(selection.clone_inner(), Some(1));
+        assert_eq!(books.clone_inner(), [book("Emma", 474), book("Ulysses", 730), book("Candide", 94)]);
+
+        table.select_row(2);
+        assert_eq!(selection.clone_inner(), Some(2));
+
+        sort.set(Some(TableSort::ascending(0)));
+        assert_eq!(cells(), ["Candide 94", "Emma 474", "Ulysses 730"]);
+        assert_eq!(table.selected_rows(), [0]);
+
+        // The edited item is sorted again.
+        table.edit_cell(0, 0, "Zadig");
+        assert_eq!(books.clone_inner()[2], book("Zadig", 94));
+        assert_eq!(cells(), ["Emma 474", "Ulysses 730", "Zadig 94"]);
+        assert_eq!(table.selected_rows(), [2]);
+
+        // The activated row is the index of the item.
+        table.activate_row(1);
+        assert_eq!(app.events(), [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't edit the cell")]
+    fn read_only_column() {
+        let books = State::new(vec![book("Emma", 474)]);
+        let app = launch(&books, &State::new(None), &State::new(None));
+        app.find_by_id("books").unwrap().edit_cell(0, 1, "500");
+    }
+}
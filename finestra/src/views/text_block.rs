@@ -131,9 +131,33 @@ impl<Delegate, State: 'static> View<Delegate, State> for TextBlock<State>
         });
 
         label.as_ref().subscribe_text_update(self.text.as_state());
+        self.base.register_handle(|| *label.as_ref());
 
         WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Label(label))
     }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let label = self.text.with(|text| {
+            gtk4::Label::new(Some(text))
+        });
+        label.set_wrap(true);
+
+        crate::platform::gtk::state::attach_text_block_state(view_id, self, &label);
+        label.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{HeadlessView, HeadlessViewKind};
+
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let label = HeadlessView::new(view_id, HeadlessViewKind::TextBlock);
+        crate::platform::headless::state::attach_text_block_state(self, &label);
+        label
+    }
 }
 
 impl<Delegate, State> From<TextBlock<State>> for Box<dyn View<Delegate, State>>
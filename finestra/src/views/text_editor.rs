@@ -0,0 +1,334 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+use crate::{event::EventHandlerMap, AppDelegate, StateChangeOrigin, StateOrRaw, TextSelection, View, ViewBase, Window};
+
+use super::base::BaseView;
+
+/// A text editor is a scrollable view in which the user can enter multiple
+/// lines of text. Unlike a [`TextField`](crate::TextField), the return key
+/// inserts a new line.
+///
+/// ```
+/// # use finestra::*;
+/// let notes = TextValue::new("Groceries:\n".to_owned());
+/// let selection = State::new(TextSelection::caret_at(11));
+///
+/// # let _: TextEditor<()> =
+/// TextEditor::new(&notes)
+///     .with_selection(&selection)
+///     .with_word_wrap(false)
+///     .with_on_change(|_, text, _| println!("The notes are now {} lines long", text.lines().count()));
+/// ```
+pub struct TextEditor<State> {
+    pub(crate) base: ViewBase,
+    pub(crate) text: StateOrRaw<String>,
+    pub(crate) selection: Option<crate::State<TextSelection>>,
+    pub(crate) editable: StateOrRaw<bool>,
+    pub(crate) word_wrap: bool,
+    event_handler_map: EventHandlerMap<State>,
+}
+
+impl<State> TextEditor<State> {
+    /// Creates a new [`TextEditor`] with an associated string that contains
+    /// the initial text. If the value is a [`TextValue`](crate::TextValue),
+    /// this value will be updated if the text changes.
+    #[must_use]
+    pub fn new(text: impl Into<StateOrRaw<String>>) -> Self {
+        Self {
+            base: ViewBase::default(),
+            text: text.into(),
+            selection: None,
+            editable: StateOrRaw::Raw(true),
+            word_wrap: true,
+            event_handler_map: Default::default(),
+        }
+    }
+
+    /// Get notified when the text is changed by the user.
+    pub fn set_on_change(&mut self, action: impl Fn(&mut State, String, Window) + 'static) {
+        self.event_handler_map.text_changed = Some(Rc::new(action));
+    }
+
+    /// Get notified when the text is changed by the user.
+    #[must_use]
+    pub fn with_on_change(mut self, action: impl Fn(&mut State, String, Window) + 'static) -> Self {
+        self.set_on_change(action);
+        self
+    }
+
+    /// Bind the selected text and the position of the caret to the
+    /// `selection`, which is updated when the user moves the caret, and
+    /// selects the text when it is changed by the program.
+    #[must_use]
+    pub fn with_selection(mut self, selection: &crate::State<TextSelection>) -> Self {
+        self.set_selection(selection);
+        self
+    }
+
+    /// Bind the selected text and the position of the caret to the
+    /// `selection`, which is updated when the user moves the caret, and
+    /// selects the text when it is changed by the program.
+    pub fn set_selection(&mut self, selection: &crate::State<TextSelection>) {
+        self.selection = Some(selection.clone());
+    }
+
+    /// Set whether the user can change the text. A read-only editor can still
+    /// be scrolled, and its text can be selected and copied. Editors are
+    /// editable by default.
+    #[must_use]
+    pub fn with_editable(mut self, editable: impl Into<StateOrRaw<bool>>) -> Self {
+        self.set_editable(editable);
+        self
+    }
+
+    /// Set whether the user can change the text. A read-only editor can still
+    /// be scrolled, and its text can be selected and copied. Editors are
+    /// editable by default.
+    pub fn set_editable(&mut self, editable: impl Into<StateOrRaw<bool>>) {
+        self.editable = editable.into();
+    }
+
+    /// Set whether lines that are wider than the editor are wrapped, which is
+    /// the default. Otherwise, the editor scrolls horizontally.
+    #[must_use]
+    pub fn with_word_wrap(mut self, word_wrap: bool) -> Self {
+        self.set_word_wrap(word_wrap);
+        self
+    }
+
+    /// Set whether lines that are wider than the editor are wrapped, which is
+    /// the default. Otherwise, the editor scrolls horizontally.
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.word_wrap = word_wrap;
+    }
+}
+
+impl<State> BaseView for TextEditor<State> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for TextEditor<State>
+        where Delegate: 'static, State: 'static {
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::{Event, NSTextView};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let text_view = NSTextView::new(self.word_wrap);
+        crate::platform::macos::state::attach_text_editor_state(view_id, self, &text_view);
+
+        let text = self.text.as_state();
+        let dispatcher = tree.create_dispatcher();
+        text_view.set_text_did_change(move |value| {
+            dispatcher.dispatch_event(Event::TextFieldChanged(view_id, value.clone().into()));
+
+            if let Some(text) = &text {
+                text.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            }
+        });
+
+        if let Some(selection) = self.selection.clone() {
+            text_view.set_selection_did_change(move |value| {
+                if selection.clone_inner() != value {
+                    selection.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+                }
+            });
+        }
+
+        text_view.into()
+    }
+
+    /// Text editors aren't supported on Win32 yet.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        _parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        use crate::platform::win32::view::{WinView, WinViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        WinView::new(tree.exchange_events_for_id(map), WinViewKind::Empty)
+    }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let text_view = gtk4::TextView::new();
+        text_view.set_wrap_mode(if self.word_wrap { gtk4::WrapMode::WordChar } else { gtk4::WrapMode::None });
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_text_editor_state(view_id, self, &text_view, &guard);
+
+        let buffer = text_view.buffer();
+        let text = self.text.as_state();
+        let dispatcher = tree.create_dispatcher();
+        let changed_guard = guard.clone();
+        buffer.connect_changed(move |buffer| {
+            if changed_guard.is_active() {
+                return;
+            }
+
+            let value = buffer.text(&buffer.start_iter(), &buffer.end_iter(), false).to_string();
+            dispatcher.dispatch_event(Event::TextFieldChanged(view_id, value.clone().into()));
+
+            if let Some(text) = &text {
+                text.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            }
+        });
+
+        if let Some(selection) = self.selection.clone() {
+            // The caret is the "insert" mark, and the anchor is the
+            // "selection_bound" mark.
+            buffer.connect_mark_set(move |buffer, _, mark| {
+                if guard.is_active() || (*mark != buffer.get_insert() && *mark != buffer.selection_bound()) {
+                    return;
+                }
+
+                let offset = |mark| buffer.iter_at_mark(&mark).offset().max(0) as usize;
+                let value = TextSelection::new(offset(buffer.selection_bound()), offset(buffer.get_insert()));
+                if selection.clone_inner() != value {
+                    selection.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+                }
+            });
+        }
+
+        let scrolled_window = gtk4::ScrolledWindow::new();
+        scrolled_window.set_min_content_width(200);
+        scrolled_window.set_min_content_height(100);
+        scrolled_window.set_child(Some(&text_view));
+        scrolled_window.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let text_editor = HeadlessView::new(view_id, HeadlessViewKind::TextEditor);
+        crate::platform::headless::state::attach_text_editor_state(self, &text_editor);
+
+        let text = self.text.as_state();
+        text_editor.set_text_did_change(move |value| {
+            HeadlessContext::dispatch(Event::TextFieldChanged(view_id, value.to_string().into()));
+
+            if let Some(text) = &text {
+                text.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            }
+        });
+
+        if let Some(selection) = self.selection.clone() {
+            text_editor.set_text_selection_did_change(move |value| {
+                selection.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            });
+        }
+
+        text_editor
+    }
+}
+
+impl<Delegate, State> From<TextEditor<State>> for Box<dyn View<Delegate, State>>
+        where Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: TextEditor<State>) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{State, TextSelection, TextValue};
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct Notes {
+        text: TextValue,
+        selection: State<TextSelection>,
+        editable: State<bool>,
+    }
+
+    fn launch(text: &str) -> (ViewTestApp<String>, Notes) {
+        let notes = Notes {
+            text: TextValue::new(text.to_owned()),
+            selection: State::new(TextSelection::default()),
+            editable: State::new(true),
+        };
+
+        let app = TestApp::launch_view({
+            let notes = notes.clone();
+            move || {
+                TextEditor::new(&notes.text)
+                    .with_selection(&notes.selection)
+                    .with_editable(&notes.editable)
+                    .with_on_change(|changes: &mut Vec<String>, text, _| changes.push(text))
+            }
+        });
+        (app, notes)
+    }
+
+    #[test]
+    fn replace_selection() {
+        let (app, notes) = launch("Hello world");
+        let editor = app.root();
+        assert_eq!(editor.frame().size, euclid::size2(200.0, 100.0));
+
+        editor.select_text(6..11);
+        assert_eq!(notes.selection.clone_inner(), TextSelection::new(6, 11));
+
+        editor.type_text("you\nall");
+        assert_eq!(editor.text(), "Hello you\nall");
+        assert_eq!(notes.text.clone_inner(), "Hello you\nall");
+        assert_eq!(editor.text_selection(), TextSelection::caret_at(13));
+        assert_eq!(notes.selection.clone_inner(), TextSelection::caret_at(13));
+        assert_eq!(app.events().len(), 7);
+        assert_eq!(app.events()[0], "Hello y");
+    }
+
+    #[test]
+    fn follow_state() {
+        let (app, notes) = launch("Grüße");
+        let editor = app.root();
+
+        // The offsets count characters, not bytes.
+        notes.selection.set(TextSelection::new(5, 2));
+        editor.type_text("!");
+        assert_eq!(editor.text(), "Gr!");
+
+        notes.text.set("G".to_owned());
+        assert_eq!(editor.text(), "G");
+        assert_eq!(notes.selection.clone_inner(), TextSelection::caret_at(1));
+        assert_eq!(app.events(), ["Gr!"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't type text into this view")]
+    fn type_when_read_only() {
+        let (app, notes) = launch("Read me");
+        notes.editable.set(false);
+
+        // The text can still be selected.
+        let editor = app.root();
+        editor.select_text(0..4);
+        assert_eq!(editor.text_selection().range(), 0..4);
+
+        editor.type_text("Write");
+    }
+}
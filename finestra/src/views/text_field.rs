@@ -1,14 +1,52 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use std::rc::Rc;
+
 use crate::*;
 use super::base::BaseView;
 
 /// A text field is a view that the user can enter text into.
+///
+/// ## Passwords
+/// A [secure](Self::with_secure) text field masks the text, e.g. for
+/// passwords. The text can be shown using a [reveal](Self::with_reveal)
+/// binding, like the eye button of many login screens:
+///
+/// ```
+/// # use finestra::*;
+/// let password = TextValue::default();
+/// let reveal = State::new(false);
+///
+/// # let _: Stack<(), ()> =
+/// Stack::horizontal()
+///     .with(TextField::new(&password)
+///         .with_secure(true)
+///         .with_reveal(&reveal))
+///     .with(Switch::new("Show password", &reveal));
+/// ```
+///
+/// ## Validation
+/// A [validator](Self::with_validator) converts the text into a typed value,
+/// which is only stored when the text is valid. Otherwise, the field shows
+/// the message of the validator:
+///
+/// ```
+/// # use finestra::*;
+/// let age = State::new(0);
+///
+/// # let _: TextField<()> =
+/// TextField::new("")
+///     .with_placeholder("Age")
+///     .with_validator(Validator::integer(), &age);
+/// ```
 pub struct TextField<State> {
     pub(crate) base: ViewBase,
     pub(crate) text: StateOrRaw<String>,
     pub(crate) placeholder_text: StateOrRaw<String>,
+    pub(crate) secure: bool,
+    pub(crate) reveal: StateOrRaw<bool>,
+    pub(crate) validation: Option<Validation>,
     event_handler_map: EventHandlerMap<State>,
 }
 
@@ -22,13 +60,16 @@ impl<State> TextField<State> {
             base: ViewBase::default(),
             text: text.into(),
             placeholder_text: Default::default(),
+            secure: false,
+            reveal: StateOrRaw::Raw(false),
+            validation: None,
             event_handler_map: Default::default(),
         }
     }
 
     /// Get notified when the text is changed.
     pub fn set_on_change(&mut self, action: impl Fn(&mut State, String, Window) + 'static) {
-        self.event_handler_map.text_changed = Some(Box::new(action));
+        self.event_handler_map.text_changed = Some(Rc::new(action));
     }
 
     /// Get notified when the text is changed.
@@ -50,6 +91,68 @@ impl<State> TextField<State> {
     pub fn set_placeholder(&mut self, placeholder: impl Into<StateOrRaw<String>>) {
         self.placeholder_text = placeholder.into();
     }
+
+    /// Set whether the field is used for a password or another secret. The
+    /// text is masked, can't be copied, isn't suggested by autocompletion,
+    /// and is left out of the `Debug` output of Finestra.
+    #[must_use]
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.set_secure(secure);
+        self
+    }
+
+    /// Set whether the field is used for a password or another secret. The
+    /// text is masked, can't be copied, isn't suggested by autocompletion,
+    /// and is left out of the `Debug` output of Finestra.
+    pub fn set_secure(&mut self, secure: bool) {
+        self.secure = secure;
+    }
+
+    /// Set whether the text of a [secure](Self::with_secure) field is shown
+    /// instead of masked. This is `false` by default.
+    #[must_use]
+    pub fn with_reveal(mut self, reveal: impl Into<StateOrRaw<bool>>) -> Self {
+        self.set_reveal(reveal);
+        self
+    }
+
+    /// Set whether the text of a [secure](Self::with_secure) field is shown
+    /// instead of masked. This is `false` by default.
+    pub fn set_reveal(&mut self, reveal: impl Into<StateOrRaw<bool>>) {
+        self.reveal = reveal.into();
+    }
+
+    /// Check the text using the `validator` when the field is shown, and
+    /// every time the text is changed. The converted text is stored in the
+    /// `value`, but only when it is valid. Otherwise, the field is marked as
+    /// invalid, and the message of the validator is shown as its tooltip.
+    #[must_use]
+    pub fn with_validator<T: 'static>(mut self, validator: Validator<T>, value: &crate::State<T>) -> Self {
+        self.set_validator(validator, value);
+        self
+    }
+
+    /// Check the text using the `validator` when the field is shown, and
+    /// every time the text is changed. The converted text is stored in the
+    /// `value`, but only when it is valid. Otherwise, the field is marked as
+    /// invalid, and the message of the validator is shown as its tooltip.
+    pub fn set_validator<T: 'static>(&mut self, validator: Validator<T>, value: &crate::State<T>) {
+        self.validation = Some(Validation::new(validator, value));
+    }
+
+    /// Validates the initial text, and the text that is set by the program.
+    /// The text the user enters is validated by the backends.
+    fn validate_text(&self, view_id: ViewId) {
+        let Some(validation) = self.validation.clone() else {
+            return;
+        };
+
+        self.text.with(|text| validation.validate(text));
+
+        if let Some(text) = self.text.as_state() {
+            text.add_listener_with_origin(move |text| validation.validate(text), StateChangeOrigin::Owner(view_id));
+        }
+    }
 }
 
 impl<State> BaseView for TextField<State> {
@@ -71,11 +174,7 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for TextField<St
         let map = std::mem::take(&mut self.event_handler_map);
         let view_id = tree.exchange_events_for_id(map);
 
-        let delegate = MacOSTextFieldDelegate{
-            view_id,
-            dispatcher: tree.create_dispatcher(),
-            text: self.text.as_state(),
-        };
+        let delegate = MacOSTextFieldDelegate::new(view_id, tree.create_dispatcher(), self.text.as_state(), self.secure, self.validation.clone());
 
         let text_field = cacao::input::TextField::with(delegate);
         text_field.set_uses_single_line(true);
@@ -87,6 +186,7 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for TextField<St
         });
 
         crate::platform::macos::state::attach_text_field_state(view_id, self, &text_field);
+        self.validate_text(view_id);
         text_field.width.constraint_equal_to_constant(100.).set_active(true);
         text_field.into()
     }
@@ -103,6 +203,71 @@ impl<Delegate: AppDelegate<State>, State> View<Delegate, State> for TextField<St
         _ = &self.text;
         WinView::new(tree.exchange_events_for_id(Default::default()), WinViewKind::Empty)
     }
+
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use crate::platform::gtk::{Event, SignalGuard};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let text_field = gtk4::Entry::new();
+        self.text.with(|text| text_field.set_text(text));
+
+        let guard = SignalGuard::default();
+        crate::platform::gtk::state::attach_text_field_state(view_id, self, &text_field, &guard);
+        self.validate_text(view_id);
+
+        let text = self.text.as_state();
+        let secure = self.secure;
+        let validation = self.validation.clone();
+        let dispatcher = tree.create_dispatcher();
+        text_field.connect_changed(move |text_field| {
+            if guard.is_active() {
+                return;
+            }
+
+            let value = text_field.text().to_string();
+            dispatcher.dispatch_event(Event::TextFieldChanged(view_id, ChangedText::new(value.clone(), secure)));
+
+            if let Some(validation) = &validation {
+                validation.validate(&value);
+            }
+
+            if let Some(text) = &text {
+                text.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            }
+        });
+        text_field.into()
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        use crate::platform::headless::{Event, HeadlessContext, HeadlessView, HeadlessViewKind};
+
+        let map = std::mem::take(&mut self.event_handler_map);
+        let view_id = tree.exchange_events_for_id(map);
+
+        let text_field = HeadlessView::new(view_id, HeadlessViewKind::TextField);
+        crate::platform::headless::state::attach_text_field_state(self, &text_field);
+        self.validate_text(view_id);
+
+        let text = self.text.as_state();
+        let secure = self.secure;
+        let validation = self.validation.clone();
+        text_field.set_text_did_change(move |value| {
+            HeadlessContext::dispatch(Event::TextFieldChanged(view_id, ChangedText::new(value.to_string(), secure)));
+
+            if let Some(validation) = &validation {
+                validation.validate(value);
+            }
+
+            if let Some(text) = &text {
+                text.set_with_origin(value, StateChangeOrigin::Owner(view_id));
+            }
+        });
+        text_field
+    }
 }
 
 impl<Delegate, State> From<TextField<State>> for Box<dyn View<Delegate, State>>
@@ -111,3 +276,116 @@ impl<Delegate, State> From<TextField<State>> for Box<dyn View<Delegate, State>>
         Box::new(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Stack, State, Switch, TextValue, Validator};
+
+    use super::*;
+
+    #[test]
+    fn secure_text() {
+        let password = TextValue::default();
+        let reveal = State::new(false);
+        let app = TestApp::launch_view({
+            let (password, reveal) = (password.clone(), reveal.clone());
+            move || {
+                Stack::vertical()
+                    .with(TextField::new(&password)
+                        .with_id("password")
+                        .with_secure(true)
+                        .with_reveal(&reveal)
+                        .with_on_change(|attempts: &mut Vec<String>, text, _| attempts.push(text)))
+                    .with(Switch::new("Show password", &reveal))
+            }
+        });
+
+        let field = app.find_by_id("password").unwrap();
+        field.type_text("hunter2");
+        assert!(field.is_masked());
+        assert_eq!(field.text(), "hunter2");
+        assert_eq!(password.clone_inner(), "hunter2");
+        assert_eq!(app.events().last().map(String::as_str), Some("hunter2"));
+
+        // The password isn't shown in logs, even when it is revealed.
+        app.find_by_text("Show password").unwrap().toggle();
+        assert!(!field.is_masked());
+        assert!(!format!("{field:?}").contains("hunter"));
+        assert!(!format!("{:?}", app.root()).contains("hunter"));
+
+        reveal.set(false);
+        assert!(field.is_masked());
+    }
+
+    #[test]
+    fn validation() {
+        let age = State::new(0);
+        let email = State::default();
+        let app: ViewTestApp<()> = TestApp::launch_view({
+            let (age, email) = (age.clone(), email.clone());
+            move || {
+                Stack::vertical()
+                    .with(TextField::new("")
+                        .with_id("age")
+                        .with_tooltip("Your age in years")
+                        .with_validator(Validator::integer(), &age))
+                    .with(TextField::new("")
+                        .with_id("email")
+                        .with_validator(Validator::email(), &email))
+            }
+        });
+
+        // The empty text is validated when the field is shown.
+        let field = app.find_by_id("age").unwrap();
+        assert_eq!(field.validation_error().as_deref(), Some("Enter a whole number"));
+        assert_eq!(age.clone_inner(), 0);
+
+        field.type_text("42");
+        assert_eq!(age.clone_inner(), 42);
+        assert_eq!(field.validation_error(), None);
+
+        // The last valid value is kept.
+        field.type_text("a");
+        assert_eq!(age.clone_inner(), 42);
+        assert_eq!(field.validation_error().as_deref(), Some("Enter a whole number"));
+        assert_eq!(field.tooltip(), "Enter a whole number");
+
+        field.clear_text();
+        field.type_text("7");
+        assert_eq!(age.clone_inner(), 7);
+        assert_eq!(field.validation_error(), None);
+        assert_eq!(field.tooltip(), "Your age in years");
+
+        let field = app.find_by_id("email").unwrap();
+        field.type_text("tristan@thewoosh");
+        assert!(field.validation_error().is_some());
+        assert_eq!(email.clone_inner(), "");
+
+        field.type_text(".org");
+        assert_eq!(field.validation_error(), None);
+        assert_eq!(email.clone_inner(), "tristan@thewoosh.org");
+    }
+
+    #[test]
+    fn validate_text_set_by_program() {
+        let text = TextValue::new("12".to_owned());
+        let amount = State::new(0.0);
+        let app: ViewTestApp<()> = TestApp::launch_view({
+            let (text, amount) = (text.clone(), amount.clone());
+            move || TextField::new(&text).with_validator(Validator::float(), &amount)
+        });
+
+        let field = app.find_by_text("12").unwrap();
+        assert_eq!(field.validation_error(), None);
+        assert_eq!(amount.clone_inner(), 12.0);
+
+        text.set("twelve".to_owned());
+        assert_eq!(field.validation_error().as_deref(), Some("Enter a number"));
+        assert_eq!(amount.clone_inner(), 12.0);
+
+        text.set("0.5".to_owned());
+        assert_eq!(field.validation_error(), None);
+        assert_eq!(amount.clone_inner(), 0.5);
+    }
+}
@@ -0,0 +1,800 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{AppDelegate, GridTrack, Key, List, StateChangeOrigin, View, ViewBase, ViewId, Window};
+
+use super::base::BaseView;
+
+type ChildrenFn<T> = Rc<dyn Fn(&T) -> Vec<T>>;
+type HasChildrenFn<T> = Rc<dyn Fn(&T) -> bool>;
+type ItemBuilder<T, Delegate, State> = Rc<dyn Fn(&T) -> Box<dyn View<Delegate, State>>>;
+type ActivateFn<T, State> = Rc<dyn Fn(&mut State, &T, Window)>;
+
+/// The space a row is indented per level.
+const INDENT: f32 = 16.0;
+
+/// The width of the button that expands or collapses a row.
+const DISCLOSURE_WIDTH: f32 = 32.0;
+
+/// A tree displays hierarchical items, such as the files and folders of a
+/// file system. The children of an item are loaded using a callback, when they
+/// are needed.
+///
+/// ```
+/// # use finestra::*;
+/// let folders = State::new(vec!["/".to_string()]);
+/// let expanded = State::new(Vec::new());
+/// let selected = State::new(None);
+///
+/// # let _: Tree<String, (), ()> =
+/// Tree::new(&folders, list_folder, |folder: &String| Label::new(folder.clone()))
+///     .with_expanded(&expanded)
+///     .with_selection(&selected)
+///     .with_on_activate(|_, folder, _| println!("Opened {folder}"));
+///
+/// fn list_folder(folder: &String) -> Vec<String> {
+///     std::fs::read_dir(folder)
+///         .map(|entries| entries.flatten()
+///             .filter(|entry| entry.path().is_dir())
+///             .map(|entry| entry.path().display().to_string())
+///             .collect())
+///         .unwrap_or_default()
+/// }
+/// ```
+///
+/// The rows are expanded and collapsed using the button in front of them, or
+/// using the left and right arrow keys.
+pub struct Tree<T, State, Delegate> {
+    base: ViewBase,
+
+    pub(crate) items: crate::State<Vec<T>>,
+    children: ChildrenFn<T>,
+    has_children: Option<HasChildrenFn<T>>,
+    row: ItemBuilder<T, Delegate, State>,
+    pub(crate) expanded: crate::State<Vec<T>>,
+    pub(crate) selection: Option<crate::State<Option<T>>>,
+    on_activate: Option<ActivateFn<T, State>>,
+}
+
+impl<T, State: 'static, Delegate> Tree<T, State, Delegate>
+        where Delegate: AppDelegate<State> + 'static {
+    /// Create a tree that displays the root `items`. The `children` of an item
+    /// are loaded when the item is displayed or expanded for the first time,
+    /// and `row` creates the view of every item.
+    #[must_use]
+    pub fn new<V>(
+        items: &crate::State<Vec<T>>,
+        children: impl Fn(&T) -> Vec<T> + 'static,
+        row: impl Fn(&T) -> V + 'static,
+    ) -> Self
+            where V: Into<Box<dyn View<Delegate, State>>> {
+        Self {
+            base: ViewBase::default(),
+
+            items: items.clone(),
+            children: Rc::new(children),
+            has_children: None,
+            row: Rc::new(move |item| row(item).into()),
+            expanded: crate::State::new(Vec::new()),
+            selection: None,
+            on_activate: None,
+        }
+    }
+}
+
+impl<T, State, Delegate> Tree<T, State, Delegate> {
+    /// Tell whether an item has children, without loading them. By default,
+    /// the children of the displayed items are loaded to find out whether the
+    /// items can be expanded.
+    #[must_use]
+    pub fn with_has_children(mut self, has_children: impl Fn(&T) -> bool + 'static) -> Self {
+        self.set_has_children(has_children);
+        self
+    }
+
+    /// Tell whether an item has children, without loading them. By default,
+    /// the children of the displayed items are loaded to find out whether the
+    /// items can be expanded.
+    pub fn set_has_children(&mut self, has_children: impl Fn(&T) -> bool + 'static) {
+        self.has_children = Some(Rc::new(has_children));
+    }
+
+    /// Bind the expanded items to a state, which is updated when the user
+    /// expands or collapses an item, and can be changed to expand other items.
+    #[must_use]
+    pub fn with_expanded(mut self, expanded: &crate::State<Vec<T>>) -> Self {
+        self.set_expanded(expanded);
+        self
+    }
+
+    /// Bind the expanded items to a state, which is updated when the user
+    /// expands or collapses an item, and can be changed to expand other items.
+    pub fn set_expanded(&mut self, expanded: &crate::State<Vec<T>>) {
+        self.expanded = expanded.clone();
+    }
+
+    /// Let the user select a single item. The state contains the selected
+    /// item, and can be changed to select another item that is displayed.
+    #[must_use]
+    pub fn with_selection(mut self, selection: &crate::State<Option<T>>) -> Self {
+        self.set_selection(selection);
+        self
+    }
+
+    /// Let the user select a single item. The state contains the selected
+    /// item, and can be changed to select another item that is displayed.
+    pub fn set_selection(&mut self, selection: &crate::State<Option<T>>) {
+        self.selection = Some(selection.clone());
+    }
+
+    /// Get notified when the user activates an item, e.g. by double clicking
+    /// it, or by pressing the return key.
+    pub fn set_on_activate(&mut self, action: impl Fn(&mut State, &T, Window) + 'static) {
+        self.on_activate = Some(Rc::new(action));
+    }
+
+    /// Get notified when the user activates an item, e.g. by double clicking
+    /// it, or by pressing the return key.
+    #[must_use]
+    pub fn with_on_activate(mut self, action: impl Fn(&mut State, &T, Window) + 'static) -> Self {
+        self.set_on_activate(action);
+        self
+    }
+}
+
+impl<T, State, Delegate> BaseView for Tree<T, State, Delegate> {
+    fn base(&self) -> &ViewBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut ViewBase {
+        &mut self.base
+    }
+}
+
+impl<T, Delegate, State> From<Tree<T, State, Delegate>> for Box<dyn View<Delegate, State>>
+        where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    fn from(value: Tree<T, State, Delegate>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<T, State, Delegate> Tree<T, State, Delegate>
+        where T: Clone + PartialEq + 'static, Delegate: AppDelegate<State> + 'static, State: 'static {
+    /// The tree is displayed as a [`List`] of the visible rows, which are
+    /// indented by their depth. The returned controller expands and collapses
+    /// the rows.
+    fn compose(&mut self, tree: &mut crate::event::ViewTree<State>) -> (List<TreeRow<T>, State, Delegate>, TreeController<T>) {
+        let view_id = tree.exchange_events_for_id(Default::default());
+
+        let model = TreeModel::new(
+            self.items.clone_inner(),
+            Rc::clone(&self.children),
+            self.has_children.clone(),
+            &self.expanded.clone_inner(),
+        );
+
+        let selected_row = self.selection.as_ref()
+            .and_then(|selection| selection.clone_inner())
+            .and_then(|item| model.row_of(&item));
+
+        let controller = TreeController {
+            view_id,
+            rows: crate::State::new(model.rows().to_vec()),
+            model: Rc::new(RefCell::new(model)),
+            expanded: self.expanded.clone(),
+            selected_row: crate::State::new(selected_row),
+        };
+        controller.observe(&self.items, self.selection.as_ref());
+
+        let mut list = List::with_row_builder(&controller.rows, self.row_builder(&controller))
+            .with_selection(&controller.selected_row);
+        *list.base_mut() = std::mem::take(&mut self.base);
+
+        if let Some(on_activate) = self.on_activate.clone() {
+            let model = Rc::clone(&controller.model);
+            list.set_on_activate(move |state, row, window| {
+                let item = model.borrow().rows().get(row).map(|row| row.item.clone());
+                if let Some(item) = item {
+                    on_activate(state, &item, window);
+                }
+            });
+        }
+
+        (list, controller)
+    }
+
+    /// Creates the view of a row: a button to expand or collapse the row, and
+    /// the view of the item, indented by the depth of the row.
+    fn row_builder(&self, controller: &TreeController<T>) -> crate::RowBuilder<TreeRow<T>, Delegate, State> {
+        use crate::{Button, Grid};
+
+        let row = Rc::clone(&self.row);
+        let controller = controller.clone();
+        Rc::new(move |tree_row: &TreeRow<T>| {
+            let indent = (tree_row.path.len() - 1) as f32 * INDENT;
+            let mut grid = Grid::new()
+                .with_columns([GridTrack::Fixed(indent), GridTrack::Fixed(DISCLOSURE_WIDTH), GridTrack::Fraction(1.0)])
+                .with_column_spacing(0.0);
+
+            if tree_row.expandable {
+                let controller = controller.clone();
+                let item = tree_row.item.clone();
+                let expanded = tree_row.expanded;
+                grid = grid.with(0, 1, Button::new(if expanded { "▾" } else { "▸" })
+                    .with_on_click(move |_, _| controller.toggle(&item)));
+            }
+
+            grid.with(0, 2, row(&tree_row.item)).into()
+        })
+    }
+}
+
+impl<T, Delegate: AppDelegate<State>, State> View<Delegate, State> for Tree<T, State, Delegate>
+        where T: Clone + PartialEq + 'static, Delegate: 'static, State: 'static {
+    /// The `NSTableView` moves the selection using the up and down arrow
+    /// keys, and the left and right arrow keys are handled by the tree.
+    #[cfg(target_os = "macos")]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::macos::DynamicViewWrapper {
+        use crate::platform::macos::set_key_action;
+
+        let (mut list, controller) = self.compose(tree);
+        let view = list.build_native(tree);
+        set_key_action(view.objc(), move |key| controller.press_key(key));
+        view
+    }
+
+    /// The rows of a [`List`] can't be selected or focused on Win32 yet, so
+    /// the arrow keys don't expand or collapse the rows. The disclosure
+    /// buttons of the rows still expand and collapse them.
+    #[cfg(target_os = "windows")]
+    fn build_native(
+        &mut self,
+        tree: &mut crate::event::ViewTree<State>,
+        parent: windows::Win32::Foundation::HWND,
+    ) -> crate::platform::win32::view::WinView {
+        let (mut list, _controller) = self.compose(tree);
+        list.build_native(tree, parent)
+    }
+
+    /// The `GtkListView` moves the selection using the up and down arrow
+    /// keys, and the left and right arrow keys are handled by the tree.
+    #[cfg(all(target_os = "linux", feature = "gtk"))]
+    fn build_native(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::gtk::GtkView {
+        use gtk4::prelude::*;
+        use gtk4::gdk;
+
+        let (mut list, controller) = self.compose(tree);
+        let view = list.build_native(tree);
+
+        let keys = gtk4::EventControllerKey::new();
+        keys.set_propagation_phase(gtk4::PropagationPhase::Capture);
+        keys.connect_key_pressed(move |_, key, _, _| {
+            let key = match key {
+                gdk::Key::Left => Key::Left,
+                gdk::Key::Right => Key::Right,
+                _ => return gtk4::glib::Propagation::Proceed,
+            };
+
+            if controller.press_key(key) {
+                gtk4::glib::Propagation::Stop
+            } else {
+                gtk4::glib::Propagation::Proceed
+            }
+        });
+        view.widget().add_controller(keys);
+
+        view
+    }
+
+    fn build_headless(&mut self, tree: &mut crate::event::ViewTree<State>) -> crate::platform::headless::HeadlessView {
+        let (mut list, controller) = self.compose(tree);
+        let view = list.build_headless(tree);
+
+        view.set_key_pressed(move |key| controller.press_key(key));
+        view
+    }
+}
+
+/// A row of a [`Tree`] that is displayed.
+#[derive(Clone, Debug)]
+pub(crate) struct TreeRow<T> {
+    /// The indices of the item and its ancestors in their parents, starting
+    /// with the index of the root item.
+    pub(crate) path: Vec<usize>,
+    pub(crate) item: T,
+    pub(crate) expandable: bool,
+    pub(crate) expanded: bool,
+}
+
+/// The rows are compared without their paths, as the view of a row doesn't
+/// change when the row moves, e.g. when a sibling is inserted before it.
+impl<T: PartialEq> PartialEq for TreeRow<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.path.len() == other.path.len()
+            && self.item == other.item
+            && self.expandable == other.expandable
+            && self.expanded == other.expanded
+    }
+}
+
+/// What happens when a key is pressed in a [`Tree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TreeNavigation {
+    Select(usize),
+    Expand(Vec<usize>),
+    Collapse(Vec<usize>),
+}
+
+struct TreeNode<T> {
+    item: T,
+    /// The children are [`None`] until they are loaded.
+    children: Option<Vec<TreeNode<T>>>,
+    expanded: bool,
+}
+
+impl<T> TreeNode<T> {
+    fn new(item: T) -> Self {
+        Self {
+            item,
+            children: None,
+            expanded: false,
+        }
+    }
+}
+
+/// The items of a [`Tree`], of which the children are loaded lazily, and the
+/// rows that are displayed.
+pub(crate) struct TreeModel<T> {
+    roots: Vec<TreeNode<T>>,
+    children: ChildrenFn<T>,
+    has_children: Option<HasChildrenFn<T>>,
+    rows: Vec<TreeRow<T>>,
+}
+
+impl<T: Clone + PartialEq> TreeModel<T> {
+    #[must_use]
+    pub(crate) fn new(roots: Vec<T>, children: ChildrenFn<T>, has_children: Option<HasChildrenFn<T>>, expanded: &[T]) -> Self {
+        let mut model = Self {
+            roots: Vec::new(),
+            children,
+            has_children,
+            rows: Vec::new(),
+        };
+        model.set_roots(roots, expanded);
+        model
+    }
+
+    /// Replace the root items, after which the `expanded` items are expanded
+    /// again. The children are loaded again as well.
+    pub(crate) fn set_roots(&mut self, roots: Vec<T>, expanded: &[T]) {
+        self.roots = roots.into_iter().map(TreeNode::new).collect();
+        self.set_expanded_items(expanded);
+    }
+
+    /// Expand the `expanded` items, and collapse the other items.
+    pub(crate) fn set_expanded_items(&mut self, expanded: &[T]) {
+        fn apply<T: PartialEq>(nodes: &mut [TreeNode<T>], expanded: &[T], children: &ChildrenFn<T>) {
+            for node in nodes {
+                node.expanded = expanded.contains(&node.item);
+                if node.expanded && node.children.is_none() {
+                    node.children = Some(children(&node.item).into_iter().map(TreeNode::new).collect());
+                }
+
+                if let Some(nodes) = &mut node.children {
+                    apply(nodes, expanded, children);
+                }
+            }
+        }
+
+        apply(&mut self.roots, expanded, &self.children);
+        self.update_rows();
+    }
+
+    /// Get the expanded items, in the order they are displayed.
+    #[must_use]
+    pub(crate) fn expanded_items(&self) -> Vec<T> {
+        fn collect<T: Clone>(nodes: &[TreeNode<T>], items: &mut Vec<T>) {
+            for node in nodes {
+                if node.expanded {
+                    items.push(node.item.clone());
+                }
+
+                if let Some(nodes) = &node.children {
+                    collect(nodes, items);
+                }
+            }
+        }
+
+        let mut items = Vec::new();
+        collect(&self.roots, &mut items);
+        items
+    }
+
+    /// Expand or collapse the item at the `path`, loading its children when
+    /// it is expanded for the first time. Returns `false` if nothing changed.
+    pub(crate) fn set_expanded(&mut self, path: &[usize], expanded: bool) -> bool {
+        let children = Rc::clone(&self.children);
+        let Some(node) = self.node_mut(path) else {
+            return false;
+        };
+
+        if node.expanded == expanded {
+            return false;
+        }
+
+        node.expanded = expanded;
+        if expanded && node.children.is_none() {
+            node.children = Some(children(&node.item).into_iter().map(TreeNode::new).collect());
+        }
+
+        self.update_rows();
+        true
+    }
+
+    /// Get the rows that are displayed: the root items, and the descendants
+    /// of the expanded items.
+    #[must_use]
+    pub(crate) fn rows(&self) -> &[TreeRow<T>] {
+        &self.rows
+    }
+
+    /// Find the row that displays the `item`.
+    #[must_use]
+    pub(crate) fn row_of(&self, item: &T) -> Option<usize> {
+        self.rows.iter().position(|row| row.item == *item)
+    }
+
+    /// Decide what happens when the `key` is pressed, while the `selected`
+    /// row is selected. The left and right arrow keys collapse and expand the
+    /// row, or move to its parent and its first child.
+    #[must_use]
+    pub(crate) fn navigate(&self, selected: Option<usize>, key: Key) -> Option<TreeNavigation> {
+        let Some(selected) = selected else {
+            return match key {
+                Key::Up | Key::Down if !self.rows.is_empty() => Some(TreeNavigation::Select(0)),
+                _ => None,
+            };
+        };
+
+        let row = self.rows.get(selected)?;
+        match key {
+            Key::Up => selected.checked_sub(1).map(TreeNavigation::Select),
+            Key::Down => Some(selected + 1).filter(|row| *row < self.rows.len()).map(TreeNavigation::Select),
+            Key::Left if row.expanded => Some(TreeNavigation::Collapse(row.path.clone())),
+            Key::Left => {
+                let parent = &row.path[..row.path.len() - 1];
+                self.rows[..selected].iter()
+                    .rposition(|row| row.path == parent)
+                    .map(TreeNavigation::Select)
+            }
+            Key::Right if row.expandable && !row.expanded => Some(TreeNavigation::Expand(row.path.clone())),
+            Key::Right => self.rows.get(selected + 1)
+                .filter(|child| child.path.len() > row.path.len())
+                .map(|_| TreeNavigation::Select(selected + 1)),
+            Key::Return => None,
+        }
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode<T>> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get_mut(*first)?;
+        for index in rest {
+            node = node.children.as_mut()?.get_mut(*index)?;
+        }
+        Some(node)
+    }
+
+    fn update_rows(&mut self) {
+        fn visit<T: Clone>(
+            nodes: &mut [TreeNode<T>],
+            path: &mut Vec<usize>,
+            rows: &mut Vec<TreeRow<T>>,
+            children: &ChildrenFn<T>,
+            has_children: Option<&HasChildrenFn<T>>,
+        ) {
+            for (index, node) in nodes.iter_mut().enumerate() {
+                path.push(index);
+
+                let expandable = match (&node.children, has_children) {
+                    (Some(nodes), _) => !nodes.is_empty(),
+                    (None, Some(has_children)) => has_children(&node.item),
+                    (None, None) => {
+                        let nodes: Vec<_> = children(&node.item).into_iter().map(TreeNode::new).collect();
+                        let expandable = !nodes.is_empty();
+                        node.children = Some(nodes);
+                        expandable
+                    }
+                };
+
+                rows.push(TreeRow {
+                    path: path.clone(),
+                    item: node.item.clone(),
+                    expandable,
+                    expanded: node.expanded && expandable,
+                });
+
+                if node.expanded {
+                    if let Some(nodes) = &mut node.children {
+                        visit(nodes, path, rows, children, has_children);
+                    }
+                }
+
+                path.pop();
+            }
+        }
+
+        let mut rows = Vec::new();
+        visit(&mut self.roots, &mut Vec::new(), &mut rows, &self.children, self.has_children.as_ref());
+        self.rows = rows;
+    }
+}
+
+/// Keeps the rows of a [`Tree`] and the states bound to it up to date.
+pub(crate) struct TreeController<T> {
+    view_id: ViewId,
+    model: Rc<RefCell<TreeModel<T>>>,
+    rows: crate::State<Vec<TreeRow<T>>>,
+    expanded: crate::State<Vec<T>>,
+    selected_row: crate::State<Option<usize>>,
+}
+
+impl<T> Clone for TreeController<T> {
+    fn clone(&self) -> Self {
+        Self {
+            view_id: self.view_id,
+            model: Rc::clone(&self.model),
+            rows: self.rows.clone(),
+            expanded: self.expanded.clone(),
+            selected_row: self.selected_row.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> TreeController<T> {
+    fn observe(&self, items: &crate::State<Vec<T>>, selection: Option<&crate::State<Option<T>>>) {
+        let owner = StateChangeOrigin::Owner(self.view_id);
+
+        let this = self.clone();
+        items.add_listener(move |items| {
+            let expanded = this.expanded.clone_inner();
+            this.update(|model| model.set_roots(items.clone(), &expanded));
+        });
+
+        let this = self.clone();
+        self.expanded.add_listener_with_origin(move |expanded| {
+            this.update(|model| model.set_expanded_items(expanded));
+        }, owner);
+
+        let Some(selection) = selection else {
+            return;
+        };
+
+        // The row is selected in the list, but the item is stored in the
+        // selection of the tree. The model is used to find the item of a row,
+        // as the rows can't be read while they are being changed.
+        let model = Rc::clone(&self.model);
+        let selection_state = selection.clone();
+        self.selected_row.add_listener_with_origin(move |row| {
+            let item = row.and_then(|row| model.borrow().rows().get(row).map(|row| row.item.clone()));
+            selection_state.set_with_origin(item, owner);
+        }, owner);
+
+        let model = Rc::clone(&self.model);
+        let selected_row = self.selected_row.clone();
+        selection.add_listener_with_origin(move |item| {
+            let row = item.as_ref().and_then(|item| model.borrow().row_of(item));
+            selected_row.set_with_origin(row, owner);
+        }, owner);
+    }
+
+    /// Modifies the model, after which the rows are updated. The model isn't
+    /// borrowed while the listeners of the rows are invoked.
+    fn update(&self, f: impl FnOnce(&mut TreeModel<T>)) {
+        let rows = {
+            let mut model = self.model.borrow_mut();
+            f(&mut model);
+            model.rows().to_vec()
+        };
+
+        self.rows.set(rows);
+    }
+
+    fn set_expanded(&self, path: &[usize], expanded: bool) {
+        let mut changed = false;
+        self.update(|model| changed = model.set_expanded(path, expanded));
+
+        if changed {
+            let expanded = self.model.borrow().expanded_items();
+            self.expanded.set_with_origin(expanded, StateChangeOrigin::Owner(self.view_id));
+        }
+    }
+
+    /// Expands or collapses the row of the `item`. The row is looked up
+    /// again, as it might have moved since its view was built.
+    fn toggle(&self, item: &T) {
+        let row = {
+            let model = self.model.borrow();
+            model.row_of(item).map(|row| model.rows()[row].clone())
+        };
+
+        if let Some(row) = row {
+            self.set_expanded(&row.path, !row.expanded);
+        }
+    }
+
+    /// Handles a key that the user pressed. Returns `false` if the key didn't
+    /// do anything.
+    fn press_key(&self, key: Key) -> bool {
+        let navigation = self.model.borrow().navigate(self.selected_row.clone_inner(), key);
+        match navigation {
+            Some(TreeNavigation::Select(row)) => self.selected_row.set_with_origin(Some(row), StateChangeOrigin::System),
+            Some(TreeNavigation::Expand(path)) => self.set_expanded(&path, true),
+            Some(TreeNavigation::Collapse(path)) => self.set_expanded(&path, false),
+            None => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{TestApp, ViewTestApp};
+    use crate::{BaseViewExt, Label, State};
+
+    use super::*;
+
+    /// The items are numbers, and the children of `n` are `n * 10 + 1` and
+    /// `n * 10 + 2`, until a depth of three.
+    fn model(expanded: &[u32]) -> TreeModel<u32> {
+        let children: ChildrenFn<u32> = Rc::new(|n: &u32| {
+            if *n >= 100 {
+                Vec::new()
+            } else {
+                vec![n * 10 + 1, n * 10 + 2]
+            }
+        });
+
+        TreeModel::new(vec![1, 2], children, None, expanded)
+    }
+
+    fn items(model: &TreeModel<u32>) -> Vec<u32> {
+        model.rows().iter().map(|row| row.item).collect()
+    }
+
+    #[test]
+    fn expand_and_collapse() {
+        let mut model = model(&[]);
+        assert_eq!(items(&model), [1, 2]);
+
+        assert!(model.set_expanded(&[1], true));
+        assert!(!model.set_expanded(&[1], true));
+        assert_eq!(items(&model), [1, 2, 21, 22]);
+
+        assert!(model.set_expanded(&[1, 0], true));
+        assert_eq!(items(&model), [1, 2, 21, 211, 212, 22]);
+        assert_eq!(model.rows()[3].path, [1, 0, 0]);
+        assert!(!model.rows()[3].expandable);
+
+        // The children stay expanded when the parent is collapsed.
+        assert!(model.set_expanded(&[1], false));
+        assert_eq!(items(&model), [1, 2]);
+        assert_eq!(model.expanded_items(), [21]);
+    }
+
+    #[test]
+    fn children_are_loaded_lazily() {
+        let loaded = Rc::new(RefCell::new(Vec::new()));
+        let children: ChildrenFn<u32> = {
+            let loaded = Rc::clone(&loaded);
+            Rc::new(move |n: &u32| {
+                loaded.borrow_mut().push(*n);
+                vec![n * 10]
+            })
+        };
+
+        let mut model = TreeModel::new(vec![1], children, Some(Rc::new(|_: &u32| true)), &[]);
+        assert!(loaded.borrow().is_empty());
+
+        model.set_expanded(&[0], true);
+        assert_eq!(*loaded.borrow(), [1]);
+        assert_eq!(items(&model), [1, 10]);
+    }
+
+    #[test]
+    fn restore_expanded_items() {
+        let mut model = model(&[2, 21]);
+        assert_eq!(items(&model), [1, 2, 21, 211, 212, 22]);
+
+        model.set_roots(vec![3, 2], &[2]);
+        assert_eq!(items(&model), [3, 2, 21, 22]);
+        assert_eq!(model.row_of(&22), Some(3));
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let model = model(&[1]);
+        assert_eq!(items(&model), [1, 11, 12, 2]);
+
+        assert_eq!(model.navigate(None, Key::Down), Some(TreeNavigation::Select(0)));
+        assert_eq!(model.navigate(Some(0), Key::Up), None);
+        assert_eq!(model.navigate(Some(0), Key::Down), Some(TreeNavigation::Select(1)));
+        assert_eq!(model.navigate(Some(3), Key::Down), None);
+
+        assert_eq!(model.navigate(Some(0), Key::Right), Some(TreeNavigation::Select(1)));
+        assert_eq!(model.navigate(Some(0), Key::Left), Some(TreeNavigation::Collapse(vec![0])));
+        assert_eq!(model.navigate(Some(2), Key::Left), Some(TreeNavigation::Select(0)));
+        assert_eq!(model.navigate(Some(2), Key::Right), Some(TreeNavigation::Expand(vec![0, 1])));
+        assert_eq!(model.navigate(Some(3), Key::Left), None);
+    }
+
+    fn launch(roots: &State<Vec<String>>, expanded: &State<Vec<String>>, selection: &State<Option<String>>) -> ViewTestApp<String> {
+        let (roots, expanded, selection) = (roots.clone(), expanded.clone(), selection.clone());
+        TestApp::launch_view(move || {
+            let children = |name: &String| {
+                let children: &[&str] = match name.as_str() {
+                    "Animals" => &["Cats", "Dogs"],
+                    "Plants" => &["Trees"],
+                    _ => &[],
+                };
+                children.iter().map(|child| child.to_string()).collect()
+            };
+
+            Tree::new(&roots, children, |name: &String| Label::new(name.clone()))
+                .with_id("taxonomy")
+                .with_expanded(&expanded)
+                .with_selection(&selection)
+                .with_on_activate(|opened: &mut Vec<String>, name, _| opened.push(name.clone()))
+        })
+    }
+
+    #[test]
+    fn expansion_and_navigation() {
+        let roots = State::new(vec!["Animals".to_string(), "Plants".to_string()]);
+        let (expanded, selection) = (State::new(Vec::new()), State::new(None));
+        let app = launch(&roots, &expanded, &selection);
+
+        let tree = app.find_by_id("taxonomy").unwrap();
+        let names = || tree.children().iter()
+            .map(|row| row.children().last().unwrap().text())
+            .collect::<Vec<_>>();
+        assert_eq!(names(), ["Animals", "Plants"]);
+
+        tree.select_row(0);
+        assert_eq!(selection.clone_inner().as_deref(), Some("Animals"));
+
+        tree.press_key(Key::Right);
+        assert_eq!(expanded.clone_inner(), ["Animals"]);
+        assert_eq!(names(), ["Animals", "Cats", "Dogs", "Plants"]);
+
+        tree.press_key(Key::Right);
+        assert_eq!(selection.clone_inner().as_deref(), Some("Cats"));
+
+        tree.press_key(Key::Left);
+        tree.press_key(Key::Left);
+        assert_eq!(selection.clone_inner().as_deref(), Some("Animals"));
+        assert_eq!(names(), ["Animals", "Plants"]);
+        assert!(expanded.clone_inner().is_empty());
+
+        expanded.set(vec!["Plants".to_string()]);
+        assert_eq!(names(), ["Animals", "Plants", "Trees"]);
+
+        // The disclosure button in front of the row collapses it.
+        tree.children()[1].children()[0].click();
+        assert_eq!(names(), ["Animals", "Plants"]);
+        assert!(expanded.clone_inner().is_empty());
+
+        selection.set(Some("Plants".to_string()));
+        assert_eq!(tree.selected_rows(), [1]);
+
+        roots.set(vec!["Fungi".to_string(), "Animals".to_string(), "Plants".to_string()]);
+        assert_eq!(names(), ["Fungi", "Animals", "Plants"]);
+        assert_eq!(tree.selected_rows(), [2]);
+
+        tree.activate_row(2);
+        assert_eq!(app.events(), ["Plants"]);
+    }
+}
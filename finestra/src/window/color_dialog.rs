@@ -0,0 +1,69 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::borrow::Cow;
+
+use crate::Color;
+
+/// Builds and presents a dialog in which the user picks a color. To create
+/// it, use `window.pick_color()`.
+///
+/// ## Example
+/// ```rust,ignore
+/// # use finestra::*;
+/// let window: Window;
+/// let accent: ColorValue;
+///
+/// window.pick_color()
+///         .color(accent.clone_inner())
+///         .show(move |color| accent.set(color));
+/// ```
+pub struct ColorDialogBuilder {
+    inner: Box<dyn ColorDialogApi>,
+}
+
+impl ColorDialogBuilder {
+    pub(crate) fn new(inner: Box<dyn ColorDialogApi>) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+    /// Sets the color that is initially selected.
+    pub fn color(self, color: impl Into<Color>) -> Self {
+        self.inner.set_color(color.into());
+        self
+    }
+
+    /// Sets whether the user can pick the opacity of the color, in which case
+    /// the picked color can be a [`Color::rgba()`]. This is enabled by
+    /// default.
+    pub fn alpha(self, alpha: bool) -> Self {
+        self.inner.set_alpha(alpha);
+        self
+    }
+
+    /// Sets the title of the dialog. This is initially set to the title of the
+    /// window.
+    pub fn title(self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.set_title(title.into());
+        self
+    }
+
+    /// Shows the dialog, which offers the named [`SystemColor`](crate::SystemColor)s
+    /// as swatches where the platform supports it. Does not block: the picked
+    /// color is given to `on_pick`, which isn't invoked when the user cancels.
+    ///
+    /// On macOS, the color panel isn't modal, and `on_pick` is invoked for
+    /// every color the user picks until the panel is closed.
+    pub fn show(self, on_pick: impl Fn(Color) + 'static) {
+        self.inner.show(Box::new(on_pick))
+    }
+}
+
+pub(crate) trait ColorDialogApi {
+    fn set_alpha(&self, alpha: bool);
+    fn set_color(&self, color: Color);
+    fn set_title(&self, title: Cow<'static, str>);
+    fn show(&self, on_pick: Box<dyn Fn(Color)>);
+}
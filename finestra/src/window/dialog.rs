@@ -68,6 +68,7 @@ pub enum DialogKind {
 
 pub(crate) trait DialogApi {
     fn set_kind(&self, kind: DialogKind);
+    #[allow(unused)]
     fn set_text(&self, text: Cow<'static, str>);
     fn set_title(&self, title: Cow<'static, str>);
     fn show(&self);
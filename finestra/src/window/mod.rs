@@ -1,14 +1,20 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+mod color_dialog;
 mod config;
 mod dialog;
+mod view_handle;
 
 use std::borrow::Cow;
 use std::sync::Arc;
 
+pub use self::color_dialog::*;
 pub use self::config::*;
 pub use self::dialog::*;
+pub use self::view_handle::*;
+
+use crate::AppContext;
 
 // Internal: this is a wrapper for invoking methods or making changes to the
 //           window by user code.
@@ -43,10 +49,96 @@ impl Window {
     pub fn create_dialog(&self, text: impl Into<Cow<'static, str>>) -> DialogBuilder {
         self.delegator.create_dialog(text.into())
     }
+
+    /// Creates a new dialog in which the user picks a color. Use the
+    /// [`ColorDialogBuilder`] to set additional properties, before calling
+    /// [`ColorDialogBuilder::show()`], which gives the picked color to the
+    /// closure.
+    ///
+    /// ## Example
+    /// ```rust,no_run,ignore
+    /// # use finestra::*;
+    /// let window: Window;
+    /// let background: ColorValue;
+    ///
+    /// window.pick_color()
+    ///         .color(SystemColor::Teal)
+    ///         .alpha(false)
+    ///         .show(move |color| background.set(color));
+    /// ```
+    #[inline]
+    pub fn pick_color(&self) -> ColorDialogBuilder {
+        self.delegator.pick_color()
+    }
+
+    /// Closes the window. Since Finestra apps have a single window, this will
+    /// also terminate the application.
+    #[inline]
+    pub fn close(&self) {
+        self.delegator.close();
+    }
+
+    /// Get a handle to the view that was given the identifier `id` using
+    /// [`BaseViewExt::with_id()`](crate::BaseViewExt::with_id). Returns
+    /// [`None`] if there is no such view in this window.
+    ///
+    /// ## Example
+    /// ```rust,no_run,ignore
+    /// # use finestra::Window;
+    /// let window: Window;
+    ///
+    /// if let Some(field) = window.view_by_id("name") {
+    ///     field.set_text("");
+    ///     field.focus();
+    /// }
+    /// ```
+    #[must_use]
+    pub fn view_by_id(&self, id: &str) -> Option<ViewHandle> {
+        AppContext::view_by_id(id)
+    }
+
+    /// Open the `url` in the default browser of the user, or using the opener
+    /// that was given to [`App::with_url_opener()`](crate::App::with_url_opener).
+    ///
+    /// ## Example
+    /// ```rust,no_run,ignore
+    /// # use finestra::Window;
+    /// let window: Window;
+    ///
+    /// window.open_url("https://github.com/usadson/finestra");
+    /// ```
+    pub fn open_url(&self, url: &str) {
+        AppContext::open_url(url);
+    }
+
+    /// Scroll the [`ScrollView`](crate::ScrollView) that contains the view
+    /// with the given identifier, such that the view becomes visible. Returns
+    /// `false` if there is no such view in this window.
+    ///
+    /// Scroll views don't scroll on the [`UIBackend::Win32`](crate::UIBackend::Win32)
+    /// yet, so this only finds the view there.
+    ///
+    /// ## Example
+    /// ```rust,no_run,ignore
+    /// # use finestra::Window;
+    /// let window: Window;
+    ///
+    /// window.scroll_to_view("terms-and-conditions");
+    /// ```
+    pub fn scroll_to_view(&self, id: &str) -> bool {
+        let Some(view) = self.view_by_id(id) else {
+            return false;
+        };
+
+        view.scroll_into_view();
+        true
+    }
 }
 
 pub(crate) trait WindowDelegator {
     fn create_dialog(&self, text: Cow<'static, str>) -> DialogBuilder;
+    fn pick_color(&self) -> ColorDialogBuilder;
+    fn close(&self);
 }
 
 unsafe impl Send for Window {}
@@ -0,0 +1,106 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// A handle to a view in the window, which can be used to modify it at runtime.
+/// Use [`Window::view_by_id()`](crate::Window::view_by_id) to get the handle of
+/// a view that was given an identifier using
+/// [`BaseViewExt::with_id()`](crate::BaseViewExt::with_id).
+///
+/// ## Example
+/// ```rust,ignore
+/// # use finestra::Window;
+/// let window: Window;
+///
+/// if let Some(button) = window.view_by_id("save-button") {
+///     button.set_enabled(false);
+/// }
+/// ```
+///
+/// ## Remarks
+/// Changes made using the handle aren't propagated to the
+/// [`State`](crate::State) the view was created with. Prefer using a `State`
+/// for properties that change often.
+#[derive(Clone)]
+pub struct ViewHandle {
+    id: String,
+    inner: Rc<dyn ViewHandleApi>,
+}
+
+impl ViewHandle {
+    pub(crate) fn new(id: String, inner: Rc<dyn ViewHandleApi>) -> Self {
+        Self {
+            id,
+            inner,
+        }
+    }
+
+    /// Get the identifier of the view.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the text of the view, e.g. the title of a [`Button`](crate::Button)
+    /// or the contents of a [`TextField`](crate::TextField). Views without text
+    /// return an empty string.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.inner.text()
+    }
+
+    /// Change the text of the view. This is ignored by views without text.
+    pub fn set_text(&self, text: impl Into<String>) {
+        self.inner.set_text(&text.into());
+    }
+
+    /// Returns `true` if the user can interact with the view.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.inner.is_enabled()
+    }
+
+    /// Enable or disable the view. A disabled view can't be interacted with by
+    /// the user, and is usually grayed out.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.inner.set_enabled(enabled);
+    }
+
+    /// Move the keyboard focus to this view.
+    pub fn focus(&self) {
+        self.inner.focus();
+    }
+
+    /// Scroll the [`ScrollView`](crate::ScrollView) that contains this view,
+    /// such that this view becomes visible. This doesn't do anything if the
+    /// view isn't in a scroll view.
+    pub fn scroll_into_view(&self) {
+        self.inner.scroll_into_view();
+    }
+
+    /// Draw the view again, e.g. to let a [`Canvas`](crate::Canvas) paint
+    /// the data that changed.
+    pub fn invalidate(&self) {
+        self.inner.invalidate();
+    }
+}
+
+impl Debug for ViewHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ViewHandle")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) trait ViewHandleApi {
+    fn text(&self) -> String;
+    fn set_text(&self, text: &str);
+    fn is_enabled(&self) -> bool;
+    fn set_enabled(&self, enabled: bool);
+    fn focus(&self);
+    fn scroll_into_view(&self);
+    fn invalidate(&self);
+}